@@ -0,0 +1,142 @@
+use std::{
+    collections::VecDeque,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use tracing::debug;
+
+use crate::{
+    config::{AcceptMode, InputSettings},
+    input::{self, InputBackend},
+};
+
+/// Held while an action runs, so workers watching different clients take turns with the one
+/// mouse and keyboard.
+static INPUT_LOCK: Mutex<()> = Mutex::new(());
+
+/// A unit of synthetic input. Actions run one at a time so they never interleave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputAction {
+    /// Accept the ready check at logical desktop coordinates.
+    Accept { x: i32, y: i32, mode: AcceptMode },
+}
+
+impl InputAction {
+    /// Actions of the same kind replace each other while queued; only the latest one matters.
+    fn same_kind(&self, other: &InputAction) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+/// FIFO of pending actions with de-duplication and a minimum gap between dispatches.
+#[derive(Debug)]
+pub struct ActionQueue {
+    pending: VecDeque<InputAction>,
+    min_gap: Duration,
+    last_dispatch: Option<Instant>,
+}
+
+impl ActionQueue {
+    pub fn new(min_gap: Duration) -> Self {
+        Self {
+            pending: VecDeque::new(),
+            min_gap,
+            last_dispatch: None,
+        }
+    }
+
+    /// Queues `action`, replacing a pending action of the same kind. Returns `false` when an
+    /// earlier action was replaced.
+    pub fn push(&mut self, action: InputAction) -> bool {
+        if let Some(existing) = self
+            .pending
+            .iter_mut()
+            .find(|queued| queued.same_kind(&action))
+        {
+            *existing = action;
+            return false;
+        }
+        self.pending.push_back(action);
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Time until the next action may be dispatched.
+    pub fn wait_time(&self, now: Instant) -> Duration {
+        self.last_dispatch
+            .map(|last| (last + self.min_gap).saturating_duration_since(now))
+            .unwrap_or_default()
+    }
+
+    /// Removes the next action if the rate limit allows dispatching it at `now`.
+    pub fn pop_ready(&mut self, now: Instant) -> Option<InputAction> {
+        if !self.wait_time(now).is_zero() {
+            return None;
+        }
+        let action = self.pending.pop_front()?;
+        self.last_dispatch = Some(now);
+        Some(action)
+    }
+}
+
+/// Owns the input backend and funnels every action through an [`ActionQueue`].
+pub struct Dispatcher {
+    backend: Box<dyn InputBackend>,
+    queue: ActionQueue,
+}
+
+impl Dispatcher {
+    pub fn new(backend: Box<dyn InputBackend>, min_gap: Duration) -> Self {
+        Self {
+            backend,
+            queue: ActionQueue::new(min_gap),
+        }
+    }
+
+    pub fn backend(&mut self) -> &mut dyn InputBackend {
+        self.backend.as_mut()
+    }
+
+    pub fn submit(&mut self, action: InputAction) {
+        if !self.queue.push(action) {
+            debug!(?action, "replaced pending input action");
+        }
+    }
+
+    /// Runs every queued action, waiting out the rate limit between them. Returns the first
+    /// error; later actions still run.
+    pub fn run_pending(&mut self, settings: &InputSettings) -> Result<()> {
+        let mut first_error = None;
+        while !self.queue.is_empty() {
+            let wait = self.queue.wait_time(Instant::now());
+            if !wait.is_zero() {
+                thread::sleep(wait);
+            }
+            let Some(action) = self.queue.pop_ready(Instant::now()) else {
+                continue;
+            };
+            let _turn = INPUT_LOCK
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let outcome = match action {
+                InputAction::Accept { x, y, mode } => {
+                    input::accept(self.backend.as_mut(), x, y, mode, settings)
+                }
+            };
+            if let Err(err) = outcome {
+                first_error.get_or_insert(err);
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+}
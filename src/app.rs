@@ -1,54 +1,273 @@
 use std::{
-    collections::VecDeque,
-    path::PathBuf,
+    collections::{BTreeSet, HashMap, HashSet, VecDeque},
+    path::{Path, PathBuf},
     sync::{
         Arc,
         atomic::{AtomicBool, Ordering},
     },
-    thread,
     time::{Duration, Instant},
 };
 
 use anyhow::{Context, Result};
-use crossbeam_channel::{Receiver, Sender};
+use chrono::{DateTime, Local, NaiveTime, Timelike, Weekday};
+use crossbeam_channel::{Receiver, TryRecvError};
 use egui::{Align, ComboBox, Layout, RichText};
+use image::GrayImage;
 use tracing::{error, info, warn};
 
 use crate::{
+    autostart,
+    autostop::{AutoStopAction, AutoStopTimer},
+    backup::{self, SettingsBundle},
     capture::{self, CapturedFrame, MonitorInfo},
-    config::{self, AppConfig},
-    detect::{self, Template},
-    input,
+    champ_select::ChampSelectOutcome,
+    cli::Cli,
+    config::{
+        self, AcceptMode, AppConfig, CaptureSource, ChampionPriorities, ClickAnchor, ClickCount,
+        ClientSlot, FlashKey, HonorTarget, IndicatorPalette, InputBackendKind,
+        LEAGUE_CLIENT_WINDOW_TITLE, Language, Profiles, RuneSource, ScheduleWindow, SearchRegion,
+        SoundCue, SpellPreset, SummonerSpellSettings, ThemeMode, ThemeSettings, TradeResponse,
+    },
+    counters::Counters,
+    crash,
+    detect::{self, Detection, Template},
+    elevation,
+    gameflow::{self, QueueStatus, RequeueOutcome},
+    history::{self, History, HistoryOutcome},
+    hotkey::{HotkeyAction, Hotkeys},
+    hotplug::{DisplayChanges, DisplayWatcher},
+    i18n::{self, tr, trf},
+    indicator::{Cooldown, Indicator, IndicatorShape, contrasting},
+    kiosk::{KioskLock, UnlockOutcome},
+    layout::{self, WindowLayout},
+    logpipe::{self, LogFilter, LogLine},
+    metrics::WorkerMetrics,
+    notifications::{self, DiscordWebhook, TelegramBot},
+    overlay::{self, OVERLAY_DURATION},
+    presets::Preset,
+    probe::{self, Probe},
+    process,
+    reload::{self, ConfigWatcher},
+    scales, schedule,
+    scores::ScoreTrace,
+    sound::SoundPlayer,
+    speech::Announcer,
+    spells,
+    stats::{self, SessionStats, StatsTotals},
+    summary::{RunSummary, RunTracker, format_duration},
+    templates::{self, TemplateKind},
+    theme,
+    toast::{ToastKind, Toasts},
+    tray::{self, Tray, TrayCommand},
+    tuning::{self, Phase, Samples},
+    update::{self, AvailableUpdate},
+    window::{self, WindowId},
+    worker::{self, FrameSnapshot, LiveSettings, WorkerEvent, WorkerHandle},
 };
 
 const MAX_LOG_ENTRIES: usize = 500;
+/// How often the monitoring schedule is re-checked while enabled.
+const SCHEDULE_POLL: Duration = Duration::from_secs(15);
+/// Repaint interval while the cooldown bar is counting down.
+const COOLDOWN_REPAINT: Duration = Duration::from_millis(100);
+/// Repaint interval while toasts are up, for their countdown lines.
+const TOAST_REPAINT: Duration = Duration::from_millis(100);
+/// Width of a toast in points.
+const TOAST_WIDTH: f32 = 280.0;
+/// How often the idle auto-stop is re-checked while monitoring.
+const AUTO_STOP_POLL: Duration = Duration::from_secs(5);
+/// Inner size of the full window.
+pub const WINDOW_SIZE: [f32; 2] = [520.0, 720.0];
+/// Inner size of the always-on-top mini mode window.
+const MINI_WINDOW_SIZE: [f32; 2] = [280.0, 110.0];
+/// Accent offered when a custom one is first enabled: the League client's gold.
+const DEFAULT_ACCENT: [u8; 3] = [0xc8, 0xaa, 0x6e];
+/// Time to bring a ready check to the front before the setup wizard takes its screenshot.
+const ONBOARDING_CAPTURE_DELAY: Duration = Duration::from_secs(3);
+/// How often the process list is scanned while following the League client.
+const CLIENT_POLL: Duration = Duration::from_secs(2);
 
 pub struct LolAutoAcceptApp {
     config: AppConfig,
     saved_config: AppConfig,
+    /// The configuration as the file holds it, without the command-line overrides.
+    file_config: AppConfig,
+    /// Command-line overrides, in force for this run and never saved.
+    overrides: Cli,
+    /// Watches the configuration file for edits made outside the app.
+    config_watcher: Option<ConfigWatcher>,
+    /// Configuration edited on disk while there were unsaved edits here, until one side is kept.
+    external_config: Option<AppConfig>,
     monitors: Vec<MonitorInfo>,
     running: bool,
     worker: Option<WorkerHandle>,
     events_rx: Option<Receiver<WorkerEvent>>,
-    log_rx: Receiver<String>,
-    logs: VecDeque<String>,
+    log_rx: Receiver<LogLine>,
+    logs: VecDeque<LogLine>,
+    log_filter: LogFilter,
+    /// Keep the Logs panel where it is instead of following new lines.
+    log_scroll_paused: bool,
+    /// Append the on-disk session log when exporting logs.
+    export_session_log: bool,
     last_detection: Option<DetectionSnapshot>,
     status_line: String,
     exit_requested: bool,
+    /// The "Monitoring is active" exit confirmation is open.
+    confirm_exit: bool,
     template_path_input: String,
     last_config_error: Option<String>,
+    /// Why monitoring could not find its template, while the recovery dialog is open.
+    template_recovery: Option<String>,
+    capture_degraded: bool,
+    /// Polling interval and scale count while the worker's battery saver is active.
+    battery_saver: Option<(u64, Option<usize>)>,
+    /// The worker found the League client running as administrator while this app is not.
+    elevation_mismatch: bool,
+    /// The last start, accept or capture failed; shown as the red state until the next success.
+    state_error: bool,
+    /// End of the post-click cooldown, for the yellow state.
+    cooldown: Option<Cooldown>,
+    /// The monitoring run in progress.
+    run: Option<RunTracker>,
+    /// Card shown after monitoring stops, until closed.
+    run_summary: Option<RunSummary>,
+    toasts: Toasts,
+    /// State last pushed to the tray and taskbar icons.
+    shown_indicator: Option<(Indicator, IndicatorPalette)>,
+    /// Own windows currently kept out of screen capture by streamer mode.
+    capture_excluded: HashSet<WindowId>,
+    metrics: Option<WorkerMetrics>,
+    display_watcher: DisplayWatcher,
+    display_changes: DisplayChanges,
+    hotkeys: Option<Hotkeys>,
+    hotkey_input: String,
+    mini_hotkey_input: String,
+    /// The window is shrunk to the always-on-top mini mode.
+    mini_mode: bool,
+    /// Window geometry and open sections, saved on exit.
+    layout: WindowLayout,
+    /// The keyboard shortcut cheat sheet is open.
+    show_shortcuts: bool,
+    /// Report of a crash to point out, and whether it happened in this session (a worker panic)
+    /// rather than the previous one.
+    crash_report: Option<(PathBuf, bool)>,
+    /// Release check running in the background.
+    update_check: Option<Receiver<Result<Option<AvailableUpdate>>>>,
+    /// The running check was started with *Check now*, so its outcome is always reported.
+    update_check_manual: bool,
+    /// Newer release found by the last check.
+    available_update: Option<AvailableUpdate>,
+    /// The update window with the changelog is open.
+    show_update: bool,
+    /// Download and replacement of the executable running in the background.
+    update_install: Option<Receiver<Result<()>>>,
+    /// An update was installed and takes effect after a restart.
+    update_installed: bool,
+    /// *Test detection now* running in the background.
+    probe: Option<Receiver<Result<Probe>>>,
+    /// Annotated result of the last *Test detection now*, while its window is open.
+    probe_view: Option<ProbeView>,
+    /// The last-frame viewer, while it is open.
+    frame_viewer: Option<FrameViewer>,
+    /// The threshold tuning wizard, while its window is open.
+    tuning: Option<TuningWizard>,
+    /// Workers for `config.extra_clients`, started and stopped with the main one.
+    client_workers: Vec<ClientWorker>,
+    /// Frames, detections, clicks and errors since launch, with the worker's heartbeat.
+    counters: Counters,
+    /// Scale typed next to *Add* in the template scale list.
+    scale_input: f32,
+    /// Start, end and step of *Replace with range*.
+    scale_range: [f32; 3],
+    /// Sizes of the enabled templates for the scale cost estimate, with when they were read.
+    template_sizes: Option<(Instant, Vec<(u32, u32)>)>,
+    /// Scales the running worker searches, to extrapolate its match time.
+    worker_scales: Vec<f32>,
+    hotkey_error: Option<String>,
+    /// Comma-separated pick/ban lists being edited, in `ChampionPriorities::ROLES` order.
+    pick_inputs: Vec<String>,
+    ban_inputs: Vec<String>,
+    /// Comma-separated ARAM bench wishlist being edited.
+    bench_input: String,
+    /// Comma-separated list of champions we are willing to trade away.
+    give_up_input: String,
+    profiles: Profiles,
+    /// Hotkey being edited for the active profile.
+    profile_hotkey_input: String,
+    /// Name typed for *Save as profile*.
+    profile_name_input: String,
+    /// Whether the schedule wanted monitoring on at the last check; `None` while it is off.
+    schedule_active: Option<bool>,
+    /// Counts down to the idle auto-stop while monitoring.
+    auto_stop: AutoStopTimer,
+    /// Whether the app is registered to launch at login; `None` where that is unsupported.
+    launch_at_login: Option<bool>,
+    /// Latest client queue state and when it arrived, for the live timers.
+    queue_status: Option<(QueueStatus, Instant)>,
+    /// Whether ready checks currently come from client events (`true`) or the screen, and
+    /// since when; `None` until the worker reports either.
+    events_live: Option<(bool, Instant)>,
+    /// Whether the League client process was running at the last scan; `None` when unknown.
+    client_running: Option<bool>,
+    client_checked: Option<Instant>,
+    tray: Option<Tray>,
+    /// Plays the *Test* previews in the notification settings.
+    sound: SoundPlayer,
+    /// Speaks the *Test* announcement.
+    speech: Announcer,
+    /// Outcome of a *Test webhook* / *Test Telegram* message still being sent.
+    notification_test: Option<Receiver<(&'static str, anyhow::Result<()>)>>,
+    stats: SessionStats,
+    history: History,
+    /// Best score of each recent frame, for the score graph.
+    scores: ScoreTrace,
+    /// Uploaded history thumbnails by entry id.
+    history_textures: HashMap<u64, egui::TextureHandle>,
+    /// Theme settings and OS preference the current visuals were built from.
+    applied_theme: Option<(ThemeSettings, bool)>,
+    /// Pixels per point last handed to egui for the UI scale.
+    applied_pixels_per_point: Option<f32>,
+    /// The template manager window, while it is open.
+    template_manager: Option<TemplateManager>,
+    /// For waking the GUI from other threads.
+    egui_ctx: egui::Context,
+    /// Hidden to the tray by the app.
+    hidden: bool,
+    /// Minimize on the first frame (`--minimized` or `start_minimized`).
+    launch_minimized: bool,
+    /// On screen (neither hidden nor minimized); read by the worker event relay, which only
+    /// wakes a window that is off screen for events that change state.
+    window_awake: Arc<AtomicBool>,
+    dropped_template: Option<DroppedTemplate>,
+    /// Screenshot the search region is being drawn on, while its editor window is open.
+    region_editor: Option<RegionEditor>,
+    /// First-run setup wizard, shown in place of the settings until finished or skipped.
+    onboarding: Option<Onboarding>,
+    /// Whether kiosk mode currently hides the settings.
+    kiosk: KioskLock,
+    kiosk_pin_input: String,
 }
 
 impl LolAutoAcceptApp {
     pub fn new(
-        _cc: &eframe::CreationContext<'_>,
+        cc: &eframe::CreationContext<'_>,
         config: AppConfig,
-        log_rx: Receiver<String>,
+        overrides: Cli,
+        log_rx: Receiver<LogLine>,
+        first_run: bool,
+        layout: WindowLayout,
     ) -> Self {
+        let display_watcher = DisplayWatcher::start();
         let monitors = capture::enumerate_monitors().unwrap_or_default();
-        let mut config = config;
-        if !monitors.is_empty() && config.monitor_index >= monitors.len() {
-            config.monitor_index = 0;
+        let file_config = config;
+        let mut config = overrides.applied(&file_config).unwrap_or_else(|err| {
+            warn!(error = ?err, "command-line overrides not applied");
+            file_config.clone()
+        });
+        if let Some(index) =
+            capture::remap_monitor_index(&monitors, config.monitor_id, config.monitor_index)
+        {
+            config.monitor_index = index;
         }
         let template_path_input = config
             .template_path
@@ -56,21 +275,163 @@ impl LolAutoAcceptApp {
             .map(|p| p.display().to_string())
             .unwrap_or_default();
 
-        Self {
+        i18n::set_language(config.language);
+        i18n::install_fonts(&cc.egui_ctx);
+
+        let profiles = config::load_profiles().unwrap_or_else(|err| {
+            warn!(error = ?err, "failed to load profiles");
+            Profiles::default()
+        });
+
+        let repaint_ctx = cc.egui_ctx.clone();
+        let mut hotkey_error = None;
+        let hotkeys = match Hotkeys::new(move || repaint_ctx.request_repaint()) {
+            Ok(mut hotkeys) => {
+                for (action, binding) in [
+                    (HotkeyAction::ToggleMonitoring, &config.toggle_hotkey),
+                    (HotkeyAction::ToggleMiniMode, &config.mini_mode_hotkey),
+                ] {
+                    if let Err(err) = hotkeys.bind(action.clone(), binding) {
+                        warn!(?action, error = ?err, "failed to register hotkey");
+                        hotkey_error = Some(format!("{err:#}"));
+                    }
+                }
+                Some(hotkeys)
+            }
+            Err(err) => {
+                warn!(error = ?err, "global hotkeys disabled");
+                hotkey_error = Some(format!("{err:#}"));
+                None
+            }
+        };
+
+        let watch_ctx = cc.egui_ctx.clone();
+        let config_watcher = config::config_path()
+            .and_then(|path| ConfigWatcher::start(&path, move || watch_ctx.request_repaint()))
+            .map_err(|err| warn!(error = ?err, "configuration file changes will not be picked up"))
+            .ok();
+
+        let launch_minimized = (overrides.minimized || config.start_minimized) && !first_run;
+        let mut app = Self {
+            hotkey_input: config.toggle_hotkey.clone(),
+            mini_hotkey_input: config.mini_mode_hotkey.clone(),
+            mini_mode: false,
+            layout,
+            show_shortcuts: false,
+            crash_report: crash::crash_dir()
+                .ok()
+                .and_then(|dir| crash::take_pending(&dir))
+                .map(|path| (path, false)),
+            update_check: None,
+            update_check_manual: false,
+            available_update: None,
+            show_update: false,
+            update_install: None,
+            update_installed: false,
+            probe: None,
+            probe_view: None,
+            frame_viewer: None,
+            tuning: None,
+            client_workers: Vec::new(),
+            counters: Counters::new(),
+            scale_input: 1.0,
+            scale_range: [0.7, 1.3, 0.05],
+            template_sizes: None,
+            worker_scales: Vec::new(),
+            pick_inputs: champion_inputs(&config.auto_pick.champions),
+            ban_inputs: champion_inputs(&config.auto_ban.champions),
+            bench_input: config.aram_bench.champions.join(", "),
+            give_up_input: config.trades.give_up.join(", "),
+            profile_hotkey_input: profiles
+                .active
+                .as_deref()
+                .and_then(|name| profiles.hotkey(name))
+                .unwrap_or_default()
+                .to_string(),
+            profiles,
+            profile_name_input: String::new(),
+            schedule_active: None,
+            auto_stop: AutoStopTimer::new(Instant::now()),
+            launch_at_login: autostart::is_supported().then(|| {
+                autostart::is_enabled().unwrap_or_else(|err| {
+                    warn!(error = ?err, "failed to read launch-at-login state");
+                    false
+                })
+            }),
             saved_config: config.clone(),
             config,
+            file_config,
+            overrides,
+            config_watcher,
+            external_config: None,
             monitors,
             running: false,
             worker: None,
             events_rx: None,
             log_rx,
             logs: VecDeque::new(),
+            log_filter: LogFilter::default(),
+            log_scroll_paused: false,
+            export_session_log: false,
             last_detection: None,
-            status_line: "Idle".to_string(),
+            status_line: tr("Idle").to_string(),
             exit_requested: false,
+            confirm_exit: false,
             template_path_input,
             last_config_error: None,
+            template_recovery: None,
+            capture_degraded: false,
+            battery_saver: None,
+            elevation_mismatch: false,
+            state_error: false,
+            cooldown: None,
+            run: None,
+            run_summary: None,
+            toasts: Toasts::default(),
+            shown_indicator: None,
+            capture_excluded: HashSet::new(),
+            metrics: None,
+            display_changes: display_watcher.subscribe(),
+            display_watcher,
+            hotkeys,
+            hotkey_error,
+            queue_status: None,
+            events_live: None,
+            client_running: None,
+            client_checked: None,
+            tray: Tray::create(&cc.egui_ctx),
+            sound: SoundPlayer::default(),
+            speech: Announcer::default(),
+            notification_test: None,
+            history: History::default(),
+            scores: ScoreTrace::default(),
+            applied_theme: None,
+            applied_pixels_per_point: None,
+            history_textures: HashMap::new(),
+            onboarding: first_run.then(Onboarding::default),
+            region_editor: None,
+            template_manager: None,
+            egui_ctx: cc.egui_ctx.clone(),
+            hidden: false,
+            launch_minimized,
+            window_awake: Arc::new(AtomicBool::new(true)),
+            dropped_template: None,
+            kiosk: KioskLock::new(),
+            kiosk_pin_input: String::new(),
+            stats: SessionStats::new(stats::load_lifetime().unwrap_or_else(|err| {
+                warn!(error = ?err, "failed to load statistics, starting from zero");
+                StatsTotals::default()
+            })),
+        };
+        app.bind_profile_hotkeys();
+        if app.config.check_for_updates {
+            app.check_for_updates(false);
+        }
+        if app.config.auto_start && app.onboarding.is_none() {
+            info!("auto-starting monitoring");
+            app.start_monitoring();
         }
+        app
     }
 
     fn start_monitoring(&mut self) {
@@ -80,48 +441,592 @@ impl LolAutoAcceptApp {
 
         if let Err(err) = self.apply_template_path_from_input() {
             self.last_config_error = Some(err.to_string());
-            self.status_line = "Template path error".to_string();
+            self.state_error = true;
+            self.status_line = tr("Template path error").to_string();
             error!(error = ?err, "failed to parse template path");
+            self.template_recovery = Some(format!("{err:#}"));
             return;
         } else {
             self.last_config_error = None;
         }
 
-        match self.spawn_worker() {
+        let templates =
+            match templates::load_enabled(&self.config).context("Template image lookup failed") {
+                Ok(templates) => templates,
+                Err(err) => {
+                    self.state_error = true;
+                    self.status_line = trf(
+                        "Failed to start: {error}",
+                        &[("error", &format!("{err:#}"))],
+                    );
+                    error!(error = ?err, "template lookup failed");
+                    self.template_recovery = Some(format!("{err:#}"));
+                    return;
+                }
+            };
+        self.template_recovery = None;
+        self.elevation_mismatch = false;
+        match self.spawn_worker(templates) {
             Ok(_) => {
+                self.spawn_client_workers();
+                self.counters.on_start(Instant::now());
                 self.running = true;
-                self.status_line = "Monitoring...".to_string();
+                self.state_error = false;
+                self.cooldown = None;
+                self.stats.set_running(true, Instant::now());
+                self.run = Some(RunTracker::new(
+                    Local::now(),
+                    Instant::now(),
+                    self.config.threshold,
+                ));
+                self.run_summary = None;
+                self.auto_stop = AutoStopTimer::new(Instant::now());
+                self.status_line = tr("Monitoring...").to_string();
                 info!("Monitoring started");
             }
             Err(err) => {
-                self.status_line = format!("Failed to start: {err:#}");
+                self.state_error = true;
+                self.status_line = trf(
+                    "Failed to start: {error}",
+                    &[("error", &format!("{err:#}"))],
+                );
                 error!(error = ?err, "failed to start worker");
             }
         }
     }
 
     fn stop_monitoring(&mut self) {
+        self.stop_client_workers();
         if let Some(mut worker) = self.worker.take() {
             worker.request_stop();
             worker.join();
-            self.status_line = "Stopped".to_string();
+            self.status_line = tr("Stopped").to_string();
             info!("Monitoring stopped");
         }
         self.running = false;
+        self.capture_degraded = false;
+        self.battery_saver = None;
+        self.queue_status = None;
+        self.events_live = None;
+        self.save_stats();
+    }
+
+    /// Stops the uptime clock, writes the lifetime totals and sums up the run that ended.
+    fn save_stats(&mut self) {
+        let now = Instant::now();
+        self.stats.set_running(false, now);
+        if let Some(run) = self.run.take() {
+            self.run_summary = Some(run.finish(now));
+        }
+        if let Err(err) = stats::store_lifetime(&self.stats.lifetime(now)) {
+            warn!(error = ?err, "failed to save statistics");
+        }
+    }
+
+    /// Starts or stops monitoring when the League client launches or exits. Only transitions
+    /// act, so a manual stop sticks while the client keeps running.
+    fn follow_client_process(&mut self, ctx: &egui::Context) {
+        if !self.config.follow_client {
+            self.client_running = None;
+            self.client_checked = None;
+            return;
+        }
+        ctx.request_repaint_after(CLIENT_POLL);
+        if self
+            .client_checked
+            .is_some_and(|checked| checked.elapsed() < CLIENT_POLL)
+        {
+            return;
+        }
+        self.client_checked = Some(Instant::now());
+        let running = process::is_running(process::LEAGUE_CLIENT_PROCESS);
+        if running == self.client_running {
+            return;
+        }
+        match running {
+            Some(true) if !self.running => {
+                info!("League client started, starting monitoring");
+                self.start_monitoring();
+            }
+            Some(false) if self.running && self.client_running == Some(true) => {
+                info!("League client exited, stopping monitoring");
+                self.stop_monitoring();
+            }
+            _ => {}
+        }
+        self.client_running = running;
+    }
+
+    /// Starts monitoring when a schedule window opens and stops it when the window closes.
+    fn follow_schedule(&mut self, ctx: &egui::Context) {
+        let active = schedule::is_active(&self.config.schedule, Local::now().naive_local());
+        if active.is_some() {
+            ctx.request_repaint_after(SCHEDULE_POLL);
+        }
+        if active == self.schedule_active {
+            return;
+        }
+        match active {
+            Some(true) if !self.running => {
+                info!("schedule window opened, starting monitoring");
+                self.start_monitoring();
+            }
+            Some(false) if self.running => {
+                info!("outside the schedule, stopping monitoring");
+                self.stop_monitoring();
+                self.status_line = tr("Stopped: outside the monitoring schedule").to_string();
+            }
+            _ => {}
+        }
+        self.schedule_active = active;
+    }
+
+    /// Warns, then stops monitoring, once nothing has been detected for the configured time.
+    fn follow_auto_stop(&mut self, ctx: &egui::Context) {
+        if !self.running || !self.config.auto_stop.enabled {
+            return;
+        }
+        ctx.request_repaint_after(AUTO_STOP_POLL);
+        match self.auto_stop.poll(&self.config.auto_stop, Instant::now()) {
+            Some(AutoStopAction::Warn { remaining }) => {
+                let minutes = remaining.as_secs().div_ceil(60);
+                warn!(minutes, "nothing detected for a while, auto-stop pending");
+                self.status_line = trf(
+                    "Nothing detected for a while, monitoring stops in {minutes} min",
+                    &[("minutes", &minutes)],
+                );
+                notifications::notify_auto_stop(
+                    &self.config.effective_notifications(),
+                    Some(remaining),
+                );
+            }
+            Some(AutoStopAction::Stop) => {
+                info!("nothing detected for too long, stopping monitoring");
+                self.stop_monitoring();
+                self.status_line = tr("Stopped: nothing detected for a while").to_string();
+                notifications::notify_auto_stop(&self.config.effective_notifications(), None);
+            }
+            None => {}
+        }
+    }
+
+    fn indicator(&self) -> Indicator {
+        Indicator::current(
+            self.running,
+            self.state_error || self.capture_degraded,
+            self.cooldown.map(|cooldown| cooldown.until),
+            Instant::now(),
+        )
+    }
+
+    /// Recolors the tray and taskbar icons when the state changes, and repaints when a cooldown
+    /// runs out so the indicator turns green again.
+    fn follow_indicator(&mut self, ctx: &egui::Context) {
+        let indicator = self.indicator();
+        if indicator == Indicator::Cooldown
+            && let Some(cooldown) = self.cooldown
+        {
+            ctx.request_repaint_after(cooldown.remaining(Instant::now()));
+        }
+        let palette = self.config.theme.indicator_palette;
+        if self.shown_indicator == Some((indicator, palette)) {
+            return;
+        }
+        self.shown_indicator = Some((indicator, palette));
+        if let Some(tray) = &self.tray {
+            tray.set_indicator(indicator, palette);
+        }
+        ctx.send_viewport_cmd(egui::ViewportCommand::Icon(Some(Arc::new(
+            egui::IconData {
+                rgba: tray::icon_rgba(WINDOW_ICON_SIZE, indicator, palette),
+                width: WINDOW_ICON_SIZE,
+                height: WINDOW_ICON_SIZE,
+            },
+        ))));
+    }
+
+    /// Keeps every window of the app, including overlays opened since the last frame, out of
+    /// screen capture while streamer mode wants it, and lets them be captured again otherwise.
+    fn follow_streamer_mode(&mut self) {
+        if self.config.hides_windows() {
+            for window in window::own_windows() {
+                if self.capture_excluded.insert(window)
+                    && let Err(err) = window::set_capture_excluded(window, true)
+                {
+                    warn!(error = ?err, "failed to hide window from screen capture");
+                }
+            }
+        } else {
+            for window in self.capture_excluded.drain() {
+                // Windows closed in the meantime fail harmlessly.
+                let _ = window::set_capture_excluded(window, false);
+            }
+        }
+    }
+
+    fn poll_tray(&mut self, ctx: &egui::Context) {
+        let Some(tray) = &self.tray else {
+            return;
+        };
+        tray.set_running(self.running);
+        while let Some(command) = self.tray.as_ref().and_then(Tray::poll) {
+            match command {
+                TrayCommand::Start => self.start_monitoring(),
+                TrayCommand::Stop => self.stop_monitoring(),
+                TrayCommand::Show => {
+                    self.set_visible(ctx, true);
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+                }
+                TrayCommand::Exit => self.request_exit(ctx),
+            }
+        }
+    }
+
+    /// Applies `--minimized` or `start_minimized` on the first frame: hidden to the tray when it
+    /// is available, a minimized window otherwise.
+    fn minimize_on_launch(&mut self, ctx: &egui::Context) {
+        if !std::mem::take(&mut self.launch_minimized) {
+            return;
+        }
+        info!("starting minimized");
+        if self.tray.is_some() && self.config.minimize_to_tray {
+            self.set_visible(ctx, false);
+        } else {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(true));
+        }
+    }
+
+    fn render_elevation_warning(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal_wrapped(|ui| {
+            ui.label(
+                RichText::new(tr(
+                    "The League client runs as administrator and this app does not: Windows ignores its clicks.",
+                ))
+                .strong()
+                .color(egui::Color32::RED),
+            );
+            if ui
+                .button(tr("Restart as administrator"))
+                .on_hover_text(tr(
+                    "Start this app again with administrator rights, then close this copy",
+                ))
+                .clicked()
+            {
+                match elevation::restart_elevated() {
+                    Ok(()) => {
+                        info!("restarted as administrator, exiting");
+                        self.exit_requested = true;
+                    }
+                    Err(err) => {
+                        warn!(error = ?err, "failed to restart as administrator");
+                        self.status_line = trf(
+                            "Could not restart as administrator: {error}",
+                            &[("error", &format!("{err:#}"))],
+                        );
+                    }
+                }
+            }
+        });
+    }
+
+    /// Shows or hides the window (to the tray), remembering which for the event relay.
+    fn set_visible(&mut self, ctx: &egui::Context, visible: bool) {
+        self.hidden = !visible;
+        ctx.send_viewport_cmd(egui::ViewportCommand::Visible(visible));
+    }
+
+    /// Publishes whether the window is on screen, so routine worker events only repaint it then.
+    fn track_visibility(&mut self, ctx: &egui::Context) {
+        let minimized = ctx.input(|i| i.viewport().minimized == Some(true));
+        self.window_awake
+            .store(!self.hidden && !minimized, Ordering::Relaxed);
+    }
+
+    /// Hides the window instead of closing or minimizing it while a tray icon is available; the
+    /// worker keeps running.
+    fn hide_to_tray(&mut self, ctx: &egui::Context) {
+        if self.tray.is_none() || !self.config.minimize_to_tray || self.exit_requested {
+            return;
+        }
+        let (close, minimized) =
+            ctx.input(|i| (i.viewport().close_requested(), i.viewport().minimized));
+        if close {
+            ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+        }
+        if close || minimized == Some(true) {
+            info!("window hidden to tray");
+            self.set_visible(ctx, false);
+        }
+    }
+
+    /// Exits right away, unless monitoring is running and the user wants to be asked first.
+    fn request_exit(&mut self, ctx: &egui::Context) {
+        if self.running && self.config.confirm_exit {
+            self.confirm_exit = true;
+            self.set_visible(ctx, true);
+            ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+            ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+        } else {
+            self.exit_requested = true;
+        }
+    }
+
+    /// Turns a window close that [`Self::hide_to_tray`] left alone into [`Self::request_exit`].
+    fn confirm_close(&mut self, ctx: &egui::Context) {
+        let hidden_to_tray = self.tray.is_some() && self.config.minimize_to_tray;
+        if hidden_to_tray || self.exit_requested || !ctx.input(|i| i.viewport().close_requested()) {
+            return;
+        }
+        if self.running && self.config.confirm_exit {
+            ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+            self.request_exit(ctx);
+        }
+    }
+
+    fn render_exit_confirmation(&mut self, ctx: &egui::Context) {
+        if !self.confirm_exit {
+            return;
+        }
+        egui::Window::new(tr("Exit?"))
+            .id(egui::Id::new("confirm_exit"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label(tr(
+                    "Monitoring is active. Exiting stops the auto-accepter, even mid-queue.",
+                ));
+                ui.checkbox(
+                    &mut self.config.confirm_exit,
+                    tr("Ask before exiting while monitoring"),
+                );
+                ui.horizontal(|ui| {
+                    if ui.button(tr("Stop and exit")).clicked() {
+                        self.confirm_exit = false;
+                        self.stop_monitoring();
+                        self.exit_requested = true;
+                    }
+                    let minimize = if self.tray.is_some() {
+                        tr("Minimize to tray")
+                    } else {
+                        tr("Minimize")
+                    };
+                    if ui
+                        .button(minimize)
+                        .on_hover_text(tr("Keep monitoring in the background"))
+                        .clicked()
+                    {
+                        self.confirm_exit = false;
+                        if self.tray.is_some() {
+                            self.set_visible(ctx, false);
+                        } else {
+                            ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(true));
+                        }
+                    }
+                    if ui.button(tr("Cancel")).clicked() {
+                        self.confirm_exit = false;
+                    }
+                });
+            });
+    }
+
+    /// Follows the window geometry for [`layout::store`]. Minimized frames are skipped, and so
+    /// is the size while maximized or in mini mode, so the normal full size is what is restored.
+    fn track_layout(&mut self, ctx: &egui::Context) {
+        let (outer, inner, maximized, minimized) = ctx.input(|i| {
+            let viewport = i.viewport();
+            (
+                viewport.outer_rect,
+                viewport.inner_rect,
+                viewport.maximized,
+                viewport.minimized,
+            )
+        });
+        if minimized == Some(true) {
+            return;
+        }
+        if let Some(maximized) = maximized {
+            self.layout.maximized = maximized;
+        }
+        if self.layout.maximized {
+            return;
+        }
+        if let Some(outer) = outer {
+            self.layout.position = Some([outer.min.x, outer.min.y]);
+        }
+        if !self.mini_mode
+            && let Some(inner) = inner
+        {
+            self.layout.size = Some([inner.width(), inner.height()]);
+        }
+    }
+
+    fn toggle_monitoring(&mut self) {
+        if self.running {
+            self.stop_monitoring();
+        } else {
+            self.start_monitoring();
+        }
+    }
+
+    /// Shrinks the window to an always-on-top status strip, or restores it.
+    fn set_mini_mode(&mut self, ctx: &egui::Context, mini: bool) {
+        if self.mini_mode == mini {
+            return;
+        }
+        self.mini_mode = mini;
+        info!(mini, "mini mode toggled");
+        let (size, level) = if mini {
+            (MINI_WINDOW_SIZE, egui::WindowLevel::AlwaysOnTop)
+        } else {
+            (
+                self.layout.size.unwrap_or(WINDOW_SIZE),
+                egui::WindowLevel::Normal,
+            )
+        };
+        ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(level));
+        ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(size.into()));
+    }
+
+    fn poll_hotkeys(&mut self, ctx: &egui::Context) {
+        let actions = match &self.hotkeys {
+            Some(hotkeys) => hotkeys.poll(),
+            None => return,
+        };
+        for action in actions {
+            match action {
+                HotkeyAction::ToggleMonitoring => {
+                    info!("toggle hotkey pressed");
+                    self.toggle_monitoring();
+                }
+                HotkeyAction::ToggleMiniMode => {
+                    self.set_mini_mode(ctx, !self.mini_mode);
+                    // Bring the window back if it was hidden to the tray.
+                    self.set_visible(ctx, true);
+                }
+                HotkeyAction::SwitchProfile(_) if self.settings_locked() => {
+                    info!("profile hotkey ignored, settings are locked");
+                }
+                HotkeyAction::SwitchProfile(name) => {
+                    info!(profile = %name, "profile hotkey pressed");
+                    self.switch_profile_by_hotkey(&name);
+                }
+            }
+        }
+    }
+
+    /// Registers the hotkeys of all profiles, replacing any bound before.
+    fn bind_profile_hotkeys(&mut self) {
+        let Some(hotkeys) = &mut self.hotkeys else {
+            return;
+        };
+        hotkeys.retain(|action| !matches!(action, HotkeyAction::SwitchProfile(_)));
+        for (name, binding) in &self.profiles.hotkeys {
+            let action = HotkeyAction::SwitchProfile(name.clone());
+            if let Err(err) = hotkeys.bind(action.clone(), binding) {
+                warn!(?action, error = ?err, "failed to register hotkey");
+                self.hotkey_error = Some(format!("{err:#}"));
+            }
+        }
+    }
+
+    /// Switches to profile `name` without the window, restarting monitoring if it was running
+    /// so the worker picks up the new settings.
+    fn switch_profile_by_hotkey(&mut self, name: &str) {
+        if self.profiles.active.as_deref() == Some(name) {
+            self.status_line = trf("Already on profile {name}", &[("name", &name)]);
+            return;
+        }
+        let was_running = self.running;
+        if was_running {
+            self.stop_monitoring();
+        }
+        self.switch_profile(name);
+        if was_running {
+            self.start_monitoring();
+            if self.running {
+                self.status_line = trf("Switched to profile {name}", &[("name", &name)]);
+            }
+        }
+    }
+
+    fn apply_hotkey_input(&mut self, action: HotkeyAction) {
+        let binding = self.hotkey_input_mut(&action).trim().to_string();
+        let current = self.hotkey_binding(&action);
+        let Some(hotkeys) = &mut self.hotkeys else {
+            return;
+        };
+        match hotkeys.bind(action.clone(), &binding) {
+            Ok(()) => {
+                self.hotkey_error = None;
+                self.store_hotkey_binding(&action, binding);
+            }
+            Err(err) => {
+                self.hotkey_error = Some(format!("{err:#}"));
+                // Keep the previous binding active.
+                let _ = hotkeys.bind(action, &current);
+            }
+        }
+    }
+
+    /// The text field editing `action`'s binding.
+    fn hotkey_input_mut(&mut self, action: &HotkeyAction) -> &mut String {
+        match action {
+            HotkeyAction::ToggleMonitoring => &mut self.hotkey_input,
+            HotkeyAction::ToggleMiniMode => &mut self.mini_hotkey_input,
+            HotkeyAction::SwitchProfile(_) => &mut self.profile_hotkey_input,
+        }
+    }
+
+    /// `action`'s saved binding; empty when unbound.
+    fn hotkey_binding(&self, action: &HotkeyAction) -> String {
+        match action {
+            HotkeyAction::ToggleMonitoring => self.config.toggle_hotkey.clone(),
+            HotkeyAction::ToggleMiniMode => self.config.mini_mode_hotkey.clone(),
+            HotkeyAction::SwitchProfile(name) => {
+                self.profiles.hotkey(name).unwrap_or_default().to_string()
+            }
+        }
+    }
+
+    /// Records a binding that was just registered: in the config, or for profile hotkeys in the
+    /// profiles file right away.
+    fn store_hotkey_binding(&mut self, action: &HotkeyAction, binding: String) {
+        match action {
+            HotkeyAction::ToggleMonitoring => self.config.toggle_hotkey = binding,
+            HotkeyAction::ToggleMiniMode => self.config.mini_mode_hotkey = binding,
+            HotkeyAction::SwitchProfile(name) => {
+                self.profiles.set_hotkey(name, &binding);
+                self.store_profiles();
+            }
+        }
     }
 
     fn refresh_monitors(&mut self) {
+        let selected_id = self.config.monitor_id.or_else(|| {
+            self.monitors
+                .get(self.config.monitor_index)
+                .map(|monitor| monitor.id)
+        });
         match capture::enumerate_monitors() {
             Ok(list) => {
                 self.monitors = list;
-                if !self.monitors.is_empty() && self.config.monitor_index >= self.monitors.len() {
-                    self.config.monitor_index = 0;
+                if let Some(index) = capture::remap_monitor_index(
+                    &self.monitors,
+                    selected_id,
+                    self.config.monitor_index,
+                ) {
+                    self.config.monitor_index = index;
                 }
                 info!("Monitor list refreshed");
             }
             Err(err) => {
                 error!(error = ?err, "failed to refresh monitor list");
-                self.status_line = format!("Monitor refresh failed: {err:#}");
+                self.status_line = trf(
+                    "Monitor refresh failed: {error}",
+                    &[("error", &format!("{err:#}"))],
+                );
             }
         }
     }
@@ -151,29 +1056,132 @@ impl LolAutoAcceptApp {
                 self.handle_event(event);
             }
         }
+        let mut client_events = Vec::new();
+        for (index, client) in self.client_workers.iter().enumerate() {
+            client_events.extend(client.events.try_iter().map(|event| (index, event)));
+        }
+        updated |= !client_events.is_empty();
+        for (index, event) in client_events {
+            self.handle_client_event(index, event);
+        }
         if updated {
             ctx.request_repaint();
         }
     }
 
-    fn check_worker_lifecycle(&mut self) {
-        if let Some(worker) = self.worker.as_mut() {
-            if worker.is_finished() {
-                worker.join();
-                self.worker = None;
-                self.running = false;
-                self.status_line = "Worker exited".to_string();
+    fn poll_notification_test(&mut self, ctx: &egui::Context) {
+        let Some(rx) = &self.notification_test else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok((target, outcome)) => {
+                self.status_line = match outcome {
+                    Ok(()) => trf("Test notification sent to {target}", &[("target", &target)]),
+                    Err(err) => trf(
+                        "Test notification to {target} failed: {error}",
+                        &[("target", &target), ("error", &format!("{err:#}"))],
+                    ),
+                };
+                self.notification_test = None;
             }
+            Err(TryRecvError::Empty) => ctx.request_repaint_after(Duration::from_millis(200)),
+            Err(TryRecvError::Disconnected) => self.notification_test = None,
+        }
+    }
+
+    /// Rebuilds the visuals when the theme settings or the OS light/dark preference change.
+    fn apply_theme(&mut self, ctx: &egui::Context, frame: &eframe::Frame) {
+        let system_dark = frame.info().system_theme != Some(eframe::Theme::Light);
+        let current = (self.config.theme.clone(), system_dark);
+        if self.applied_theme.as_ref() != Some(&current) {
+            ctx.set_visuals(theme::visuals(&current.0, system_dark));
+            self.applied_theme = Some(current);
+        }
+        // Rescaling mid-drag would move the slider out from under the pointer.
+        if ctx.is_using_pointer() {
+            return;
+        }
+        let native = ctx.native_pixels_per_point().unwrap_or(1.0);
+        let pixels_per_point = theme::pixels_per_point(native, self.config.ui_scale);
+        if self.applied_pixels_per_point != Some(pixels_per_point) {
+            ctx.set_pixels_per_point(pixels_per_point);
+            self.applied_pixels_per_point = Some(pixels_per_point);
+        }
+    }
+
+    fn check_worker_lifecycle(&mut self) {
+        if let Some(worker) = self.worker.as_mut()
+            && worker.is_finished()
+        {
+            worker.join();
+            self.worker = None;
+            self.running = false;
+            self.stop_client_workers();
+            self.status_line = tr("Worker exited").to_string();
+            self.save_stats();
         }
     }
 
-    fn push_log(&mut self, line: String) {
+    fn push_log(&mut self, line: LogLine) {
         if self.logs.len() >= MAX_LOG_ENTRIES {
             self.logs.pop_front();
         }
         self.logs.push_back(line);
     }
 
+    /// Pops up `text` in the corner of the window, unless toasts are turned off.
+    fn toast(&mut self, kind: ToastKind, text: String) {
+        if self.config.toasts {
+            self.toasts.push(kind, text, Instant::now());
+        }
+    }
+
+    fn render_toasts(&mut self, ctx: &egui::Context) {
+        let now = Instant::now();
+        self.toasts.prune(now);
+        if self.toasts.is_empty() {
+            return;
+        }
+        let mut dismissed = None;
+        egui::Area::new(egui::Id::new("toasts"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, [-12.0, -12.0])
+            .order(egui::Order::Foreground)
+            .interactable(true)
+            .show(ctx, |ui| {
+                ui.set_width(TOAST_WIDTH);
+                for (index, toast) in self.toasts.iter().enumerate() {
+                    let [r, g, b] = toast.kind.color();
+                    let color = egui::Color32::from_rgb(r, g, b);
+                    let response = egui::Frame::popup(ui.style())
+                        .stroke(egui::Stroke::new(1.0, color))
+                        .show(ui, |ui| {
+                            ui.set_width(TOAST_WIDTH);
+                            let text = if toast.repeats > 0 {
+                                format!("{} (×{})", toast.text, toast.repeats + 1)
+                            } else {
+                                toast.text.clone()
+                            };
+                            ui.label(RichText::new(text).color(color));
+                            ui.add(
+                                egui::ProgressBar::new(toast.remaining(now))
+                                    .desired_height(2.0)
+                                    .fill(color),
+                            );
+                        })
+                        .response
+                        .interact(egui::Sense::click())
+                        .on_hover_text(tr("Click to dismiss"));
+                    if response.clicked() {
+                        dismissed = Some(index);
+                    }
+                }
+            });
+        if let Some(index) = dismissed {
+            self.toasts.dismiss(index);
+        }
+        ctx.request_repaint_after(TOAST_REPAINT);
+    }
+
     fn handle_event(&mut self, event: WorkerEvent) {
         match event {
             WorkerEvent::Detection {
@@ -181,87 +1189,1046 @@ impl LolAutoAcceptApp {
                 image_coords,
                 screen_coords,
                 template_size,
+                screen_rect,
                 scale,
+                thumbnail,
             } => {
+                self.counters.on_detection();
+                self.stats.on_detection(score, Instant::now());
+                if let Some(run) = &mut self.run {
+                    run.on_detection();
+                }
+                self.auto_stop.on_activity(Instant::now());
+                self.history
+                    .record_detection(Local::now(), score, scale, screen_coords, thumbnail);
                 self.last_detection = Some(DetectionSnapshot {
                     timestamp: Instant::now(),
                     score,
                     image_coords,
                     screen_coords,
                     template_size,
+                    screen_rect,
                     scale,
                 });
-                self.status_line = format!(
-                    "Detected @ ({}, {}) score {:.3} scale {:.2}",
-                    screen_coords.0, screen_coords.1, score, scale
+                self.toast(
+                    ToastKind::Info,
+                    trf(
+                        "Ready check detected (score {score})",
+                        &[("score", &format!("{score:.3}"))],
+                    ),
+                );
+                self.status_line = trf(
+                    "Detected @ ({x}, {y}) score {score} scale {scale}",
+                    &[
+                        ("x", &screen_coords.0),
+                        ("y", &screen_coords.1),
+                        ("score", &format!("{score:.3}")),
+                        ("scale", &format!("{scale:.2}")),
+                    ],
                 );
             }
             WorkerEvent::Clicked { screen_coords } => {
-                self.status_line = format!("Clicked at ({}, {})", screen_coords.0, screen_coords.1);
+                self.counters.on_click();
+                if let Some(run) = &mut self.run {
+                    run.on_click();
+                }
+                self.stats.on_accepted(Instant::now());
+                self.state_error = false;
+                self.cooldown = Some(Cooldown::new(
+                    Instant::now(),
+                    Duration::from_millis(self.config.cooldown_ms),
+                ));
+                self.history
+                    .resolve(HistoryOutcome::Clicked, Some(screen_coords));
+                self.status_line = trf(
+                    "Clicked at ({x}, {y})",
+                    &[("x", &screen_coords.0), ("y", &screen_coords.1)],
+                );
+                self.toast(ToastKind::Success, self.status_line.clone());
+            }
+            WorkerEvent::ReadyCheckAccepted => {
+                self.counters.on_click();
+                self.state_error = false;
+                self.stats.on_accepted(Instant::now());
+                self.status_line = tr("Ready check accepted through the client API").to_string();
+                self.toast(ToastKind::Success, self.status_line.clone());
+            }
+            WorkerEvent::ChampSelect(outcome) => {
+                self.status_line = match outcome {
+                    ChampSelectOutcome::Hovered { champion } => {
+                        trf("Hovering {champion}", &[("champion", &champion)])
+                    }
+                    ChampSelectOutcome::Locked { champion } => {
+                        trf("Locked in {champion}", &[("champion", &champion)])
+                    }
+                    ChampSelectOutcome::Banned { champion } => {
+                        trf("Banned {champion}", &[("champion", &champion)])
+                    }
+                    ChampSelectOutcome::RunesApplied { champion } => {
+                        trf("Rune page set for {champion}", &[("champion", &champion)])
+                    }
+                    ChampSelectOutcome::RunesFailed { reason } => {
+                        trf("Rune import failed: {reason}", &[("reason", &reason)])
+                    }
+                    ChampSelectOutcome::SpellsSet { spells } => {
+                        trf("Summoner spells: {spells}", &[("spells", &spells)])
+                    }
+                    ChampSelectOutcome::SpellsFailed { reason } => trf(
+                        "Setting summoner spells failed: {reason}",
+                        &[("reason", &reason)],
+                    ),
+                    ChampSelectOutcome::NoneAvailable => {
+                        tr("No champion from the pick list is available").to_string()
+                    }
+                    ChampSelectOutcome::TradeAnswered { champion, accepted } => {
+                        if accepted {
+                            trf("Traded away {champion}", &[("champion", &champion)])
+                        } else {
+                            tr("Declined a champion trade").to_string()
+                        }
+                    }
+                    ChampSelectOutcome::PickOrderSwapAnswered { accepted } => tr(if accepted {
+                        "Accepted a pick order swap"
+                    } else {
+                        "Declined a pick order swap"
+                    })
+                    .to_string(),
+                    ChampSelectOutcome::BenchSwapped { champion } => trf(
+                        "Swapped to {champion} from the bench",
+                        &[("champion", &champion)],
+                    ),
+                    ChampSelectOutcome::NothingToBan => {
+                        tr("Ban skipped: nothing from the ban list is left").to_string()
+                    }
+                };
+            }
+            WorkerEvent::CaptureGated { paused, phase } => {
+                self.status_line = if paused {
+                    trf(
+                        "Capture paused: {phase}",
+                        &[("phase", &tr(gameflow::phase_label(&phase)))],
+                    )
+                } else if phase.is_empty() {
+                    tr("Client phase unknown, watching the screen").to_string()
+                } else {
+                    trf(
+                        "{phase}, watching the screen",
+                        &[("phase", &tr(gameflow::phase_label(&phase)))],
+                    )
+                };
+            }
+            WorkerEvent::Honored { player } => {
+                self.status_line = trf("Honored {player}", &[("player", &player)]);
+            }
+            WorkerEvent::Requeue(outcome) => {
+                self.status_line = match outcome {
+                    RequeueOutcome::Scheduled { attempt, max } => trf(
+                        "Champ select dodged, re-queueing ({attempt}/{max})",
+                        &[("attempt", &attempt), ("max", &max)],
+                    ),
+                    RequeueOutcome::LimitReached { max } => trf(
+                        "Dodged again: re-queued {max} times already, queue manually",
+                        &[("max", &max)],
+                    ),
+                    RequeueOutcome::Started { attempt } => trf(
+                        "Searching again (re-queue {attempt})",
+                        &[("attempt", &attempt)],
+                    ),
+                };
+            }
+            WorkerEvent::QueueStatus(status) => {
+                self.queue_status = Some((status, Instant::now()));
+            }
+            WorkerEvent::LcuEventsLive(live) => {
+                self.events_live = Some((live, Instant::now()));
+                if !live {
+                    self.queue_status = None;
+                }
+                self.status_line = if live {
+                    tr("Listening to League client events").to_string()
+                } else {
+                    tr("League client events unavailable, watching the screen").to_string()
+                };
+            }
+            WorkerEvent::ClickVerified { attempts } => {
+                self.status_line = if attempts > 1 {
+                    trf(
+                        "Accept confirmed after {attempts} clicks",
+                        &[("attempts", &attempts)],
+                    )
+                } else {
+                    tr("Accept confirmed").to_string()
+                };
+            }
+            WorkerEvent::ClickUnconfirmed { attempts } => {
+                self.status_line = trf(
+                    "Accept not confirmed after {attempts} clicks - check the client",
+                    &[("attempts", &attempts)],
+                );
+            }
+            WorkerEvent::ClickAborted { reason } => {
+                self.stats.on_detection_dropped();
+                self.history.resolve(HistoryOutcome::Aborted, None);
+                self.status_line = trf(
+                    "Click aborted ({reason}), waiting for next match",
+                    &[("reason", &reason)],
+                );
+            }
+            WorkerEvent::AcceptAttempt { mode, attempt } => {
+                self.stats.on_accept_attempt(attempt);
+                if attempt > 1 {
+                    self.status_line = trf(
+                        "Accept attempt {attempt} via {mode}",
+                        &[("attempt", &attempt), ("mode", &accept_mode_label(mode))],
+                    );
+                }
+            }
+            WorkerEvent::WouldClick { screen_coords } => {
+                self.stats.on_detection_dropped();
+                self.history
+                    .resolve(HistoryOutcome::WouldClick, Some(screen_coords));
+                self.status_line = trf(
+                    "Dry run: would click at ({x}, {y})",
+                    &[("x", &screen_coords.0), ("y", &screen_coords.1)],
+                );
             }
             WorkerEvent::CooldownActive {
                 remaining_ms,
                 score,
             } => {
-                self.status_line = format!(
-                    "Cooldown active ({remaining_ms} ms remaining), last score {:.3}",
-                    score
+                self.cooldown = Some(Cooldown::sync(
+                    self.cooldown,
+                    Instant::now(),
+                    Duration::from_millis(remaining_ms),
+                ));
+                self.status_line = trf(
+                    "Cooldown active, last score {score}",
+                    &[("score", &format!("{score:.3}"))],
                 );
             }
-            WorkerEvent::Error(message) => {
-                self.status_line = format!("Worker error: {message}");
-                warn!("Worker error: {message}");
+            WorkerEvent::UserActive {
+                idle_ms,
+                required_ms,
+            } => {
+                self.status_line = trf(
+                    "Ready check seen, holding off: input {idle}s ago (needs {required}s idle)",
+                    &[
+                        ("idle", &format!("{:.1}", idle_ms as f32 / 1000.0)),
+                        ("required", &format!("{:.0}", required_ms as f32 / 1000.0)),
+                    ],
+                );
             }
-            WorkerEvent::Info(message) => {
-                self.status_line = message;
+            WorkerEvent::CaptureDegraded {
+                monitor_index,
+                failures,
+                retry_in_ms,
+            } => {
+                self.capture_degraded = true;
+                self.status_line = trf(
+                    "Capture degraded: {failures} failures, retrying monitor #{monitor_index} in {retry_in_ms} ms",
+                    &[
+                        ("failures", &failures),
+                        ("monitor_index", &monitor_index),
+                        ("retry_in_ms", &retry_in_ms),
+                    ],
+                );
             }
-            WorkerEvent::Stopped => {
-                self.running = false;
-                self.status_line = "Worker stopped".to_string();
+            WorkerEvent::CaptureRecovered { monitor_index } => {
+                self.capture_degraded = false;
+                self.state_error = false;
+                self.status_line = trf(
+                    "Capture recovered on monitor #{monitor_index}",
+                    &[("monitor_index", &monitor_index)],
+                );
+                info!(monitor = monitor_index, "capture recovered");
             }
-        }
-    }
-
-    fn spawn_worker(&mut self) -> Result<()> {
-        let config = self.config.clone();
-        let template_path = config
-            .resolve_template_path()
-            .context("Template image lookup failed")?;
-        let template = detect::load_template(&template_path)?;
-        let (tx, rx) = crossbeam_channel::unbounded();
-        let stop_flag = Arc::new(AtomicBool::new(false));
-        let worker_stop = stop_flag.clone();
-
-        let handle = thread::Builder::new()
-            .name("lol-auto-accept-worker".to_string())
-            .spawn(move || run_worker(config, template, tx, worker_stop))
-            .context("Failed to spawn worker thread")?;
-
-        self.worker = Some(WorkerHandle {
-            stop_flag,
-            thread: Some(handle),
-        });
-        self.events_rx = Some(rx);
-        Ok(())
+            WorkerEvent::MonitorsChanged {
+                monitor_index,
+                monitor_count,
+            } => {
+                self.status_line = trf(
+                    "Displays changed ({monitor_count} connected), capturing monitor #{monitor_index}",
+                    &[
+                        ("monitor_count", &monitor_count),
+                        ("monitor_index", &monitor_index),
+                    ],
+                );
+            }
+            WorkerEvent::FrameScored(score) => {
+                if let Some(run) = &mut self.run {
+                    run.on_score(score);
+                }
+                self.scores.push(Instant::now(), score);
+            }
+            WorkerEvent::PossibleMiss(miss) => {
+                self.status_line = trf(
+                    "Possible missed queue pop: {frames} frames scored up to {score}, just under the threshold {threshold}",
+                    &[
+                        ("frames", &miss.frames),
+                        ("score", &format!("{:.3}", miss.score)),
+                        ("threshold", &format!("{:.2}", miss.threshold)),
+                    ],
+                );
+                self.toast(ToastKind::Warning, self.status_line.clone());
+            }
+            WorkerEvent::PowerChanged {
+                on_battery,
+                interval_ms,
+                scales,
+            } => {
+                self.battery_saver = on_battery.then_some((interval_ms, scales));
+                self.status_line = if on_battery {
+                    tr("On battery: monitoring slowed down to save power").to_string()
+                } else {
+                    tr("Plugged in: monitoring back to full speed").to_string()
+                };
+                self.toast(ToastKind::Info, self.status_line.clone());
+            }
+            WorkerEvent::Frame(snapshot) => {
+                if let Some(viewer) = &mut self.frame_viewer {
+                    viewer.show(&self.egui_ctx, snapshot);
+                }
+            }
+            WorkerEvent::Metrics(metrics) => {
+                if let Some(run) = &mut self.run {
+                    run.on_metrics(&metrics);
+                }
+                self.stats.on_frames(metrics.frames);
+                self.counters.on_metrics(metrics.frames, Instant::now());
+                self.metrics = Some(metrics);
+            }
+            WorkerEvent::ElevationMismatch => {
+                self.elevation_mismatch = true;
+                self.status_line =
+                    tr("The League client runs as administrator; clicks will be ignored")
+                        .to_string();
+                self.toast(ToastKind::Error, self.status_line.clone());
+            }
+            WorkerEvent::Crashed(message) => {
+                error!("worker crashed: {message}");
+                self.counters.on_error();
+                self.running = false;
+                self.stop_client_workers();
+                self.state_error = true;
+                self.save_stats();
+                self.status_line = trf("Worker crashed: {message}", &[("message", &message)]);
+                self.toast(ToastKind::Error, self.status_line.clone());
+                self.crash_report = crash::crash_dir()
+                    .ok()
+                    .and_then(|dir| crash::take_pending(&dir))
+                    .map(|path| (path, true));
+            }
+            WorkerEvent::Error(message) => {
+                self.counters.on_error();
+                self.state_error = true;
+                self.status_line = trf("Worker error: {message}", &[("message", &message)]);
+                self.toast(ToastKind::Error, self.status_line.clone());
+                warn!("Worker error: {message}");
+            }
+            WorkerEvent::Info(message) => {
+                self.status_line = message;
+            }
+            WorkerEvent::Stopped => {
+                self.running = false;
+                self.stop_client_workers();
+                self.save_stats();
+                self.capture_degraded = false;
+                self.battery_saver = None;
+                self.status_line = tr("Worker stopped").to_string();
+            }
+        }
+    }
+
+    fn spawn_worker(&mut self, templates: Vec<Template>) -> Result<()> {
+        let config = self.config.clone();
+        let backend = capture::open_backend(&config.effective_capture_source())?;
+        self.worker_scales = scales::effective(&config.template_scales);
+        let (handle, rx) = worker::spawn(
+            config,
+            templates,
+            backend,
+            Some(self.display_watcher.subscribe()),
+        )?;
+        let ctx = self.egui_ctx.clone();
+        let awake = self.window_awake.clone();
+        let rx = worker::relay(rx, move |routine| {
+            if !routine || awake.load(Ordering::Relaxed) {
+                ctx.request_repaint();
+            }
+        })?;
+
+        self.worker = Some(handle);
+        self.events_rx = Some(rx);
+        Ok(())
+    }
+
+    /// Starts a worker for every enabled extra client; one that fails to start is reported and
+    /// left out while the others run.
+    fn spawn_client_workers(&mut self) {
+        let slots: Vec<ClientSlot> = self.config.extra_clients.clone();
+        for (index, slot) in slots.iter().enumerate().filter(|(_, slot)| slot.enabled) {
+            let name = client_name(index, slot);
+            match self.spawn_client_worker(slot) {
+                Ok((handle, events)) => {
+                    info!(client = %name, monitor = slot.monitor_index, "client worker started");
+                    self.client_workers.push(ClientWorker {
+                        name,
+                        slot: slot.clone(),
+                        handle: Some(handle),
+                        events,
+                        status: tr("Monitoring...").to_string(),
+                        error: false,
+                    });
+                }
+                Err(err) => {
+                    error!(client = %name, error = ?err, "failed to start client worker");
+                    self.toast(
+                        ToastKind::Error,
+                        trf(
+                            "{client}: failed to start: {error}",
+                            &[("client", &name), ("error", &format!("{err:#}"))],
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    fn spawn_client_worker(
+        &mut self,
+        slot: &ClientSlot,
+    ) -> Result<(WorkerHandle, Receiver<WorkerEvent>)> {
+        let config = self.config.for_client(slot);
+        let templates = templates::load_enabled(&config).context("Template image lookup failed")?;
+        let backend = capture::open_backend(&config.effective_capture_source())?;
+        let (handle, rx) = worker::spawn(
+            config,
+            templates,
+            backend,
+            Some(self.display_watcher.subscribe()),
+        )?;
+        let ctx = self.egui_ctx.clone();
+        let awake = self.window_awake.clone();
+        let rx = worker::relay(rx, move |routine| {
+            if !routine || awake.load(Ordering::Relaxed) {
+                ctx.request_repaint();
+            }
+        })?;
+        Ok((handle, rx))
+    }
+
+    fn stop_client_workers(&mut self) {
+        for mut client in self.client_workers.drain(..) {
+            if let Some(mut handle) = client.handle.take() {
+                handle.request_stop();
+                handle.join();
+                info!(client = %client.name, "client worker stopped");
+            }
+        }
+    }
+
+    /// Keeps the worker of one extra client apart from the main one: its own status line, with
+    /// accepts and errors also shown as pop-ups.
+    fn handle_client_event(&mut self, index: usize, event: WorkerEvent) {
+        let Some(client) = self.client_workers.get_mut(index) else {
+            return;
+        };
+        let name = client.name.clone();
+        let toast = match event {
+            WorkerEvent::Detection { score, .. } => {
+                self.counters.on_detection();
+                client.status = trf(
+                    "Ready check detected (score {score})",
+                    &[("score", &format!("{score:.3}"))],
+                );
+                self.auto_stop.on_activity(Instant::now());
+                None
+            }
+            WorkerEvent::Clicked { screen_coords } => {
+                self.counters.on_click();
+                client.error = false;
+                client.status = trf(
+                    "Clicked at ({x}, {y})",
+                    &[("x", &screen_coords.0), ("y", &screen_coords.1)],
+                );
+                Some(ToastKind::Success)
+            }
+            WorkerEvent::Error(message) => {
+                self.counters.on_error();
+                warn!(client = %name, "Worker error: {message}");
+                client.error = true;
+                client.status = trf("Worker error: {message}", &[("message", &message)]);
+                Some(ToastKind::Error)
+            }
+            WorkerEvent::Crashed(message) => {
+                self.counters.on_error();
+                error!(client = %name, "worker crashed: {message}");
+                client.error = true;
+                client.handle = None;
+                client.status = trf("Worker crashed: {message}", &[("message", &message)]);
+                Some(ToastKind::Error)
+            }
+            WorkerEvent::Stopped => {
+                client.status = tr("Worker stopped").to_string();
+                None
+            }
+            WorkerEvent::Metrics(metrics) => {
+                // Only the main worker's reports count as the heartbeat.
+                self.counters.frames += u64::from(metrics.frames);
+                None
+            }
+            WorkerEvent::Info(message) => {
+                client.status = message;
+                None
+            }
+            _ => None,
+        };
+        if let Some(kind) = toast {
+            let text = format!("{name}: {}", self.client_workers[index].status);
+            self.toast(kind, text);
+        }
     }
 
-    fn save_configuration(&mut self) {
+    /// Writes the config file (and the active profile); `false` if that failed.
+    fn save_configuration(&mut self) -> bool {
         if let Err(err) = self.apply_template_path_from_input() {
             self.last_config_error = Some(err.to_string());
-            self.status_line = "Template path error".to_string();
-            return;
+            self.status_line = tr("Template path error").to_string();
+            return false;
         }
 
-        match config::store(&self.config) {
-            Ok(_) => {
+        let stored = self
+            .persisted_config()
+            .and_then(|persisted| config::store(&persisted).map(|()| persisted));
+        match stored {
+            Ok(persisted) => {
+                self.file_config = persisted;
                 self.saved_config = self.config.clone();
-                self.status_line = "Configuration saved".to_string();
+                // The file now holds our edits; an edit made on disk meanwhile is overwritten.
+                self.external_config = None;
+                self.status_line = tr("Configuration saved").to_string();
+                self.toast(ToastKind::Success, self.status_line.clone());
                 self.last_config_error = None;
                 info!("Configuration saved");
+                if self.profiles.active.is_some() {
+                    self.profiles.update_active(&self.file_config);
+                    self.store_profiles();
+                }
+                true
             }
             Err(err) => {
-                self.status_line = format!("Failed to save config: {err:#}");
+                self.status_line = trf(
+                    "Failed to save config: {error}",
+                    &[("error", &format!("{err:#}"))],
+                );
+                self.toast(ToastKind::Error, self.status_line.clone());
                 error!(error = ?err, "failed to save configuration");
+                false
+            }
+        }
+    }
+
+    /// The edited configuration as it goes into files: without this run's command-line
+    /// overrides, unless the user changed those settings since.
+    fn persisted_config(&self) -> Result<AppConfig> {
+        self.overrides
+            .without_overrides(&self.config, &self.file_config)
+    }
+
+    /// Loads `config`, as a file holds it, with this run's command-line overrides applied.
+    fn load_file_config(&mut self, config: AppConfig) {
+        let applied = self.overrides.applied(&config).unwrap_or_else(|err| {
+            warn!(error = ?err, "command-line overrides not applied");
+            config.clone()
+        });
+        self.file_config = config;
+        self.load_config(applied);
+    }
+
+    /// Discards unsaved edits by reloading the last saved configuration into every field.
+    fn revert_configuration(&mut self) {
+        self.load_config(self.saved_config.clone());
+        self.status_line = tr("Reverted to the saved configuration").to_string();
+        info!("Unsaved configuration changes reverted");
+    }
+
+    /// Reloads the configuration file once an edit made outside the app settles. With unsaved
+    /// edits here the user picks which side to keep instead.
+    fn poll_config_file(&mut self, ctx: &egui::Context) {
+        let Some(watcher) = &mut self.config_watcher else {
+            return;
+        };
+        let now = Instant::now();
+        let due = watcher.poll(now);
+        if let Some(remaining) = watcher.remaining(now) {
+            ctx.request_repaint_after(remaining);
+        }
+        if !due || !self.config.reload_on_change {
+            return;
+        }
+        let loaded = match config::config_path().and_then(|path| config::read(&path)) {
+            Ok(loaded) => loaded,
+            Err(err) => {
+                // Often a save caught halfway; the next write is picked up again.
+                warn!(error = ?err, "ignoring unreadable configuration file");
+                self.status_line = trf(
+                    "Configuration file not reloaded: {error}",
+                    &[("error", &format!("{err:#}"))],
+                );
+                return;
+            }
+        };
+        // Our own save, or a write that changed nothing.
+        if loaded == self.file_config {
+            return;
+        }
+        if self.config != self.saved_config {
+            info!("configuration file changed on disk while there are unsaved edits");
+            self.external_config = Some(loaded);
+            return;
+        }
+        self.apply_external_config(loaded);
+    }
+
+    /// Makes `loaded`, read from the configuration file, the saved and edited configuration, and
+    /// hands the settings a running worker can switch to over to the main and every extra
+    /// client's worker. The command-line overrides stay in force: an edit to the file cannot turn
+    /// off `--dry-run`.
+    fn apply_external_config(&mut self, loaded: AppConfig) {
+        self.external_config = None;
+        let previous = std::mem::take(&mut self.saved_config);
+        self.load_file_config(loaded);
+        self.saved_config = self.config.clone();
+        let restart = self.running && reload::needs_restart(&previous, &self.config);
+        let live = LiveSettings::of(&self.config);
+        if let Some(worker) = &self.worker
+            && live != LiveSettings::of(&previous)
+        {
+            worker.update(live);
+        }
+        for client in &self.client_workers {
+            let live = LiveSettings::of(&self.config.for_client(&client.slot));
+            if let Some(handle) = &client.handle
+                && live != LiveSettings::of(&previous.for_client(&client.slot))
+            {
+                handle.update(live);
+            }
+        }
+        self.status_line = if restart {
+            tr("Configuration reloaded from disk; some changes apply the next time monitoring starts")
+        } else {
+            tr("Configuration reloaded from disk")
+        }
+        .to_string();
+        self.toast(ToastKind::Info, self.status_line.clone());
+        info!(restart, "configuration reloaded from disk");
+    }
+
+    fn render_external_config(&mut self, ui: &mut egui::Ui) {
+        if self.external_config.is_none() {
+            return;
+        }
+        ui.horizontal_wrapped(|ui| {
+            ui.label(
+                RichText::new(tr(
+                    "The configuration file was changed outside the app while you have unsaved edits.",
+                ))
+                .color(egui::Color32::YELLOW),
+            );
+            if ui
+                .button(tr("Reload"))
+                .on_hover_text(tr("Discard your unsaved edits and use the file"))
+                .clicked()
+                && let Some(loaded) = self.external_config.take()
+            {
+                self.apply_external_config(loaded);
+            }
+            if ui
+                .button(tr("Keep mine"))
+                .on_hover_text(tr("Keep your edits; saving them overwrites the file"))
+                .clicked()
+            {
+                self.external_config = None;
+            }
+        });
+    }
+
+    /// Stores the current configuration as a profile named after `profile_name_input`.
+    fn save_profile_as(&mut self) {
+        if let Err(err) = self.apply_template_path_from_input() {
+            self.last_config_error = Some(err.to_string());
+            self.status_line = tr("Template path error").to_string();
+            return;
+        }
+        let name = self.profile_name_input.trim().to_string();
+        let saved = self
+            .persisted_config()
+            .and_then(|persisted| self.profiles.save_as(&name, &persisted));
+        if let Err(err) = saved {
+            self.status_line = format!("{err:#}");
+            return;
+        }
+        self.profile_name_input.clear();
+        self.profile_hotkey_input = self.profiles.hotkey(&name).unwrap_or_default().to_string();
+        info!(profile = %name, "profile saved");
+        // Also writes the profiles file, now that the new profile is active.
+        if self.save_configuration() {
+            self.status_line = trf("Saved profile {name}", &[("name", &name)]);
+        }
+    }
+
+    /// Replaces the configuration with profile `name` and makes it the main config file.
+    fn switch_profile(&mut self, name: &str) {
+        let Some(config) = self.profiles.switch(name) else {
+            return;
+        };
+        info!(profile = %name, "switched profile");
+        self.profile_hotkey_input = self.profiles.hotkey(name).unwrap_or_default().to_string();
+        self.load_file_config(config);
+        if self.save_configuration() {
+            self.status_line = trf("Switched to profile {name}", &[("name", &name)]);
+        } else {
+            // Remember the switch even if the main config file could not be written.
+            self.store_profiles();
+        }
+    }
+
+    fn delete_active_profile(&mut self) {
+        let Some(name) = self.profiles.active.clone() else {
+            return;
+        };
+        if let Some(hotkeys) = &mut self.hotkeys {
+            hotkeys.retain(|action| *action != HotkeyAction::SwitchProfile(name.clone()));
+        }
+        self.profiles.remove(&name);
+        self.profile_hotkey_input.clear();
+        info!(profile = %name, "profile deleted");
+        self.store_profiles();
+        self.status_line = trf("Deleted profile {name}", &[("name", &name)]);
+    }
+
+    fn store_profiles(&mut self) {
+        if let Err(err) = config::store_profiles(&self.profiles) {
+            error!(error = ?err, "failed to save profiles");
+            self.status_line = trf(
+                "Failed to save profiles: {error}",
+                &[("error", &format!("{err:#}"))],
+            );
+        }
+    }
+
+    /// Swaps in a whole new configuration and resets the text fields and hotkey derived from it.
+    fn load_config(&mut self, mut config: AppConfig) {
+        if let Some(index) =
+            capture::remap_monitor_index(&self.monitors, config.monitor_id, config.monitor_index)
+        {
+            config.monitor_index = index;
+        }
+        self.template_path_input = config
+            .template_path
+            .as_ref()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default();
+        self.pick_inputs = champion_inputs(&config.auto_pick.champions);
+        self.ban_inputs = champion_inputs(&config.auto_ban.champions);
+        self.bench_input = config.aram_bench.champions.join(", ");
+        self.give_up_input = config.trades.give_up.join(", ");
+        self.hotkey_input = config.toggle_hotkey.clone();
+        self.mini_hotkey_input = config.mini_mode_hotkey.clone();
+        self.last_config_error = None;
+        self.config = config;
+        self.apply_hotkey_input(HotkeyAction::ToggleMonitoring);
+        self.apply_hotkey_input(HotkeyAction::ToggleMiniMode);
+    }
+
+    /// Makes a PNG dropped onto the window the active template and opens its preview.
+    fn poll_dropped_files(&mut self, ctx: &egui::Context) {
+        if self.settings_locked() {
+            return;
+        }
+        let Some(path) = ctx.input(|i| {
+            i.raw
+                .dropped_files
+                .iter()
+                .find_map(|file| file.path.clone())
+        }) else {
+            return;
+        };
+        let is_png = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("png"));
+        let preview = if is_png {
+            detect::load_template(&path)
+                .and_then(|_| template_texture(ctx, &path))
+                .map_err(|err| format!("{err:#}"))
+        } else {
+            Err(tr("Only PNG images can be used as templates").to_string())
+        };
+        match &preview {
+            Ok(_) => {
+                info!(path = %path.display(), "template dropped onto the window");
+                self.template_path_input = path.display().to_string();
+                self.config.template_path = Some(path.clone());
+                self.last_config_error = None;
+                self.status_line = trf(
+                    "Template set to {path}; save to keep it",
+                    &[("path", &path.display())],
+                );
+            }
+            Err(err) => {
+                warn!(path = %path.display(), error = %err, "dropped file is not a usable template");
+            }
+        }
+        self.dropped_template = Some(DroppedTemplate {
+            path,
+            preview,
+            saved_to: None,
+        });
+    }
+
+    /// Copies the dropped template next to the configuration and points the template path at it.
+    fn save_dropped_template(&mut self) {
+        let Some(dropped) = &mut self.dropped_template else {
+            return;
+        };
+        let saved =
+            config::user_templates_dir().and_then(|dir| templates::copy_into(&dropped.path, &dir));
+        match saved {
+            Ok(path) => {
+                info!(path = %path.display(), "template saved");
+                templates::add(&mut self.config.templates, path.clone());
+                self.template_path_input = path.display().to_string();
+                self.config.template_path = Some(path.clone());
+                self.status_line = trf("Template saved to {path}", &[("path", &path.display())]);
+                dropped.saved_to = Some(path);
+            }
+            Err(err) => {
+                error!(error = ?err, "failed to save template");
+                self.status_line = trf(
+                    "Failed to save template: {error}",
+                    &[("error", &format!("{err:#}"))],
+                );
+            }
+        }
+    }
+
+    fn render_run_summary(&mut self, ctx: &egui::Context) {
+        let Some(summary) = &self.run_summary else {
+            return;
+        };
+        let mut open = true;
+        let mut close = false;
+        egui::Window::new(tr("Session summary"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                let rows = summary.rows();
+                egui::Grid::new("run_summary")
+                    .num_columns(2)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for (label, value) in &rows {
+                            ui.label(tr(label));
+                            ui.label(RichText::new(value).strong());
+                            ui.end_row();
+                        }
+                    });
+                ui.horizontal(|ui| {
+                    if ui.button(tr("Copy as text")).clicked() {
+                        let mut text = tr("Session summary").to_string();
+                        for (label, value) in &rows {
+                            text.push_str(&format!("\n{}: {value}", tr(label)));
+                        }
+                        ui.ctx().copy_text(text);
+                    }
+                    close = ui.button(tr("Close")).clicked();
+                });
+            });
+        if !open || close {
+            self.run_summary = None;
+        }
+    }
+
+    fn render_dropped_template(&mut self, ctx: &egui::Context) {
+        let hovering = ctx.input(|i| !i.raw.hovered_files.is_empty());
+        if hovering {
+            egui::Area::new(egui::Id::new("drop_hint"))
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .order(egui::Order::Foreground)
+                .show(ctx, |ui| {
+                    egui::Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.heading(tr("Drop a PNG to use it as the template"));
+                    });
+                });
+        }
+        let Some(dropped) = &self.dropped_template else {
+            return;
+        };
+        let mut open = true;
+        let mut save = false;
+        egui::Window::new(tr("Dropped template"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.label(dropped.path.display().to_string());
+                match &dropped.preview {
+                    Ok(texture) => {
+                        let [width, height] = texture.size();
+                        let size = egui::vec2(width as f32, height as f32);
+                        let scale = (2.0 * TEMPLATE_THUMBNAIL_WIDTH / size.x).min(1.0);
+                        ui.image((texture.id(), size * scale));
+                        ui.label(trf(
+                            "{width}×{height} px, now the active template",
+                            &[("width", &width), ("height", &height)],
+                        ));
+                        match &dropped.saved_to {
+                            Some(path) => {
+                                ui.label(trf("Template saved to {path}", &[("path", &path.display())]));
+                            }
+                            None => {
+                                save = ui
+                                    .button(tr("Save into templates folder"))
+                                    .on_hover_text(tr("Keep a copy next to the configuration, so moving or deleting the original does not break detection"))
+                                    .clicked();
+                            }
+                        }
+                    }
+                    Err(err) => {
+                        ui.colored_label(egui::Color32::LIGHT_RED, err);
+                    }
+                }
+            });
+        if save {
+            self.save_dropped_template();
+        }
+        if !open {
+            self.dropped_template = None;
+        }
+    }
+
+    /// Offers the ways out when monitoring could not find its template: pick the file, cut a new
+    /// one from the screen with the setup wizard, or fall back to the built-in image.
+    fn render_template_recovery(&mut self, ctx: &egui::Context) {
+        let Some(error) = &self.template_recovery else {
+            return;
+        };
+        let mut open = true;
+        let mut choice = None;
+        egui::Window::new(tr("Template not found"))
+            .id(egui::Id::new("template_recovery"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label(tr("Monitoring cannot start without the image of the Accept button:"));
+                ui.colored_label(egui::Color32::RED, error);
+                ui.add_space(6.0);
+                if ui
+                    .button(tr("Locate the file..."))
+                    .on_hover_text(tr("Point to the template image in a file dialog"))
+                    .clicked()
+                {
+                    choice = Some(TemplateFix::Locate);
+                }
+                if ui
+                    .button(tr("Capture from screen"))
+                    .on_hover_text(tr(
+                        "Open the setup wizard's template page and cut the Accept button out of a screenshot",
+                    ))
+                    .clicked()
+                {
+                    choice = Some(TemplateFix::Capture);
+                }
+                if ui
+                    .button(tr("Use the built-in template"))
+                    .on_hover_text(tr(
+                        "Write the default image that ships inside the program next to the configuration and use it",
+                    ))
+                    .clicked()
+                {
+                    choice = Some(TemplateFix::BuiltIn);
+                }
+            });
+        if !open {
+            self.template_recovery = None;
+        }
+        let fixed = match choice {
+            Some(TemplateFix::Locate) => {
+                self.browse_template_path();
+                self.config.resolve_template_path().is_ok()
+            }
+            Some(TemplateFix::Capture) => {
+                self.template_recovery = None;
+                self.onboarding = Some(Onboarding {
+                    step: OnboardingStep::Template,
+                    ..Default::default()
+                });
+                false
+            }
+            Some(TemplateFix::BuiltIn) => self.use_embedded_template(),
+            None => false,
+        };
+        if fixed {
+            self.template_recovery = None;
+            self.start_monitoring();
+        }
+    }
+
+    /// Writes the built-in template next to the configuration and points the template path at it.
+    fn use_embedded_template(&mut self) -> bool {
+        match config::user_templates_dir().and_then(|dir| templates::write_embedded(&dir)) {
+            Ok(path) => {
+                info!(path = %path.display(), "restored the built-in template");
+                templates::add(&mut self.config.templates, path.clone());
+                self.template_path_input = path.display().to_string();
+                self.config.template_path = Some(path.clone());
+                self.last_config_error = None;
+                self.status_line = trf(
+                    "Template set to {path}; save to keep it",
+                    &[("path", &path.display())],
+                );
+                true
+            }
+            Err(err) => {
+                error!(error = ?err, "failed to restore the built-in template");
+                self.status_line = trf(
+                    "Failed to save template: {error}",
+                    &[("error", &format!("{err:#}"))],
+                );
+                false
+            }
+        }
+    }
+
+    /// Lets the user pick the template in a native dialog and checks right away that it loads.
+    fn browse_template_path(&mut self) {
+        let mut dialog = rfd::FileDialog::new().add_filter(tr("Images"), &["png", "jpg", "jpeg"]);
+        if let Some(dir) = Path::new(self.template_path_input.trim())
+            .parent()
+            .filter(|dir| dir.is_dir())
+        {
+            dialog = dialog.set_directory(dir);
+        }
+        let Some(path) = dialog.pick_file() else {
+            return;
+        };
+        self.template_path_input = path.display().to_string();
+        match detect::load_template(&path) {
+            Ok(_) => {
+                self.config.template_path = Some(path.clone());
+                self.last_config_error = None;
+                self.status_line = trf(
+                    "Template set to {path}; save to keep it",
+                    &[("path", &path.display())],
+                );
+            }
+            Err(err) => {
+                warn!(error = ?err, "picked template does not load");
+                self.last_config_error = Some(format!("{err:#}"));
+                self.status_line = tr("Template path error").to_string();
             }
         }
     }
@@ -280,108 +2247,554 @@ impl LolAutoAcceptApp {
         Ok(())
     }
 
+    fn render_crash_banner(&mut self, ui: &mut egui::Ui) {
+        let Some((path, this_session)) = &self.crash_report else {
+            return;
+        };
+        let mut dismiss = false;
+        egui::Frame::none()
+            .fill(ui.visuals().error_fg_color.gamma_multiply(0.15))
+            .inner_margin(6.0)
+            .rounding(4.0)
+            .show(ui, |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    ui.colored_label(
+                        ui.visuals().error_fg_color,
+                        tr(if *this_session {
+                            "Monitoring crashed. A crash report was saved."
+                        } else {
+                            "The previous session crashed. A crash report was saved."
+                        }),
+                    )
+                    .on_hover_text(path.display().to_string());
+                    if ui.button(tr("Open report")).clicked()
+                        && let Err(err) = crash::open_report(path)
+                    {
+                        warn!(error = ?err, "failed to open the crash report");
+                        self.status_line = trf(
+                            "Failed to open the crash report: {error}",
+                            &[("error", &format!("{err:#}"))],
+                        );
+                    }
+                    if ui.button(tr("Dismiss")).clicked() {
+                        dismiss = true;
+                    }
+                });
+            });
+        if dismiss {
+            self.crash_report = None;
+        }
+    }
+
+    /// Kiosk mode is on and the PIN has not been entered this session.
+    fn settings_locked(&self) -> bool {
+        self.kiosk.is_locked(&self.config.kiosk)
+    }
+
+    /// Stands in for the settings while kiosk mode locks them.
+    fn render_kiosk_unlock(&mut self, ui: &mut egui::Ui) {
+        ui.add_space(8.0);
+        ui.label(RichText::new(tr("Settings are locked")).strong());
+        ui.label(tr(
+            "Start and Stop stay available. Enter the PIN to change anything else.",
+        ));
+        ui.horizontal(|ui| {
+            ui.label(tr("PIN"));
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.kiosk_pin_input)
+                    .password(true)
+                    .desired_width(120.0),
+            );
+            let submitted =
+                response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+            if ui.button(tr("Unlock")).clicked() || submitted {
+                self.unlock_settings();
+            }
+        });
+    }
+
+    fn unlock_settings(&mut self) {
+        let pin = std::mem::take(&mut self.kiosk_pin_input);
+        self.status_line = match self
+            .kiosk
+            .try_unlock(&self.config.kiosk, &pin, Instant::now())
+        {
+            UnlockOutcome::Unlocked => {
+                info!("settings unlocked");
+                tr("Settings unlocked").to_string()
+            }
+            UnlockOutcome::WrongPin { remaining } => {
+                warn!(remaining, "wrong kiosk PIN");
+                trf(
+                    "Wrong PIN, {remaining} attempts left",
+                    &[("remaining", &remaining)],
+                )
+            }
+            UnlockOutcome::LockedOut { retry_in } => trf(
+                "Too many wrong PINs, try again in {seconds} s",
+                &[("seconds", &retry_in.as_secs().max(1))],
+            ),
+            UnlockOutcome::NoPin => {
+                tr("No PIN is set; turn off kiosk mode in the configuration file").to_string()
+            }
+        };
+    }
+
+    /// Session counters, plus a warning when a running worker stops reporting.
+    fn render_counters(&self, ui: &mut egui::Ui) {
+        if !self.running && self.counters.frames == 0 {
+            return;
+        }
+        let counters = &self.counters;
+        let rate = counters
+            .detection_rate()
+            .map_or_else(|| "–".to_string(), |rate| format!("{rate:.2}%"));
+        ui.label(
+            RichText::new(trf(
+                "Frames {frames} • detections {detections} ({rate}) • clicks {clicks} • errors {errors}",
+                &[
+                    ("frames", &counters.frames),
+                    ("detections", &counters.detections),
+                    ("rate", &rate),
+                    ("clicks", &counters.clicks),
+                    ("errors", &counters.errors),
+                ],
+            ))
+            .small(),
+        );
+        if !self.running {
+            return;
+        }
+        let now = Instant::now();
+        if counters.is_stalled(now) {
+            let age = counters.heartbeat_age(now).unwrap_or_default();
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                trf(
+                    "No word from the worker for {duration}; it may be stuck. Stop and start again if this persists.",
+                    &[("duration", &format_duration(age))],
+                ),
+            );
+        }
+        // The worker wakes the window with every report; this catches it going quiet.
+        ui.ctx().request_repaint_after(Duration::from_secs(1));
+    }
+
     fn render_status_panel(&mut self, ui: &mut egui::Ui) {
+        self.render_crash_banner(ui);
         ui.horizontal(|ui| {
+            render_indicator(ui, self.indicator(), self.config.theme.indicator_palette);
             ui.heading("LoL Auto Accept (Rust)");
             ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                 if ui
-                    .add_enabled(!self.running, egui::Button::new("Start"))
+                    .add_enabled(!self.running, egui::Button::new(tr("Start")))
                     .clicked()
                 {
                     self.start_monitoring();
                 }
                 if ui
-                    .add_enabled(self.running, egui::Button::new("Stop"))
+                    .add_enabled(self.running, egui::Button::new(tr("Stop")))
                     .clicked()
                 {
                     self.stop_monitoring();
                 }
-                if ui.button("Exit").clicked() {
-                    self.exit_requested = true;
+                if ui.button(tr("Exit")).clicked() {
+                    self.request_exit(ui.ctx());
                 }
-            });
+                if self.settings_locked() {
+                    return;
+                }
+                if self.config.kiosk.enabled
+                    && ui
+                        .button(tr("Lock settings"))
+                        .on_hover_text(tr("Hide the settings again until the PIN is entered"))
+                        .clicked()
+                {
+                    self.kiosk.lock();
+                    info!("settings locked");
+                }
+                if let Some(available) = &self.available_update
+                    && ui
+                        .button(trf(
+                            "Update to {version}",
+                            &[("version", &available.version)],
+                        ))
+                        .clicked()
+                {
+                    self.show_update = true;
+                }
+                if ui
+                    .add_enabled(
+                        self.probe.is_none(),
+                        egui::Button::new(tr("Test detection now")),
+                    )
+                    .on_hover_text(tr(
+                        "Capture the screen once and show what the detector finds, without starting monitoring",
+                    ))
+                    .clicked()
+                {
+                    self.start_probe();
+                }
+                if ui
+                    .button(tr("Last frame"))
+                    .on_hover_text(tr(
+                        "Show the frame the running detector saw last, with its best match",
+                    ))
+                    .clicked()
+                {
+                    self.frame_viewer = match self.frame_viewer {
+                        Some(_) => None,
+                        None => Some(FrameViewer::default()),
+                    };
+                }
+                if ui
+                    .button("?")
+                    .on_hover_text(tr("Keyboard shortcuts (F1)"))
+                    .clicked()
+                {
+                    self.show_shortcuts = !self.show_shortcuts;
+                }
+                if self.onboarding.is_none()
+                    && ui
+                        .button(tr("Mini mode"))
+                        .on_hover_text(tr("Shrink to a small always-on-top window"))
+                        .clicked()
+                {
+                    self.set_mini_mode(ui.ctx(), true);
+                }
+            });
         });
         ui.separator();
         ui.label(RichText::new(&self.status_line).strong());
+        self.render_cooldown(ui);
+        self.render_counters(ui);
+        for client in &self.client_workers {
+            let text = format!("{}: {}", client.name, client.status);
+            if client.error {
+                ui.colored_label(egui::Color32::LIGHT_RED, text);
+            } else {
+                ui.label(text);
+            }
+        }
+        if self.config.follow_client {
+            ui.label(tr(match self.client_running {
+                Some(true) => "League client: running",
+                Some(false) => "League client: not running",
+                None => "League client: process list unavailable",
+            }));
+        }
+        if let Some((live, since)) = self.events_live
+            && self.config.lcu.listen_events
+        {
+            let (source, color) = if live {
+                (tr("client API"), egui::Color32::LIGHT_GREEN)
+            } else {
+                (
+                    tr("screen detection (client unreachable)"),
+                    egui::Color32::YELLOW,
+                )
+            };
+            ui.label(
+                RichText::new(trf(
+                    "Ready checks: {source} for {duration}",
+                    &[
+                        ("source", &source),
+                        ("duration", &format_duration(since.elapsed())),
+                    ],
+                ))
+                .color(color),
+            );
+        }
+        if let Some((status, received)) = &self.queue_status {
+            render_queue_status(ui, status, received.elapsed().as_secs_f32());
+        }
+        if let Some((interval_ms, scales)) = self.battery_saver {
+            let scales = scales.map_or_else(|| tr("all").to_string(), |scales| scales.to_string());
+            ui.label(trf(
+                "Battery saver: polling every {interval_ms} ms, {scales} scales",
+                &[("interval_ms", &interval_ms), ("scales", &scales)],
+            ));
+        }
+        if self.elevation_mismatch {
+            self.render_elevation_warning(ui);
+        }
+        if self.capture_degraded {
+            ui.label(
+                RichText::new(tr("Screen capture is failing; retrying with backoff"))
+                    .color(egui::Color32::YELLOW),
+            );
+        }
+        if let Some(metrics) = &self.metrics {
+            let memory = metrics.resident_bytes.map_or_else(
+                || "–".to_string(),
+                |bytes| format!("{:.0} MB", bytes as f64 / (1024.0 * 1024.0)),
+            );
+            ui.label(
+                RichText::new(trf(
+                    "{fps} fps • capture {capture_ms} ms • match {match_ms} ms • total {latency_ms} ms • memory {memory}",
+                    &[
+                        ("fps", &format!("{:.1}", metrics.capture_fps)),
+                        ("capture_ms", &format!("{:.1}", metrics.avg_capture_ms)),
+                        ("match_ms", &format!("{:.1}", metrics.avg_match_ms)),
+                        ("latency_ms", &format!("{:.1}", metrics.avg_latency_ms)),
+                        ("memory", &memory),
+                    ],
+                ))
+                .small()
+                .monospace(),
+            );
+            ui.label(trf(
+                "Frame buffers: {allocations} allocations, {reuses} reuses, {mb} MB held",
+                &[
+                    ("allocations", &metrics.arena.allocations),
+                    ("reuses", &metrics.arena.reuses),
+                    (
+                        "mb",
+                        &format!("{:.1}", metrics.arena.bytes_held as f64 / (1024.0 * 1024.0)),
+                    ),
+                ],
+            ));
+        }
         if let Some(snapshot) = &self.last_detection {
-            ui.label(format!(
-                "Last detection: {:.3} score at screen ({}, {}) – image ({}, {}) – template {}x{} (scale {:.2}) – {} ago",
-                snapshot.score,
-                snapshot.screen_coords.0,
-                snapshot.screen_coords.1,
-                snapshot.image_coords.0,
-                snapshot.image_coords.1,
-                snapshot.template_size.0,
-                snapshot.template_size.1,
-                snapshot.scale,
-                format_duration(snapshot.timestamp.elapsed())
+            ui.label(trf(
+                "Last detection: {score} score at screen ({screen_x}, {screen_y}) – image ({image_x}, {image_y}) – template {width}x{height} (scale {scale}) – {age} ago",
+                &[
+                    ("score", &format!("{:.3}", snapshot.score)),
+                    ("screen_x", &snapshot.screen_coords.0),
+                    ("screen_y", &snapshot.screen_coords.1),
+                    ("image_x", &snapshot.image_coords.0),
+                    ("image_y", &snapshot.image_coords.1),
+                    ("width", &snapshot.template_size.0),
+                    ("height", &snapshot.template_size.1),
+                    ("scale", &format!("{:.2}", snapshot.scale)),
+                    ("age", &format_duration(snapshot.timestamp.elapsed())),
+                ],
             ));
         } else {
-            ui.label("No detections yet");
+            ui.label(tr("No detections yet"));
         }
     }
 
     fn render_settings(&mut self, ui: &mut egui::Ui) {
-        egui::CollapsingHeader::new("Monitoring Settings")
-            .default_open(true)
+        let response = egui::CollapsingHeader::new(tr("Monitoring Settings"))
+            .id_source("settings")
+            .default_open(self.layout.section_open("settings", true))
             .show(ui, |ui| {
-                ui.add(
-                    egui::Slider::new(&mut self.config.threshold, 0.5..=0.99)
-                        .text("Match threshold")
-                        .suffix(" score"),
-                );
+                self.render_profile_selector(ui);
+
+                ui.horizontal(|ui| {
+                    ui.label(tr("Preset"));
+                    let current = Preset::current(&self.config);
+                    for preset in Preset::ALL {
+                        if ui
+                            .selectable_label(current == Some(preset), tr(preset.label()))
+                            .on_hover_text(tr(preset.description()))
+                            .clicked()
+                        {
+                            preset.apply(&mut self.config);
+                        }
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::Slider::new(&mut self.config.threshold, 0.5..=0.99)
+                            .text(tr("Match threshold"))
+                            .suffix(" score"),
+                    );
+                    if ui
+                        .button(tr("Tune..."))
+                        .on_hover_text(tr(
+                            "Measure scores with and without the ready check on screen and pick a threshold between them",
+                        ))
+                        .clicked()
+                    {
+                        self.tuning.get_or_insert_with(TuningWizard::default);
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    let near_miss = &mut self.config.near_miss;
+                    ui.checkbox(&mut near_miss.enabled, tr("Warn about possible missed pops"))
+                        .on_hover_text(tr(
+                            "Warn when the score stays just under the threshold for several frames in a row",
+                        ));
+                    ui.add_enabled_ui(near_miss.enabled, |ui| {
+                        ui.label(tr("within"));
+                        ui.add(
+                            egui::DragValue::new(&mut near_miss.margin)
+                                .range(0.005..=0.2)
+                                .speed(0.005)
+                                .fixed_decimals(3),
+                        );
+                        ui.label(tr("for"));
+                        ui.add(
+                            egui::DragValue::new(&mut near_miss.frames)
+                                .range(1..=100)
+                                .suffix(tr(" frames")),
+                        );
+                    });
+                });
 
                 ui.horizontal(|ui| {
-                    ui.label("Polling interval (ms)");
+                    ui.label(tr("Polling interval (ms)"));
                     ui.add(egui::DragValue::new(&mut self.config.interval_ms).speed(5));
+                    ui.checkbox(&mut self.config.skip_unchanged, tr("Skip unchanged frames"))
+                        .on_hover_text(tr("Reuse the last result while the screen has not changed, saving CPU"));
                 });
 
                 ui.horizontal(|ui| {
-                    ui.label("Cooldown (ms)");
+                    ui.label(tr("Cooldown (ms)"));
                     ui.add(egui::DragValue::new(&mut self.config.cooldown_ms).speed(10));
                 });
 
                 ui.horizontal(|ui| {
-                    ui.label("Click offset X");
+                    ui.label(tr("Click offset X"));
                     ui.add(egui::DragValue::new(&mut self.config.click_offset_x).speed(1));
                     ui.label("Y");
                     ui.add(egui::DragValue::new(&mut self.config.click_offset_y).speed(1));
                 });
 
                 ui.horizontal(|ui| {
-                    ui.label("Monitor");
-                    let count = self.monitors.len();
-                    ComboBox::from_id_source("monitor_selector")
-                        .selected_text(monitor_label(
-                            self.config.monitor_index,
-                            self.monitors.get(self.config.monitor_index),
-                            count,
-                        ))
+                    ui.label(tr("Click anchor"));
+                    let anchor = &mut self.config.click_anchor;
+                    ComboBox::from_id_source("click_anchor")
+                        .selected_text(click_anchor_label(*anchor))
                         .show_ui(ui, |ui| {
-                            for (index, info) in self.monitors.iter().enumerate() {
-                                ui.selectable_value(
-                                    &mut self.config.monitor_index,
-                                    index,
-                                    monitor_label(index, Some(info), count),
-                                );
+                            ui.selectable_value(anchor, ClickAnchor::Center, tr("Center"));
+                            ui.selectable_value(anchor, ClickAnchor::TopLeft, tr("Top-left"));
+                            if !matches!(anchor, ClickAnchor::Percent { .. }) {
+                                let (x, y) = anchor.fractions();
+                                let custom = ClickAnchor::Percent {
+                                    x: x * 100.0,
+                                    y: y * 100.0,
+                                };
+                                if ui.selectable_label(false, tr("Custom %")).clicked() {
+                                    *anchor = custom;
+                                }
                             }
-                        });
+                        })
+                        .response
+                        .on_hover_text(tr("Point inside the template to click, before the offsets"));
+                    if let ClickAnchor::Percent { x, y } = anchor {
+                        ui.add(egui::DragValue::new(x).range(0.0..=100.0).suffix("% X"));
+                        ui.add(egui::DragValue::new(y).range(0.0..=100.0).suffix("% Y"));
+                    }
+                });
+
+                self.render_monitor_selector(ui);
+
+                ui.horizontal(|ui| {
+                    ui.label(tr("Capture source"));
+                    ComboBox::from_id_source("capture_source")
+                        .selected_text(capture_source_label(&self.config.capture_source))
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(
+                                &mut self.config.capture_source,
+                                CaptureSource::Screen,
+                                capture_source_label(&CaptureSource::Screen),
+                            );
+                            if cfg!(windows) {
+                                let window = CaptureSource::Window {
+                                    title: LEAGUE_CLIENT_WINDOW_TITLE.to_string(),
+                                };
+                                let label = capture_source_label(&window);
+                                ui.selectable_value(&mut self.config.capture_source, window, label);
+                            }
+                        })
+                        .response
+                        .on_hover_text(
+                            tr("Window capture keeps working while the client is covered by other windows"),
+                        );
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label(tr("Search region"));
+                    ui.label(match self.config.search_region {
+                        Some(region) => format!(
+                            "{}x{} @ ({}, {})",
+                            region.width, region.height, region.x, region.y
+                        ),
+                        None => tr("Whole monitor").to_string(),
+                    });
+                    if ui
+                        .button(tr("Select…"))
+                        .on_hover_text(tr("Drag a box over a screenshot of the selected monitor; matching only looks inside it"))
+                        .clicked()
+                    {
+                        self.open_region_editor(ui.ctx());
+                    }
+                    if ui
+                        .add_enabled(
+                            self.config.search_region.is_some(),
+                            egui::Button::new(tr("Clear")),
+                        )
+                        .clicked()
+                    {
+                        self.config.search_region = None;
+                    }
+                });
 
-                    if ui.button("Refresh").clicked() {
-                        self.refresh_monitors();
+                ui.checkbox(
+                    &mut self.config.mask_cursor,
+                    tr("Mask mouse cursor before matching"),
+                )
+                .on_hover_text(tr("Prevents the cursor from lowering the score when it hovers the button"));
+                ui.checkbox(&mut self.config.dry_run, tr("Detect only, don't click"))
+                    .on_hover_text(tr("Report where the click would land without moving the mouse"));
+                ui.add_enabled(
+                    self.tray.is_some(),
+                    egui::Checkbox::new(&mut self.config.minimize_to_tray, tr("Minimize to tray")),
+                )
+                .on_hover_text(tr("Closing or minimizing hides the window; use the tray icon to show it or exit"))
+                .on_disabled_hover_text(tr("No tray icon on this platform"));
+                ui.checkbox(&mut self.config.confirm_exit, tr("Ask before exiting while monitoring"))
+                    .on_hover_text(tr("Exit and closing the window offer to stop and exit, minimize or cancel"));
+                ui.checkbox(&mut self.config.follow_client, tr("Follow League client"))
+                    .on_hover_text(tr("Start monitoring when the client launches and stop when it exits"));
+                ui.checkbox(&mut self.config.auto_start, tr("Start monitoring on launch"))
+                    .on_hover_text(tr("Begin monitoring as soon as the app opens, without pressing Start"));
+                ui.checkbox(&mut self.config.start_minimized, tr("Start minimized"))
+                    .on_hover_text(tr("Open minimized, or hidden to the tray, instead of showing the window; launching at login always does"));
+                ui.checkbox(&mut self.config.reload_on_change, tr("Reload edits to the configuration file"))
+                    .on_hover_text(tr("Apply changes made to the file outside the app, e.g. by a sync tool; threshold, interval, cooldown and dry run also reach a running monitor"));
+                self.render_launch_at_login(ui);
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.config.check_for_updates, tr("Check for updates at launch"))
+                        .on_hover_text(tr("Look for a newer release on GitHub when the app opens; nothing is installed without asking"));
+                    if ui
+                        .add_enabled(self.update_check.is_none(), egui::Button::new(tr("Check now")))
+                        .clicked()
+                    {
+                        self.check_for_updates(true);
+                    }
+                    if self.update_check.is_some() {
+                        ui.spinner();
                     }
                 });
+                ui.checkbox(&mut self.config.show_overlay, tr("Show detection overlay"))
+                    .on_hover_text(tr("Outline each match on screen for two seconds in a click-through window"));
 
                 ui.horizontal(|ui| {
-                    ui.label("Template path");
+                    ui.label(tr("Template path"));
                     let response = ui.text_edit_singleline(&mut self.template_path_input);
                     if response.changed() {
                         self.last_config_error = None;
                     }
-                    if ui.button("Reset").clicked() {
+                    if ui
+                        .button(tr("Browse…"))
+                        .on_hover_text(tr("Pick the template image in a file dialog"))
+                        .clicked()
+                    {
+                        self.browse_template_path();
+                    }
+                    if ui.button(tr("Reset")).clicked() {
                         self.template_path_input.clear();
                         self.config.template_path = None;
                     }
+                    if ui.button(tr("Manage templates…")).clicked() {
+                        self.template_manager = Some(TemplateManager::open());
+                    }
                 });
 
                 if let Some(err) = &self.last_config_error {
@@ -397,235 +2810,3106 @@ impl LolAutoAcceptApp {
                             .map(|p| p.display().to_string())
                             .unwrap_or_default();
 
+                self.render_hotkey_row(ui, tr("Start/stop hotkey"), HotkeyAction::ToggleMonitoring);
+                self.render_hotkey_row(ui, tr("Mini mode hotkey"), HotkeyAction::ToggleMiniMode);
+                if let Some(err) = &self.hotkey_error {
+                    ui.colored_label(egui::Color32::LIGHT_RED, err);
+                }
+
+                self.render_scale_settings(ui);
+                self.render_appearance_settings(ui);
+                self.render_input_settings(ui);
+                self.render_lcu_settings(ui);
+                self.render_champ_select_settings(ui);
+                self.render_notification_settings(ui);
+                self.render_streamer_settings(ui);
+                self.render_client_settings(ui);
+                self.render_schedule_settings(ui);
+                self.render_auto_stop_settings(ui);
+                self.render_battery_settings(ui);
+
+                self.render_external_config(ui);
                 ui.horizontal(|ui| {
-                    if ui.button("Save configuration").clicked() {
+                    if ui.button(tr("Save configuration")).clicked() {
                         self.save_configuration();
                     }
+                    if ui
+                        .add_enabled(dirty, egui::Button::new(tr("Revert")))
+                        .on_hover_text(tr("Discard unsaved changes and restore the saved configuration"))
+                        .clicked()
+                    {
+                        self.revert_configuration();
+                    }
+                    if ui
+                        .button(tr("Export settings…"))
+                        .on_hover_text(tr("Write the configuration and all profiles to a JSON file"))
+                        .clicked()
+                    {
+                        self.export_settings();
+                    }
+                    if ui
+                        .add_enabled(!self.running, egui::Button::new(tr("Import settings…")))
+                        .on_hover_text(tr("Replace the configuration and all profiles with an exported file"))
+                        .on_disabled_hover_text(tr("Stop monitoring to import settings."))
+                        .clicked()
+                    {
+                        self.import_settings();
+                    }
                     if dirty {
-                        ui.label(RichText::new("Unsaved changes").italics());
+                        ui.label(RichText::new(tr("Unsaved changes")).italics());
+                    }
+                });
+            });
+        self.layout
+            .set_section_open("settings", response.openness > 0.5);
+    }
+
+    fn render_hotkey_row(&mut self, ui: &mut egui::Ui, label: &str, action: HotkeyAction) {
+        let enabled = self.hotkeys.is_some();
+        ui.horizontal(|ui| {
+            ui.label(label);
+            let response = ui.add_enabled(
+                enabled,
+                egui::TextEdit::singleline(self.hotkey_input_mut(&action))
+                    .desired_width(120.0)
+                    .hint_text(tr("e.g. Ctrl+Alt+A")),
+            );
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            let current = self.hotkey_binding(&action);
+            let changed = self.hotkey_input_mut(&action).trim() != current;
+            if (ui
+                .add_enabled(enabled, egui::Button::new(tr("Apply")))
+                .clicked()
+                || submitted)
+                && changed
+            {
+                self.apply_hotkey_input(action);
+            }
+        })
+        .response
+        .on_hover_text(tr(
+            "Works even when this window is not focused. Leave empty to disable.",
+        ));
+    }
+
+    fn render_launch_at_login(&mut self, ui: &mut egui::Ui) {
+        let Some(enabled) = self.launch_at_login.as_mut() else {
+            return;
+        };
+        let label = tr(if cfg!(windows) {
+            "Start with Windows"
+        } else {
+            "Start at login"
+        });
+        if ui
+            .checkbox(enabled, label)
+            .on_hover_text(tr("Launch this app when you log in. Applied immediately."))
+            .changed()
+        {
+            let enabled = *enabled;
+            match autostart::set_enabled(enabled) {
+                Ok(()) => {
+                    info!(enabled, "launch at login updated");
+                    self.status_line = tr(if enabled {
+                        "The app will launch at login"
+                    } else {
+                        "The app will no longer launch at login"
+                    })
+                    .to_string();
+                }
+                Err(err) => {
+                    error!(error = ?err, "failed to update launch at login");
+                    self.launch_at_login = Some(!enabled);
+                    self.status_line = trf(
+                        "Failed to change launch at login: {error}",
+                        &[("error", &format!("{err:#}"))],
+                    );
+                }
+            }
+        }
+    }
+
+    fn render_profile_selector(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal_wrapped(|ui| {
+            ui.label(tr("Profile"));
+            let mut selected = None;
+            ui.add_enabled_ui(!self.running, |ui| {
+                ComboBox::from_id_source("profile_selector")
+                    .selected_text(self.profiles.active.as_deref().unwrap_or(tr("(none)")))
+                    .show_ui(ui, |ui| {
+                        for name in self.profiles.names() {
+                            let active = self.profiles.active.as_deref() == Some(name);
+                            if ui.selectable_label(active, name).clicked() && !active {
+                                selected = Some(name.to_string());
+                            }
+                        }
+                    });
+            })
+            .response
+            .on_disabled_hover_text(tr("Stop monitoring to switch profiles."));
+            if let Some(name) = selected {
+                self.switch_profile(&name);
+            }
+            if self.profiles.active.is_some() && ui.button(tr("Delete")).clicked() {
+                self.delete_active_profile();
+            }
+            if let Some(active) = self.profiles.active.clone() {
+                self.render_hotkey_row(
+                    ui,
+                    tr("Profile hotkey"),
+                    HotkeyAction::SwitchProfile(active),
+                );
+            }
+            ui.add(
+                egui::TextEdit::singleline(&mut self.profile_name_input)
+                    .hint_text(tr("New profile name"))
+                    .desired_width(120.0),
+            );
+            if ui
+                .add_enabled(
+                    !self.profile_name_input.trim().is_empty(),
+                    egui::Button::new(tr("Save as profile")),
+                )
+                .clicked()
+            {
+                self.save_profile_as();
+            }
+        });
+    }
+
+    fn render_monitor_selector(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label(tr("Monitor"));
+            let count = self.monitors.len();
+            ComboBox::from_id_source("monitor_selector")
+                .selected_text(monitor_label(
+                    self.config.monitor_index,
+                    self.monitors.get(self.config.monitor_index),
+                    count,
+                ))
+                .show_ui(ui, |ui| {
+                    for (index, info) in self.monitors.iter().enumerate() {
+                        if ui
+                            .selectable_value(
+                                &mut self.config.monitor_index,
+                                index,
+                                monitor_label(index, Some(info), count),
+                            )
+                            .clicked()
+                        {
+                            self.config.monitor_id = Some(info.id);
+                        }
+                    }
+                });
+
+            if ui.button(tr("Refresh")).clicked() {
+                self.refresh_monitors();
+            }
+        });
+    }
+
+    /// Freezes a screenshot of the selected monitor for the search region editor.
+    fn open_region_editor(&mut self, ctx: &egui::Context) {
+        let capture = capture::capture_once(
+            &self.config.effective_capture_source(),
+            self.config.monitor_index,
+        );
+        match capture {
+            Ok(frame) => {
+                let texture = ctx.load_texture(
+                    "search-region-snapshot",
+                    egui::ColorImage::from_gray(
+                        [frame.image.width() as usize, frame.image.height() as usize],
+                        frame.image.as_raw(),
+                    ),
+                    egui::TextureOptions::LINEAR,
+                );
+                let selection = self.config.search_region.map(|region| {
+                    (
+                        (region.x, region.y),
+                        (region.x + region.width, region.y + region.height),
+                    )
+                });
+                self.region_editor = Some(RegionEditor { texture, selection });
+            }
+            Err(err) => {
+                error!(error = ?err, "failed to capture the search region screenshot");
+                self.status_line = trf(
+                    "Screen capture failed: {error}",
+                    &[("error", &format!("{err:#}"))],
+                );
+            }
+        }
+    }
+
+    /// Asks GitHub for a newer release on a background thread; [`Self::poll_update`] picks it up.
+    fn check_for_updates(&mut self, manual: bool) {
+        if self.update_check.is_some() {
+            return;
+        }
+        self.update_check_manual = manual;
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        std::thread::spawn(move || {
+            let _ = tx.send(update::check());
+        });
+        self.update_check = Some(rx);
+    }
+
+    /// Downloads and swaps in the confirmed release on a background thread.
+    fn install_update(&mut self) {
+        let Some(available) = &self.available_update else {
+            return;
+        };
+        let version = available.version.clone();
+        info!(version, "installing update");
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        std::thread::spawn(move || {
+            let _ = tx.send(update::install(&version));
+        });
+        self.update_install = Some(rx);
+        self.status_line = tr("Downloading the update...").to_string();
+    }
+
+    fn poll_update(&mut self, ctx: &egui::Context) {
+        if let Some(rx) = &self.update_check {
+            match rx.try_recv() {
+                Ok(Ok(Some(available))) => {
+                    info!(version = available.version, "update available");
+                    self.status_line = trf(
+                        "Version {version} is available",
+                        &[("version", &available.version)],
+                    );
+                    self.available_update = Some(available);
+                    self.show_update |= self.update_check_manual;
+                    self.update_check = None;
+                }
+                Ok(Ok(None)) => {
+                    info!(version = update::CURRENT_VERSION, "no newer release");
+                    if self.update_check_manual {
+                        self.status_line = trf(
+                            "Version {version} is the latest",
+                            &[("version", &update::CURRENT_VERSION)],
+                        );
+                    }
+                    self.update_check = None;
+                }
+                Ok(Err(err)) => {
+                    warn!(error = ?err, "update check failed");
+                    if self.update_check_manual {
+                        self.status_line = trf(
+                            "Update check failed: {error}",
+                            &[("error", &format!("{err:#}"))],
+                        );
+                    }
+                    self.update_check = None;
+                }
+                Err(TryRecvError::Empty) => ctx.request_repaint_after(Duration::from_millis(500)),
+                Err(TryRecvError::Disconnected) => self.update_check = None,
+            }
+        }
+        if let Some(rx) = &self.update_install {
+            match rx.try_recv() {
+                Ok(Ok(())) => {
+                    self.status_line =
+                        tr("Update installed; restart the app to use it").to_string();
+                    self.update_installed = true;
+                    self.update_install = None;
+                }
+                Ok(Err(err)) => {
+                    error!(error = ?err, "update failed");
+                    self.status_line =
+                        trf("Update failed: {error}", &[("error", &format!("{err:#}"))]);
+                    self.update_install = None;
+                }
+                Err(TryRecvError::Empty) => ctx.request_repaint_after(Duration::from_millis(200)),
+                Err(TryRecvError::Disconnected) => self.update_install = None,
+            }
+        }
+    }
+
+    fn render_update(&mut self, ctx: &egui::Context) {
+        if !self.show_update {
+            return;
+        }
+        let Some(available) = &self.available_update else {
+            self.show_update = false;
+            return;
+        };
+        let mut open = true;
+        let mut install = false;
+        let mut later = false;
+        egui::Window::new(tr("Update available"))
+            .id(egui::Id::new("update_available"))
+            .open(&mut open)
+            .collapsible(false)
+            .default_width(480.0)
+            .show(ctx, |ui| {
+                ui.heading(format!(
+                    "{} → {}",
+                    update::CURRENT_VERSION,
+                    available.version
+                ));
+                if !available.name.is_empty() || !available.date.is_empty() {
+                    ui.label(format!("{} {}", available.name, available.date));
+                }
+                egui::ScrollArea::vertical()
+                    .max_height(240.0)
+                    .show(ui, |ui| {
+                        if available.changelog.is_empty() {
+                            ui.weak(tr("No release notes"));
+                        } else {
+                            ui.label(&available.changelog);
+                        }
+                    });
+                if let Some(url) = &available.url {
+                    ui.hyperlink_to(tr("Release page"), url);
+                }
+                ui.separator();
+                if self.update_installed {
+                    ui.colored_label(
+                        egui::Color32::LIGHT_GREEN,
+                        tr("Update installed; restart the app to use it"),
+                    );
+                    return;
+                }
+                ui.horizontal(|ui| {
+                    let mut button = ui
+                        .add_enabled(
+                            self.update_install.is_none() && update::can_install(),
+                            egui::Button::new(tr("Download and install")),
+                        )
+                        .on_hover_text(tr(
+                            "Replaces this executable with the new release; it runs after a restart",
+                        ));
+                    if !update::can_install() {
+                        button = button.on_disabled_hover_text(tr(
+                            "This build cannot verify the signature of downloads; get the release from its page",
+                        ));
+                    }
+                    if button.clicked() {
+                        install = true;
+                    }
+                    if self.update_install.is_some() {
+                        ui.spinner();
+                    } else if ui.button(tr("Later")).clicked() {
+                        later = true;
+                    }
+                });
+            });
+        if install {
+            self.install_update();
+        }
+        if later || !open {
+            self.show_update = false;
+        }
+    }
+
+    /// Runs one capture + detect on a background thread; [`Self::poll_probe`] picks it up.
+    fn start_probe(&mut self) {
+        if let Err(err) = self.apply_template_path_from_input() {
+            self.status_line = tr("Template path error").to_string();
+            error!(error = ?err, "failed to parse template path");
+            return;
+        }
+        let config = self.config.clone();
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        std::thread::spawn(move || {
+            let _ = tx.send(probe::run(&config));
+        });
+        self.probe = Some(rx);
+        self.status_line = tr("Testing detection...").to_string();
+    }
+
+    fn poll_probe(&mut self, ctx: &egui::Context) {
+        let Some(rx) = &self.probe else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok(Ok(result)) => {
+                let annotated = probe::annotate(&result, self.config.search_region);
+                let texture = ctx.load_texture(
+                    "detection-test",
+                    egui::ColorImage::from_rgba_unmultiplied(
+                        [annotated.width() as usize, annotated.height() as usize],
+                        annotated.as_raw(),
+                    ),
+                    egui::TextureOptions::LINEAR,
+                );
+                match &result.detection {
+                    Some(detection) => info!(
+                        score = detection.score,
+                        matched = result.matched,
+                        click = ?result.click,
+                        "test detection finished"
+                    ),
+                    None => info!("test detection found no match"),
+                }
+                self.status_line = tr("Test detection finished").to_string();
+                self.probe_view = Some(ProbeView {
+                    texture,
+                    detection: result.detection,
+                    matched: result.matched,
+                    click: result.click,
+                });
+                self.probe = None;
+            }
+            Ok(Err(err)) => {
+                error!(error = ?err, "test detection failed");
+                self.status_line = trf(
+                    "Test detection failed: {error}",
+                    &[("error", &format!("{err:#}"))],
+                );
+                self.probe = None;
+            }
+            Err(TryRecvError::Empty) => ctx.request_repaint_after(Duration::from_millis(100)),
+            Err(TryRecvError::Disconnected) => self.probe = None,
+        }
+    }
+
+    fn render_probe(&mut self, ctx: &egui::Context) {
+        let Some(view) = &self.probe_view else {
+            return;
+        };
+        let mut open = true;
+        let mut again = false;
+        egui::Window::new(tr("Test detection"))
+            .id(egui::Id::new("detection_test"))
+            .open(&mut open)
+            .collapsible(false)
+            .default_width(640.0)
+            .show(ctx, |ui| {
+                match &view.detection {
+                    Some(detection) => {
+                        let (color, verdict) = if view.matched {
+                            (egui::Color32::LIGHT_GREEN, tr("Would click"))
+                        } else {
+                            (egui::Color32::YELLOW, tr("Below the threshold, no click"))
+                        };
+                        ui.colored_label(
+                            color,
+                            trf(
+                                "{verdict}: score {score} (threshold {threshold}), scale {scale}",
+                                &[
+                                    ("verdict", &verdict),
+                                    ("score", &format!("{:.3}", detection.score)),
+                                    (
+                                        "threshold",
+                                        &format!(
+                                            "{:.2}",
+                                            detection.threshold.unwrap_or(self.config.threshold)
+                                        ),
+                                    ),
+                                    ("scale", &format!("{:.2}", detection.scale)),
+                                ],
+                            ),
+                        );
+                    }
+                    None => {
+                        ui.colored_label(
+                            egui::Color32::YELLOW,
+                            tr("The template is larger than the captured screen"),
+                        );
+                    }
+                }
+                if let Some((x, y)) = view.click {
+                    ui.label(trf("Click point: ({x}, {y})", &[("x", &x), ("y", &y)]));
+                }
+                ui.label(tr(
+                    "Blue: search region · Green/yellow: best match · Red: click point",
+                ));
+                let [width, height] = view.texture.size().map(|side| side as f32);
+                let scale = (ui.available_width() / width).min(1.0);
+                ui.image((view.texture.id(), egui::vec2(width, height) * scale));
+                if ui.button(tr("Run again")).clicked() {
+                    again = true;
+                }
+            });
+        if !open {
+            self.probe_view = None;
+        }
+        if again && self.probe.is_none() {
+            self.start_probe();
+        }
+    }
+
+    fn start_tuning_capture(&mut self, phase: Phase) {
+        if let Err(err) = self.apply_template_path_from_input() {
+            self.status_line = tr("Template path error").to_string();
+            error!(error = ?err, "failed to parse template path");
+            return;
+        }
+        let Some(wizard) = &mut self.tuning else {
+            return;
+        };
+        let config = self.config.clone();
+        let (tx, rx) = crossbeam_channel::unbounded();
+        std::thread::spawn(move || tuning::sample(&config, tuning::SAMPLES_PER_CAPTURE, &tx));
+        wizard.capture = Some((phase, rx));
+        wizard.error = None;
+    }
+
+    fn poll_tuning(&mut self, ctx: &egui::Context) {
+        let Some(wizard) = &mut self.tuning else {
+            return;
+        };
+        let Some((phase, rx)) = &wizard.capture else {
+            return;
+        };
+        let phase = *phase;
+        loop {
+            match rx.try_recv() {
+                Ok(Ok(score)) => {
+                    info!(?phase, score, "tuning sample");
+                    wizard.samples.push(phase, score);
+                }
+                Ok(Err(err)) => {
+                    warn!(error = ?err, "tuning capture failed");
+                    wizard.error = Some(format!("{err:#}"));
+                }
+                Err(TryRecvError::Empty) => {
+                    ctx.request_repaint_after(Duration::from_millis(100));
+                    return;
+                }
+                Err(TryRecvError::Disconnected) => {
+                    wizard.capture = None;
+                    return;
+                }
+            }
+        }
+    }
+
+    fn render_tuning(&mut self, ctx: &egui::Context) {
+        let Some(wizard) = &mut self.tuning else {
+            return;
+        };
+        let mut open = true;
+        let mut capture = None;
+        let mut apply = None;
+        let monitoring = self.worker.is_some();
+        egui::Window::new(tr("Tune the threshold"))
+            .id(egui::Id::new("threshold_tuning"))
+            .open(&mut open)
+            .collapsible(false)
+            .default_width(420.0)
+            .show(ctx, |ui| {
+                let capturing = wizard.capture.as_ref().map(|(phase, _)| *phase);
+                if monitoring {
+                    ui.colored_label(
+                        egui::Color32::YELLOW,
+                        tr("Monitoring is running and would accept the ready check; stop it while tuning."),
+                    );
+                }
+                ui.label(tr(
+                    "1. With no ready check on screen (lobby or home screen), capture a few frames.",
+                ));
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(
+                            capturing.is_none(),
+                            egui::Button::new(tr("Capture without the dialog")),
+                        )
+                        .clicked()
+                    {
+                        capture = Some(Phase::Absent);
+                    }
+                    if capturing == Some(Phase::Absent) {
+                        ui.spinner();
+                    }
+                    if let Some(max) = wizard.samples.absent.iter().copied().reduce(f32::max) {
+                        ui.label(trf(
+                            "{count} frames, highest score {score}",
+                            &[
+                                ("count", &wizard.samples.absent.len()),
+                                ("score", &format!("{max:.3}")),
+                            ],
+                        ));
+                    }
+                });
+                ui.label(tr(
+                    "2. Queue up, and as soon as the ready check appears, capture again; it stays about ten seconds.",
+                ));
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(
+                            capturing.is_none(),
+                            egui::Button::new(tr("Capture with the dialog")),
+                        )
+                        .clicked()
+                    {
+                        capture = Some(Phase::Present);
                     }
+                    if capturing == Some(Phase::Present) {
+                        ui.spinner();
+                    }
+                    if let Some(min) = wizard.samples.present.iter().copied().reduce(f32::min) {
+                        ui.label(trf(
+                            "{count} frames, lowest score {score}",
+                            &[
+                                ("count", &wizard.samples.present.len()),
+                                ("score", &format!("{min:.3}")),
+                            ],
+                        ));
+                    }
+                });
+                if let Some(error) = &wizard.error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+                ui.separator();
+                match wizard.samples.separation() {
+                    Some(separation) => {
+                        let gap = format!("{:.3}", separation.gap());
+                        match separation.recommended() {
+                            Some(threshold) => {
+                                ui.colored_label(
+                                    egui::Color32::LIGHT_GREEN,
+                                    trf(
+                                        "Separation {gap}: recommended threshold {threshold}",
+                                        &[
+                                            ("gap", &gap),
+                                            ("threshold", &format!("{threshold:.3}")),
+                                        ],
+                                    ),
+                                );
+                                ui.horizontal(|ui| {
+                                    if ui.button(tr("Apply threshold")).clicked() {
+                                        apply = Some(threshold);
+                                    }
+                                    ui.label(trf(
+                                        "Current: {threshold}",
+                                        &[("threshold", &format!("{:.3}", self.config.threshold))],
+                                    ));
+                                });
+                            }
+                            None if separation.gap() > 0.0 => {
+                                ui.colored_label(
+                                    egui::Color32::YELLOW,
+                                    trf(
+                                        "Separation {gap}, but the threshold between the scores is outside {min} to {max}",
+                                        &[
+                                            ("gap", &gap),
+                                            ("min", &tuning::MIN_THRESHOLD),
+                                            ("max", &tuning::MAX_THRESHOLD),
+                                        ],
+                                    ),
+                                );
+                            }
+                            None => {
+                                ui.colored_label(
+                                    egui::Color32::RED,
+                                    trf(
+                                        "The scores overlap by {gap}; no threshold tells them apart. Try a tighter template or search region.",
+                                        &[("gap", &format!("{:.3}", -separation.gap()))],
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                    None => {
+                        ui.label(tr("Capture both steps to see how far apart the scores are."));
+                    }
+                }
+                if ui
+                    .add_enabled(capturing.is_none(), egui::Button::new(tr("Start over")))
+                    .clicked()
+                {
+                    wizard.samples.clear();
+                    wizard.error = None;
+                }
+            });
+        if let Some(threshold) = apply {
+            info!(threshold, "threshold set by the tuning wizard");
+            self.config.threshold = threshold;
+            self.status_line = trf(
+                "Threshold set to {threshold}; save the settings to keep it",
+                &[("threshold", &format!("{threshold:.3}"))],
+            );
+        }
+        if !open {
+            self.tuning = None;
+        } else if let Some(phase) = capture {
+            self.start_tuning_capture(phase);
+        }
+    }
+
+    fn render_frame_viewer(&mut self, ctx: &egui::Context) {
+        let Some(viewer) = &mut self.frame_viewer else {
+            return;
+        };
+        let now = Instant::now();
+        if let Some(worker) = &self.worker {
+            let due = viewer
+                .requested_at
+                .is_none_or(|requested| now.duration_since(requested) >= FRAME_VIEWER_REFRESH);
+            if viewer.live && due {
+                worker.request_frame();
+                viewer.requested_at = Some(now);
+            }
+            if viewer.live {
+                ctx.request_repaint_after(FRAME_VIEWER_REFRESH);
+            }
+        }
+        let mut open = true;
+        let mut fit = false;
+        egui::Window::new(tr("Last frame"))
+            .id(egui::Id::new("frame_viewer"))
+            .open(&mut open)
+            .default_size([720.0, 480.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut viewer.live, tr("Live"))
+                        .on_hover_text(tr("Fetch a new frame twice a second"));
+                    if ui
+                        .add_enabled(self.worker.is_some(), egui::Button::new(tr("Refresh")))
+                        .clicked()
+                        && let Some(worker) = &self.worker
+                    {
+                        worker.request_frame();
+                        viewer.requested_at = Some(now);
+                    }
+                    ui.separator();
+                    ui.add(
+                        egui::Slider::new(&mut viewer.zoom, FRAME_VIEWER_ZOOM)
+                            .logarithmic(true)
+                            .text(tr("Zoom")),
+                    );
+                    fit = ui.button(tr("Fit")).clicked();
+                });
+                if self.worker.is_none() {
+                    ui.colored_label(
+                        egui::Color32::YELLOW,
+                        tr("Start monitoring to see frames"),
+                    );
+                }
+                let Some(texture) = &viewer.texture else {
+                    if self.worker.is_some() {
+                        ui.label(tr("Waiting for the next frame…"));
+                    }
+                    return;
+                };
+                match &viewer.detection {
+                    Some(detection) => {
+                        let matched = detection.passes(viewer.threshold);
+                        ui.colored_label(
+                            frame_match_color(matched),
+                            trf(
+                                "Best match: score {score} (threshold {threshold}), scale {scale} at ({x}, {y})",
+                                &[
+                                    ("score", &format!("{:.3}", detection.score)),
+                                    (
+                                        "threshold",
+                                        &format!(
+                                            "{:.2}",
+                                            detection.threshold.unwrap_or(viewer.threshold)
+                                        ),
+                                    ),
+                                    ("scale", &format!("{:.2}", detection.scale)),
+                                    ("x", &detection.position.0),
+                                    ("y", &detection.position.1),
+                                ],
+                            ),
+                        );
+                    }
+                    None => {
+                        ui.label(tr("No match in this frame"));
+                    }
+                }
+                if let Some(received) = viewer.received {
+                    ui.label(
+                        RichText::new(trf(
+                            "Captured {time} · Ctrl+scroll to zoom, drag to pan",
+                            &[("time", &received.format("%H:%M:%S%.3f"))],
+                        ))
+                        .small()
+                        .weak(),
+                    );
+                }
+                let [width, height] = texture.size().map(|side| side as f32);
+                if fit {
+                    let available = ui.available_size();
+                    viewer.zoom = (available.x / width)
+                        .min(available.y / height)
+                        .clamp(*FRAME_VIEWER_ZOOM.start(), *FRAME_VIEWER_ZOOM.end());
+                }
+                egui::ScrollArea::both()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        let response = ui.add(
+                            egui::Image::new((
+                                texture.id(),
+                                egui::vec2(width, height) * viewer.zoom,
+                            ))
+                            .sense(egui::Sense::hover()),
+                        );
+                        if response.hovered() {
+                            let delta = ui.input(|i| i.zoom_delta());
+                            if delta != 1.0 {
+                                viewer.zoom = (viewer.zoom * delta)
+                                    .clamp(*FRAME_VIEWER_ZOOM.start(), *FRAME_VIEWER_ZOOM.end());
+                            }
+                        }
+                        if let Some(detection) = &viewer.detection {
+                            let (x, y) = detection.position;
+                            let (w, h) = detection.template_size;
+                            let min = response.rect.min
+                                + egui::vec2(x as f32, y as f32) * viewer.zoom;
+                            let rect = egui::Rect::from_min_size(
+                                min,
+                                egui::vec2(w as f32, h as f32) * viewer.zoom,
+                            );
+                            ui.painter().rect_stroke(
+                                rect,
+                                0.0,
+                                egui::Stroke::new(
+                                    2.0,
+                                    frame_match_color(detection.passes(viewer.threshold)),
+                                ),
+                            );
+                        }
+                    });
+            });
+        if !open {
+            self.frame_viewer = None;
+        }
+    }
+
+    fn render_template_manager(&mut self, ctx: &egui::Context) {
+        let Some(manager) = &mut self.template_manager else {
+            return;
+        };
+        let mut open = true;
+        let mut status = None;
+        egui::Window::new(tr("Templates"))
+            .id(egui::Id::new("template_manager"))
+            .open(&mut open)
+            .default_width(560.0)
+            .show(ctx, |ui| {
+                if self.config.template_path.is_some() {
+                    ui.colored_label(
+                        egui::Color32::YELLOW,
+                        tr("The template path is set, so only that file is used; clear it to use this list."),
+                    );
+                }
+                ui.label(tr("Enabled templates are all matched; the best match relative to its threshold wins. Changes apply the next time monitoring starts."));
+                let mut known = templates::known(&self.config.templates, &manager.discovered);
+                let mut changed = None;
+                let mut removed = None;
+                egui::ScrollArea::vertical()
+                    .max_height(360.0)
+                    .show(ui, |ui| {
+                        egui::Grid::new("template_grid")
+                            .num_columns(5)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for (index, template) in known.iter_mut().enumerate() {
+                                    if ui.checkbox(&mut template.enabled, "").changed() {
+                                        changed = Some(index);
+                                    }
+                                    match manager.thumbnail(ctx, &template.path) {
+                                        Some(texture) => {
+                                            let [width, height] =
+                                                texture.size().map(|side| side as f32);
+                                            let scale = (TEMPLATE_THUMBNAIL_WIDTH / width).min(1.0);
+                                            ui.image((texture.id(), egui::vec2(width, height) * scale))
+                                                .on_hover_text(template.path.display().to_string());
+                                        }
+                                        None => {
+                                            ui.colored_label(egui::Color32::LIGHT_RED, tr("Unreadable"))
+                                                .on_hover_text(template.path.display().to_string());
+                                        }
+                                    }
+                                    ui.vertical(|ui| {
+                                        ui.label(template.name());
+                                        ui.weak(template_kind_label(&template.kind));
+                                    });
+                                    ui.horizontal(|ui| {
+                                        let mut own = template.threshold.is_some();
+                                        if ui
+                                            .checkbox(&mut own, tr("Own threshold"))
+                                            .changed()
+                                        {
+                                            template.threshold = own.then_some(self.config.threshold);
+                                            changed = Some(index);
+                                        }
+                                        if let Some(threshold) = &mut template.threshold
+                                            && ui
+                                                .add(egui::DragValue::new(threshold).range(0.5..=1.0).speed(0.005))
+                                                .changed()
+                                        {
+                                            changed = Some(index);
+                                        }
+                                    });
+                                    if template.kind == TemplateKind::User {
+                                        if ui.button(tr("Remove")).clicked() {
+                                            removed = Some(index);
+                                        }
+                                    } else {
+                                        ui.label("");
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+                if let Some(index) = changed {
+                    templates::update(&mut self.config.templates, &known[index]);
+                }
+                if let Some(index) = removed {
+                    templates::remove(&mut self.config.templates, &known[index].path);
+                }
+                if known.iter().all(|template| !template.enabled) {
+                    ui.colored_label(egui::Color32::YELLOW, tr("No template is enabled"));
+                }
+                ui.horizontal(|ui| {
+                    if ui.button(tr("Add…")).clicked()
+                        && let Some(path) = rfd::FileDialog::new()
+                            .add_filter(tr("Images"), &["png"])
+                            .pick_file()
+                    {
+                        status = Some(if templates::add(&mut self.config.templates, path) {
+                            tr("Template added").to_string()
+                        } else {
+                            tr("That template is already listed").to_string()
+                        });
+                    }
+                    if ui.button(tr("Rescan")).clicked() {
+                        manager.discovered = templates::discover(&templates::bundled_dirs());
+                        manager.thumbnails.clear();
+                    }
+                });
+            });
+        if let Some(status) = status {
+            self.status_line = status;
+        }
+        if !open {
+            self.template_manager = None;
+        }
+    }
+
+    fn render_region_editor(&mut self, ctx: &egui::Context) {
+        let Some(editor) = &mut self.region_editor else {
+            return;
+        };
+        let mut open = true;
+        let mut close = false;
+        egui::Window::new(tr("Search region"))
+            .id(egui::Id::new("search_region_editor"))
+            .open(&mut open)
+            .collapsible(false)
+            .default_width(640.0)
+            .show(ctx, |ui| {
+                ui.label(tr(
+                    "Drag a box around the area where the Accept button appears.",
+                ));
+                drag_select(ui, &editor.texture, &mut editor.selection);
+                let region = editor
+                    .selection
+                    .map(|(start, end)| SearchRegion::from_corners(start, end))
+                    .filter(|region| region.width > 0 && region.height > 0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(region.is_some(), egui::Button::new(tr("Apply")))
+                        .clicked()
+                    {
+                        self.config.search_region = region;
+                        close = true;
+                    }
+                    if ui.button(tr("Cancel")).clicked() {
+                        close = true;
+                    }
+                });
+            });
+        if close || !open {
+            self.region_editor = None;
+        }
+    }
+
+    fn render_lcu_settings(&mut self, ui: &mut egui::Ui) {
+        let response = egui::CollapsingHeader::new(tr("League client"))
+            .id_source("league_client")
+            .default_open(self.layout.section_open("league_client", false))
+            .show(ui, |ui| {
+                let lcu = &mut self.config.lcu;
+                ui.checkbox(&mut lcu.listen_events, tr("Hybrid: client events first, screen as fallback"))
+                    .on_hover_text(tr("Accept as soon as the client announces a ready check. While the client cannot be reached the screen is watched instead, and accepts click even when Accept with is Client API"));
+                ui.checkbox(&mut lcu.gate_capture, tr("Only watch the screen while queued"))
+                    .on_hover_text(tr("Pause capture in the lobby, champ select and in game; capture runs as usual whenever the client cannot be reached"));
+                ui.checkbox(&mut lcu.queue_status, tr("Show queue status"))
+                    .on_hover_text(tr("Show the client's phase, time in queue and ready-check countdown in the status panel"));
+                if let Some(path) = &lcu.lockfile {
+                    ui.label(trf("Lockfile: {path}", &[("path", &path.display())]));
+                }
+                let honor = &mut self.config.honor;
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut honor.enabled, tr("Honor a teammate after the game"))
+                        .on_hover_text(tr("Vote on the honor screen so it does not hold up the return to the lobby"));
+                    ui.add_enabled_ui(honor.enabled, |ui| {
+                        ui.radio_value(&mut honor.target, HonorTarget::Random, tr("Random"));
+                        ui.radio_value(&mut honor.target, HonorTarget::BestPerformance, tr("Best KDA"));
+                    });
+                });
+                let requeue = &mut self.config.requeue;
+                ui.checkbox(&mut requeue.enabled, tr("Re-queue after a dodge"))
+                    .on_hover_text(tr("Search again when champ select ends and the party is back in the lobby"));
+                ui.add_enabled_ui(requeue.enabled, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(tr("At most"));
+                        ui.add(egui::DragValue::new(&mut requeue.max_retries).range(1..=20));
+                        ui.label(tr("times per game, after"));
+                        ui.add(
+                            egui::DragValue::new(&mut requeue.delay_ms)
+                                .speed(100)
+                                .range(0..=30_000)
+                                .suffix(" ms"),
+                        );
+                    });
+                });
+            });
+        self.layout
+            .set_section_open("league_client", response.openness > 0.5);
+    }
+
+    fn render_champ_select_settings(&mut self, ui: &mut egui::Ui) {
+        let response = egui::CollapsingHeader::new(tr("Champ select"))
+            .id_source("champ_select")
+            .default_open(self.layout.section_open("champ_select", false))
+            .show(ui, |ui| {
+                let auto_pick = &mut self.config.auto_pick;
+                ui.checkbox(&mut auto_pick.enabled, tr("Auto pick"))
+                    .on_hover_text(tr("Hover and lock the first available champion from your list through the client API"));
+                ui.add_enabled_ui(auto_pick.enabled, |ui| {
+                    queue_toggles(ui, &mut auto_pick.queues);
+                    champion_lists(ui, "pick_lists", &mut auto_pick.champions, &mut self.pick_inputs);
+                    ui.label(
+                        RichText::new(tr("Fill is used in blind pick and after the role's own list."))
+                            .small(),
+                    );
+                });
+
+                ui.separator();
+                let auto_ban = &mut self.config.auto_ban;
+                ui.checkbox(&mut auto_ban.enabled, tr("Auto ban"))
+                    .on_hover_text(tr("Ban the first champion from your list that nobody has banned, picked or declared"));
+                ui.add_enabled_ui(auto_ban.enabled, |ui| {
+                    queue_toggles(ui, &mut auto_ban.queues);
+                    ui.horizontal(|ui| {
+                        ui.label(tr("Ban after"));
+                        ui.add(
+                            egui::DragValue::new(&mut auto_ban.delay_ms)
+                                .speed(100)
+                                .range(0..=25_000)
+                                .suffix(" ms"),
+                        )
+                        .on_hover_text(tr("Give teammates time to declare their picks before banning"));
+                    });
+                    champion_lists(ui, "ban_lists", &mut auto_ban.champions, &mut self.ban_inputs);
+                });
+
+                ui.separator();
+                let aram_bench = &mut self.config.aram_bench;
+                ui.checkbox(&mut aram_bench.enabled, tr("ARAM bench swap"))
+                    .on_hover_text(tr("Take a champion from the bench when it ranks above yours on the wishlist"));
+                ui.add_enabled_ui(aram_bench.enabled, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(tr("Wishlist"));
+                        if ui
+                            .add(
+                                egui::TextEdit::singleline(&mut self.bench_input)
+                                    .desired_width(260.0)
+                                    .hint_text(tr("most wanted first, e.g. Jinx, Lux")),
+                            )
+                            .changed()
+                        {
+                            aram_bench.champions = parse_champion_list(&self.bench_input);
+                        }
+                    });
+                });
+
+                ui.separator();
+                let trades = &mut self.config.trades;
+                egui::Grid::new("trade_responses").num_columns(2).show(ui, |ui| {
+                    ui.label(tr("Champion trades"));
+                    trade_response_combo(ui, "champion_trades", &mut trades.champion_trades);
+                    ui.end_row();
+                    ui.label(tr("Pick order swaps"));
+                    trade_response_combo(ui, "pick_order_swaps", &mut trades.pick_order_swaps);
+                    ui.end_row();
+                });
+                ui.add_enabled_ui(trades.champion_trades == TradeResponse::Accept, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(tr("Willing to give up"));
+                        if ui
+                            .add(
+                                egui::TextEdit::singleline(&mut self.give_up_input)
+                                    .desired_width(260.0)
+                                    .hint_text(tr("e.g. Yuumi, Teemo")),
+                            )
+                            .changed()
+                        {
+                            trades.give_up = parse_champion_list(&self.give_up_input);
+                        }
+                    })
+                    .response
+                    .on_hover_text(tr("Trades for any other champion are left for you to answer"));
+                });
+
+                ui.separator();
+                let runes = &mut self.config.runes;
+                ui.checkbox(&mut runes.enabled, tr("Import runes"))
+                    .on_hover_text(tr("Write a rune page for your champion once it is locked in"));
+                ui.add_enabled_ui(runes.enabled, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(tr("Source"));
+                        let is_url = matches!(runes.source, RuneSource::Url { .. });
+                        if ui
+                            .radio(runes.source == RuneSource::Client, tr("Client recommendation"))
+                            .clicked()
+                        {
+                            runes.source = RuneSource::Client;
+                        }
+                        if ui
+                            .radio(runes.source == RuneSource::Presets, tr("Presets only"))
+                            .clicked()
+                        {
+                            runes.source = RuneSource::Presets;
+                        }
+                        if ui.radio(is_url, "URL").clicked() && !is_url {
+                            runes.source = RuneSource::Url {
+                                template: String::new(),
+                            };
+                        }
+                    });
+                    if let RuneSource::Url { template } = &mut runes.source {
+                        ui.add(
+                            egui::TextEdit::singleline(template)
+                                .desired_width(320.0)
+                                .hint_text("https://example.com/runes/{champion}/{position}.json"),
+                        );
+                    }
+                    ui.horizontal(|ui| {
+                        ui.label(tr("Page name"));
+                        ui.text_edit_singleline(&mut runes.page_name);
+                    });
+                    ui.label(
+                        RichText::new(format!(
+                            "{} preset(s) from the config file are checked first.",
+                            runes.presets.len()
+                        ))
+                        .small(),
+                    );
+                });
+
+                ui.separator();
+                render_spell_settings(ui, &mut self.config.summoner_spells);
+            });
+        self.layout
+            .set_section_open("champ_select", response.openness > 0.5);
+    }
+
+    fn render_scale_settings(&mut self, ui: &mut egui::Ui) {
+        let response = egui::CollapsingHeader::new(tr("Template scales"))
+            .id_source("template_scales")
+            .default_open(self.layout.section_open("template_scales", false))
+            .show(ui, |ui| {
+                ui.label(tr(
+                    "The template is matched at each of these sizes on every frame. More scales cope with more client sizes but cost more CPU.",
+                ));
+                let mut scales = self.config.template_scales.clone();
+                ui.horizontal_wrapped(|ui| {
+                    let mut removed = None;
+                    for (index, scale) in scales.iter().enumerate() {
+                        if ui
+                            .button(format!("{scale:.2} ✕"))
+                            .on_hover_text(tr("Remove this scale"))
+                            .clicked()
+                        {
+                            removed = Some(index);
+                        }
+                    }
+                    if let Some(index) = removed {
+                        scales.remove(index);
+                    }
+                    if scales.is_empty() {
+                        ui.label(tr("None: only the template's own size is searched"));
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::DragValue::new(&mut self.scale_input)
+                            .range(scales::MIN_SCALE..=scales::MAX_SCALE)
+                            .speed(0.01)
+                            .fixed_decimals(2),
+                    );
+                    if ui
+                        .add_enabled(
+                            scales.len() < scales::MAX_SCALES,
+                            egui::Button::new(tr("Add")),
+                        )
+                        .clicked()
+                    {
+                        scales.push(self.scale_input);
+                    }
+                    if ui.button(tr("Reset to defaults")).clicked() {
+                        scales = scales::DEFAULT_SCALES.to_vec();
+                    }
+                });
+                ui.horizontal(|ui| {
+                    let [start, end, step] = &mut self.scale_range;
+                    let bounds = scales::MIN_SCALE..=scales::MAX_SCALE;
+                    ui.label(tr("Range"));
+                    ui.add(
+                        egui::DragValue::new(start)
+                            .range(bounds.clone())
+                            .speed(0.01)
+                            .fixed_decimals(2),
+                    );
+                    ui.label(tr("to"));
+                    ui.add(
+                        egui::DragValue::new(end)
+                            .range(bounds)
+                            .speed(0.01)
+                            .fixed_decimals(2),
+                    );
+                    ui.label(tr("step"));
+                    ui.add(
+                        egui::DragValue::new(step)
+                            .range(0.01..=1.0)
+                            .speed(0.005)
+                            .fixed_decimals(2),
+                    );
+                    let range = scales::range(*start, *end, *step);
+                    let button = ui
+                        .add_enabled(range.is_some(), egui::Button::new(tr("Replace with range")))
+                        .on_disabled_hover_text(trf(
+                            "The range must be non-empty and hold at most {max} scales",
+                            &[("max", &scales::MAX_SCALES)],
+                        ));
+                    if button.clicked()
+                        && let Some(range) = range
+                    {
+                        scales = range;
+                    }
+                });
+                if scales != self.config.template_scales {
+                    self.config.template_scales = scales::normalized(&scales);
+                }
+                self.render_scale_cost(ui);
+                ui.label(
+                    RichText::new(tr("Changes apply the next time monitoring starts."))
+                        .small()
+                        .weak(),
+                );
+            });
+        self.layout
+            .set_section_open("template_scales", response.openness > 0.5);
+    }
+
+    /// Variant count and estimated per-frame matching cost of the edited scale list.
+    fn render_scale_cost(&mut self, ui: &mut egui::Ui) {
+        let now = Instant::now();
+        let stale = self
+            .template_sizes
+            .as_ref()
+            .is_none_or(|(read_at, _)| now.duration_since(*read_at) >= TEMPLATE_SIZES_REFRESH);
+        if stale {
+            self.template_sizes = Some((now, templates::enabled_sizes(&self.config)));
+        }
+        let sizes = self
+            .template_sizes
+            .as_ref()
+            .map(|(_, sizes)| sizes.as_slice())
+            .unwrap_or_default();
+        let area = match (
+            self.config.search_region,
+            self.monitors.get(self.config.monitor_index),
+        ) {
+            (Some(region), _) => Some((region.width, region.height)),
+            (None, Some(monitor)) => Some((
+                (monitor.width as f32 * monitor.scale_factor).round() as u32,
+                (monitor.height as f32 * monitor.scale_factor).round() as u32,
+            )),
+            (None, None) => None,
+        };
+        let scales = scales::effective(&self.config.template_scales);
+        let variants: usize = sizes
+            .iter()
+            .map(|&size| {
+                scales
+                    .iter()
+                    .filter(|&&scale| scales::variant_size(size, scale).is_some())
+                    .count()
+            })
+            .sum();
+        ui.label(trf(
+            "{variants} variants from {templates} template(s)",
+            &[("variants", &variants), ("templates", &sizes.len())],
+        ));
+        let Some(area) = area else {
+            return;
+        };
+        let cost = |scales: &[f32]| -> u64 {
+            sizes
+                .iter()
+                .map(|&size| scales::match_cost(size, area, scales))
+                .sum()
+        };
+        let estimate = cost(&scales);
+        ui.label(trf(
+            "About {cost} pixel comparisons per frame on {width}x{height}",
+            &[
+                ("cost", &scales::format_cost(estimate)),
+                ("width", &area.0),
+                ("height", &area.1),
+            ],
+        ));
+        let measured = self
+            .metrics
+            .as_ref()
+            .filter(|_| self.running)
+            .map(|metrics| metrics.avg_match_ms);
+        match measured.and_then(|ms| scales::estimate_ms(ms, cost(&self.worker_scales), estimate)) {
+            Some(ms) => {
+                ui.label(trf(
+                    "Estimated matching time: {ms} ms per frame (now {now} ms)",
+                    &[
+                        ("ms", &format!("{ms:.1}")),
+                        ("now", &format!("{:.1}", measured.unwrap_or_default())),
+                    ],
+                ));
+            }
+            None => {
+                ui.label(
+                    RichText::new(tr("Start monitoring to estimate the time per frame"))
+                        .small()
+                        .weak(),
+                );
+            }
+        }
+    }
+
+    fn render_appearance_settings(&mut self, ui: &mut egui::Ui) {
+        let response = egui::CollapsingHeader::new(tr("Appearance"))
+            .id_source("appearance")
+            .default_open(self.layout.section_open("appearance", false))
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(tr("Language"));
+                    ComboBox::from_id_source("language")
+                        .selected_text(self.config.language.native_name())
+                        .show_ui(ui, |ui| {
+                            for language in Language::ALL {
+                                ui.selectable_value(
+                                    &mut self.config.language,
+                                    language,
+                                    language.native_name(),
+                                );
+                            }
+                        });
+                });
+                let theme = &mut self.config.theme;
+                ui.horizontal(|ui| {
+                    ui.label(tr("Theme"));
+                    ui.radio_value(&mut theme.mode, ThemeMode::System, tr("System"));
+                    ui.radio_value(&mut theme.mode, ThemeMode::Dark, tr("Dark"));
+                    ui.radio_value(&mut theme.mode, ThemeMode::Light, tr("Light"));
+                });
+                ui.horizontal(|ui| {
+                    ui.label(tr("Status colors"));
+                    ComboBox::from_id_source("indicator_palette")
+                        .selected_text(tr(theme.indicator_palette.label()))
+                        .show_ui(ui, |ui| {
+                            for palette in IndicatorPalette::ALL {
+                                ui.selectable_value(
+                                    &mut theme.indicator_palette,
+                                    palette,
+                                    tr(palette.label()),
+                                );
+                            }
+                        })
+                        .response
+                        .on_hover_text(tr(
+                            "Colorblind-friendly and high-contrast palettes also give each state its own shape: ring when idle, dot while monitoring, pause bars during the cooldown, cross on errors",
+                        ));
+                });
+                ui.horizontal(|ui| {
+                    let mut custom = theme.accent.is_some();
+                    if ui.checkbox(&mut custom, tr("Accent color")).changed() {
+                        theme.accent = custom.then_some(DEFAULT_ACCENT);
+                    }
+                    if let Some(accent) = &mut theme.accent {
+                        ui.color_edit_button_srgb(accent);
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label(tr("UI scale"));
+                    ui.add(
+                        egui::Slider::new(&mut self.config.ui_scale, theme::UI_SCALE_RANGE)
+                            .step_by(0.05)
+                            .custom_formatter(|value, _| format!("{:.0}%", value * 100.0))
+                            .custom_parser(|text| {
+                                let percent = text.trim().trim_end_matches('%').trim();
+                                percent.parse::<f64>().ok().map(|value| value / 100.0)
+                            }),
+                    )
+                    .on_hover_text(tr("Enlarges text and widgets on high-DPI screens"));
+                    if ui.button(tr("Reset")).clicked() {
+                        self.config.ui_scale = 1.0;
+                    }
+                });
+                ui.checkbox(&mut self.config.toasts, tr("Pop-up notifications"))
+                    .on_hover_text(tr(
+                        "Show saves, detections and errors briefly in the corner of the window",
+                    ));
+            });
+        self.layout
+            .set_section_open("appearance", response.openness > 0.5);
+    }
+
+    fn render_schedule_settings(&mut self, ui: &mut egui::Ui) {
+        let response = egui::CollapsingHeader::new(tr("Schedule"))
+            .id_source("schedule")
+            .default_open(self.layout.section_open("schedule", false))
+            .show(ui, |ui| {
+                let schedule = &mut self.config.schedule;
+                ui.checkbox(&mut schedule.enabled, tr("Run only on a schedule"))
+                    .on_hover_text(tr(
+                        "Monitoring starts when a time window opens and stops when it closes",
+                    ));
+                let mut remove = None;
+                for (index, window) in schedule.windows.iter_mut().enumerate() {
+                    ui.horizontal_wrapped(|ui| {
+                        for day in WEEKDAYS {
+                            let mut on = window.days.contains(&day);
+                            if ui.toggle_value(&mut on, weekday_label(day)).changed() {
+                                if on {
+                                    window.days.push(day);
+                                    window.days.sort_by_key(|day| day.num_days_from_monday());
+                                } else {
+                                    window.days.retain(|d| *d != day);
+                                }
+                            }
+                        }
+                        time_input(ui, &mut window.start);
+                        ui.label("–");
+                        time_input(ui, &mut window.end);
+                        if ui.button(tr("Remove")).clicked() {
+                            remove = Some(index);
+                        }
+                    });
+                }
+                if let Some(index) = remove {
+                    schedule.windows.remove(index);
+                }
+                if ui.button(tr("Add time window")).clicked() {
+                    schedule.windows.push(ScheduleWindow::default());
+                }
+                match self.schedule_active {
+                    Some(true) => ui.label(tr("Inside a scheduled window")),
+                    Some(false) => ui.label(tr("Outside the schedule; monitoring stays off")),
+                    None => ui.label(""),
+                };
+            });
+        self.layout
+            .set_section_open("schedule", response.openness > 0.5);
+    }
+
+    fn render_auto_stop_settings(&mut self, ui: &mut egui::Ui) {
+        let auto_stop = &mut self.config.auto_stop;
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut auto_stop.enabled, tr("Stop after idle"))
+                .on_hover_text(tr(
+                    "Stops monitoring when nothing has been detected for this long",
+                ));
+            ui.add_enabled(
+                auto_stop.enabled,
+                egui::DragValue::new(&mut auto_stop.after_minutes)
+                    .range(1..=1440)
+                    .suffix(" min"),
+            );
+        });
+        ui.add_enabled_ui(auto_stop.enabled, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(tr("Warn before stopping"));
+                ui.add(
+                    egui::DragValue::new(&mut auto_stop.warn_minutes)
+                        .range(0..=60)
+                        .suffix(" min"),
+                )
+                .on_hover_text(tr("0 disables the warning"));
+            });
+        });
+    }
+
+    fn render_battery_settings(&mut self, ui: &mut egui::Ui) {
+        let battery = &mut self.config.battery;
+        ui.checkbox(&mut battery.enabled, tr("Slow down on battery"))
+            .on_hover_text(tr(
+                "On a laptop running on battery, poll less often and match fewer scales; full speed returns when plugged in",
+            ));
+        ui.add_enabled_ui(battery.enabled, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(tr("Interval on battery"));
+                ui.add(
+                    egui::DragValue::new(&mut battery.interval_ms)
+                        .range(10..=5000)
+                        .suffix(" ms"),
+                );
+                ui.label(tr("Scales on battery"));
+                ui.add(egui::DragValue::new(&mut battery.max_scales).range(1..=scales::MAX_SCALES));
+            });
+            ui.checkbox(
+                &mut battery.skip_unchanged,
+                tr("Skip unchanged frames on battery"),
+            );
+        });
+    }
+
+    fn render_client_settings(&mut self, ui: &mut egui::Ui) {
+        let response = egui::CollapsingHeader::new(tr("More clients"))
+            .id_source("clients")
+            .default_open(self.layout.section_open("clients", false))
+            .show(ui, |ui| {
+                ui.label(tr(
+                    "Watch further League clients on other monitors, each with its own worker, monitor, template and cooldown. The main client uses the settings above.",
+                ));
+                ui.label(
+                    RichText::new(tr(
+                        "Extra clients accept by screen only: client API features stay with the main client. Clicks take turns with the mouse. Changes apply the next time monitoring starts.",
+                    ))
+                    .small(),
+                );
+                let count = self.monitors.len();
+                let mut removed = None;
+                for (index, slot) in self.config.extra_clients.iter_mut().enumerate() {
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut slot.enabled, "");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut slot.name)
+                                .hint_text(client_name(index, &ClientSlot::default()))
+                                .desired_width(140.0),
+                        );
+                        if ui.button(tr("Remove")).clicked() {
+                            removed = Some(index);
+                        }
+                    });
+                    egui::Grid::new(("client_slot", index)).show(ui, |ui| {
+                        ui.label(tr("Monitor"));
+                        ComboBox::from_id_source(("client_monitor", index))
+                            .selected_text(monitor_label(
+                                slot.monitor_index,
+                                self.monitors.get(slot.monitor_index),
+                                count,
+                            ))
+                            .show_ui(ui, |ui| {
+                                for (monitor, info) in self.monitors.iter().enumerate() {
+                                    if ui
+                                        .selectable_value(
+                                            &mut slot.monitor_index,
+                                            monitor,
+                                            monitor_label(monitor, Some(info), count),
+                                        )
+                                        .clicked()
+                                    {
+                                        slot.monitor_id = Some(info.id);
+                                    }
+                                }
+                            });
+                        ui.end_row();
+                        ui.label(tr("Template"));
+                        let mut path = slot
+                            .template_path
+                            .as_ref()
+                            .map(|path| path.display().to_string())
+                            .unwrap_or_default();
+                        if ui
+                            .add(
+                                egui::TextEdit::singleline(&mut path)
+                                    .hint_text(tr("same as the main client")),
+                            )
+                            .changed()
+                        {
+                            slot.template_path =
+                                (!path.trim().is_empty()).then(|| PathBuf::from(path.trim()));
+                        }
+                        ui.end_row();
+                        ui.label(tr("Cooldown"));
+                        ui.add(
+                            egui::DragValue::new(&mut slot.cooldown_ms)
+                                .range(0..=60_000)
+                                .suffix(" ms"),
+                        );
+                        ui.end_row();
+                    });
+                }
+                if let Some(index) = removed {
+                    self.config.extra_clients.remove(index);
+                }
+                if ui.button(tr("Add client")).clicked() {
+                    let used: Vec<usize> = std::iter::once(self.config.monitor_index)
+                        .chain(self.config.extra_clients.iter().map(|slot| slot.monitor_index))
+                        .collect();
+                    let monitor_index = (0..count.max(1))
+                        .find(|index| !used.contains(index))
+                        .unwrap_or(0);
+                    self.config.extra_clients.push(ClientSlot {
+                        monitor_index,
+                        monitor_id: self.monitors.get(monitor_index).map(|info| info.id),
+                        cooldown_ms: self.config.cooldown_ms,
+                        ..ClientSlot::default()
+                    });
+                }
+            });
+        self.layout
+            .set_section_open("clients", response.openness > 0.5);
+    }
+
+    fn render_streamer_settings(&mut self, ui: &mut egui::Ui) {
+        let response = egui::CollapsingHeader::new(tr("Streamer mode"))
+            .id_source("streamer")
+            .default_open(self.layout.section_open("streamer", false))
+            .show(ui, |ui| {
+                let streamer = &mut self.config.streamer;
+                ui.checkbox(&mut streamer.enabled, tr("Streamer mode"))
+                    .on_hover_text(tr("Keep this app off your stream and screen shares"));
+                ui.add_enabled_ui(streamer.enabled, |ui| {
+                    ui.checkbox(
+                        &mut streamer.hide_windows,
+                        tr("Hide the window and overlay from screen capture"),
+                    )
+                    .on_hover_text(tr(
+                        "OBS, Discord and other screen sharing see nothing where the app is; you still see it. Needs Windows 10 version 2004 or later, older versions show a black box.",
+                    ));
+                    ui.checkbox(
+                        &mut streamer.hide_notifications,
+                        tr("Hide notification details"),
+                    )
+                    .on_hover_text(tr(
+                        "No desktop pop-ups; Discord and Telegram get a plain line without scores, times or screenshots",
+                    ));
+                });
+                if !cfg!(windows) && streamer.hide_windows {
+                    ui.colored_label(
+                        egui::Color32::YELLOW,
+                        tr("Hiding windows from capture is only available on Windows"),
+                    );
+                }
+                ui.label(
+                    RichText::new(tr(
+                        "Takes effect right away for the window; notifications follow the next time monitoring starts.",
+                    ))
+                    .small()
+                    .weak(),
+                );
+            });
+        self.layout
+            .set_section_open("streamer", response.openness > 0.5);
+    }
+
+    fn render_notification_settings(&mut self, ui: &mut egui::Ui) {
+        let response = egui::CollapsingHeader::new(tr("Notifications"))
+            .id_source("notifications")
+            .default_open(self.layout.section_open("notifications", false))
+            .show(ui, |ui| {
+                ui.checkbox(
+                    &mut self.config.notifications.desktop,
+                    tr("Desktop notification on accept"),
+                )
+                .on_hover_text(tr(
+                    "Show a system notification with the time and match score",
+                ));
+                let discord = &mut self.config.notifications.discord;
+                ui.checkbox(&mut discord.enabled, tr("Discord webhook"));
+                ui.horizontal(|ui| {
+                    ui.label(tr("Webhook URL"));
+                    ui.add(
+                        egui::TextEdit::singleline(&mut discord.webhook_url)
+                            .password(true)
+                            .hint_text("https://discord.com/api/webhooks/…"),
+                    );
+                    let idle = self.notification_test.is_none();
+                    if ui
+                        .add_enabled(idle, egui::Button::new(tr("Test webhook")))
+                        .clicked()
+                    {
+                        self.notification_test = Some(notifications::send_test(Box::new(
+                            DiscordWebhook::new(discord),
+                        )));
+                    }
+                });
+                ui.checkbox(&mut discord.attach_screenshot, tr("Attach detection frame"))
+                    .on_hover_text(tr("Add the grayscale frame the Accept button was found in, as the matcher saw it"));
+                let telegram = &mut self.config.notifications.telegram;
+                ui.checkbox(&mut telegram.enabled, tr("Telegram bot"))
+                    .on_hover_text(tr("Also reports found matches and worker errors"));
+                egui::Grid::new("telegram_settings").show(ui, |ui| {
+                    ui.label(tr("Bot token"));
+                    ui.add(egui::TextEdit::singleline(&mut telegram.bot_token).password(true));
+                    ui.end_row();
+                    ui.label(tr("Chat ID"));
+                    ui.text_edit_singleline(&mut telegram.chat_id);
+                    let idle = self.notification_test.is_none();
+                    if ui
+                        .add_enabled(idle, egui::Button::new(tr("Test Telegram")))
+                        .clicked()
+                    {
+                        self.notification_test = Some(notifications::send_test(Box::new(
+                            TelegramBot::new(telegram),
+                        )));
+                    }
+                    ui.end_row();
+                });
+                ui.separator();
+                let sound = &mut self.config.sound;
+                render_sound_cue(
+                    ui,
+                    tr("Detection sound"),
+                    &mut sound.detection,
+                    &mut self.sound,
+                );
+                render_sound_cue(ui, tr("Click sound"), &mut sound.click, &mut self.sound);
+                ui.separator();
+                let speech = &mut self.config.speech;
+                ui.checkbox(&mut speech.enabled, tr("Voice announcements"))
+                    .on_hover_text(tr(
+                        "Read the phrases out loud with the system's text-to-speech voice, in addition to the sounds",
+                    ));
+                ui.add_enabled_ui(speech.enabled, |ui| {
+                    egui::Grid::new("speech_settings").show(ui, |ui| {
+                        ui.label(tr("When found"));
+                        ui.text_edit_singleline(&mut speech.found)
+                            .on_hover_text(tr("Leave empty to stay silent"));
+                        ui.end_row();
+                        ui.label(tr("When accepted"));
+                        ui.text_edit_singleline(&mut speech.accepted)
+                            .on_hover_text(tr("Leave empty to stay silent"));
+                        ui.end_row();
+                    });
+                    ui.horizontal(|ui| {
+                        ui.add(egui::Slider::new(&mut speech.volume, 0.0..=1.0).text(tr("volume")));
+                        let text = format!("{} {}", speech.found.trim(), speech.accepted.trim());
+                        if ui
+                            .add_enabled(!text.trim().is_empty(), egui::Button::new(tr("Test")))
+                            .clicked()
+                        {
+                            self.speech.say(text.trim(), speech.volume);
+                        }
+                    });
+                });
+            });
+        self.layout
+            .set_section_open("notifications", response.openness > 0.5);
+    }
+
+    fn render_input_settings(&mut self, ui: &mut egui::Ui) {
+        let response = egui::CollapsingHeader::new(tr("Input"))
+            .id_source("input")
+            .default_open(self.layout.section_open("input", false))
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(tr("Backend"));
+                    let backend = &mut self.config.input.backend;
+                    egui::ComboBox::from_id_source("input_backend")
+                        .selected_text(input_backend_label(*backend))
+                        .show_ui(ui, |ui| {
+                            for kind in [
+                                InputBackendKind::Enigo,
+                                InputBackendKind::SendInput,
+                                InputBackendKind::Xdotool,
+                                InputBackendKind::Interception,
+                            ] {
+                                ui.selectable_value(backend, kind, input_backend_label(kind));
+                            }
+                        })
+                        .response
+                        .on_hover_text(tr("Try another backend if the client ignores clicks"));
+                });
+                if self.config.input.backend == InputBackendKind::Interception {
+                    ui.colored_label(
+                        egui::Color32::from_rgb(220, 120, 60),
+                        tr("The Interception backend injects input through a kernel driver. Install the driver yourself and check your game's rules before enabling it."),
+                    );
+                    ui.checkbox(
+                        &mut self.config.input.allow_driver_input,
+                        tr("I understand, allow driver-level input"),
+                    );
+                }
+                ui.horizontal(|ui| {
+                    ui.label(tr("Accept with"));
+                    let input = &mut self.config.input;
+                    ui.radio_value(&mut input.accept_mode, AcceptMode::Click, tr("Click"));
+                    ui.radio_value(&mut input.accept_mode, AcceptMode::Key, tr("Key"))
+                        .on_hover_text(tr("Leaves the cursor alone; the client must have keyboard focus"));
+                    ui.radio_value(&mut input.accept_mode, AcceptMode::ClickAndKey, tr("Both"));
+                    ui.radio_value(&mut input.accept_mode, AcceptMode::Lcu, tr("Client API"))
+                        .on_hover_text(tr("Accept through the League client's local API using its lockfile; no mouse or keyboard input"));
+                    ui.add_enabled(
+                        matches!(input.accept_mode, AcceptMode::Key | AcceptMode::ClickAndKey),
+                        egui::TextEdit::singleline(&mut input.accept_key).desired_width(60.0),
+                    )
+                    .on_hover_text(tr("Enter, Space, Tab, Escape or a single character"));
+                });
+                ui.checkbox(
+                    &mut self.config.input.restore_cursor,
+                    tr("Restore cursor after click"),
+                )
+                .on_hover_text(tr("Glide the mouse back to where it was before the accept click"));
+                let input = &mut self.config.input;
+                ui.horizontal(|ui| {
+                    ui.label(tr("Settle (ms)"))
+                        .on_hover_text(tr("Pause after the cursor arrives before pressing"));
+                    ui.add(
+                        egui::DragValue::new(&mut input.click_delay_ms)
+                            .speed(1)
+                            .range(0..=500),
+                    );
+                    ui.label(tr("Hold (ms)"));
+                    ui.add(egui::DragValue::new(&mut input.hold_ms).speed(1).range(0..=500));
+                    ui.label(tr("± jitter (ms)"));
+                    ui.add(
+                        egui::DragValue::new(&mut input.timing_jitter_ms)
+                            .speed(1)
+                            .range(0..=200),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.label(tr("Wait for idle mouse"));
+                    ui.add(
+                        egui::DragValue::new(&mut input.idle_grace_ms)
+                            .speed(10)
+                            .range(0..=3_000)
+                            .suffix(" ms"),
+                    )
+                    .on_hover_text(tr("Delay the accept until your mouse has been still this long (0 = off)"));
+                });
+                ui.horizontal(|ui| {
+                    ui.label(tr("Only when away for"));
+                    ui.add(
+                        egui::DragValue::new(&mut input.require_user_idle_secs)
+                            .range(0..=600)
+                            .suffix(" s"),
+                    )
+                    .on_hover_text(
+                        tr("Skip ready checks while you used the keyboard or mouse within this time (0 = off)"),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.label(tr("Clicks"));
+                    ui.radio_value(&mut input.click_count, ClickCount::Single, tr("Single"));
+                    ui.radio_value(&mut input.click_count, ClickCount::Double, tr("Double"))
+                        .on_hover_text(tr("Use when an overlay swallows the first click"));
+                    ui.add_enabled(
+                        input.click_count == ClickCount::Double,
+                        egui::DragValue::new(&mut input.double_click_gap_ms)
+                            .speed(1)
+                            .range(10..=500)
+                            .suffix(" ms gap"),
+                    );
+                });
+                ui.checkbox(
+                    &mut self.config.input.focus_client,
+                    tr("Focus the League client before clicking"),
+                )
+                .on_hover_text(tr("Raises the client window for the click, then gives focus back to the previous window"));
+                let guard = &mut self.config.click_guard;
+                ui.checkbox(&mut guard.check_foreground, tr("Abort if the foreground window changed"))
+                    .on_hover_text(tr("Skip the click when another window was brought forward after detection"));
+                ui.checkbox(&mut guard.reverify_pixels, tr("Re-check the button right before clicking"))
+                    .on_hover_text(tr("Captures one more frame and requires the button at the same position"));
+                let verify = &mut self.config.click_verify;
+                ui.checkbox(&mut verify.enabled, tr("Verify accept and retry"))
+                    .on_hover_text(tr("Check that the dialog disappeared and click again if it did not"));
+                ui.add_enabled_ui(verify.enabled, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(tr("Check after (ms)"));
+                        ui.add(
+                            egui::DragValue::new(&mut verify.delay_ms)
+                                .speed(10)
+                                .range(100..=5_000),
+                        );
+                        ui.label(tr("Max attempts"));
+                        ui.add(egui::DragValue::new(&mut verify.max_attempts).range(1..=10));
+                        ui.label(tr("Retry spread (px)"));
+                        ui.add(egui::DragValue::new(&mut verify.retry_jitter_px).range(0..=30));
+                    });
+                });
+                let escalation = &mut self.config.escalation;
+                ui.add_enabled_ui(self.config.click_verify.enabled, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut escalation.enabled, tr("Escalate when ignored:"))
+                            .on_hover_text(tr("After the retries of one method fail, fall back to the next"));
+                        ui.add_enabled_ui(escalation.enabled, |ui| {
+                            ui.checkbox(&mut escalation.click, tr("Click"));
+                            ui.label("→");
+                            ui.checkbox(&mut escalation.key, tr("Accept key"));
+                            ui.label("→");
+                            ui.checkbox(&mut escalation.lcu, tr("Client API"));
+                        });
+                    });
+                });
+                let humanize = &mut self.config.input.humanize;
+                ui.checkbox(&mut humanize.enabled, tr("Humanized mouse movement"))
+                    .on_hover_text(tr("Move along a slightly curved path with variable speed instead of jumping to the button"));
+                ui.add_enabled_ui(humanize.enabled, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(tr("Duration (ms)"));
+                        ui.add(
+                            egui::DragValue::new(&mut humanize.duration_ms)
+                                .speed(5)
+                                .range(20..=2_000),
+                        );
+                    });
+                    ui.add(
+                        egui::Slider::new(&mut humanize.curvature, 0.0..=0.5).text(tr("Curvature")),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut humanize.overshoot_px, 0.0..=30.0)
+                            .text(tr("Overshoot"))
+                            .suffix(" px"),
+                    );
+                });
+            });
+        self.layout
+            .set_section_open("input", response.openness > 0.5);
+    }
+
+    fn render_statistics(&mut self, ui: &mut egui::Ui) {
+        let response = egui::CollapsingHeader::new(tr("Statistics"))
+            .id_source("statistics")
+            .default_open(self.layout.section_open("statistics", false))
+            .show(ui, |ui| {
+                let now = Instant::now();
+                let session = stats_rows(&self.stats.session(now));
+                let lifetime = stats_rows(&self.stats.lifetime(now));
+                egui::Grid::new("statistics").striped(true).show(ui, |ui| {
+                    ui.label("");
+                    ui.strong(tr("Session"));
+                    ui.strong(tr("Lifetime"));
+                    ui.end_row();
+                    for ((label, session), (_, lifetime)) in session.into_iter().zip(lifetime) {
+                        ui.label(label);
+                        ui.label(session);
+                        ui.label(lifetime);
+                        ui.end_row();
+                    }
+                });
+                if self.running {
+                    ui.ctx().request_repaint_after(Duration::from_secs(1));
+                }
+            });
+        self.layout
+            .set_section_open("statistics", response.openness > 0.5);
+    }
+
+    /// In-window shortcuts; see [`shortcut_list`]. Space only counts while no widget has focus,
+    /// so typing in a text field is unaffected.
+    fn handle_shortcuts(&mut self, ctx: &egui::Context) {
+        let command = |key| egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, key);
+        let nothing_focused = ctx.memory(|memory| memory.focused().is_none());
+        let (toggle, save, clear_logs, refresh, help) = ctx.input_mut(|input| {
+            (
+                nothing_focused && input.consume_key(egui::Modifiers::NONE, egui::Key::Space),
+                input.consume_shortcut(&command(egui::Key::S)),
+                input.consume_shortcut(&command(egui::Key::L)),
+                input.consume_key(egui::Modifiers::NONE, egui::Key::F5),
+                input.consume_key(egui::Modifiers::NONE, egui::Key::F1),
+            )
+        });
+        if toggle {
+            self.toggle_monitoring();
+        }
+        if self.settings_locked() {
+            return;
+        }
+        if save {
+            self.save_configuration();
+        }
+        if clear_logs {
+            self.logs.clear();
+            self.status_line = tr("Logs cleared").to_string();
+        }
+        if refresh {
+            self.refresh_monitors();
+            self.status_line = tr("Monitors refreshed").to_string();
+        }
+        if help {
+            self.show_shortcuts = !self.show_shortcuts;
+        }
+    }
+
+    fn render_shortcuts(&mut self, ctx: &egui::Context) {
+        egui::Window::new(tr("Keyboard shortcuts"))
+            .open(&mut self.show_shortcuts)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                egui::Grid::new("shortcuts").striped(true).show(ui, |ui| {
+                    for (keys, action) in shortcut_list(ctx) {
+                        ui.monospace(keys);
+                        ui.label(action);
+                        ui.end_row();
+                    }
+                });
+            });
+    }
+
+    /// Outlines the latest detection on screen for [`OVERLAY_DURATION`] when enabled.
+    fn show_overlay(&self, ctx: &egui::Context) {
+        let Some(detection) = &self.last_detection else {
+            return;
+        };
+        let age = detection.timestamp.elapsed();
+        if !self.config.show_overlay || age >= OVERLAY_DURATION {
+            return;
+        }
+        let (min, max) = detection.screen_rect;
+        overlay::show(ctx, min, max, detection.score);
+        ctx.request_repaint_after(OVERLAY_DURATION - age);
+    }
+
+    /// Status line, latest score and Start/Stop, for the mini mode window.
+    /// Countdown bar for the post-click cooldown while monitoring, ticking with the GUI clock.
+    fn render_cooldown(&self, ui: &mut egui::Ui) {
+        let now = Instant::now();
+        let Some(cooldown) = self.cooldown.filter(|cooldown| cooldown.until > now) else {
+            return;
+        };
+        if !self.running {
+            return;
+        }
+        let remaining = cooldown.remaining(now).as_secs_f32();
+        ui.add(egui::ProgressBar::new(cooldown.progress(now)).text(trf(
+            "Cooldown: {seconds} s left",
+            &[("seconds", &format!("{remaining:.1}"))],
+        )));
+        ui.ctx().request_repaint_after(COOLDOWN_REPAINT);
+    }
+
+    fn render_mini_mode(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            render_indicator(ui, self.indicator(), self.config.theme.indicator_palette);
+            if self.running {
+                if ui.button(tr("Stop")).clicked() {
+                    self.stop_monitoring();
+                }
+            } else if ui.button(tr("Start")).clicked() {
+                self.start_monitoring();
+            }
+            let score = match self.scores.latest() {
+                Some(score) if self.running => format!("{score:.3}"),
+                _ => "–".to_string(),
+            };
+            ui.label(trf(
+                "Score {score} / {threshold}",
+                &[
+                    ("score", &score),
+                    ("threshold", &format!("{:.2}", self.config.threshold)),
+                ],
+            ));
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                if ui.button(tr("Full window")).clicked() {
+                    self.set_mini_mode(ui.ctx(), false);
+                }
+            });
+        });
+        ui.label(RichText::new(&self.status_line).strong());
+        self.render_cooldown(ui);
+        if self.running {
+            ui.ctx().request_repaint_after(Duration::from_millis(250));
+        }
+    }
+
+    fn render_score_graph(&mut self, ui: &mut egui::Ui) {
+        let response = egui::CollapsingHeader::new(tr("Match score"))
+            .id_source("match_score")
+            .default_open(self.layout.section_open("match_score", false))
+            .show(ui, |ui| {
+                let window = self.scores.window().as_secs_f64();
+                let threshold = f64::from(self.config.threshold);
+                egui_plot::Plot::new("match_score")
+                    .height(160.0)
+                    .include_x(-window)
+                    .include_x(0.0)
+                    .include_y(0.0)
+                    .include_y(1.0)
+                    .allow_drag(false)
+                    .allow_zoom(false)
+                    .allow_scroll(false)
+                    .x_axis_label(tr("Seconds ago"))
+                    .show(ui, |plot| {
+                        plot.line(
+                            egui_plot::Line::new(self.scores.points(Instant::now()))
+                                .name(tr("Best score")),
+                        );
+                        plot.hline(
+                            egui_plot::HLine::new(threshold)
+                                .name(tr("Threshold"))
+                                .color(egui::Color32::from_rgb(220, 80, 60)),
+                        );
+                    });
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::Slider::new(&mut self.config.threshold, 0.5..=0.99)
+                            .text(tr("Match threshold")),
+                    );
+                    if let Some(peak) = self.scores.peak() {
+                        ui.label(trf("Peak {score}", &[("score", &format!("{peak:.3}"))]));
+                    }
+                    if ui.button(tr("Clear")).clicked() {
+                        self.scores.clear();
+                    }
+                });
+                if self.running {
+                    ui.ctx().request_repaint_after(Duration::from_millis(500));
+                }
+            });
+        self.layout
+            .set_section_open("match_score", response.openness > 0.5);
+    }
+
+    fn render_history(&mut self, ui: &mut egui::Ui) {
+        let response = egui::CollapsingHeader::new(trf(
+            "Detection history ({count})",
+            &[("count", &self.history.len())],
+        ))
+        .id_source("detection_history")
+        .default_open(self.layout.section_open("detection_history", false))
+        .show(ui, |ui| {
+            if self.history.is_empty() {
+                ui.label(tr("No detections yet"));
+                return;
+            }
+            ui.horizontal(|ui| {
+                if ui.button(tr("Clear")).clicked() {
+                    self.history.clear();
+                }
+                if ui
+                    .button(tr("Export CSV…"))
+                    .on_hover_text(tr("Save time, event, score, scale and screen position of each detection for a spreadsheet"))
+                    .clicked()
+                {
+                    self.export_history();
+                }
+            });
+            let oldest = self
+                .history
+                .entries()
+                .next()
+                .map_or(u64::MAX, |entry| entry.id);
+            self.history_textures.retain(|id, _| *id >= oldest);
+            egui::ScrollArea::vertical()
+                .max_height(260.0)
+                .show(ui, |ui| {
+                    egui::Grid::new("detection_history_grid")
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for heading in ["Time", "Event", "Score", "Scale", "Screen", "Region"] {
+                                ui.strong(tr(heading));
+                            }
+                            ui.end_row();
+                            for entry in self.history.entries().rev() {
+                                ui.label(entry.at.format("%H:%M:%S").to_string());
+                                ui.label(history_outcome_label(entry.outcome));
+                                ui.label(format!("{:.3}", entry.score));
+                                ui.label(format!("{:.2}", entry.scale));
+                                ui.label(format!(
+                                    "({}, {})",
+                                    entry.screen_coords.0, entry.screen_coords.1
+                                ));
+                                let texture =
+                                    self.history_textures.entry(entry.id).or_insert_with(|| {
+                                        let thumbnail = &entry.thumbnail;
+                                        ui.ctx().load_texture(
+                                            format!("history-{}", entry.id),
+                                            egui::ColorImage::from_gray(
+                                                [
+                                                    thumbnail.width() as usize,
+                                                    thumbnail.height() as usize,
+                                                ],
+                                                thumbnail.as_raw(),
+                                            ),
+                                            egui::TextureOptions::LINEAR,
+                                        )
+                                    });
+                                ui.image((texture.id(), texture.size_vec2()));
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+        self.layout
+            .set_section_open("detection_history", response.openness > 0.5);
+    }
+
+    /// Collects the screenshot or test detection the setup wizard is waiting for.
+    fn poll_onboarding(&mut self, ctx: &egui::Context) {
+        let Some(onboarding) = &mut self.onboarding else {
+            return;
+        };
+        if let Some(rx) = &onboarding.capture {
+            match rx.try_recv() {
+                Ok(Ok(frame)) => {
+                    let texture = ctx.load_texture(
+                        "onboarding-snapshot",
+                        egui::ColorImage::from_gray(
+                            [frame.image.width() as usize, frame.image.height() as usize],
+                            frame.image.as_raw(),
+                        ),
+                        egui::TextureOptions::LINEAR,
+                    );
+                    onboarding.snapshot = Some((frame.image, texture));
+                    onboarding.selection = None;
+                    onboarding.capture = None;
+                }
+                Ok(Err(err)) => {
+                    self.status_line = trf(
+                        "Screen capture failed: {error}",
+                        &[("error", &format!("{err:#}"))],
+                    );
+                    onboarding.capture = None;
+                }
+                Err(TryRecvError::Empty) => ctx.request_repaint_after(Duration::from_millis(200)),
+                Err(TryRecvError::Disconnected) => onboarding.capture = None,
+            }
+        }
+        if let Some(rx) = &onboarding.test {
+            match rx.try_recv() {
+                Ok(outcome) => {
+                    onboarding.test_outcome = Some(outcome.map_err(|err| format!("{err:#}")));
+                    onboarding.test = None;
+                }
+                Err(TryRecvError::Empty) => ctx.request_repaint_after(Duration::from_millis(200)),
+                Err(TryRecvError::Disconnected) => onboarding.test = None,
+            }
+        }
+    }
+
+    fn render_onboarding(&mut self, ui: &mut egui::Ui) {
+        let Some(step) = self.onboarding.as_ref().map(|onboarding| onboarding.step) else {
+            return;
+        };
+        ui.heading(trf(
+            "Setup {current}/{total}: {title}",
+            &[
+                ("current", &(step as usize + 1)),
+                ("total", &OnboardingStep::ALL.len()),
+                ("title", &step.title()),
+            ],
+        ));
+        ui.separator();
+        egui::ScrollArea::vertical()
+            .max_height(ui.available_height() - 40.0)
+            .show(ui, |ui| match step {
+                OnboardingStep::Monitor => {
+                    ui.label(tr("Pick the display the League client runs on. Detection only looks at this monitor."));
+                    self.render_monitor_selector(ui);
+                }
+                OnboardingStep::Template => self.render_onboarding_template(ui),
+                OnboardingStep::TestDetection => self.render_onboarding_test(ui),
+                OnboardingStep::Notifications => {
+                    ui.label(tr("Choose how you want to hear about a match while you are away from the screen."));
+                    ui.checkbox(
+                        &mut self.config.notifications.desktop,
+                        tr("Desktop notification on accept"),
+                    );
+                    let mut detection_sound = !self.config.sound.detection.muted;
+                    if ui
+                        .checkbox(&mut detection_sound, tr("Play a sound when a match is found"))
+                        .changed()
+                    {
+                        self.config.sound.detection.muted = !detection_sound;
+                    }
+                    ui.label(tr("Discord and Telegram messages can be set up later under Notifications."));
+                }
+            });
+        ui.separator();
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(step.previous().is_some(), egui::Button::new(tr("Back")))
+                .clicked()
+                && let (Some(onboarding), Some(previous)) = (&mut self.onboarding, step.previous())
+            {
+                onboarding.step = previous;
+            }
+            match step.next() {
+                Some(next) => {
+                    if ui.button(tr("Next")).clicked()
+                        && let Some(onboarding) = &mut self.onboarding
+                    {
+                        onboarding.step = next;
+                    }
+                }
+                None => {
+                    if ui.button(tr("Finish")).clicked() {
+                        self.finish_onboarding();
+                    }
+                }
+            }
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                if ui.button(tr("Skip setup")).clicked() {
+                    self.finish_onboarding();
+                }
+            });
+        });
+    }
+
+    fn render_onboarding_template(&mut self, ui: &mut egui::Ui) {
+        match self.config.resolve_template_path() {
+            Ok(path) => {
+                ui.label(trf("Using template {path}", &[("path", &path.display())]));
+            }
+            Err(err) => {
+                ui.colored_label(egui::Color32::YELLOW, format!("{err:#}"));
+            }
+        }
+        ui.label(tr("To make your own, show a ready check (or a screenshot of one) on the selected monitor, capture the screen and drag a box around the Accept button."));
+        let Some(onboarding) = &mut self.onboarding else {
+            return;
+        };
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    onboarding.capture.is_none(),
+                    egui::Button::new(tr("Capture screen in 3 s")),
+                )
+                .clicked()
+            {
+                let source = self.config.effective_capture_source();
+                let monitor_index = self.config.monitor_index;
+                let (tx, rx) = crossbeam_channel::bounded(1);
+                std::thread::spawn(move || {
+                    std::thread::sleep(ONBOARDING_CAPTURE_DELAY);
+                    let _ = tx.send(capture::capture_once(&source, monitor_index));
+                });
+                onboarding.capture = Some(rx);
+            }
+            if onboarding.capture.is_some() {
+                ui.spinner();
+            }
+        });
+        let Some((image, texture)) = &onboarding.snapshot else {
+            return;
+        };
+        drag_select(ui, texture, &mut onboarding.selection);
+        let Some((start, end)) = onboarding.selection else {
+            ui.label(tr("Drag over the Accept button to select it."));
+            return;
+        };
+        let cropped = detect::crop_template(image, start, end);
+        if ui
+            .add_enabled(cropped.is_some(), egui::Button::new(tr("Save as template")))
+            .clicked()
+            && let Some(cropped) = cropped
+        {
+            let saved = config::user_template_path().and_then(|path| {
+                cropped
+                    .save(&path)
+                    .with_context(|| format!("Failed to write template {path:?}"))?;
+                Ok(path)
+            });
+            match saved {
+                Ok(path) => {
+                    info!(path = %path.display(), "template saved");
+                    self.status_line =
+                        trf("Template saved to {path}", &[("path", &path.display())]);
+                    self.template_path_input = path.display().to_string();
+                    self.config.template_path = Some(path);
+                    onboarding.snapshot = None;
+                    onboarding.selection = None;
+                }
+                Err(err) => {
+                    error!(error = ?err, "failed to save template");
+                    self.status_line = trf(
+                        "Failed to save template: {error}",
+                        &[("error", &format!("{err:#}"))],
+                    );
+                }
+            }
+        }
+    }
+
+    fn render_onboarding_test(&mut self, ui: &mut egui::Ui) {
+        ui.label(tr("Show a ready check (or a screenshot of one) on the selected monitor and run a test detection. You can also skip this and test during your next queue."));
+        let Some(onboarding) = &mut self.onboarding else {
+            return;
+        };
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    onboarding.test.is_none(),
+                    egui::Button::new(tr("Run test detection")),
+                )
+                .clicked()
+            {
+                let config = self.config.clone();
+                let (tx, rx) = crossbeam_channel::bounded(1);
+                std::thread::spawn(move || {
+                    let _ = tx.send(probe::run(&config).map(|probe| probe.detection));
                 });
-            });
+                onboarding.test = Some(rx);
+                onboarding.test_outcome = None;
+            }
+            if onboarding.test.is_some() {
+                ui.spinner();
+            }
+        });
+        match &onboarding.test_outcome {
+            Some(Ok(Some(detection))) if detection.passes(self.config.threshold) => {
+                ui.colored_label(
+                    egui::Color32::LIGHT_GREEN,
+                    trf(
+                        "Accept button found: score {score} at ({x}, {y})",
+                        &[
+                            ("score", &format!("{:.3}", detection.score)),
+                            ("x", &detection.position.0),
+                            ("y", &detection.position.1),
+                        ],
+                    ),
+                );
+            }
+            Some(Ok(Some(detection))) => {
+                ui.colored_label(
+                    egui::Color32::YELLOW,
+                    trf(
+                        "Best match scored {score}, below the threshold {threshold}. Is a ready check on screen?",
+                        &[
+                            ("score", &format!("{:.3}", detection.score)),
+                            (
+                                "threshold",
+                                &format!(
+                                    "{:.2}",
+                                    detection.threshold.unwrap_or(self.config.threshold)
+                                ),
+                            ),
+                        ],
+                    ),
+                );
+            }
+            Some(Ok(None)) => {
+                ui.colored_label(
+                    egui::Color32::YELLOW,
+                    tr("The template is larger than the captured screen"),
+                );
+            }
+            Some(Err(err)) => {
+                ui.colored_label(egui::Color32::RED, err);
+            }
+            None => {}
+        }
+    }
+
+    /// Closes the wizard and writes the configuration, so it does not come back next launch.
+    fn finish_onboarding(&mut self) {
+        self.onboarding = None;
+        self.save_configuration();
     }
 
     fn render_logs(&mut self, ui: &mut egui::Ui) {
-        egui::CollapsingHeader::new("Logs")
-            .default_open(true)
+        let response = egui::CollapsingHeader::new(tr("Logs"))
+            .id_source("logs")
+            .default_open(self.layout.section_open("logs", true))
             .show(ui, |ui| {
+                self.render_log_filter(ui);
+                let filter = &self.log_filter;
                 egui::ScrollArea::vertical()
-                    .stick_to_bottom(true)
+                    .stick_to_bottom(!self.log_scroll_paused)
                     .show(ui, |ui| {
-                        for line in &self.logs {
-                            ui.label(line);
+                        for line in self.logs.iter().filter(|line| filter.matches(line)) {
+                            let text = RichText::new(&line.text);
+                            ui.label(match line.level {
+                                tracing::Level::ERROR => text.color(ui.visuals().error_fg_color),
+                                tracing::Level::WARN => text.color(ui.visuals().warn_fg_color),
+                                _ => text,
+                            });
                         }
                     });
             });
+        self.layout
+            .set_section_open("logs", response.openness > 0.5);
+    }
+
+    fn render_log_filter(&mut self, ui: &mut egui::Ui) {
+        let modules: BTreeSet<&str> = self.logs.iter().map(|line| line.module.as_str()).collect();
+        ui.horizontal_wrapped(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.log_filter.query)
+                    .hint_text(tr("Search logs"))
+                    .desired_width(160.0),
+            );
+            ComboBox::from_id_source("log_level")
+                .selected_text(log_level_label(self.log_filter.level))
+                .show_ui(ui, |ui| {
+                    for level in LogFilter::LEVELS {
+                        ui.selectable_value(
+                            &mut self.log_filter.level,
+                            level,
+                            log_level_label(level),
+                        );
+                    }
+                });
+            ComboBox::from_id_source("log_module")
+                .selected_text(
+                    self.log_filter
+                        .module
+                        .as_deref()
+                        .unwrap_or(tr("All modules")),
+                )
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.log_filter.module, None, tr("All modules"));
+                    for module in modules {
+                        ui.selectable_value(
+                            &mut self.log_filter.module,
+                            Some(module.to_string()),
+                            module,
+                        );
+                    }
+                });
+            ui.checkbox(&mut self.log_scroll_paused, tr("Pause auto-scroll"));
+            if self.log_filter != LogFilter::default() && ui.button(tr("Reset filter")).clicked() {
+                self.log_filter = LogFilter::default();
+            }
+        });
+        ui.horizontal(|ui| {
+            if ui.button(tr("Export logs…")).clicked() {
+                self.export_logs();
+            }
+            ui.checkbox(&mut self.export_session_log, tr("Include full session log"));
+        });
+    }
+
+    /// Asks where to save and writes the log buffer (and the session log if chosen) there.
+    fn export_settings(&mut self) {
+        if let Err(err) = self.apply_template_path_from_input() {
+            self.last_config_error = Some(err.to_string());
+            self.status_line = tr("Template path error").to_string();
+            return;
+        }
+        let file_name = format!(
+            "lol-auto-accept-settings-{}.json",
+            Local::now().format("%Y%m%d")
+        );
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(file_name)
+            .add_filter(tr("Settings"), &["json"])
+            .save_file()
+        else {
+            return;
+        };
+        let persisted = match self.persisted_config() {
+            Ok(persisted) => persisted,
+            Err(err) => {
+                self.status_line = format!("{err:#}");
+                return;
+            }
+        };
+        let bundle = SettingsBundle::new(&persisted, &self.profiles);
+        self.status_line = match backup::export(&path, &bundle) {
+            Ok(()) => {
+                info!(path = %path.display(), "exported settings");
+                trf("Settings exported to {path}", &[("path", &path.display())])
+            }
+            Err(err) => {
+                error!("{err:#}");
+                trf(
+                    "Settings export failed: {error}",
+                    &[("error", &format!("{err:#}"))],
+                )
+            }
+        };
+    }
+
+    /// Replaces the configuration and profiles with an exported file and saves both.
+    fn import_settings(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter(tr("Settings"), &["json"])
+            .pick_file()
+        else {
+            return;
+        };
+        let bundle = match backup::import(&path) {
+            Ok(bundle) => bundle,
+            Err(err) => {
+                error!("{err:#}");
+                self.status_line = trf(
+                    "Settings import failed: {error}",
+                    &[("error", &format!("{err:#}"))],
+                );
+                return;
+            }
+        };
+        let missing = bundle.missing_templates();
+        for template in &missing {
+            warn!(path = %template.display(), "imported template not found");
+        }
+        info!(path = %path.display(), "importing settings");
+        self.profiles = bundle.profiles;
+        self.profile_hotkey_input = self
+            .profiles
+            .active
+            .as_deref()
+            .and_then(|name| self.profiles.hotkey(name))
+            .unwrap_or_default()
+            .to_string();
+        self.bind_profile_hotkeys();
+        self.load_file_config(bundle.config);
+        self.store_profiles();
+        if self.save_configuration() {
+            self.status_line = if missing.is_empty() {
+                trf(
+                    "Settings imported from {path}",
+                    &[("path", &path.display())],
+                )
+            } else {
+                trf(
+                    "Settings imported, but {count} template images were not found; check Manage templates",
+                    &[("count", &missing.len())],
+                )
+            };
+        }
+    }
+
+    fn export_history(&mut self) {
+        let file_name = format!(
+            "detection-history-{}.csv",
+            Local::now().format("%Y%m%d-%H%M%S")
+        );
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(file_name)
+            .add_filter(tr("CSV files"), &["csv"])
+            .save_file()
+        else {
+            return;
+        };
+        match history::export_csv(&path, &self.history) {
+            Ok(()) => {
+                info!(path = %path.display(), entries = self.history.len(), "detection history exported");
+                self.status_line = trf(
+                    "Detection history exported to {path}",
+                    &[("path", &path.display())],
+                );
+            }
+            Err(err) => {
+                error!("{err:#}");
+                self.status_line = trf(
+                    "History export failed: {error}",
+                    &[("error", &format!("{err:#}"))],
+                );
+            }
+        }
+    }
+
+    fn export_logs(&mut self) {
+        let file_name = format!(
+            "lol-auto-accept-{}.log",
+            Local::now().format("%Y%m%d-%H%M%S")
+        );
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(file_name)
+            .add_filter(tr("Log files"), &["log", "txt"])
+            .save_file()
+        else {
+            return;
+        };
+        let lines: Vec<&str> = self.logs.iter().map(|line| line.text.as_str()).collect();
+        let session_log = if self.export_session_log {
+            logpipe::session_log_path().ok()
+        } else {
+            None
+        };
+        self.status_line = match logpipe::export(&path, &lines, session_log.as_deref()) {
+            Ok(()) => {
+                info!(path = %path.display(), "exported logs");
+                trf("Logs exported to {path}", &[("path", &path.display())])
+            }
+            Err(err) => {
+                error!("{err:#}");
+                trf(
+                    "Log export failed: {error}",
+                    &[("error", &format!("{err:#}"))],
+                )
+            }
+        };
     }
 }
 
 impl eframe::App for LolAutoAcceptApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+    /// Transparent, so the detection overlay window shows only what it paints.
+    fn clear_color(&self, _visuals: &egui::Visuals) -> [f32; 4] {
+        [0.0; 4]
+    }
+
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        self.save_stats();
+        if let Err(err) = layout::store(&self.layout) {
+            warn!(error = ?err, "failed to save window layout");
+        }
+    }
+
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        self.apply_theme(ctx, frame);
+        i18n::set_language(self.config.language);
         self.poll_logs(ctx);
         self.poll_events(ctx);
+        self.poll_hotkeys(ctx);
+        self.poll_config_file(ctx);
+        self.follow_client_process(ctx);
+        self.follow_schedule(ctx);
+        self.follow_auto_stop(ctx);
+        self.poll_notification_test(ctx);
+        self.poll_probe(ctx);
+        self.poll_tuning(ctx);
+        self.poll_update(ctx);
+        self.poll_onboarding(ctx);
+        self.poll_tray(ctx);
+        self.poll_dropped_files(ctx);
+        self.follow_indicator(ctx);
+        self.follow_streamer_mode();
+        self.track_layout(ctx);
+        self.minimize_on_launch(ctx);
+        self.hide_to_tray(ctx);
+        self.track_visibility(ctx);
+        self.confirm_close(ctx);
+        if self.display_changes.changed() {
+            self.refresh_monitors();
+            ctx.request_repaint();
+        }
         self.check_worker_lifecycle();
+        if self.onboarding.is_none() {
+            self.handle_shortcuts(ctx);
+        }
 
         if self.exit_requested {
             ctx.send_viewport_cmd(egui::ViewportCommand::Close);
             return;
         }
 
+        self.show_overlay(ctx);
+        self.render_exit_confirmation(ctx);
+        if self.mini_mode {
+            egui::CentralPanel::default().show(ctx, |ui| self.render_mini_mode(ui));
+            return;
+        }
+
         egui::TopBottomPanel::top("status_panel").show(ctx, |ui| {
             self.render_status_panel(ui);
         });
 
         egui::CentralPanel::default().show(ctx, |ui| {
+            if self.settings_locked() {
+                self.render_kiosk_unlock(ui);
+                return;
+            }
+            if self.onboarding.is_some() {
+                self.render_onboarding(ui);
+                return;
+            }
             self.render_settings(ui);
+            self.render_statistics(ui);
+            self.render_score_graph(ui);
+            self.render_history(ui);
             ui.separator();
             self.render_logs(ui);
         });
-
-        if self.running {
-            ctx.request_repaint_after(Duration::from_millis(16));
+        if !self.settings_locked() {
+            self.render_region_editor(ctx);
+            self.render_template_manager(ctx);
+            self.render_dropped_template(ctx);
+            self.render_probe(ctx);
+            self.render_frame_viewer(ctx);
+            self.render_tuning(ctx);
+            self.render_template_recovery(ctx);
+            self.render_update(ctx);
+            self.render_shortcuts(ctx);
         }
+        self.render_run_summary(ctx);
+        self.render_toasts(ctx);
+        // No repaint loop while monitoring: the worker event relay wakes the window.
     }
 }
 
-struct WorkerHandle {
-    stop_flag: Arc<AtomicBool>,
-    thread: Option<thread::JoinHandle<()>>,
+/// Pages of the first-run setup wizard, in order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum OnboardingStep {
+    #[default]
+    Monitor,
+    Template,
+    TestDetection,
+    Notifications,
 }
 
-impl WorkerHandle {
-    fn request_stop(&self) {
-        self.stop_flag.store(true, Ordering::Relaxed);
-    }
+impl OnboardingStep {
+    const ALL: [OnboardingStep; 4] = [
+        OnboardingStep::Monitor,
+        OnboardingStep::Template,
+        OnboardingStep::TestDetection,
+        OnboardingStep::Notifications,
+    ];
 
-    fn join(&mut self) {
-        if let Some(handle) = self.thread.take() {
-            if let Err(err) = handle.join() {
-                error!("Worker thread join error: {err:?}");
-            }
-        }
+    fn next(self) -> Option<Self> {
+        Self::ALL.get(self as usize + 1).copied()
     }
 
-    fn is_finished(&self) -> bool {
-        matches!(&self.thread, Some(handle) if handle.is_finished())
+    fn previous(self) -> Option<Self> {
+        (self as usize).checked_sub(1).map(|index| Self::ALL[index])
     }
-}
 
-impl Drop for WorkerHandle {
-    fn drop(&mut self) {
-        self.request_stop();
-        self.join();
+    fn title(self) -> &'static str {
+        tr(match self {
+            OnboardingStep::Monitor => "Monitor",
+            OnboardingStep::Template => "Accept button template",
+            OnboardingStep::TestDetection => "Test detection",
+            OnboardingStep::Notifications => "Notifications",
+        })
     }
 }
 
-#[derive(Debug)]
-struct DetectionSnapshot {
-    timestamp: Instant,
-    score: f32,
-    image_coords: (u32, u32),
-    screen_coords: (i32, i32),
-    template_size: (u32, u32),
-    scale: f32,
+/// Wizard state: the current page plus the screenshot and test detection it works with.
+#[derive(Default)]
+struct Onboarding {
+    step: OnboardingStep,
+    /// Screenshot to cut a template from, with its texture.
+    snapshot: Option<(GrayImage, egui::TextureHandle)>,
+    /// Corners of the box dragged over the snapshot, in its pixels.
+    selection: Option<Corners>,
+    capture: Option<Receiver<Result<CapturedFrame>>>,
+    test: Option<Receiver<Result<Option<Detection>>>>,
+    test_outcome: Option<Result<Option<Detection>, String>>,
 }
 
-enum WorkerEvent {
-    Detection {
-        score: f32,
-        image_coords: (u32, u32),
-        screen_coords: (i32, i32),
-        template_size: (u32, u32),
-        scale: f32,
-    },
-    Clicked {
-        screen_coords: (i32, i32),
-    },
-    CooldownActive {
-        score: f32,
-        remaining_ms: u64,
-    },
-    Error(String),
-    Info(String),
-    Stopped,
+/// A way out of the template recovery dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TemplateFix {
+    Locate,
+    Capture,
+    BuiltIn,
 }
 
-fn run_worker(
-    config: AppConfig,
-    template: Template,
-    events_tx: Sender<WorkerEvent>,
-    stop_flag: Arc<AtomicBool>,
-) {
-    let mut last_click = None;
-    let cooldown = Duration::from_millis(config.cooldown_ms);
-    let interval = Duration::from_millis(config.interval_ms.max(10));
-    info!(
-        monitor = config.monitor_index,
-        threshold = config.threshold,
-        cooldown_ms = config.cooldown_ms,
-        interval_ms = config.interval_ms,
-        "worker started"
-    );
+/// Opposite corners of a box dragged over a screenshot, in its pixels.
+type Corners = ((u32, u32), (u32, u32));
 
-    if events_tx
-        .send(WorkerEvent::Info("Monitoring active".to_string()))
-        .is_err()
-    {
-        return;
-    }
-
-    while !stop_flag.load(Ordering::Relaxed) {
-        match capture::capture_monitor_gray(config.monitor_index) {
-            Ok(frame) => handle_frame(
-                &config,
-                &template,
-                &events_tx,
-                frame,
-                &mut last_click,
-                cooldown,
-            ),
-            Err(err) => {
-                error!(error = ?err, "screen capture failed");
-                let _ = events_tx.send(WorkerEvent::Error(format!("Capture failed: {err:#}")));
-                thread::sleep(Duration::from_millis(250));
-            }
-        }
+struct ProbeView {
+    texture: egui::TextureHandle,
+    detection: Option<Detection>,
+    matched: bool,
+    click: Option<(i32, i32)>,
+}
 
-        if stop_flag.load(Ordering::Relaxed) {
-            break;
-        }
+/// How long template sizes read for the scale cost estimate are reused.
+const TEMPLATE_SIZES_REFRESH: Duration = Duration::from_secs(5);
+/// How often the last-frame viewer asks the worker for a new frame while live.
+const FRAME_VIEWER_REFRESH: Duration = Duration::from_millis(500);
+/// The worker of one of `config.extra_clients` while monitoring runs.
+struct ClientWorker {
+    name: String,
+    /// The slot it was started with.
+    slot: ClientSlot,
+    /// `None` once the worker crashed.
+    handle: Option<WorkerHandle>,
+    events: Receiver<WorkerEvent>,
+    status: String,
+    /// The last thing it reported was an error.
+    error: bool,
+}
 
-        thread::sleep(interval);
+/// `slot`'s name, or "Client 2", "Client 3", … after the main client.
+fn client_name(index: usize, slot: &ClientSlot) -> String {
+    if slot.name.trim().is_empty() {
+        trf("Client {number}", &[("number", &(index + 2))])
+    } else {
+        slot.name.trim().to_string()
     }
+}
+
+/// Zoom range of the last-frame viewer.
+const FRAME_VIEWER_ZOOM: std::ops::RangeInclusive<f32> = 0.1..=8.0;
 
-    let _ = events_tx.send(WorkerEvent::Stopped);
-    info!("worker stopped");
+/// The threshold tuning wizard: scores collected so far and the capture in progress.
+#[derive(Default)]
+struct TuningWizard {
+    samples: Samples,
+    capture: Option<(Phase, Receiver<Result<f32>>)>,
+    error: Option<String>,
 }
 
-fn handle_frame(
-    config: &AppConfig,
-    template: &Template,
-    events_tx: &Sender<WorkerEvent>,
-    frame: CapturedFrame,
-    last_click: &mut Option<Instant>,
-    cooldown: Duration,
-) {
-    if let Some(result) = detect::detect(&frame.image, template) {
-        if result.score < config.threshold {
-            return;
+/// The last-frame viewer: the most recent frame the worker sent back, with its best match.
+struct FrameViewer {
+    texture: Option<egui::TextureHandle>,
+    detection: Option<Detection>,
+    threshold: f32,
+    received: Option<DateTime<Local>>,
+    zoom: f32,
+    /// Keep asking for new frames; off freezes the one shown.
+    live: bool,
+    requested_at: Option<Instant>,
+}
+
+impl Default for FrameViewer {
+    fn default() -> Self {
+        Self {
+            texture: None,
+            detection: None,
+            threshold: 0.0,
+            received: None,
+            zoom: 0.5,
+            live: true,
+            requested_at: None,
         }
+    }
+}
 
-        let now = Instant::now();
-        if let Some(last) = last_click {
-            let elapsed = now.duration_since(*last);
-            if elapsed < cooldown {
-                let remaining = cooldown.saturating_sub(elapsed);
-                let _ = events_tx.send(WorkerEvent::CooldownActive {
-                    score: result.score,
-                    remaining_ms: remaining.as_millis() as u64,
-                });
-                return;
+impl FrameViewer {
+    fn show(&mut self, ctx: &egui::Context, snapshot: FrameSnapshot) {
+        let image = egui::ColorImage::from_gray(
+            [
+                snapshot.image.width() as usize,
+                snapshot.image.height() as usize,
+            ],
+            snapshot.image.as_raw(),
+        );
+        // Nearest filtering keeps single pixels visible when zoomed in.
+        match &mut self.texture {
+            Some(texture) => texture.set(image, egui::TextureOptions::NEAREST),
+            None => {
+                self.texture =
+                    Some(ctx.load_texture("last-frame", image, egui::TextureOptions::NEAREST));
             }
         }
+        self.detection = snapshot.detection;
+        self.threshold = snapshot.threshold;
+        self.received = Some(Local::now());
+    }
+}
 
-        let template_half_w = (result.template_size.0 as i32) / 2;
-        let template_half_h = (result.template_size.1 as i32) / 2;
+/// Green for a match that reaches its threshold, yellow for one below it.
+fn frame_match_color(matched: bool) -> egui::Color32 {
+    if matched {
+        egui::Color32::LIGHT_GREEN
+    } else {
+        egui::Color32::YELLOW
+    }
+}
 
-        let screen_x =
-            frame.origin.0 + result.position.0 as i32 + template_half_w + config.click_offset_x;
-        let screen_y =
-            frame.origin.1 + result.position.1 as i32 + template_half_h + config.click_offset_y;
+/// Size of the taskbar icon, which mirrors the state indicator.
+const WINDOW_ICON_SIZE: u32 = 64;
 
-        let _ = events_tx.send(WorkerEvent::Detection {
-            score: result.score,
-            image_coords: result.position,
-            screen_coords: (screen_x, screen_y),
-            template_size: result.template_size,
-            scale: result.scale,
-        });
+/// Colored dot (with the state's shape outside the standard palette) plus state name, the first
+/// thing on the status and mini-mode rows.
+fn render_indicator(ui: &mut egui::Ui, indicator: Indicator, palette: IndicatorPalette) {
+    let rgb = |[r, g, b]: [u8; 3]| egui::Color32::from_rgb(r, g, b);
+    let color = rgb(indicator.color(palette));
+    let mark = rgb(contrasting(indicator.color(palette)));
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(18.0, 18.0), egui::Sense::hover());
+    let painter = ui.painter();
+    let center = rect.center();
+    painter.circle_filled(center, 8.0, color);
+    match indicator.shape(palette) {
+        IndicatorShape::Dot => {}
+        IndicatorShape::Ring => {
+            painter.circle_filled(center, 4.0, mark);
+        }
+        IndicatorShape::Pause => {
+            for x in [-2.2, 2.2] {
+                painter.rect_filled(
+                    egui::Rect::from_center_size(center + egui::vec2(x, 0.0), egui::vec2(2.2, 8.0)),
+                    0.0,
+                    mark,
+                );
+            }
+        }
+        IndicatorShape::Cross => {
+            let stroke = egui::Stroke::new(2.2, mark);
+            painter.line_segment(
+                [
+                    center + egui::vec2(-4.0, -4.0),
+                    center + egui::vec2(4.0, 4.0),
+                ],
+                stroke,
+            );
+            painter.line_segment(
+                [
+                    center + egui::vec2(-4.0, 4.0),
+                    center + egui::vec2(4.0, -4.0),
+                ],
+                stroke,
+            );
+        }
+    }
+    ui.label(RichText::new(tr(indicator.label())).strong().color(color));
+}
 
-        if let Err(err) = input::click_at(screen_x, screen_y) {
-            error!(error = ?err, "failed to click accept button");
-            let _ = events_tx.send(WorkerEvent::Error(format!("Click failed: {err:#}")));
-            return;
+/// Widest a template thumbnail is drawn in the manager.
+const TEMPLATE_THUMBNAIL_WIDTH: f32 = 160.0;
+
+struct TemplateManager {
+    /// Bundled templates found when the window opened or on *Rescan*.
+    discovered: Vec<(PathBuf, TemplateKind)>,
+    /// Preview per template path; `None` when the image could not be read.
+    thumbnails: HashMap<PathBuf, Option<egui::TextureHandle>>,
+}
+
+impl TemplateManager {
+    fn open() -> Self {
+        Self {
+            discovered: templates::discover(&templates::bundled_dirs()),
+            thumbnails: HashMap::new(),
         }
+    }
 
-        info!(
-            score = result.score,
-            scale = result.scale,
-            template_width = result.template_size.0,
-            template_height = result.template_size.1,
-            screen_x,
-            screen_y,
-            "accept button clicked"
-        );
-        let _ = events_tx.send(WorkerEvent::Clicked {
-            screen_coords: (screen_x, screen_y),
-        });
-        *last_click = Some(now);
+    fn thumbnail(&mut self, ctx: &egui::Context, path: &Path) -> Option<&egui::TextureHandle> {
+        self.thumbnails
+            .entry(path.to_path_buf())
+            .or_insert_with(|| template_texture(ctx, path).ok())
+            .as_ref()
+    }
+}
+
+/// Full-color texture of the template image at `path`, for previews.
+fn template_texture(ctx: &egui::Context, path: &Path) -> Result<egui::TextureHandle> {
+    let image = image::open(path)
+        .with_context(|| format!("Failed to load template {path:?}"))?
+        .into_rgba8();
+    Ok(ctx.load_texture(
+        format!("template-{}", path.display()),
+        egui::ColorImage::from_rgba_unmultiplied(
+            [image.width() as usize, image.height() as usize],
+            image.as_raw(),
+        ),
+        egui::TextureOptions::LINEAR,
+    ))
+}
+
+/// A template image dropped onto the window, previewed until dismissed.
+struct DroppedTemplate {
+    path: PathBuf,
+    /// The preview, or why the file cannot be used as a template.
+    preview: Result<egui::TextureHandle, String>,
+    /// Where *Save into templates folder* copied it.
+    saved_to: Option<PathBuf>,
+}
+
+fn template_kind_label(kind: &TemplateKind) -> String {
+    match kind {
+        TemplateKind::Bundled => tr("Bundled").to_string(),
+        TemplateKind::Locale(locale) => trf("Bundled ({locale})", &[("locale", locale)]),
+        TemplateKind::User => tr("Added by you").to_string(),
     }
 }
 
+struct RegionEditor {
+    texture: egui::TextureHandle,
+    /// Corners of the box being dragged, in screenshot pixels.
+    selection: Option<Corners>,
+}
+
+#[derive(Debug)]
+struct DetectionSnapshot {
+    timestamp: Instant,
+    score: f32,
+    image_coords: (u32, u32),
+    screen_coords: (i32, i32),
+    template_size: (u32, u32),
+    screen_rect: ((i32, i32), (i32, i32)),
+    scale: f32,
+}
+
 fn monitor_label(index: usize, info: Option<&MonitorInfo>, total: usize) -> String {
     match info {
         Some(monitor) => format!(
@@ -634,9 +5918,9 @@ fn monitor_label(index: usize, info: Option<&MonitorInfo>, total: usize) -> Stri
             monitor.height,
             monitor.scale_factor * 100.0,
             if monitor.is_primary {
-                " • primary"
+                format!(" • {}", tr("primary"))
             } else {
-                ""
+                String::new()
             },
             if total > 1 {
                 format!(" • id {}", monitor.id)
@@ -644,17 +5928,416 @@ fn monitor_label(index: usize, info: Option<&MonitorInfo>, total: usize) -> Stri
                 String::new()
             }
         ),
-        None => format!("#{index} (disconnected)"),
+        None => trf("#{index} (disconnected)", &[("index", &index)]),
     }
 }
 
-fn format_duration(duration: Duration) -> String {
-    let secs = duration.as_secs();
-    if secs > 60 {
-        format!("{}m {:02}s", secs / 60, secs % 60)
-    } else if secs > 0 {
-        format!("{secs}s")
-    } else {
-        format!("{}ms", duration.as_millis())
+/// Shows the screenshot in `texture` scaled to the available width and lets the user drag a box
+/// over it; `selection` holds the box corners in screenshot pixels and is drawn when set.
+fn drag_select(ui: &mut egui::Ui, texture: &egui::TextureHandle, selection: &mut Option<Corners>) {
+    let [width, height] = texture.size().map(|side| side as f32);
+    let scale = (ui.available_width() / width).min(1.0);
+    let response = ui.add(
+        egui::Image::new((texture.id(), egui::vec2(width, height) * scale))
+            .sense(egui::Sense::drag()),
+    );
+    let to_pixel = |pos: egui::Pos2| {
+        let local = (pos - response.rect.min) / scale;
+        (
+            local.x.clamp(0.0, width) as u32,
+            local.y.clamp(0.0, height) as u32,
+        )
+    };
+    if let Some(pos) = response.interact_pointer_pos() {
+        if response.drag_started() {
+            *selection = Some((to_pixel(pos), to_pixel(pos)));
+        } else if response.dragged()
+            && let Some((_, end)) = selection
+        {
+            *end = to_pixel(pos);
+        }
+    }
+    if let Some((start, end)) = *selection {
+        let corner =
+            |(x, y): (u32, u32)| response.rect.min + egui::vec2(x as f32, y as f32) * scale;
+        ui.painter().rect_stroke(
+            egui::Rect::from_two_pos(corner(start), corner(end)),
+            0.0,
+            egui::Stroke::new(2.0, egui::Color32::YELLOW),
+        );
+    }
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+fn weekday_label(day: Weekday) -> &'static str {
+    tr(match day {
+        Weekday::Mon => "Mon",
+        Weekday::Tue => "Tue",
+        Weekday::Wed => "Wed",
+        Weekday::Thu => "Thu",
+        Weekday::Fri => "Fri",
+        Weekday::Sat => "Sat",
+        Weekday::Sun => "Sun",
+    })
+}
+
+/// Hour and minute drag values for `time`.
+fn time_input(ui: &mut egui::Ui, time: &mut NaiveTime) {
+    let (mut hour, mut minute) = (time.hour(), time.minute());
+    let changed = ui
+        .add(
+            egui::DragValue::new(&mut hour)
+                .range(0..=23)
+                .custom_formatter(|n, _| format!("{n:02}")),
+        )
+        .changed()
+        | ui.add(
+            egui::DragValue::new(&mut minute)
+                .range(0..=59)
+                .custom_formatter(|n, _| format!("{n:02}")),
+        )
+        .changed();
+    if changed && let Some(updated) = NaiveTime::from_hms_opt(hour, minute, 0) {
+        *time = updated;
+    }
+}
+
+/// Keys and what they do, for the cheat sheet.
+fn shortcut_list(ctx: &egui::Context) -> [(String, &'static str); 5] {
+    let command =
+        |key| ctx.format_shortcut(&egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, key));
+    [
+        ("Space".to_string(), tr("Start or stop monitoring")),
+        (command(egui::Key::S), tr("Save configuration")),
+        (command(egui::Key::L), tr("Clear logs")),
+        ("F5".to_string(), tr("Refresh monitors")),
+        ("F1".to_string(), tr("Show or hide this list")),
+    ]
+}
+
+fn history_outcome_label(outcome: HistoryOutcome) -> &'static str {
+    tr(match outcome {
+        HistoryOutcome::Detected => "Detected",
+        HistoryOutcome::Clicked => "Clicked",
+        HistoryOutcome::WouldClick => "Would click",
+        HistoryOutcome::Aborted => "Aborted",
+    })
+}
+
+fn log_level_label(level: tracing::Level) -> &'static str {
+    tr(match level {
+        tracing::Level::ERROR => "Errors",
+        tracing::Level::WARN => "Warnings and above",
+        tracing::Level::INFO => "Info and above",
+        _ => "Debug and above",
+    })
+}
+
+/// Label and value of each statistics row.
+fn stats_rows(totals: &StatsTotals) -> [(&'static str, String); 5] {
+    let score = match totals.average_score() {
+        Some(average) => trf(
+            "{average} avg ({min}–{max})",
+            &[
+                ("average", &format!("{average:.3}")),
+                (
+                    "min",
+                    &format!("{:.3}", totals.score_min.unwrap_or_default()),
+                ),
+                (
+                    "max",
+                    &format!("{:.3}", totals.score_max.unwrap_or_default()),
+                ),
+            ],
+        ),
+        None => "–".to_string(),
+    };
+    let reaction = totals
+        .average_reaction()
+        .map_or("–".to_string(), |reaction| {
+            trf("{ms} ms avg", &[("ms", &reaction.as_millis())])
+        });
+    [
+        (tr("Matches accepted"), totals.accepted.to_string()),
+        (tr("Detection score"), score),
+        (tr("Detection to click"), reaction),
+        (tr("Frames processed"), totals.frames.to_string()),
+        (tr("Uptime"), format_duration(totals.uptime())),
+    ]
+}
+
+/// Mute toggle, volume and file for one sound cue, with a button to preview it.
+fn render_sound_cue(ui: &mut egui::Ui, label: &str, cue: &mut SoundCue, player: &mut SoundPlayer) {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        ui.checkbox(&mut cue.muted, tr("Mute"));
+        ui.add_enabled(
+            !cue.muted,
+            egui::Slider::new(&mut cue.volume, 0.0..=1.0).text(tr("volume")),
+        );
+        if ui
+            .add_enabled(!cue.muted, egui::Button::new(tr("Test")))
+            .clicked()
+        {
+            player.play(cue);
+        }
+    });
+    ui.horizontal(|ui| {
+        ui.label(tr("File"));
+        let mut path = cue
+            .path
+            .as_ref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_default();
+        if ui
+            .text_edit_singleline(&mut path)
+            .on_hover_text(tr("WAV or OGG; leave empty for the built-in chime"))
+            .changed()
+        {
+            cue.path = (!path.is_empty()).then(|| PathBuf::from(path));
+        }
+    });
+}
+
+/// Phase line plus queue timer and ready-check countdown, advanced by `since` seconds.
+fn render_queue_status(ui: &mut egui::Ui, status: &QueueStatus, since: f32) {
+    ui.label(trf(
+        "Client: {phase}",
+        &[("phase", &tr(gameflow::phase_label(&status.phase)))],
+    ));
+    if let Some(search) = &status.search {
+        ui.label(trf(
+            "In queue {elapsed} (estimated {estimated})",
+            &[
+                ("elapsed", &format_clock(search.time_in_queue + since)),
+                ("estimated", &format_clock(search.estimated_time)),
+            ],
+        ));
+    }
+    if let Some(check) = &status.ready_check {
+        let remaining = (check.remaining() - since).max(0.0);
+        let text = match check.response.as_str() {
+            "Accepted" => trf(
+                "Ready check: accepted, {remaining}s left",
+                &[("remaining", &format!("{remaining:.0}"))],
+            ),
+            "Declined" => tr("Ready check: declined").to_string(),
+            _ => trf(
+                "Ready check: {remaining}s to respond",
+                &[("remaining", &format!("{remaining:.0}"))],
+            ),
+        };
+        ui.label(RichText::new(text).color(egui::Color32::LIGHT_GREEN));
+    }
+}
+
+fn format_clock(seconds: f32) -> String {
+    let seconds = seconds.max(0.0) as u64;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+fn render_spell_settings(ui: &mut egui::Ui, settings: &mut SummonerSpellSettings) {
+    ui.checkbox(&mut settings.enabled, tr("Summoner spells"))
+        .on_hover_text(tr(
+            "Set spells from the most specific matching preset once your champion is locked",
+        ));
+    ui.add_enabled_ui(settings.enabled, |ui| {
+        ui.horizontal(|ui| {
+            ui.label(tr("Flash on"));
+            ui.radio_value(&mut settings.flash_key, FlashKey::D, "D");
+            ui.radio_value(&mut settings.flash_key, FlashKey::F, "F");
+        });
+        let mut remove = None;
+        egui::Grid::new("spell_presets")
+            .num_columns(5)
+            .show(ui, |ui| {
+                ui.label(tr("Champion"));
+                ui.label(tr("Role"));
+                ui.label(tr("Spells"));
+                ui.end_row();
+                for (index, preset) in settings.presets.iter_mut().enumerate() {
+                    preset.spells.resize(2, String::new());
+                    ui.add(
+                        egui::TextEdit::singleline(&mut preset.champion)
+                            .desired_width(100.0)
+                            .hint_text(tr("any")),
+                    );
+                    ComboBox::from_id_source(("spell_role", index))
+                        .selected_text(if preset.role.is_empty() {
+                            tr("Any")
+                        } else {
+                            role_label(&preset.role)
+                        })
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut preset.role, String::new(), tr("Any"));
+                            for role in &ChampionPriorities::ROLES[..5] {
+                                ui.selectable_value(
+                                    &mut preset.role,
+                                    role.to_string(),
+                                    role_label(role),
+                                );
+                            }
+                        });
+                    for slot in 0..2 {
+                        ComboBox::from_id_source(("spell", index, slot))
+                            .selected_text(preset.spells[slot].as_str())
+                            .show_ui(ui, |ui| {
+                                for name in spells::spell_names() {
+                                    ui.selectable_value(
+                                        &mut preset.spells[slot],
+                                        name.to_string(),
+                                        name,
+                                    );
+                                }
+                            });
+                    }
+                    if ui.small_button("✖").clicked() {
+                        remove = Some(index);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(index) = remove {
+            settings.presets.remove(index);
+        }
+        if ui.button(tr("Add preset")).clicked() {
+            settings.presets.push(SpellPreset {
+                spells: vec!["Flash".to_string(), "Ignite".to_string()],
+                ..SpellPreset::default()
+            });
+        }
+    });
+}
+
+fn trade_response_combo(ui: &mut egui::Ui, id: &str, response: &mut TradeResponse) {
+    let label = |response: TradeResponse| {
+        tr(match response {
+            TradeResponse::Ignore => "Ask me",
+            TradeResponse::Accept => "Accept",
+            TradeResponse::Decline => "Decline",
+        })
+    };
+    ComboBox::from_id_source(id)
+        .selected_text(label(*response))
+        .show_ui(ui, |ui| {
+            for option in [
+                TradeResponse::Ignore,
+                TradeResponse::Accept,
+                TradeResponse::Decline,
+            ] {
+                ui.selectable_value(response, option, label(option));
+            }
+        });
+}
+
+fn queue_toggles(ui: &mut egui::Ui, queues: &mut Vec<u32>) {
+    ui.horizontal_wrapped(|ui| {
+        ui.label(tr("Queues"));
+        for &(queue, label) in config::KNOWN_QUEUES {
+            let mut enabled = queues.contains(&queue);
+            if ui.checkbox(&mut enabled, tr(label)).changed() {
+                queues.retain(|&known| known != queue);
+                if enabled {
+                    queues.push(queue);
+                }
+            }
+        }
+    });
+}
+
+/// One comma-separated champion list per role; `inputs` keeps the raw text being typed.
+fn champion_lists(
+    ui: &mut egui::Ui,
+    id: &str,
+    champions: &mut ChampionPriorities,
+    inputs: &mut [String],
+) {
+    egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+        for (role, input) in ChampionPriorities::ROLES.iter().zip(inputs) {
+            ui.label(role_label(role));
+            if ui
+                .add(
+                    egui::TextEdit::singleline(input)
+                        .desired_width(260.0)
+                        .hint_text(tr("e.g. Ahri, Syndra")),
+                )
+                .changed()
+            {
+                *champions.role_mut(role) = parse_champion_list(input);
+            }
+            ui.end_row();
+        }
+    });
+}
+
+fn champion_inputs(champions: &ChampionPriorities) -> Vec<String> {
+    ChampionPriorities::ROLES
+        .iter()
+        .map(|role| champions.role(role).join(", "))
+        .collect()
+}
+
+fn parse_champion_list(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn role_label(role: &str) -> &'static str {
+    tr(match role {
+        "top" => "Top",
+        "jungle" => "Jungle",
+        "middle" => "Mid",
+        "bottom" => "Bot",
+        "utility" => "Support",
+        _ => "Fill",
+    })
+}
+
+fn accept_mode_label(mode: AcceptMode) -> &'static str {
+    tr(match mode {
+        AcceptMode::Click => "click",
+        AcceptMode::Key => "key",
+        AcceptMode::ClickAndKey => "click + key",
+        AcceptMode::Lcu => "client API",
+    })
+}
+
+fn click_anchor_label(anchor: ClickAnchor) -> &'static str {
+    tr(match anchor {
+        ClickAnchor::Center => "Center",
+        ClickAnchor::TopLeft => "Top-left",
+        ClickAnchor::Percent { .. } => "Custom %",
+    })
+}
+
+fn input_backend_label(kind: InputBackendKind) -> &'static str {
+    match kind {
+        InputBackendKind::Enigo => "enigo",
+        InputBackendKind::SendInput => "SendInput (Windows)",
+        InputBackendKind::Xdotool => "xdotool (X11)",
+        InputBackendKind::Interception => "Interception driver (Windows)",
+    }
+}
+
+fn capture_source_label(source: &CaptureSource) -> String {
+    match source {
+        CaptureSource::Screen => tr("Screen (selected monitor)").to_string(),
+        CaptureSource::Window { title } => trf("Window \"{title}\"", &[("title", title)]),
+        CaptureSource::Directory { path, .. } => {
+            trf("Frame directory {path}", &[("path", &path.display())])
+        }
     }
 }
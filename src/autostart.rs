@@ -0,0 +1,253 @@
+//! Launching the app when the user logs in: a `Run` registry value on Windows, an XDG autostart
+//! entry on Linux and a LaunchAgent on macOS, always pointing at the running executable.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// Name of the registry value, autostart entry and LaunchAgent label.
+pub const ENTRY_NAME: &str = "lol-auto-accept-rs";
+/// Opens the app minimized (to the tray when it has one). Every autostart entry passes it, so
+/// launching at login does not put a window in front of the user.
+pub const MINIMIZED_FLAG: &str = "--minimized";
+
+/// Whether this platform has an autostart mechanism we can register with.
+pub fn is_supported() -> bool {
+    cfg!(any(windows, target_os = "linux", target_os = "macos"))
+}
+
+/// Whether the app is registered to launch at login.
+pub fn is_enabled() -> Result<bool> {
+    platform::is_enabled()
+}
+
+/// Registers (or unregisters) the current executable to launch at login.
+pub fn set_enabled(enabled: bool) -> Result<()> {
+    if enabled {
+        let exe = std::env::current_exe().context("Failed to locate the executable")?;
+        platform::enable(&exe)
+    } else {
+        platform::disable()
+    }
+}
+
+/// Command line stored in the `Run` key: the quoted executable path and [`MINIMIZED_FLAG`].
+pub fn run_command(exe: &Path) -> String {
+    format!("\"{}\" {MINIMIZED_FLAG}", exe.display())
+}
+
+/// XDG autostart entry launching `exe`.
+pub fn desktop_entry(exe: &Path) -> String {
+    format!(
+        "[Desktop Entry]\nType=Application\nName=LoL Auto Accept\nExec=\"{}\" {MINIMIZED_FLAG}\nX-GNOME-Autostart-enabled=true\n",
+        exe.display()
+    )
+}
+
+/// LaunchAgent property list running `exe` at login.
+pub fn launch_agent(exe: &Path) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>Label</key>
+    <string>{ENTRY_NAME}</string>
+    <key>ProgramArguments</key>
+    <array>
+        <string>{}</string>
+        <string>{MINIMIZED_FLAG}</string>
+    </array>
+    <key>RunAtLoad</key>
+    <true/>
+</dict>
+</plist>
+"#,
+        exe.display()
+    )
+}
+
+#[cfg_attr(not(any(target_os = "linux", target_os = "macos")), allow(dead_code))]
+fn home_dir() -> Result<PathBuf> {
+    std::env::var_os("HOME")
+        .map(PathBuf::from)
+        .context("HOME is not set")
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::{path::Path, ptr};
+
+    use anyhow::{Result, anyhow, bail};
+    use windows_sys::Win32::{
+        Foundation::{ERROR_FILE_NOT_FOUND, ERROR_SUCCESS},
+        System::Registry::{
+            HKEY, HKEY_CURRENT_USER, KEY_QUERY_VALUE, KEY_SET_VALUE, REG_SZ, RegCloseKey,
+            RegDeleteValueW, RegOpenKeyExW, RegQueryValueExW, RegSetValueExW,
+        },
+    };
+
+    use super::{ENTRY_NAME, run_command};
+
+    const RUN_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Run";
+
+    fn wide(text: &str) -> Vec<u16> {
+        text.encode_utf16().chain(Some(0)).collect()
+    }
+
+    /// Runs `f` with the current user's `Run` key opened for `access`.
+    fn with_run_key<T>(access: u32, f: impl FnOnce(HKEY) -> Result<T>) -> Result<T> {
+        let subkey = wide(RUN_KEY);
+        let mut key: HKEY = ptr::null_mut();
+        // SAFETY: `subkey` is NUL-terminated and `key` is a valid out pointer.
+        let status =
+            unsafe { RegOpenKeyExW(HKEY_CURRENT_USER, subkey.as_ptr(), 0, access, &mut key) };
+        if status != ERROR_SUCCESS {
+            bail!("Failed to open the Run registry key (error {status})");
+        }
+        let result = f(key);
+        // SAFETY: `key` was opened above.
+        unsafe { RegCloseKey(key) };
+        result
+    }
+
+    pub fn is_enabled() -> Result<bool> {
+        with_run_key(KEY_QUERY_VALUE, |key| {
+            let name = wide(ENTRY_NAME);
+            // SAFETY: only asks whether the value exists; all out pointers may be null.
+            let status = unsafe {
+                RegQueryValueExW(
+                    key,
+                    name.as_ptr(),
+                    ptr::null(),
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                )
+            };
+            match status {
+                ERROR_SUCCESS => Ok(true),
+                ERROR_FILE_NOT_FOUND => Ok(false),
+                status => Err(anyhow!(
+                    "Failed to read the Run registry key (error {status})"
+                )),
+            }
+        })
+    }
+
+    pub fn enable(exe: &Path) -> Result<()> {
+        with_run_key(KEY_SET_VALUE, |key| {
+            let name = wide(ENTRY_NAME);
+            let command = wide(&run_command(exe));
+            // SAFETY: `command` is a NUL-terminated REG_SZ whose byte length includes the NUL.
+            let status = unsafe {
+                RegSetValueExW(
+                    key,
+                    name.as_ptr(),
+                    0,
+                    REG_SZ,
+                    command.as_ptr().cast(),
+                    (command.len() * 2) as u32,
+                )
+            };
+            if status != ERROR_SUCCESS {
+                bail!("Failed to write the Run registry value (error {status})");
+            }
+            Ok(())
+        })
+    }
+
+    pub fn disable() -> Result<()> {
+        with_run_key(KEY_SET_VALUE, |key| {
+            let name = wide(ENTRY_NAME);
+            // SAFETY: `name` is NUL-terminated.
+            match unsafe { RegDeleteValueW(key, name.as_ptr()) } {
+                ERROR_SUCCESS | ERROR_FILE_NOT_FOUND => Ok(()),
+                status => Err(anyhow!(
+                    "Failed to delete the Run registry value (error {status})"
+                )),
+            }
+        })
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+mod platform {
+    use std::{fs, io::ErrorKind, path::Path, path::PathBuf};
+
+    use anyhow::{Context, Result};
+
+    use super::{ENTRY_NAME, home_dir};
+
+    #[cfg(target_os = "linux")]
+    fn entry_path() -> Result<PathBuf> {
+        let config = match std::env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => home_dir()?.join(".config"),
+        };
+        Ok(config
+            .join("autostart")
+            .join(format!("{ENTRY_NAME}.desktop")))
+    }
+
+    #[cfg(target_os = "linux")]
+    fn entry_contents(exe: &Path) -> String {
+        super::desktop_entry(exe)
+    }
+
+    #[cfg(target_os = "macos")]
+    fn entry_path() -> Result<PathBuf> {
+        Ok(home_dir()?
+            .join("Library")
+            .join("LaunchAgents")
+            .join(format!("{ENTRY_NAME}.plist")))
+    }
+
+    #[cfg(target_os = "macos")]
+    fn entry_contents(exe: &Path) -> String {
+        super::launch_agent(exe)
+    }
+
+    pub fn is_enabled() -> Result<bool> {
+        Ok(entry_path()?.exists())
+    }
+
+    pub fn enable(exe: &Path) -> Result<()> {
+        let path = entry_path()?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        fs::write(&path, entry_contents(exe))
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn disable() -> Result<()> {
+        let path = entry_path()?;
+        match fs::remove_file(&path) {
+            Err(err) if err.kind() != ErrorKind::NotFound => {
+                Err(err).with_context(|| format!("Failed to remove {}", path.display()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
+mod platform {
+    use std::path::Path;
+
+    use anyhow::{Result, bail};
+
+    pub fn is_enabled() -> Result<bool> {
+        Ok(false)
+    }
+
+    pub fn enable(_exe: &Path) -> Result<()> {
+        bail!("Launch at login is not supported on this platform")
+    }
+
+    pub fn disable() -> Result<()> {
+        Ok(())
+    }
+}
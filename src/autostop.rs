@@ -0,0 +1,57 @@
+//! Stops monitoring after a stretch without any detection, warning shortly beforehand, so a
+//! forgotten session does not scan the desktop all night.
+
+use std::time::{Duration, Instant};
+
+use crate::config::AutoStopSettings;
+
+/// What the timer wants done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoStopAction {
+    /// Monitoring stops in `remaining` unless something is detected first.
+    Warn {
+        remaining: Duration,
+    },
+    Stop,
+}
+
+/// Time since the last detection (or since monitoring started).
+#[derive(Debug, Clone)]
+pub struct AutoStopTimer {
+    last_activity: Instant,
+    warned: bool,
+}
+
+impl AutoStopTimer {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            last_activity: now,
+            warned: false,
+        }
+    }
+
+    /// A detection happened; the countdown starts over.
+    pub fn on_activity(&mut self, now: Instant) {
+        self.last_activity = now;
+        self.warned = false;
+    }
+
+    /// The warning once per quiet stretch, then the stop; `None` while disabled or not due.
+    pub fn poll(&mut self, settings: &AutoStopSettings, now: Instant) -> Option<AutoStopAction> {
+        if !settings.enabled || settings.after_minutes == 0 {
+            return None;
+        }
+        let limit = Duration::from_secs(settings.after_minutes * 60);
+        let quiet = now.saturating_duration_since(self.last_activity);
+        if quiet >= limit {
+            return Some(AutoStopAction::Stop);
+        }
+        let remaining = limit - quiet;
+        let warn_before = Duration::from_secs(settings.warn_minutes * 60);
+        if !self.warned && settings.warn_minutes > 0 && remaining <= warn_before {
+            self.warned = true;
+            return Some(AutoStopAction::Warn { remaining });
+        }
+        None
+    }
+}
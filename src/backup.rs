@@ -0,0 +1,77 @@
+//! Settings export/import: the configuration and all profiles in one JSON file, for moving a
+//! setup between machines. Template images are referenced by path, not embedded.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{AppConfig, Profiles};
+
+/// Bumped when the file layout changes in a way older builds cannot read.
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SettingsBundle {
+    pub version: u32,
+    /// Version of the app that wrote the file, for troubleshooting.
+    pub app_version: String,
+    pub config: AppConfig,
+    #[serde(default)]
+    pub profiles: Profiles,
+}
+
+impl SettingsBundle {
+    pub fn new(config: &AppConfig, profiles: &Profiles) -> Self {
+        Self {
+            version: FORMAT_VERSION,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            config: config.clone(),
+            profiles: profiles.clone(),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).context("Failed to serialize settings")
+    }
+
+    /// Parses an exported file, refusing ones written by a newer format.
+    pub fn from_json(text: &str) -> Result<Self> {
+        let bundle: Self = serde_json::from_str(text).context("Not a settings export")?;
+        if bundle.version > FORMAT_VERSION {
+            bail!(
+                "Settings were exported by a newer version ({}); update the app to import them",
+                bundle.app_version
+            );
+        }
+        Ok(bundle)
+    }
+
+    /// Template images the imported settings point at that do not exist on this machine.
+    pub fn missing_templates(&self) -> Vec<PathBuf> {
+        let mut missing: Vec<PathBuf> = self
+            .config
+            .template_path
+            .iter()
+            .chain(self.config.templates.iter().map(|entry| &entry.path))
+            .filter(|path| !path.exists())
+            .cloned()
+            .collect();
+        missing.dedup();
+        missing
+    }
+}
+
+pub fn export(path: &Path, bundle: &SettingsBundle) -> Result<()> {
+    fs::write(path, bundle.to_json()?)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+pub fn import(path: &Path) -> Result<SettingsBundle> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    SettingsBundle::from_json(&text)
+}
@@ -0,0 +1,15 @@
+//! Thin console entry point: the same as `lol-auto-accept-rs --headless`, with the same
+//! command-line overrides.
+
+use anyhow::Result;
+use clap::Parser;
+use lol_auto_accept_rs::{cli::Cli, config, crash, headless, logpipe};
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    logpipe::init_console_logging()?;
+    crash::install_panic_hook();
+    let mut config = config::load_or_default()?;
+    cli.apply(&mut config)?;
+    headless::run(config)
+}
@@ -1,6 +1,175 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
 use anyhow::{Context, Result, anyhow};
-use image::{DynamicImage, GrayImage, ImageBuffer, Rgba};
+use image::GrayImage;
 use screenshots::{Screen, display_info::DisplayInfo};
+use tracing::info;
+
+use crate::config::CaptureSource;
+
+/// Optional file inside a frame directory listing frame file names (one per line) to play in
+/// order instead of every image sorted by name.
+const SEQUENCE_FILE: &str = "sequence.txt";
+
+/// Source of grayscale frames for the worker.
+pub trait CaptureBackend: Send {
+    fn name(&self) -> &str;
+
+    fn monitors(&self) -> Result<Vec<MonitorInfo>>;
+
+    fn capture_into(&mut self, monitor_index: usize, frame: &mut CapturedFrame) -> Result<()>;
+
+    /// `true` once a finite source has served its last frame.
+    fn is_exhausted(&self) -> bool {
+        false
+    }
+}
+
+pub fn open_backend(source: &CaptureSource) -> Result<Box<dyn CaptureBackend>> {
+    match source {
+        CaptureSource::Screen => Ok(Box::new(ScreenBackend)),
+        CaptureSource::Window { title } => open_window_backend(title),
+        CaptureSource::Directory { path, looping } => {
+            let backend = DirectoryBackend::open(path, *looping)?;
+            info!(
+                path = %path.display(),
+                frames = backend.len(),
+                looping,
+                "using directory capture backend"
+            );
+            Ok(Box::new(backend))
+        }
+    }
+}
+
+/// Live desktop capture through `screenshots`.
+pub struct ScreenBackend;
+
+impl CaptureBackend for ScreenBackend {
+    fn name(&self) -> &str {
+        "screen"
+    }
+
+    fn monitors(&self) -> Result<Vec<MonitorInfo>> {
+        enumerate_monitors()
+    }
+
+    fn capture_into(&mut self, monitor_index: usize, frame: &mut CapturedFrame) -> Result<()> {
+        capture_monitor_gray_into(monitor_index, frame)
+    }
+}
+
+#[cfg(windows)]
+fn open_window_backend(title: &str) -> Result<Box<dyn CaptureBackend>> {
+    info!(title, "using window capture backend");
+    Ok(Box::new(win32::WindowBackend::new(title)))
+}
+
+#[cfg(not(windows))]
+fn open_window_backend(_title: &str) -> Result<Box<dyn CaptureBackend>> {
+    Err(anyhow!("Window capture is only supported on Windows"))
+}
+
+/// Serves frames from image files on disk, either looping forever or playing the directory once.
+/// The monitor index is ignored; every frame is reported as a single 100% display at the origin.
+pub struct DirectoryBackend {
+    frames: Vec<PathBuf>,
+    next: usize,
+    looping: bool,
+}
+
+impl DirectoryBackend {
+    pub fn open(dir: &Path, looping: bool) -> Result<Self> {
+        let sequence = dir.join(SEQUENCE_FILE);
+        let frames = if sequence.exists() {
+            fs::read_to_string(&sequence)
+                .with_context(|| format!("Failed to read {sequence:?}"))?
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(|line| dir.join(line))
+                .collect()
+        } else {
+            let mut frames: Vec<PathBuf> = fs::read_dir(dir)
+                .with_context(|| format!("Failed to read frame directory {dir:?}"))?
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| is_image_file(path))
+                .collect();
+            frames.sort();
+            frames
+        };
+
+        if frames.is_empty() {
+            return Err(anyhow!("No frames found in {dir:?}"));
+        }
+        Ok(Self {
+            frames,
+            next: 0,
+            looping,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+impl CaptureBackend for DirectoryBackend {
+    fn name(&self) -> &str {
+        "directory"
+    }
+
+    fn monitors(&self) -> Result<Vec<MonitorInfo>> {
+        Ok(vec![MonitorInfo {
+            id: 0,
+            name: "Frame directory".to_string(),
+            width: 0,
+            height: 0,
+            scale_factor: 1.0,
+            is_primary: true,
+            origin_x: 0,
+            origin_y: 0,
+        }])
+    }
+
+    fn capture_into(&mut self, _monitor_index: usize, frame: &mut CapturedFrame) -> Result<()> {
+        if self.is_exhausted() {
+            return Err(anyhow!("Frame directory exhausted"));
+        }
+
+        let path = &self.frames[self.next % self.frames.len()];
+        let rgba = image::open(path)
+            .with_context(|| format!("Failed to load frame {path:?}"))?
+            .into_rgba8();
+        rgba_to_luma_into(rgba.as_raw(), rgba.width(), rgba.height(), &mut frame.image)?;
+        frame.origin = (0, 0);
+        frame.scale_factor = 1.0;
+
+        self.next += 1;
+        if self.looping {
+            self.next %= self.frames.len();
+        }
+        Ok(())
+    }
+
+    fn is_exhausted(&self) -> bool {
+        !self.looping && self.next >= self.frames.len()
+    }
+}
+
+fn is_image_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext.to_ascii_lowercase().as_str(), "png" | "jpg" | "jpeg"))
+}
 
 #[derive(Debug, Clone)]
 pub struct MonitorInfo {
@@ -37,13 +206,150 @@ pub fn enumerate_monitors() -> Result<Vec<MonitorInfo>> {
     Ok(monitors)
 }
 
+/// Logical size of the square masked around the cursor hotspot; covers the default arrow cursor.
+const CURSOR_MASK_SIZE: u32 = 32;
+
+const BACKOFF_BASE: Duration = Duration::from_millis(250);
+const BACKOFF_MAX: Duration = Duration::from_secs(8);
+
+/// Delay before the next capture attempt after `failures` consecutive errors: 250 ms doubling
+/// up to 8 s.
+pub fn backoff_delay(failures: u32) -> Duration {
+    if failures == 0 {
+        return Duration::ZERO;
+    }
+    let factor = 1u32 << (failures - 1).min(6);
+    BACKOFF_BASE.saturating_mul(factor).min(BACKOFF_MAX)
+}
+
+/// Picks the monitor to capture from a fresh enumeration: the preferred index when it still
+/// exists, otherwise the primary display, otherwise the first one.
+pub fn resolve_monitor_index(monitors: &[MonitorInfo], preferred: usize) -> Option<usize> {
+    if preferred < monitors.len() {
+        return Some(preferred);
+    }
+    monitors
+        .iter()
+        .position(|monitor| monitor.is_primary)
+        .or_else(|| (!monitors.is_empty()).then_some(0))
+}
+
+/// Finds where the display with `target_id` ended up after the monitor list changed, falling back
+/// to [`resolve_monitor_index`] when it was unplugged.
+pub fn remap_monitor_index(
+    monitors: &[MonitorInfo],
+    target_id: Option<u32>,
+    fallback: usize,
+) -> Option<usize> {
+    target_id
+        .and_then(|id| monitors.iter().position(|monitor| monitor.id == id))
+        .or_else(|| resolve_monitor_index(monitors, fallback))
+}
+
 pub struct CapturedFrame {
     pub image: GrayImage,
+    /// Top-left corner of the monitor in logical (DPI-independent) desktop coordinates.
     pub origin: (i32, i32),
+    /// Captured pixels per logical unit. The capture is taken in physical pixels, so on a
+    /// 150% scaled monitor this is `1.5`.
     pub scale_factor: f32,
 }
 
+impl CapturedFrame {
+    pub fn empty() -> Self {
+        Self {
+            image: GrayImage::new(0, 0),
+            origin: (0, 0),
+            scale_factor: 1.0,
+        }
+    }
+
+    /// Paints over the area a mouse cursor at logical `cursor` would cover, copying the row just
+    /// above it (or below, at the top edge) so the masked region blends with its surroundings.
+    pub fn mask_cursor(&mut self, cursor: (i32, i32)) {
+        let (width, height) = self.image.dimensions();
+        if width == 0 || height == 0 {
+            return;
+        }
+        let (px, py) = self.to_physical(cursor.0, cursor.1);
+        let size = CURSOR_MASK_SIZE as f32 * effective_scale(self.scale_factor);
+        let x0 = px.floor().max(0.0) as u32;
+        let y0 = py.floor().max(0.0) as u32;
+        let x1 = ((px + size).ceil().max(0.0) as u32).min(width);
+        let y1 = ((py + size).ceil().max(0.0) as u32).min(height);
+        if x0 >= x1 || y0 >= y1 {
+            return;
+        }
+
+        let source_row = if y0 > 0 {
+            y0 - 1
+        } else if y1 < height {
+            y1
+        } else {
+            return;
+        };
+        for x in x0..x1 {
+            let fill = *self.image.get_pixel(x, source_row);
+            for y in y0..y1 {
+                self.image.put_pixel(x, y, fill);
+            }
+        }
+    }
+
+    fn byte_len(&self) -> u64 {
+        self.image.as_raw().len() as u64
+    }
+
+    /// Maps a point in captured (physical) pixels to logical desktop coordinates suitable for
+    /// `input::click_at`.
+    pub fn to_logical(&self, x: f32, y: f32) -> (i32, i32) {
+        physical_to_logical(self.origin, self.scale_factor, x, y)
+    }
+
+    /// Maps logical desktop coordinates back into captured (physical) pixels.
+    pub fn to_physical(&self, x: i32, y: i32) -> (f32, f32) {
+        logical_to_physical(self.origin, self.scale_factor, x, y)
+    }
+}
+
+pub fn physical_to_logical(origin: (i32, i32), scale_factor: f32, x: f32, y: f32) -> (i32, i32) {
+    let scale = effective_scale(scale_factor);
+    (
+        origin.0 + (x / scale).round() as i32,
+        origin.1 + (y / scale).round() as i32,
+    )
+}
+
+pub fn logical_to_physical(origin: (i32, i32), scale_factor: f32, x: i32, y: i32) -> (f32, f32) {
+    let scale = effective_scale(scale_factor);
+    ((x - origin.0) as f32 * scale, (y - origin.1) as f32 * scale)
+}
+
+fn effective_scale(scale_factor: f32) -> f32 {
+    if scale_factor.is_finite() && scale_factor > 0.0 {
+        scale_factor
+    } else {
+        1.0
+    }
+}
+
+/// One frame of `monitor_index` from `source`, as the worker would see it.
+pub fn capture_once(source: &CaptureSource, monitor_index: usize) -> Result<CapturedFrame> {
+    let mut frame = CapturedFrame::empty();
+    open_backend(source)?.capture_into(monitor_index, &mut frame)?;
+    Ok(frame)
+}
+
 pub fn capture_monitor_gray(monitor_index: usize) -> Result<CapturedFrame> {
+    let mut frame = CapturedFrame::empty();
+    capture_monitor_gray_into(monitor_index, &mut frame)?;
+    Ok(frame)
+}
+
+/// Captures `monitor_index` into `frame`, reusing its grayscale buffer when the monitor
+/// resolution is unchanged. The RGBA capture itself is a new allocation every time:
+/// `screenshots` has no way to capture into an existing buffer.
+pub fn capture_monitor_gray_into(monitor_index: usize, frame: &mut CapturedFrame) -> Result<()> {
     let screens = Screen::all().context("Unable to list screens")?;
     let screen = screens
         .get(monitor_index)
@@ -51,19 +357,271 @@ pub fn capture_monitor_gray(monitor_index: usize) -> Result<CapturedFrame> {
 
     let rgba = screen.capture().context("Failed to capture screen")?;
     let (width, height) = (rgba.width(), rgba.height());
-    let raw = rgba.into_vec();
-    let rgba_buffer: ImageBuffer<Rgba<u8>, Vec<u8>> = ImageBuffer::from_raw(width, height, raw)
-        .ok_or_else(|| {
-            anyhow!(
-                "Unable to rebuild image buffer for monitor {}",
-                screen.display_info.id
-            )
-        })?;
-    let gray = DynamicImage::ImageRgba8(rgba_buffer).into_luma8();
-
-    Ok(CapturedFrame {
-        image: gray,
-        origin: (screen.display_info.x, screen.display_info.y),
-        scale_factor: screen.display_info.scale_factor,
-    })
+    rgba_to_luma_into(rgba.as_raw(), width, height, &mut frame.image).with_context(|| {
+        format!(
+            "Unable to convert capture for monitor {}",
+            screen.display_info.id
+        )
+    })?;
+
+    frame.origin = (screen.display_info.x, screen.display_info.y);
+    frame.scale_factor = capture_scale(&screen.display_info, width);
+    Ok(())
+}
+
+/// Allocation counters for a [`FrameArena`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArenaStats {
+    /// Captures that had to (re)allocate the grayscale buffer.
+    pub allocations: u64,
+    /// Captures that wrote into an existing buffer.
+    pub reuses: u64,
+    /// Bytes currently held by pooled and checked-out frames.
+    pub bytes_held: u64,
+}
+
+/// Small pool of [`CapturedFrame`]s so the worker reuses the same grayscale buffers every cycle
+/// instead of allocating them per capture. Backends may still allocate their own source image
+/// (the screen backend does); only what the worker keeps is pooled.
+pub struct FrameArena {
+    pool: Vec<CapturedFrame>,
+    max_pooled: usize,
+    stats: ArenaStats,
+}
+
+impl Default for FrameArena {
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl FrameArena {
+    pub fn new(max_pooled: usize) -> Self {
+        Self {
+            pool: Vec::new(),
+            max_pooled: max_pooled.max(1),
+            stats: ArenaStats::default(),
+        }
+    }
+
+    pub fn stats(&self) -> ArenaStats {
+        self.stats
+    }
+
+    /// Captures `monitor_index` from `backend` into a pooled frame. Hand the frame back with
+    /// [`FrameArena::recycle`] once done with it.
+    pub fn capture(
+        &mut self,
+        backend: &mut dyn CaptureBackend,
+        monitor_index: usize,
+    ) -> Result<CapturedFrame> {
+        self.fill(|frame| backend.capture_into(monitor_index, frame))
+    }
+
+    /// Runs `fill` against a pooled frame, accounting for whether its buffer had to grow.
+    pub fn fill(
+        &mut self,
+        fill: impl FnOnce(&mut CapturedFrame) -> Result<()>,
+    ) -> Result<CapturedFrame> {
+        let mut frame = self.pool.pop().unwrap_or_else(CapturedFrame::empty);
+        let before = frame.byte_len();
+        match fill(&mut frame) {
+            Ok(()) => {
+                let after = frame.byte_len();
+                if after == before && before > 0 {
+                    self.stats.reuses += 1;
+                } else {
+                    self.stats.allocations += 1;
+                    self.stats.bytes_held = (self.stats.bytes_held + after).saturating_sub(before);
+                }
+                Ok(frame)
+            }
+            Err(err) => {
+                self.recycle(frame);
+                Err(err)
+            }
+        }
+    }
+
+    pub fn recycle(&mut self, frame: CapturedFrame) {
+        if self.pool.len() < self.max_pooled {
+            self.pool.push(frame);
+        } else {
+            self.stats.bytes_held = self.stats.bytes_held.saturating_sub(frame.byte_len());
+        }
+    }
+}
+
+/// Converts tightly packed RGBA8 bytes to luma (rounded Rec. 709 weights, alpha ignored — the same
+/// result as `DynamicImage::into_luma8`) without intermediate images. `out` is reallocated only
+/// when its dimensions differ from `width`×`height`.
+pub fn rgba_to_luma_into(raw: &[u8], width: u32, height: u32, out: &mut GrayImage) -> Result<()> {
+    pixels_to_luma_into(raw, width, height, out, [0, 1, 2])
+}
+
+/// Same as [`rgba_to_luma_into`] for BGRA8 bytes, the layout of GDI device-independent bitmaps.
+pub fn bgra_to_luma_into(raw: &[u8], width: u32, height: u32, out: &mut GrayImage) -> Result<()> {
+    pixels_to_luma_into(raw, width, height, out, [2, 1, 0])
+}
+
+fn pixels_to_luma_into(
+    raw: &[u8],
+    width: u32,
+    height: u32,
+    out: &mut GrayImage,
+    [ri, gi, bi]: [usize; 3],
+) -> Result<()> {
+    let expected = width as usize * height as usize * 4;
+    if raw.len() != expected {
+        return Err(anyhow!(
+            "RGBA buffer has {} bytes, expected {expected} for {width}x{height}",
+            raw.len()
+        ));
+    }
+
+    if out.dimensions() != (width, height) {
+        *out = GrayImage::new(width, height);
+    }
+
+    for (luma, px) in out.iter_mut().zip(raw.chunks_exact(4)) {
+        let (r, g, b) = (px[ri] as u32, px[gi] as u32, px[bi] as u32);
+        *luma = ((2126 * r + 7152 * g + 722 * b + 5_000) / 10_000) as u8;
+    }
+    Ok(())
+}
+
+/// Derives the physical/logical ratio from the captured width, which stays correct even when the
+/// reported monitor bounds are already physical (per-monitor DPI aware processes).
+fn capture_scale(info: &DisplayInfo, captured_width: u32) -> f32 {
+    if info.width > 0 && captured_width > 0 {
+        captured_width as f32 / info.width as f32
+    } else {
+        info.scale_factor
+    }
+}
+
+#[cfg(windows)]
+mod win32 {
+    use std::{mem, ptr};
+
+    use anyhow::{Result, anyhow};
+    use windows_sys::Win32::{
+        Foundation::{HWND, POINT, RECT},
+        Graphics::Gdi::{
+            BI_RGB, BITMAPINFO, BITMAPINFOHEADER, ClientToScreen, CreateCompatibleBitmap,
+            CreateCompatibleDC, DIB_RGB_COLORS, DeleteDC, DeleteObject, GetDC, GetDIBits,
+            ReleaseDC, SelectObject,
+        },
+        Storage::Xps::{PW_CLIENTONLY, PrintWindow},
+        UI::WindowsAndMessaging::{FindWindowW, GetClientRect, IsIconic, PW_RENDERFULLCONTENT},
+    };
+
+    use super::{
+        CaptureBackend, CapturedFrame, MonitorInfo, bgra_to_luma_into, enumerate_monitors,
+    };
+
+    /// Captures a window's client area with `PrintWindow`, which renders it even while other
+    /// windows cover it. Minimized windows have nothing to render and report an error.
+    pub struct WindowBackend {
+        title: Vec<u16>,
+        display_title: String,
+        pixels: Vec<u8>,
+    }
+
+    impl WindowBackend {
+        pub fn new(title: &str) -> Self {
+            Self {
+                title: title.encode_utf16().chain(Some(0)).collect(),
+                display_title: title.to_string(),
+                pixels: Vec::new(),
+            }
+        }
+
+        fn find(&self) -> Result<HWND> {
+            // SAFETY: `title` is a NUL-terminated UTF-16 string that outlives the call.
+            let hwnd = unsafe { FindWindowW(ptr::null(), self.title.as_ptr()) };
+            if hwnd.is_null() {
+                return Err(anyhow!("Window {:?} not found", self.display_title));
+            }
+            Ok(hwnd)
+        }
+    }
+
+    impl CaptureBackend for WindowBackend {
+        fn name(&self) -> &str {
+            "window"
+        }
+
+        fn monitors(&self) -> Result<Vec<MonitorInfo>> {
+            enumerate_monitors()
+        }
+
+        fn capture_into(&mut self, _monitor_index: usize, frame: &mut CapturedFrame) -> Result<()> {
+            let hwnd = self.find()?;
+            // SAFETY: every GDI object created here is released before returning, and the pixel
+            // buffer is sized for the 32bpp top-down DIB requested from GetDIBits.
+            unsafe {
+                if IsIconic(hwnd) != 0 {
+                    return Err(anyhow!("Window {:?} is minimized", self.display_title));
+                }
+                let mut rect: RECT = mem::zeroed();
+                if GetClientRect(hwnd, &mut rect) == 0 {
+                    return Err(anyhow!("GetClientRect failed"));
+                }
+                let (width, height) = (rect.right - rect.left, rect.bottom - rect.top);
+                if width <= 0 || height <= 0 {
+                    return Err(anyhow!(
+                        "Window {:?} has an empty client area",
+                        self.display_title
+                    ));
+                }
+                let mut origin = POINT { x: 0, y: 0 };
+                ClientToScreen(hwnd, &mut origin);
+
+                let screen_dc = GetDC(ptr::null_mut());
+                let mem_dc = CreateCompatibleDC(screen_dc);
+                let bitmap = CreateCompatibleBitmap(screen_dc, width, height);
+                let previous = SelectObject(mem_dc, bitmap);
+
+                let printed = PrintWindow(hwnd, mem_dc, PW_CLIENTONLY | PW_RENDERFULLCONTENT);
+                let mut info: BITMAPINFO = mem::zeroed();
+                info.bmiHeader = BITMAPINFOHEADER {
+                    biSize: mem::size_of::<BITMAPINFOHEADER>() as u32,
+                    biWidth: width,
+                    biHeight: -height,
+                    biPlanes: 1,
+                    biBitCount: 32,
+                    biCompression: BI_RGB,
+                    ..mem::zeroed()
+                };
+                self.pixels.resize(width as usize * height as usize * 4, 0);
+                let lines = GetDIBits(
+                    mem_dc,
+                    bitmap,
+                    0,
+                    height as u32,
+                    self.pixels.as_mut_ptr().cast(),
+                    &mut info,
+                    DIB_RGB_COLORS,
+                );
+
+                SelectObject(mem_dc, previous);
+                DeleteObject(bitmap);
+                DeleteDC(mem_dc);
+                ReleaseDC(ptr::null_mut(), screen_dc);
+
+                if printed == 0 || lines != height {
+                    return Err(anyhow!(
+                        "PrintWindow capture of {:?} failed",
+                        self.display_title
+                    ));
+                }
+
+                bgra_to_luma_into(&self.pixels, width as u32, height as u32, &mut frame.image)?;
+                frame.origin = (origin.x, origin.y);
+                frame.scale_factor = 1.0;
+            }
+            Ok(())
+        }
+    }
 }
@@ -0,0 +1,837 @@
+//! Champ-select automation through the LCU: hover and lock a champion from the priority list,
+//! ban from the ban list, swap with the ARAM bench and answer trade requests.
+
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, bail};
+use serde_json::{Value, json};
+use tracing::{debug, info, warn};
+
+use crate::{
+    config::{AppConfig, TradeResponse},
+    lcu::LcuClient,
+    runes::{self, LockedChampion},
+    spells,
+};
+
+pub const SESSION_URI: &str = "/lol-champ-select/v1/session";
+/// WAMP topic for champ-select session updates.
+pub const SESSION_EVENT: &str = "OnJsonApiEvent_lol-champ-select_v1_session";
+
+const ACTIONS_PATH: &str = "/lol-champ-select/v1/session/actions";
+const PICKABLE_PATH: &str = "/lol-champ-select/v1/pickable-champion-ids";
+const MY_SELECTION_PATH: &str = "/lol-champ-select/v1/session/my-selection";
+const BANNABLE_PATH: &str = "/lol-champ-select/v1/bannable-champion-ids";
+const BENCH_SWAP_PATH: &str = "/lol-champ-select/v1/session/bench/swap";
+const TRADES_PATH: &str = "/lol-champ-select/v1/session/trades";
+const PICK_ORDER_SWAPS_PATH: &str = "/lol-champ-select/v1/session/pick-order-swaps";
+const CHAMPION_SUMMARY_PATH: &str = "/lol-game-data/assets/v1/champion-summary.json";
+const GAMEFLOW_SESSION_PATH: &str = "/lol-gameflow/v1/session";
+
+/// One of the local player's unfinished champ-select actions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalAction {
+    pub id: i64,
+    /// `pick` or `ban`.
+    pub kind: String,
+    /// Currently hovered champion, `0` when none.
+    pub champion_id: i64,
+    pub in_progress: bool,
+}
+
+/// Something the automation did, reported to the GUI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChampSelectOutcome {
+    Hovered {
+        champion: String,
+    },
+    Locked {
+        champion: String,
+    },
+    Banned {
+        champion: String,
+    },
+    RunesApplied {
+        champion: String,
+    },
+    RunesFailed {
+        reason: String,
+    },
+    /// Summoner spells set, e.g. `Flash + Ignite`.
+    SpellsSet {
+        spells: String,
+    },
+    SpellsFailed {
+        reason: String,
+    },
+    /// None of the configured champions can be picked.
+    NoneAvailable,
+    /// Every champion on the ban list is already banned, picked or wanted by a teammate.
+    NothingToBan,
+    /// Took a wishlisted champion from the ARAM bench.
+    BenchSwapped {
+        champion: String,
+    },
+    /// Answered a teammate's champion trade (`champion` is what we gave up).
+    TradeAnswered {
+        champion: String,
+        accepted: bool,
+    },
+    PickOrderSwapAnswered {
+        accepted: bool,
+    },
+}
+
+/// What one pass over the session did. Every step runs on its own, so a failed pick does not
+/// hold up the ban or the trades.
+#[derive(Debug, Default)]
+pub struct ChampSelectReport {
+    pub outcomes: Vec<ChampSelectOutcome>,
+    /// One per failed step, naming the step.
+    pub errors: Vec<anyhow::Error>,
+}
+
+pub fn local_player_cell(session: &Value) -> Option<i64> {
+    session.get("localPlayerCellId")?.as_i64()
+}
+
+/// The local player's `assignedPosition` (`top`, `jungle`, ...), empty in blind queues.
+pub fn assigned_position(session: &Value) -> String {
+    let Some(cell) = local_player_cell(session) else {
+        return String::new();
+    };
+    team(session, "myTeam")
+        .find(|member| member.get("cellId").and_then(Value::as_i64) == Some(cell))
+        .and_then(|member| member.get("assignedPosition")?.as_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+pub fn pending_actions(session: &Value) -> Vec<LocalAction> {
+    let Some(cell) = local_player_cell(session) else {
+        return Vec::new();
+    };
+    all_actions(session)
+        .filter(|action| action.get("actorCellId").and_then(Value::as_i64) == Some(cell))
+        .filter(|action| !flag(action, "completed"))
+        .filter_map(|action| {
+            Some(LocalAction {
+                id: action.get("id")?.as_i64()?,
+                kind: action.get("type")?.as_str()?.to_string(),
+                champion_id: action
+                    .get("championId")
+                    .and_then(Value::as_i64)
+                    .unwrap_or(0),
+                in_progress: flag(action, "isInProgress"),
+            })
+        })
+        .collect()
+}
+
+/// Champions nobody else may pick any more: bans plus every other player's pick.
+pub fn taken_champions(session: &Value) -> HashSet<i64> {
+    let cell = local_player_cell(session);
+    let mut taken: HashSet<i64> = all_actions(session)
+        .filter(|action| flag(action, "completed"))
+        .filter(|action| {
+            action.get("type").and_then(Value::as_str) == Some("ban")
+                || action.get("actorCellId").and_then(Value::as_i64) != cell
+        })
+        .filter_map(|action| action.get("championId")?.as_i64())
+        .collect();
+    for side in ["myTeamBans", "theirTeamBans"] {
+        if let Some(bans) = session
+            .get("bans")
+            .and_then(|bans| bans.get(side))
+            .and_then(Value::as_array)
+        {
+            taken.extend(bans.iter().filter_map(Value::as_i64));
+        }
+    }
+    for member in team(session, "myTeam").chain(team(session, "theirTeam")) {
+        if member.get("cellId").and_then(Value::as_i64) != cell
+            && let Some(champion) = member.get("championId").and_then(Value::as_i64)
+        {
+            taken.insert(champion);
+        }
+    }
+    taken.remove(&0);
+    taken
+}
+
+/// The champion the local player ends up with: set on their team slot once no pick action of
+/// theirs is pending (also covers ARAM, which has no pick actions).
+pub fn locked_champion(session: &Value) -> Option<i64> {
+    let cell = local_player_cell(session)?;
+    if pending_actions(session)
+        .iter()
+        .any(|action| action.kind == "pick")
+    {
+        return None;
+    }
+    team(session, "myTeam")
+        .find(|member| member.get("cellId").and_then(Value::as_i64) == Some(cell))?
+        .get("championId")?
+        .as_i64()
+        .filter(|&champion| champion > 0)
+}
+
+/// Champions waiting on the ARAM bench; empty when the mode has no bench.
+pub fn bench_champions(session: &Value) -> Vec<i64> {
+    if session.get("benchEnabled").and_then(Value::as_bool) != Some(true) {
+        return Vec::new();
+    }
+    if let Some(bench) = session.get("benchChampions").and_then(Value::as_array) {
+        return bench
+            .iter()
+            .filter_map(|entry| entry.get("championId").and_then(Value::as_i64))
+            .collect();
+    }
+    // Older clients only list the ids.
+    session
+        .get("benchChampionIds")
+        .map(champion_ids_in_order)
+        .unwrap_or_default()
+}
+
+/// Best bench champion ranked above `current` on the wishlist (ids, most wanted first).
+pub fn choose_bench_swap(wishlist: &[i64], current: Option<i64>, bench: &[i64]) -> Option<i64> {
+    let rank = |champion: i64| wishlist.iter().position(|&wanted| wanted == champion);
+    let current_rank = current.and_then(rank).unwrap_or(wishlist.len());
+    bench
+        .iter()
+        .filter_map(|&champion| Some((rank(champion)?, champion)))
+        .filter(|&(rank, _)| rank < current_rank)
+        .min_by_key(|&(rank, _)| rank)
+        .map(|(_, champion)| champion)
+}
+
+/// A swap request a teammate sent us.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwapRequest {
+    pub id: i64,
+    pub cell_id: i64,
+}
+
+/// Pending requests addressed to us under `key` (`trades` or `pickOrderSwaps`).
+pub fn received_requests(session: &Value, key: &str) -> Vec<SwapRequest> {
+    team(session, key)
+        .filter(|request| request.get("state").and_then(Value::as_str) == Some("RECEIVED"))
+        .filter_map(|request| {
+            Some(SwapRequest {
+                id: request.get("id")?.as_i64()?,
+                cell_id: request.get("cellId").and_then(Value::as_i64).unwrap_or(-1),
+            })
+        })
+        .collect()
+}
+
+/// Champions teammates have declared or are hovering; banning them would be hostile.
+pub fn teammate_intents(session: &Value) -> HashSet<i64> {
+    let cell = local_player_cell(session);
+    let mut intents: HashSet<i64> = team(session, "myTeam")
+        .filter(|member| member.get("cellId").and_then(Value::as_i64) != cell)
+        .flat_map(|member| {
+            ["championPickIntent", "championId"]
+                .into_iter()
+                .filter_map(|key| member.get(key)?.as_i64())
+        })
+        .collect();
+    let teammates: HashSet<i64> = team(session, "myTeam")
+        .filter_map(|member| member.get("cellId")?.as_i64())
+        .filter(|&member| Some(member) != cell)
+        .collect();
+    intents.extend(
+        all_actions(session)
+            .filter(|action| action.get("type").and_then(Value::as_str) == Some("pick"))
+            .filter(|action| {
+                action
+                    .get("actorCellId")
+                    .and_then(Value::as_i64)
+                    .is_some_and(|actor| teammates.contains(&actor))
+            })
+            .filter_map(|action| action.get("championId")?.as_i64()),
+    );
+    intents.remove(&0);
+    intents
+}
+
+/// First candidate that is neither taken nor missing from `pickable` (when known).
+pub fn choose_champion(
+    candidates: &[i64],
+    taken: &HashSet<i64>,
+    pickable: Option<&HashSet<i64>>,
+) -> Option<i64> {
+    candidates
+        .iter()
+        .copied()
+        .find(|id| !taken.contains(id) && pickable.is_none_or(|pickable| pickable.contains(id)))
+}
+
+fn all_actions(session: &Value) -> impl Iterator<Item = &Value> {
+    session
+        .get("actions")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_array)
+        .flatten()
+}
+
+fn team<'a>(session: &'a Value, side: &str) -> impl Iterator<Item = &'a Value> {
+    session
+        .get(side)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+}
+
+fn flag(value: &Value, key: &str) -> bool {
+    value.get(key).and_then(Value::as_bool).unwrap_or(false)
+}
+
+/// Champion ids keyed by lower-cased display name and internal alias (`Wukong`/`MonkeyKing`).
+#[derive(Debug, Default)]
+pub struct ChampionCatalog {
+    ids: HashMap<String, i64>,
+    names: HashMap<i64, String>,
+    aliases: HashMap<i64, String>,
+}
+
+impl ChampionCatalog {
+    /// Builds the catalog from the client's `champion-summary.json`.
+    pub fn from_summary(summary: &Value) -> Self {
+        let mut catalog = Self::default();
+        for champion in summary.as_array().into_iter().flatten() {
+            let Some(id) = champion.get("id").and_then(Value::as_i64) else {
+                continue;
+            };
+            // Entry -1 is the "None" placeholder.
+            if id <= 0 {
+                continue;
+            }
+            for key in ["name", "alias"] {
+                if let Some(name) = champion.get(key).and_then(Value::as_str) {
+                    catalog.ids.insert(name.to_lowercase(), id);
+                }
+            }
+            if let Some(name) = champion.get("name").and_then(Value::as_str) {
+                catalog.names.insert(id, name.to_string());
+            }
+            if let Some(alias) = champion.get("alias").and_then(Value::as_str) {
+                catalog.aliases.insert(id, alias.to_string());
+            }
+        }
+        catalog
+    }
+
+    pub fn id(&self, name: &str) -> Option<i64> {
+        self.ids.get(&name.trim().to_lowercase()).copied()
+    }
+
+    pub fn name(&self, id: i64) -> String {
+        self.names
+            .get(&id)
+            .cloned()
+            .unwrap_or_else(|| format!("#{id}"))
+    }
+
+    /// Internal name (`MonkeyKing`), falling back to the display name.
+    pub fn alias(&self, id: i64) -> String {
+        self.aliases
+            .get(&id)
+            .cloned()
+            .unwrap_or_else(|| self.name(id))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Ids for `names` in order, skipping (and logging) unknown names.
+    pub fn resolve(&self, names: &[&str]) -> Vec<i64> {
+        names
+            .iter()
+            .filter_map(|name| {
+                let id = self.id(name);
+                if id.is_none() {
+                    warn!(name, "unknown champion in list");
+                }
+                id
+            })
+            .collect()
+    }
+}
+
+/// Per-champ-select state for the automation; lives on the worker thread.
+#[derive(Default)]
+pub struct ChampSelectAutomation {
+    client: Option<LcuClient>,
+    catalog: Option<ChampionCatalog>,
+    game_id: Option<i64>,
+    /// As the gameflow session reports it: `-1` in custom games and the practice tool.
+    queue_id: Option<i64>,
+    map_id: Option<i64>,
+    /// Latest session, re-evaluated by [`Self::tick`] while a delayed ban is waiting.
+    session: Option<Value>,
+    /// Champion we hovered per action id.
+    hovered: HashMap<i64, i64>,
+    locked: HashSet<i64>,
+    /// When each of our ban turns was first seen.
+    ban_started: HashMap<i64, Instant>,
+    banned: HashSet<i64>,
+    /// Champion the rune page was last written for.
+    runes_for: Option<i64>,
+    /// Champion the summoner spells were last set for.
+    spells_for: Option<i64>,
+    /// Bench champions already requested, so a rejected swap is not repeated.
+    bench_requested: HashSet<i64>,
+    /// Trade and pick-order swap request ids already answered.
+    answered: HashSet<i64>,
+    reported_unavailable: bool,
+}
+
+impl ChampSelectAutomation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forgets everything tied to the current champ select.
+    fn reset(&mut self) {
+        self.game_id = None;
+        self.queue_id = None;
+        self.map_id = None;
+        self.session = None;
+        self.hovered.clear();
+        self.locked.clear();
+        self.ban_started.clear();
+        self.banned.clear();
+        self.runes_for = None;
+        self.spells_for = None;
+        self.bench_requested.clear();
+        self.answered.clear();
+        self.reported_unavailable = false;
+    }
+
+    /// Reacts to a champ-select session event.
+    pub fn on_session(
+        &mut self,
+        config: &AppConfig,
+        event_type: &str,
+        session: &Value,
+    ) -> ChampSelectReport {
+        if event_type == "Delete" {
+            self.reset();
+            return ChampSelectReport::default();
+        }
+        let game_id = session.get("gameId").and_then(Value::as_i64);
+        if game_id != self.game_id {
+            self.reset();
+            self.game_id = game_id;
+        }
+        self.session = Some(session.clone());
+        self.evaluate(config, Instant::now())
+    }
+
+    /// Re-evaluates the last session once a delayed ban is due, since the client does not
+    /// necessarily send another event by then.
+    pub fn tick(&mut self, config: &AppConfig, now: Instant) -> ChampSelectReport {
+        let delay = Duration::from_millis(config.auto_ban.delay_ms);
+        let ban_due = self
+            .ban_started
+            .iter()
+            .any(|(action, started)| !self.banned.contains(action) && now >= *started + delay);
+        if ban_due {
+            self.evaluate(config, now)
+        } else {
+            ChampSelectReport::default()
+        }
+    }
+
+    fn evaluate(&mut self, config: &AppConfig, now: Instant) -> ChampSelectReport {
+        let mut report = ChampSelectReport::default();
+        let Some(session) = self.session.take() else {
+            return report;
+        };
+        let steps = [
+            ("Auto pick", self.pick(config, &session)),
+            ("Auto ban", self.ban(config, &session, now)),
+            ("Bench swap", self.bench_swap(config, &session).map(Vec::from_iter)),
+            ("Trade", self.trades(config, &session)),
+        ];
+        for (step, outcome) in steps {
+            match outcome {
+                Ok(outcomes) => report.outcomes.extend(outcomes),
+                Err(err) => report.errors.push(err.context(format!("{step} failed"))),
+            }
+        }
+        report.outcomes.extend(self.runes(config, &session));
+        report.outcomes.extend(self.summoner_spells(config, &session));
+        self.session = Some(session);
+        // A request the client rejected says nothing about the connection; one that never got an
+        // answer may mean the client restarted with new credentials.
+        let unreachable = report
+            .errors
+            .iter()
+            .any(|err| err.chain().any(|cause| cause.is::<ureq::Error>()));
+        if unreachable {
+            self.client = None;
+        }
+        report
+    }
+
+    /// Connects and loads the queue id and champion catalog on first use. Returns the queue, or
+    /// `None` when the lobby is not a matchmade queue the settings could list.
+    fn prepare(&mut self, config: &AppConfig) -> Result<Option<u32>> {
+        if self.client.is_none() {
+            self.client = Some(LcuClient::connect(config.lcu.lockfile.as_deref())?);
+        }
+        let client = self
+            .client
+            .as_ref()
+            .context("League client not connected")?;
+        if self.queue_id.is_none() {
+            let gameflow = client.get(GAMEFLOW_SESSION_PATH)?;
+            // Left unset when missing, so the next event asks again.
+            self.queue_id = gameflow.pointer("/gameData/queue/id").and_then(Value::as_i64);
+            self.map_id = gameflow.pointer("/map/id").and_then(Value::as_i64);
+        }
+        if self.catalog.is_none() {
+            let catalog = ChampionCatalog::from_summary(&client.get(CHAMPION_SUMMARY_PATH)?);
+            if catalog.is_empty() {
+                bail!("Client returned an empty champion list");
+            }
+            self.catalog = Some(catalog);
+        }
+        Ok(self.queue_id.and_then(|queue| u32::try_from(queue).ok()))
+    }
+
+    fn pick(&mut self, config: &AppConfig, session: &Value) -> Result<Vec<ChampSelectOutcome>> {
+        if !config.auto_pick.enabled {
+            return Ok(Vec::new());
+        }
+        let picks: Vec<LocalAction> = pending_actions(session)
+            .into_iter()
+            .filter(|action| action.kind == "pick")
+            .collect();
+        let taken = taken_champions(session);
+        // Nothing to do while our hover is still valid and it is not our turn yet.
+        if picks.iter().all(|action| {
+            !action.in_progress
+                && self
+                    .hovered
+                    .get(&action.id)
+                    .is_some_and(|champion| !taken.contains(champion))
+        }) {
+            return Ok(Vec::new());
+        }
+        let queue_id = self.prepare(config)?;
+        if !queue_id.is_some_and(|queue| config.auto_pick.queues.contains(&queue)) {
+            debug!(?queue_id, "auto pick disabled for this queue");
+            return Ok(Vec::new());
+        }
+        let (Some(client), Some(catalog)) = (&self.client, &self.catalog) else {
+            return Ok(Vec::new());
+        };
+
+        let position = assigned_position(session);
+        let candidates = catalog.resolve(&config.auto_pick.champions.candidates(&position));
+        let pickable = champion_ids(&client.get(PICKABLE_PATH)?);
+
+        let mut outcomes = Vec::new();
+        for action in picks {
+            let Some(champion) = choose_champion(&candidates, &taken, Some(&pickable)) else {
+                if !self.reported_unavailable {
+                    self.reported_unavailable = true;
+                    outcomes.push(ChampSelectOutcome::NoneAvailable);
+                }
+                continue;
+            };
+            let name = catalog.name(champion);
+            if self.hovered.get(&action.id) != Some(&champion) {
+                client.patch(
+                    &format!("{ACTIONS_PATH}/{}", action.id),
+                    &json!({ "championId": champion }),
+                )?;
+                self.hovered.insert(action.id, champion);
+                info!(champion = name, position, "champion hovered");
+                outcomes.push(ChampSelectOutcome::Hovered {
+                    champion: name.clone(),
+                });
+            }
+            if action.in_progress && !self.locked.contains(&action.id) {
+                client.post(&format!("{ACTIONS_PATH}/{}/complete", action.id))?;
+                self.locked.insert(action.id);
+                info!(champion = name, "champion locked in");
+                outcomes.push(ChampSelectOutcome::Locked { champion: name });
+            }
+        }
+        Ok(outcomes)
+    }
+
+    fn ban(
+        &mut self,
+        config: &AppConfig,
+        session: &Value,
+        now: Instant,
+    ) -> Result<Vec<ChampSelectOutcome>> {
+        if !config.auto_ban.enabled {
+            return Ok(Vec::new());
+        }
+        let delay = Duration::from_millis(config.auto_ban.delay_ms);
+        let mut due = Vec::new();
+        for action in pending_actions(session) {
+            if action.kind != "ban" || !action.in_progress || self.banned.contains(&action.id) {
+                continue;
+            }
+            let started = *self.ban_started.entry(action.id).or_insert(now);
+            if now >= started + delay {
+                due.push(action);
+            }
+        }
+        if due.is_empty() {
+            return Ok(Vec::new());
+        }
+        let queue_id = self.prepare(config)?;
+        if !queue_id.is_some_and(|queue| config.auto_ban.queues.contains(&queue)) {
+            debug!(?queue_id, "auto ban disabled for this queue");
+            // Stop re-checking this champ select.
+            self.banned.extend(due.iter().map(|action| action.id));
+            return Ok(Vec::new());
+        }
+        let (Some(client), Some(catalog)) = (&self.client, &self.catalog) else {
+            return Ok(Vec::new());
+        };
+
+        let position = assigned_position(session);
+        let candidates = catalog.resolve(&config.auto_ban.champions.candidates(&position));
+        let mut avoid = taken_champions(session);
+        avoid.extend(teammate_intents(session));
+        let bannable = champion_ids(&client.get(BANNABLE_PATH)?);
+
+        let mut outcomes = Vec::new();
+        for action in due {
+            let Some(champion) = choose_champion(&candidates, &avoid, Some(&bannable)) else {
+                self.banned.insert(action.id);
+                outcomes.push(ChampSelectOutcome::NothingToBan);
+                continue;
+            };
+            client.patch(
+                &format!("{ACTIONS_PATH}/{}", action.id),
+                &json!({ "championId": champion }),
+            )?;
+            client.post(&format!("{ACTIONS_PATH}/{}/complete", action.id))?;
+            // Only now: a failed call is retried with the next session update.
+            self.banned.insert(action.id);
+            let name = catalog.name(champion);
+            info!(champion = name, position, "champion banned");
+            outcomes.push(ChampSelectOutcome::Banned { champion: name });
+        }
+        Ok(outcomes)
+    }
+
+    /// Takes the best wishlisted champion from the ARAM bench if it beats the current one.
+    fn bench_swap(
+        &mut self,
+        config: &AppConfig,
+        session: &Value,
+    ) -> Result<Option<ChampSelectOutcome>> {
+        if !config.aram_bench.enabled || config.aram_bench.champions.is_empty() {
+            return Ok(None);
+        }
+        let bench = bench_champions(session);
+        if bench
+            .iter()
+            .all(|champion| self.bench_requested.contains(champion))
+        {
+            return Ok(None);
+        }
+        self.prepare(config)?;
+        let (Some(client), Some(catalog)) = (&self.client, &self.catalog) else {
+            return Ok(None);
+        };
+        let names: Vec<&str> = config
+            .aram_bench
+            .champions
+            .iter()
+            .map(String::as_str)
+            .collect();
+        let wishlist = catalog.resolve(&names);
+        let available: Vec<i64> = bench
+            .into_iter()
+            .filter(|champion| !self.bench_requested.contains(champion))
+            .collect();
+        let Some(champion) = choose_bench_swap(&wishlist, locked_champion(session), &available)
+        else {
+            return Ok(None);
+        };
+        self.bench_requested.insert(champion);
+        client.post(&format!("{BENCH_SWAP_PATH}/{champion}"))?;
+        let name = catalog.name(champion);
+        info!(champion = name, "swapped with the bench");
+        Ok(Some(ChampSelectOutcome::BenchSwapped { champion: name }))
+    }
+
+    /// Accepts or declines trade and pick-order swap requests per the trade settings.
+    fn trades(&mut self, config: &AppConfig, session: &Value) -> Result<Vec<ChampSelectOutcome>> {
+        let settings = &config.trades;
+        let pending = |key, response| {
+            if response == TradeResponse::Ignore {
+                return Vec::new();
+            }
+            received_requests(session, key)
+                .into_iter()
+                .filter(|request| !self.answered.contains(&request.id))
+                .collect()
+        };
+        let trades = pending("trades", settings.champion_trades);
+        let swaps = pending("pickOrderSwaps", settings.pick_order_swaps);
+        if trades.is_empty() && swaps.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.prepare(config)?;
+        let (Some(client), Some(catalog)) = (&self.client, &self.catalog) else {
+            return Ok(Vec::new());
+        };
+
+        let mut outcomes = Vec::new();
+        if !trades.is_empty() {
+            let names: Vec<&str> = settings.give_up.iter().map(String::as_str).collect();
+            let give_up = catalog.resolve(&names);
+            let ours = locked_champion(session);
+            for request in trades {
+                let accepted = match settings.champion_trades {
+                    TradeResponse::Accept if ours.is_some_and(|ours| give_up.contains(&ours)) => {
+                        true
+                    }
+                    TradeResponse::Decline => false,
+                    // Not a champion we are willing to hand over; leave it to the player.
+                    _ => continue,
+                };
+                self.answered.insert(request.id);
+                let verb = if accepted { "accept" } else { "decline" };
+                client.post(&format!("{TRADES_PATH}/{}/{verb}", request.id))?;
+                let champion = ours.map(|id| catalog.name(id)).unwrap_or_default();
+                info!(
+                    champion,
+                    cell = request.cell_id,
+                    accepted,
+                    "champion trade answered"
+                );
+                outcomes.push(ChampSelectOutcome::TradeAnswered { champion, accepted });
+            }
+        }
+        for request in swaps {
+            let accepted = settings.pick_order_swaps == TradeResponse::Accept;
+            self.answered.insert(request.id);
+            let verb = if accepted { "accept" } else { "decline" };
+            client.post(&format!("{PICK_ORDER_SWAPS_PATH}/{}/{verb}", request.id))?;
+            info!(cell = request.cell_id, accepted, "pick order swap answered");
+            outcomes.push(ChampSelectOutcome::PickOrderSwapAnswered { accepted });
+        }
+        Ok(outcomes)
+    }
+
+    /// Writes a rune page once the local champion is locked, and again if it changes (ARAM
+    /// rerolls and trades). Failures are reported instead of retried.
+    fn runes(&mut self, config: &AppConfig, session: &Value) -> Option<ChampSelectOutcome> {
+        if !config.runes.enabled {
+            return None;
+        }
+        let champion = locked_champion(session)?;
+        if self.runes_for == Some(champion) {
+            return None;
+        }
+        self.runes_for = Some(champion);
+        let outcome = self.prepare(config).and_then(|_| {
+            let (Some(client), Some(catalog)) = (&self.client, &self.catalog) else {
+                bail!("League client not connected");
+            };
+            let name = catalog.name(champion);
+            let alias = catalog.alias(champion);
+            let position = assigned_position(session);
+            let locked = LockedChampion {
+                id: champion,
+                name: &name,
+                alias: &alias,
+                position: &position,
+                // Summoner's Rift when the gameflow session has no map.
+                map_id: self.map_id.unwrap_or(11),
+            };
+            let page = runes::resolve_page(client, &config.runes, &locked)?;
+            runes::write_page(client, &config.runes.page_name, &page)?;
+            Ok(name)
+        });
+        Some(match outcome {
+            Ok(champion) => ChampSelectOutcome::RunesApplied { champion },
+            Err(err) => {
+                warn!(error = ?err, "rune page import failed");
+                ChampSelectOutcome::RunesFailed {
+                    reason: format!("{err:#}"),
+                }
+            }
+        })
+    }
+
+    /// Sets summoner spells from the best matching preset once the champion is locked.
+    fn summoner_spells(
+        &mut self,
+        config: &AppConfig,
+        session: &Value,
+    ) -> Option<ChampSelectOutcome> {
+        let settings = &config.summoner_spells;
+        if !settings.enabled {
+            return None;
+        }
+        let champion = locked_champion(session)?;
+        if self.spells_for == Some(champion) {
+            return None;
+        }
+        self.spells_for = Some(champion);
+        let outcome = self.prepare(config).and_then(|_| {
+            let (Some(client), Some(catalog)) = (&self.client, &self.catalog) else {
+                bail!("League client not connected");
+            };
+            let name = catalog.name(champion);
+            let position = assigned_position(session);
+            let Some(preset) = spells::find_preset(
+                &settings.presets,
+                &name,
+                &catalog.alias(champion),
+                &position,
+            ) else {
+                return Ok(None);
+            };
+            let [d, f] = spells::arrange(preset, settings.flash_key)
+                .with_context(|| format!("Invalid spell preset {:?}", preset.spells))?;
+            client.patch(MY_SELECTION_PATH, &json!({ "spell1Id": d, "spell2Id": f }))?;
+            let spells = format!("{} + {}", spells::spell_name(d), spells::spell_name(f));
+            info!(champion = name, spells, "summoner spells set");
+            Ok(Some(spells))
+        });
+        match outcome {
+            Ok(spells) => spells.map(|spells| ChampSelectOutcome::SpellsSet { spells }),
+            Err(err) => {
+                warn!(error = ?err, "setting summoner spells failed");
+                Some(ChampSelectOutcome::SpellsFailed {
+                    reason: format!("{err:#}"),
+                })
+            }
+        }
+    }
+}
+
+fn champion_ids_in_order(list: &Value) -> Vec<i64> {
+    list.as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_i64)
+        .collect()
+}
+
+fn champion_ids(list: &Value) -> HashSet<i64> {
+    champion_ids_in_order(list).into_iter().collect()
+}
@@ -0,0 +1,179 @@
+//! Command line: launch switches plus overrides of configuration-file values for one run, so
+//! scripts and shortcuts can change behavior without editing the file.
+
+use std::{collections::BTreeSet, path::PathBuf};
+
+use anyhow::{Context, Result, bail};
+use clap::Parser;
+use toml::{Table, Value};
+
+use crate::config::AppConfig;
+
+#[derive(Debug, Clone, Default, PartialEq, Parser)]
+#[command(
+    name = "lol-auto-accept-rs",
+    version,
+    about = "Accepts League of Legends ready checks by matching the Accept button on screen.",
+    after_help = "Values given here win over the configuration file for this run only."
+)]
+pub struct Cli {
+    /// Run the worker without a window, printing events to stdout until Ctrl+C.
+    #[arg(long)]
+    pub headless: bool,
+    /// Open minimized, hidden to the tray when it has one.
+    #[arg(long)]
+    pub minimized: bool,
+    /// Score a match needs to count as the Accept button (0.5-1.0).
+    #[arg(long, value_name = "SCORE")]
+    pub threshold: Option<f32>,
+    /// Monitor to capture, by its position in the monitor list.
+    #[arg(long, value_name = "INDEX")]
+    pub monitor: Option<usize>,
+    /// Time between captures.
+    #[arg(long, value_name = "MS")]
+    pub interval_ms: Option<u64>,
+    /// Pause after a click before the next one.
+    #[arg(long, value_name = "MS")]
+    pub cooldown_ms: Option<u64>,
+    /// Template image to match, instead of the enabled templates.
+    #[arg(long, value_name = "PATH")]
+    pub template: Option<PathBuf>,
+    /// Detect and report where the click would land without clicking.
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Start monitoring as soon as the window opens.
+    #[arg(long)]
+    pub auto_start: bool,
+    /// Any other setting as KEY=VALUE, with dots for tables (`lcu.enabled=true`). The value is
+    /// read as TOML, falling back to plain text. Repeatable.
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    pub overrides: Vec<String>,
+}
+
+impl Cli {
+    /// Applies every override to `config`: the `--set` values in order, then the named options,
+    /// which win when both name the same setting.
+    pub fn apply(&self, config: &mut AppConfig) -> Result<()> {
+        for assignment in &self.overrides {
+            set(config, assignment)?;
+        }
+        if let Some(threshold) = self.threshold {
+            config.threshold = threshold;
+        }
+        if let Some(monitor) = self.monitor {
+            // The remembered display id would otherwise win over the index.
+            config.monitor_index = monitor;
+            config.monitor_id = None;
+        }
+        if let Some(interval_ms) = self.interval_ms {
+            config.interval_ms = interval_ms;
+        }
+        if let Some(cooldown_ms) = self.cooldown_ms {
+            config.cooldown_ms = cooldown_ms;
+        }
+        if let Some(template) = &self.template {
+            config.template_path = Some(template.clone());
+        }
+        config.dry_run |= self.dry_run;
+        config.auto_start |= self.auto_start;
+        Ok(())
+    }
+
+    /// A copy of `config` with the overrides applied.
+    pub fn applied(&self, config: &AppConfig) -> Result<AppConfig> {
+        let mut config = config.clone();
+        self.apply(&mut config)?;
+        Ok(config)
+    }
+
+    /// What to write back for `edited`, a configuration that started as `file` with the
+    /// overrides applied: settings an override changed and the user left alone get `file`'s
+    /// value again, so the overrides last for this run only. Edits the user made are kept.
+    pub fn without_overrides(&self, edited: &AppConfig, file: &AppConfig) -> Result<AppConfig> {
+        let overridden = Value::try_from(self.applied(file)?)
+            .context("Failed to serialize the configuration")?;
+        let file = Value::try_from(file).context("Failed to serialize the configuration")?;
+        let mut restored =
+            Value::try_from(edited).context("Failed to serialize the configuration")?;
+        if let (Some(restored), Some(overridden), Some(file)) = (
+            restored.as_table_mut(),
+            overridden.as_table(),
+            file.as_table(),
+        ) {
+            restore(restored, overridden, file);
+        }
+        restored
+            .try_into()
+            .context("Failed to rebuild the configuration without the command-line overrides")
+    }
+}
+
+/// Puts `file`'s value back wherever `edited` still holds the one an override gave it. A
+/// missing key stands for an unset optional setting.
+fn restore(edited: &mut Table, overridden: &Table, file: &Table) {
+    let keys: BTreeSet<&String> = overridden.keys().chain(file.keys()).collect();
+    for key in keys {
+        let (overridden, file) = (overridden.get(key), file.get(key));
+        if overridden == file {
+            continue;
+        }
+        if let (
+            Some(Value::Table(edited)),
+            Some(Value::Table(overridden)),
+            Some(Value::Table(file)),
+        ) = (edited.get_mut(key), overridden, file)
+        {
+            restore(edited, overridden, file);
+        } else if edited.get(key) == overridden {
+            match file {
+                Some(value) => edited.insert(key.clone(), value.clone()),
+                None => edited.remove(key),
+            };
+        }
+    }
+}
+
+/// Sets one `key=value` through the configuration's TOML form, so any field can be reached by
+/// the same dotted key it has in the file. Unknown keys and values of the wrong type are errors.
+pub fn set(config: &mut AppConfig, assignment: &str) -> Result<()> {
+    let Some((key, text)) = assignment.split_once('=') else {
+        bail!("`{assignment}` is not KEY=VALUE");
+    };
+    let path: Vec<&str> = key.trim().split('.').map(str::trim).collect();
+    if path.iter().any(|part| part.is_empty()) {
+        bail!("`{key}` is not a setting name");
+    }
+    let mut root = Value::try_from(&*config).context("Failed to serialize the configuration")?;
+    let (name, parents) = path.split_last().expect("split yields at least one part");
+    let mut table = root.as_table_mut().expect("the configuration is a table");
+    for part in parents {
+        table = table
+            .entry(*part)
+            .or_insert_with(|| Value::Table(Table::new()))
+            .as_table_mut()
+            .with_context(|| format!("`{part}` in `{key}` is not a table"))?;
+    }
+    table.insert(name.to_string(), parse_value(text.trim()));
+    let updated: AppConfig = root
+        .try_into()
+        .with_context(|| format!("Invalid value for `{key}`"))?;
+    // Fields the configuration does not have are dropped on the way back.
+    let known = Value::try_from(&updated).context("Failed to serialize the configuration")?;
+    if lookup(&known, &path).is_none() {
+        bail!("Unknown setting `{key}`");
+    }
+    *config = updated;
+    Ok(())
+}
+
+/// `text` as a TOML value (`0.9`, `true`, `[1, 2]`, `"quoted"`), or as a string when it is not one.
+fn parse_value(text: &str) -> Value {
+    toml::from_str::<Table>(&format!("value = {text}"))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| Value::String(text.to_string()))
+}
+
+fn lookup<'a>(value: &'a Value, path: &[&str]) -> Option<&'a Value> {
+    path.iter().try_fold(value, |value, part| value.get(part))
+}
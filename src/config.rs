@@ -1,19 +1,1000 @@
-use std::path::PathBuf;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
 
 use anyhow::{Context, Result, anyhow};
+use chrono::{NaiveTime, Weekday};
 use serde::{Deserialize, Serialize};
 
-const APP_NAME: &str = "lol-auto-accept-rs";
+use crate::scales;
 
+pub(crate) const APP_NAME: &str = "lol-auto-accept-rs";
+
+/// Points the worker at a directory of frames instead of the screen, overriding the config file.
+pub const CAPTURE_DIR_ENV: &str = "LOL_AUTO_ACCEPT_CAPTURE_DIR";
+
+/// Confy name of the profiles file (`profiles.toml` in the config directory).
+const PROFILES_NAME: &str = "profiles";
+
+pub const DEFAULT_TOGGLE_HOTKEY: &str = "Ctrl+Alt+A";
+pub const LEAGUE_CLIENT_WINDOW_TITLE: &str = "League of Legends";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CaptureSource {
+    #[default]
+    Screen,
+    /// Captures the client area of the top-level window with this exact title, even while it is
+    /// covered by other windows (Windows only).
+    Window { title: String },
+    /// Replays PNG/JPEG frames from `path` (sorted by name, or in `sequence.txt` order).
+    Directory {
+        path: PathBuf,
+        #[serde(default)]
+        looping: bool,
+    },
+}
+
+/// Number of presses issued per accept.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ClickCount {
+    #[default]
+    Single,
+    /// For overlays that swallow the first click.
+    Double,
+}
+
+impl ClickCount {
+    pub fn presses(self) -> u32 {
+        match self {
+            ClickCount::Single => 1,
+            ClickCount::Double => 2,
+        }
+    }
+}
+
+/// How the accept is delivered once the button is found.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AcceptMode {
+    #[default]
+    Click,
+    /// Press `accept_key` without moving the cursor; the client must have keyboard focus.
+    Key,
+    ClickAndKey,
+    /// Accept through the client's local REST API instead of synthesizing input.
+    Lcu,
+}
+
+/// Implementation used to synthesize mouse and keyboard events.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InputBackendKind {
+    #[default]
+    Enigo,
+    /// Raw Win32 `SendInput` (Windows only).
+    SendInput,
+    /// The `xdotool` command-line tool (X11).
+    Xdotool,
+    /// Interception driver (Windows, `interception` cargo feature, requires `allow_driver_input`).
+    Interception,
+}
+
+/// Point inside the matched template that gets clicked, before `click_offset_x/y`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ClickAnchor {
+    #[default]
+    Center,
+    TopLeft,
+    /// Position as a percentage of the template width/height from its top-left corner.
+    Percent {
+        x: f32,
+        y: f32,
+    },
+}
+
+impl ClickAnchor {
+    /// Anchor as fractions (0.0–1.0) of the template width and height.
+    pub fn fractions(self) -> (f32, f32) {
+        match self {
+            ClickAnchor::Center => (0.5, 0.5),
+            ClickAnchor::TopLeft => (0.0, 0.0),
+            ClickAnchor::Percent { x, y } => {
+                (x.clamp(0.0, 100.0) / 100.0, y.clamp(0.0, 100.0) / 100.0)
+            }
+        }
+    }
+}
+
+/// Part of the captured monitor that is searched for the accept button, in captured (physical)
+/// pixels from the monitor's top-left corner.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SearchRegion {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl SearchRegion {
+    /// The rectangle spanned by two opposite corners, given in any order.
+    pub fn from_corners(a: (u32, u32), b: (u32, u32)) -> Self {
+        Self {
+            x: a.0.min(b.0),
+            y: a.1.min(b.1),
+            width: a.0.abs_diff(b.0),
+            height: a.1.abs_diff(b.1),
+        }
+    }
+
+    /// The part of the region inside a `width` x `height` frame; `None` when nothing is left.
+    pub fn clamped(self, width: u32, height: u32) -> Option<Self> {
+        let x = self.x.min(width);
+        let y = self.y.min(height);
+        let region = Self {
+            x,
+            y,
+            width: self.width.min(width - x),
+            height: self.height.min(height - y),
+        };
+        (region.width > 0 && region.height > 0).then_some(region)
+    }
+}
+
+/// Last-moment checks that cancel a click when the screen changed since detection.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ClickGuardSettings {
+    /// Abort when a different window came to the foreground after the match was found.
+    pub check_foreground: bool,
+    /// Capture once more right before clicking and require the button at the same spot.
+    pub reverify_pixels: bool,
+}
+
+impl Default for ClickGuardSettings {
+    fn default() -> Self {
+        Self {
+            check_foreground: true,
+            reverify_pixels: false,
+        }
+    }
+}
+
+/// Falls back through accept methods when verification shows the previous one was ignored.
+/// Each enabled stage gets `click_verify.max_attempts` attempts, in order click → key → LCU.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct EscalationSettings {
+    pub enabled: bool,
+    pub click: bool,
+    /// Press `input.accept_key`.
+    pub key: bool,
+    /// Accept through the League client API.
+    pub lcu: bool,
+}
+
+impl Default for EscalationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            click: true,
+            key: true,
+            lcu: false,
+        }
+    }
+}
+
+/// Re-checks the screen after accepting and clicks again while the dialog is still there.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ClickVerifySettings {
+    pub enabled: bool,
+    /// Wait before checking whether the dialog went away.
+    pub delay_ms: u64,
+    /// Total clicks per ready check, including the first one.
+    pub max_attempts: u32,
+    /// Random ± displacement applied to retry clicks.
+    pub retry_jitter_px: u32,
+}
+
+impl Default for ClickVerifySettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            delay_ms: 700,
+            max_attempts: 3,
+            retry_jitter_px: 4,
+        }
+    }
+}
+
+/// How synthetic mouse and keyboard input is delivered.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct InputSettings {
+    pub backend: InputBackendKind,
+    /// Explicit opt-in for backends that inject input through a kernel driver.
+    pub allow_driver_input: bool,
+    pub accept_mode: AcceptMode,
+    /// Key name for keyboard acceptance: `Enter`, `Space`, `Tab`, `Escape` or a single character.
+    pub accept_key: String,
+    pub humanize: HumanizeSettings,
+    /// Move the cursor back to where it was before the click.
+    pub restore_cursor: bool,
+    /// Settle pause between reaching the target and pressing the button.
+    pub click_delay_ms: u64,
+    /// How long the button is held down.
+    pub hold_ms: u64,
+    pub click_count: ClickCount,
+    /// Pause between the releases and presses of a double click.
+    pub double_click_gap_ms: u64,
+    /// Random ± spread applied to `click_delay_ms` and `hold_ms` on every click.
+    pub timing_jitter_ms: u64,
+    /// Raise the League client window before sending input and restore the previous
+    /// foreground window afterwards.
+    pub focus_client: bool,
+    /// Minimum spacing between queued input actions so they never overlap.
+    pub min_action_gap_ms: u64,
+    /// Hold the accept until the user's mouse has been still this long. `0` disables it.
+    pub idle_grace_ms: u64,
+    /// Only accept once the OS reports no keyboard/mouse input for this many seconds. `0`
+    /// disables it.
+    pub require_user_idle_secs: u64,
+}
+
+impl Default for InputSettings {
+    fn default() -> Self {
+        Self {
+            backend: InputBackendKind::Enigo,
+            allow_driver_input: false,
+            accept_mode: AcceptMode::Click,
+            accept_key: "Enter".to_string(),
+            humanize: HumanizeSettings::default(),
+            restore_cursor: true,
+            click_delay_ms: 30,
+            hold_ms: 40,
+            click_count: ClickCount::Single,
+            double_click_gap_ms: 60,
+            timing_jitter_ms: 15,
+            focus_client: false,
+            min_action_gap_ms: 250,
+            idle_grace_ms: 400,
+            require_user_idle_secs: 0,
+        }
+    }
+}
+
+/// Curved, variable-speed cursor movement instead of teleporting to the target.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct HumanizeSettings {
+    pub enabled: bool,
+    /// Approximate travel time of the whole movement.
+    pub duration_ms: u64,
+    /// Maximum sideways bow of the path as a fraction of the travel distance.
+    pub curvature: f32,
+    /// Maximum distance to overshoot the target before settling back onto it.
+    pub overshoot_px: f32,
+}
+
+impl Default for HumanizeSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            duration_ms: 180,
+            curvature: 0.15,
+            overshoot_px: 6.0,
+        }
+    }
+}
+
+/// Queues offered in the champ-select automation toggles: `(queue id, label)`.
+pub const KNOWN_QUEUES: &[(u32, &str)] = &[
+    (400, "Normal Draft"),
+    (420, "Ranked Solo/Duo"),
+    (430, "Normal Blind"),
+    (440, "Ranked Flex"),
+    (450, "ARAM"),
+    (490, "Quickplay"),
+];
+
+/// Champion names per assigned role, in order of preference. `fill` is used when no role is
+/// assigned (blind pick) or the role's own list is exhausted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ChampionPriorities {
+    pub top: Vec<String>,
+    pub jungle: Vec<String>,
+    pub middle: Vec<String>,
+    pub bottom: Vec<String>,
+    pub utility: Vec<String>,
+    pub fill: Vec<String>,
+}
+
+impl ChampionPriorities {
+    /// Role names as the client reports them in `assignedPosition`, in display order.
+    pub const ROLES: [&'static str; 6] = ["top", "jungle", "middle", "bottom", "utility", "fill"];
+
+    pub fn role(&self, role: &str) -> &[String] {
+        match role {
+            "top" => &self.top,
+            "jungle" => &self.jungle,
+            "middle" => &self.middle,
+            "bottom" => &self.bottom,
+            "utility" => &self.utility,
+            _ => &self.fill,
+        }
+    }
+
+    pub fn role_mut(&mut self, role: &str) -> &mut Vec<String> {
+        match role {
+            "top" => &mut self.top,
+            "jungle" => &mut self.jungle,
+            "middle" => &mut self.middle,
+            "bottom" => &mut self.bottom,
+            "utility" => &mut self.utility,
+            _ => &mut self.fill,
+        }
+    }
+
+    /// Candidates for `position`: that role's list followed by `fill`, without duplicates.
+    pub fn candidates(&self, position: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for name in self
+            .role(&position.to_ascii_lowercase())
+            .iter()
+            .chain(&self.fill)
+        {
+            if !names.iter().any(|known| known.eq_ignore_ascii_case(name)) {
+                names.push(name);
+            }
+        }
+        names
+    }
+}
+
+/// Hovers and locks the first available champion from the priority list in champ select.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct AutoPickSettings {
+    pub enabled: bool,
+    /// Queue ids (see [`KNOWN_QUEUES`]) the automation runs in.
+    pub queues: Vec<u32>,
+    pub champions: ChampionPriorities,
+}
+
+impl Default for AutoPickSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            queues: vec![400, 420, 430, 440, 490],
+            champions: ChampionPriorities::default(),
+        }
+    }
+}
+
+/// Bans the first available champion from the ban list when the local player's ban turn comes.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct AutoBanSettings {
+    pub enabled: bool,
+    /// Queue ids (see [`KNOWN_QUEUES`]) the automation runs in.
+    pub queues: Vec<u32>,
+    /// Wait this long into the ban turn so teammates can declare their picks first.
+    pub delay_ms: u64,
+    pub champions: ChampionPriorities,
+}
+
+impl Default for AutoBanSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            queues: vec![400, 420, 440],
+            delay_ms: 5_000,
+            champions: ChampionPriorities::default(),
+        }
+    }
+}
+
+/// Swaps with the ARAM bench whenever a champion ranked above the current one shows up there.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct AramBenchSettings {
+    pub enabled: bool,
+    /// Wishlist, most wanted first.
+    pub champions: Vec<String>,
+}
+
+/// How to answer a swap request from a teammate.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TradeResponse {
+    /// Leave it to the player.
+    #[default]
+    Ignore,
+    Accept,
+    Decline,
+}
+
+/// Answers champion trade and pick-order swap requests in champ select.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct TradeSettings {
+    /// `Accept` only trades away champions listed in `give_up`; others are left pending.
+    pub champion_trades: TradeResponse,
+    pub pick_order_swaps: TradeResponse,
+    /// Champions we are willing to hand over in a champion trade.
+    pub give_up: Vec<String>,
+}
+
+impl TradeSettings {
+    pub fn enabled(&self) -> bool {
+        self.champion_trades != TradeResponse::Ignore
+            || self.pick_order_swaps != TradeResponse::Ignore
+    }
+}
+
+/// Where recommended rune pages come from when no preset matches.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RuneSource {
+    /// The client's own recommendation for the champion and role.
+    #[default]
+    Client,
+    /// Only local presets.
+    Presets,
+    /// HTTP(S) endpoint returning `{primaryStyleId, subStyleId, selectedPerkIds}`.
+    /// `{champion}` (lower-case alias), `{champion_id}` and `{position}` are substituted.
+    Url { template: String },
+}
+
+/// Local rune page for a champion, optionally restricted to one role.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct RunePreset {
+    pub champion: String,
+    /// `top`, `jungle`, `middle`, `bottom`, `utility`; empty matches any role.
+    pub role: String,
+    pub primary_style_id: i64,
+    pub sub_style_id: i64,
+    pub perk_ids: Vec<i64>,
+}
+
+/// Writes a rune page for the locked champion.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct RuneSettings {
+    pub enabled: bool,
+    pub source: RuneSource,
+    /// Checked before `source`; the first matching preset wins.
+    pub presets: Vec<RunePreset>,
+    /// Name of the page this tool creates and replaces.
+    pub page_name: String,
+}
+
+impl Default for RuneSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            source: RuneSource::Client,
+            presets: Vec::new(),
+            page_name: "HextechReady".to_string(),
+        }
+    }
+}
+
+/// Summoner spell key Flash should sit on.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FlashKey {
+    D,
+    #[default]
+    F,
+}
+
+/// Summoner spells for a champion and/or role; empty fields match anything.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SpellPreset {
+    pub champion: String,
+    pub role: String,
+    /// Two spell names, e.g. `["Flash", "Ignite"]`.
+    pub spells: Vec<String>,
+}
+
+/// Sets summoner spells in champ select from the most specific matching preset.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SummonerSpellSettings {
+    pub enabled: bool,
+    pub flash_key: FlashKey,
+    pub presets: Vec<SpellPreset>,
+}
+
+/// Restarts matchmaking when champ select ends in a dodge and the party lands back in the lobby.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct RequeueSettings {
+    pub enabled: bool,
+    /// Re-queues allowed before a game actually starts; the count resets once one does.
+    pub max_retries: u32,
+    /// Pause in the lobby before searching again.
+    pub delay_ms: u64,
+}
+
+impl Default for RequeueSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_retries: 3,
+            delay_ms: 3_000,
+        }
+    }
+}
+
+/// A template in the registry: one the user added, or a bundled one whose defaults were changed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct TemplateEntry {
+    pub path: PathBuf,
+    pub enabled: bool,
+    /// Score this template needs instead of the global threshold.
+    pub threshold: Option<f32>,
+}
+
+impl Default for TemplateEntry {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::new(),
+            enabled: true,
+            threshold: None,
+        }
+    }
+}
+
+/// Stops monitoring after a long stretch without detections.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct AutoStopSettings {
+    pub enabled: bool,
+    /// Minutes without any detection before monitoring stops.
+    pub after_minutes: u64,
+    /// Minutes before the stop that a warning goes out; `0` skips it.
+    pub warn_minutes: u64,
+}
+
+impl Default for AutoStopSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            after_minutes: 60,
+            warn_minutes: 5,
+        }
+    }
+}
+
+/// Warning when scores keep landing just under the threshold.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct NearMissSettings {
+    pub enabled: bool,
+    /// How far below the threshold a score still counts as a near miss.
+    pub margin: f32,
+    /// Consecutive near-miss frames before the warning.
+    pub frames: u32,
+}
+
+impl Default for NearMissSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            margin: 0.03,
+            frames: 5,
+        }
+    }
+}
+
+/// Time windows during which monitoring runs by itself; outside them it is stopped.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ScheduleSettings {
+    pub enabled: bool,
+    pub windows: Vec<ScheduleWindow>,
+}
+
+/// `start`–`end` local time on each of `days`. An `end` at or before `start` runs past midnight
+/// into the next day.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScheduleWindow {
+    pub days: Vec<Weekday>,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl Default for ScheduleWindow {
+    /// Weekday evenings, 19:00–23:00.
+    fn default() -> Self {
+        Self {
+            days: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            start: NaiveTime::from_hms_opt(19, 0, 0).expect("valid time"),
+            end: NaiveTime::from_hms_opt(23, 0, 0).expect("valid time"),
+        }
+    }
+}
+
+/// Who gets honored after a game.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HonorTarget {
+    #[default]
+    Random,
+    /// Best KDA from the end-of-game stats; random when the stats are unavailable.
+    BestPerformance,
+}
+
+/// Honors a teammate on the post-game honor screen.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct HonorSettings {
+    pub enabled: bool,
+    pub target: HonorTarget,
+}
+
+/// Posts to a Discord channel webhook.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct DiscordSettings {
+    pub enabled: bool,
+    /// `https://discord.com/api/webhooks/<id>/<token>` from the channel's integration settings.
+    pub webhook_url: String,
+    /// Attach the frame the accept button was detected in: the grayscale image the matcher saw,
+    /// not a color screenshot.
+    pub attach_screenshot: bool,
+}
+
+/// Messages from a Telegram bot; talk to @BotFather for the token.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct TelegramSettings {
+    pub enabled: bool,
+    pub bot_token: String,
+    /// Numeric chat ID (negative for groups) or `@channel` name.
+    pub chat_id: String,
+}
+
+/// Notifications sent when a match is found or accepted, and on worker errors.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct NotificationSettings {
+    /// OS toast with the accept time and match score.
+    pub desktop: bool,
+    pub discord: DiscordSettings,
+    pub telegram: TelegramSettings,
+    /// Skip the desktop toast and send other targets only a generic line, without scores, times
+    /// or screenshots. Not saved: derived from [`StreamerSettings`].
+    #[serde(skip)]
+    pub redact: bool,
+}
+
+/// Keeps the app off stream: out of screen capture and quiet in notifications.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct StreamerSettings {
+    pub enabled: bool,
+    /// Exclude the app's windows (main window and overlay) from screen capture and sharing.
+    pub hide_windows: bool,
+    /// Redact notifications while streamer mode is on.
+    pub hide_notifications: bool,
+}
+
+impl Default for StreamerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            hide_windows: true,
+            hide_notifications: true,
+        }
+    }
+}
+
+/// Another League client watched by its own worker, next to the one the main settings describe.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ClientSlot {
+    pub name: String,
+    pub enabled: bool,
+    pub monitor_index: usize,
+    pub monitor_id: Option<u32>,
+    /// `None` uses the main configuration's templates.
+    pub template_path: Option<PathBuf>,
+    pub cooldown_ms: u64,
+}
+
+impl Default for ClientSlot {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            enabled: true,
+            monitor_index: 1,
+            monitor_id: None,
+            template_path: None,
+            cooldown_ms: AppConfig::default().cooldown_ms,
+        }
+    }
+}
+
+/// Slower pacing while the laptop runs on battery.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct BatterySettings {
+    pub enabled: bool,
+    /// Polling interval on battery; a longer `interval_ms` is kept.
+    pub interval_ms: u64,
+    /// Skip template matching on frames that did not change, even if `skip_unchanged` is off.
+    pub skip_unchanged: bool,
+    /// Template scales matched on battery, spread over the configured ones.
+    pub max_scales: usize,
+}
+
+impl Default for BatterySettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_ms: 400,
+            skip_unchanged: true,
+            max_scales: 5,
+        }
+    }
+}
+
+/// Shared-machine lock: settings stay hidden behind a PIN and only Start/Stop are offered.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct KioskSettings {
+    pub enabled: bool,
+    /// Unlocks the settings for the rest of the session; empty means only editing this file
+    /// does.
+    pub pin: String,
+}
+
+/// One audio cue.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SoundCue {
+    pub muted: bool,
+    /// 0.0–1.0.
+    pub volume: f32,
+    /// WAV or OGG file; `None` plays the built-in chime.
+    pub path: Option<PathBuf>,
+}
+
+impl Default for SoundCue {
+    fn default() -> Self {
+        Self {
+            muted: true,
+            volume: 0.8,
+            path: None,
+        }
+    }
+}
+
+/// Sounds played when the accept button is detected and when it is clicked.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SoundSettings {
+    pub detection: SoundCue,
+    pub click: SoundCue,
+}
+
+/// Spoken announcements, in addition to the sounds.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SpeechSettings {
+    pub enabled: bool,
+    /// Spoken when the accept button is detected; empty stays silent.
+    pub found: String,
+    /// Spoken after the match is accepted; empty stays silent.
+    pub accepted: String,
+    /// 0.0–1.0 of the engine's range.
+    pub volume: f32,
+}
+
+impl Default for SpeechSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            found: "Match found".to_string(),
+            accepted: "Accepted".to_string(),
+            volume: 1.0,
+        }
+    }
+}
+
+/// Colors of the state indicator (status dot, tray and taskbar icons).
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IndicatorPalette {
+    /// Gray, green, yellow and red dots.
+    #[default]
+    Standard,
+    /// Blue, yellow and vermillion that stay apart with red-green color blindness, plus a
+    /// shape per state.
+    Colorblind,
+    /// Saturated cyan, yellow and magenta, plus a shape per state.
+    HighContrast,
+}
+
+impl IndicatorPalette {
+    pub const ALL: [IndicatorPalette; 3] = [
+        IndicatorPalette::Standard,
+        IndicatorPalette::Colorblind,
+        IndicatorPalette::HighContrast,
+    ];
+
+    /// Untranslated name for the settings.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Standard => "Standard",
+            Self::Colorblind => "Colorblind-friendly",
+            Self::HighContrast => "High contrast",
+        }
+    }
+}
+
+/// Light or dark GUI.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeMode {
+    /// Follow the OS setting.
+    #[default]
+    System,
+    Dark,
+    Light,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ThemeSettings {
+    pub mode: ThemeMode,
+    /// sRGB color for selections, links and pressed buttons; `None` keeps egui's blue.
+    pub accent: Option<[u8; 3]>,
+    pub indicator_palette: IndicatorPalette,
+}
+
+/// Language of the GUI.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "ja")]
+    Japanese,
+    #[serde(rename = "ko")]
+    Korean,
+    #[serde(rename = "es")]
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Language; 4] = [
+        Language::English,
+        Language::Japanese,
+        Language::Korean,
+        Language::Spanish,
+    ];
+
+    /// Name of the language in itself, as shown in the language picker.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Japanese => "日本語",
+            Language::Korean => "한국어",
+            Language::Spanish => "Español",
+        }
+    }
+}
+
+/// Access to the League client's local API (LCU).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct LcuSettings {
+    /// Client lockfile; `None` checks the default install locations.
+    pub lockfile: Option<PathBuf>,
+    /// Hybrid mode: react to ready checks pushed over the client WebSocket; screen detection
+    /// only runs while the socket is unavailable, clicking if the API accept fails too.
+    pub listen_events: bool,
+    /// Show the gameflow phase, queue timer and ready-check countdown in the status panel.
+    pub queue_status: bool,
+    /// Only capture the screen in matchmaking and ready checks; idle in the lobby and in game.
+    pub gate_capture: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
 pub struct AppConfig {
     pub threshold: f32,
     pub interval_ms: u64,
     pub cooldown_ms: u64,
     pub monitor_index: usize,
+    /// Stable display identifier; preferred over `monitor_index` whenever that display is present.
+    pub monitor_id: Option<u32>,
     pub click_offset_x: i32,
     pub click_offset_y: i32,
+    pub click_anchor: ClickAnchor,
+    /// Only this part of the monitor is searched; `None` searches all of it.
+    pub search_region: Option<SearchRegion>,
+    /// Used alone when set, instead of the template registry.
     pub template_path: Option<PathBuf>,
+    /// User-added templates and the choices made for bundled ones.
+    pub templates: Vec<TemplateEntry>,
+    /// Sizes, relative to the template image, the template is searched at on every frame.
+    pub template_scales: Vec<f32>,
+    pub capture_source: CaptureSource,
+    /// Paint over the mouse cursor before matching so it cannot drag the score down.
+    pub mask_cursor: bool,
+    /// Detect and report where the click would land without touching the mouse.
+    pub dry_run: bool,
+    /// Reuse the last match result while the captured screen has not changed, to save CPU.
+    pub skip_unchanged: bool,
+    /// Start monitoring when the League client launches and stop when it exits.
+    pub follow_client: bool,
+    /// Start monitoring as soon as the app opens.
+    pub auto_start: bool,
+    /// Open minimized, to the tray when it has one, as `--minimized` does.
+    pub start_minimized: bool,
+    /// Apply edits made to the configuration file outside the app while it runs.
+    pub reload_on_change: bool,
+    /// Briefly outline each detection on screen in a click-through overlay window.
+    pub show_overlay: bool,
+    /// Look for a newer GitHub release when the app opens.
+    pub check_for_updates: bool,
+    /// Closing or minimizing the window hides it to the tray icon (Windows).
+    pub minimize_to_tray: bool,
+    /// Ask before exiting or closing the window while monitoring.
+    pub confirm_exit: bool,
+    /// Pop up short notifications in the window for saves, detections and errors.
+    pub toasts: bool,
+    /// System-wide shortcut that starts/stops monitoring, e.g. `Ctrl+Alt+A`. Empty disables it.
+    pub toggle_hotkey: String,
+    /// System-wide shortcut that switches between the full window and mini mode. Empty disables it.
+    pub mini_mode_hotkey: String,
+    pub input: InputSettings,
+    pub click_verify: ClickVerifySettings,
+    pub click_guard: ClickGuardSettings,
+    pub escalation: EscalationSettings,
+    pub lcu: LcuSettings,
+    pub auto_pick: AutoPickSettings,
+    pub auto_ban: AutoBanSettings,
+    pub aram_bench: AramBenchSettings,
+    pub trades: TradeSettings,
+    pub runes: RuneSettings,
+    pub summoner_spells: SummonerSpellSettings,
+    pub requeue: RequeueSettings,
+    pub schedule: ScheduleSettings,
+    pub auto_stop: AutoStopSettings,
+    pub near_miss: NearMissSettings,
+    pub honor: HonorSettings,
+    pub notifications: NotificationSettings,
+    pub streamer: StreamerSettings,
+    pub kiosk: KioskSettings,
+    pub battery: BatterySettings,
+    /// Further clients, each monitored by a worker of its own.
+    pub extra_clients: Vec<ClientSlot>,
+    pub sound: SoundSettings,
+    pub speech: SpeechSettings,
+    pub theme: ThemeSettings,
+    pub language: Language,
+    /// GUI zoom on top of the display's own scaling; `1.0` keeps the OS size.
+    pub ui_scale: f32,
 }
 
 impl Default for AppConfig {
@@ -23,9 +1004,55 @@ impl Default for AppConfig {
             interval_ms: 120,
             cooldown_ms: 4_000,
             monitor_index: 0,
+            monitor_id: None,
             click_offset_x: 0,
             click_offset_y: 0,
+            click_anchor: ClickAnchor::Center,
+            search_region: None,
             template_path: None,
+            templates: Vec::new(),
+            template_scales: scales::DEFAULT_SCALES.to_vec(),
+            capture_source: CaptureSource::Screen,
+            mask_cursor: false,
+            dry_run: false,
+            skip_unchanged: false,
+            follow_client: false,
+            auto_start: false,
+            start_minimized: false,
+            reload_on_change: true,
+            show_overlay: false,
+            check_for_updates: true,
+            minimize_to_tray: true,
+            confirm_exit: true,
+            toasts: true,
+            toggle_hotkey: DEFAULT_TOGGLE_HOTKEY.to_string(),
+            mini_mode_hotkey: String::new(),
+            input: InputSettings::default(),
+            click_verify: ClickVerifySettings::default(),
+            click_guard: ClickGuardSettings::default(),
+            escalation: EscalationSettings::default(),
+            lcu: LcuSettings::default(),
+            auto_pick: AutoPickSettings::default(),
+            auto_ban: AutoBanSettings::default(),
+            aram_bench: AramBenchSettings::default(),
+            trades: TradeSettings::default(),
+            runes: RuneSettings::default(),
+            summoner_spells: SummonerSpellSettings::default(),
+            requeue: RequeueSettings::default(),
+            schedule: ScheduleSettings::default(),
+            auto_stop: AutoStopSettings::default(),
+            near_miss: NearMissSettings::default(),
+            honor: HonorSettings::default(),
+            notifications: NotificationSettings::default(),
+            streamer: StreamerSettings::default(),
+            kiosk: KioskSettings::default(),
+            battery: BatterySettings::default(),
+            extra_clients: Vec::new(),
+            sound: SoundSettings::default(),
+            speech: SpeechSettings::default(),
+            theme: ThemeSettings::default(),
+            language: Language::default(),
+            ui_scale: 1.0,
         }
     }
 }
@@ -48,6 +1075,105 @@ impl AppConfig {
             .ok_or_else(|| anyhow!("Template image not found in default locations"))
     }
 
+    /// Notification settings to send with, redacted while streamer mode asks for it.
+    pub fn effective_notifications(&self) -> NotificationSettings {
+        NotificationSettings {
+            redact: self.streamer.enabled && self.streamer.hide_notifications,
+            ..self.notifications.clone()
+        }
+    }
+
+    /// Whether the app's windows should be kept out of screen capture.
+    pub fn hides_windows(&self) -> bool {
+        self.streamer.enabled && self.streamer.hide_windows
+    }
+
+    /// Accept methods to try in order for one ready check. Without escalation this is just
+    /// `input.accept_mode`.
+    pub fn accept_stages(&self) -> Vec<AcceptMode> {
+        let escalation = &self.escalation;
+        let stages: Vec<AcceptMode> = [
+            (escalation.click, AcceptMode::Click),
+            (escalation.key, AcceptMode::Key),
+            (escalation.lcu, AcceptMode::Lcu),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, mode)| enabled.then_some(mode))
+        .collect();
+        if escalation.enabled && !stages.is_empty() {
+            stages
+        } else {
+            vec![self.input.accept_mode]
+        }
+    }
+
+    /// The worker configuration for `slot`: these settings with the slot's monitor, template and
+    /// cooldown. The client API, champ select automation and window-title lookups stay with the
+    /// main client, since they cannot tell two clients apart; the slot accepts by screen only.
+    pub fn for_client(&self, slot: &ClientSlot) -> AppConfig {
+        let mut config = self.clone();
+        config.monitor_index = slot.monitor_index;
+        config.monitor_id = slot.monitor_id;
+        config.cooldown_ms = slot.cooldown_ms;
+        if slot.template_path.is_some() {
+            config.template_path = slot.template_path.clone();
+        }
+        // A region drawn on the main monitor means nothing on another one.
+        config.search_region = None;
+        if matches!(config.capture_source, CaptureSource::Window { .. }) {
+            config.capture_source = CaptureSource::Screen;
+        }
+        config.input.focus_client = false;
+        if config.input.accept_mode == AcceptMode::Lcu {
+            config.input.accept_mode = AcceptMode::Click;
+        }
+        config.escalation.lcu = false;
+        config.lcu.listen_events = false;
+        config.lcu.queue_status = false;
+        config.lcu.gate_capture = false;
+        config.requeue.enabled = false;
+        config.honor.enabled = false;
+        config.auto_pick.enabled = false;
+        config.auto_ban.enabled = false;
+        config.aram_bench.enabled = false;
+        config.trades.champion_trades = TradeResponse::Ignore;
+        config.trades.pick_order_swaps = TradeResponse::Ignore;
+        config.runes.enabled = false;
+        config.summoner_spells.enabled = false;
+        config.extra_clients.clear();
+        config
+    }
+
+    /// Whether any champ-select feature needs the session events.
+    pub fn champ_select_automation(&self) -> bool {
+        self.auto_pick.enabled
+            || self.auto_ban.enabled
+            || self.aram_bench.enabled
+            || self.trades.enabled()
+            || self.runes.enabled
+            || self.summoner_spells.enabled
+    }
+
+    /// Title of the League client window used for focusing and window capture.
+    pub fn client_window_title(&self) -> &str {
+        match &self.capture_source {
+            CaptureSource::Window { title } => title,
+            _ => LEAGUE_CLIENT_WINDOW_TITLE,
+        }
+    }
+
+    /// The configured capture source, unless `LOL_AUTO_ACCEPT_CAPTURE_DIR` points at a frame
+    /// directory, which is then looped.
+    pub fn effective_capture_source(&self) -> CaptureSource {
+        match std::env::var_os(CAPTURE_DIR_ENV) {
+            Some(dir) if !dir.is_empty() => CaptureSource::Directory {
+                path: PathBuf::from(dir),
+                looping: true,
+            },
+            _ => self.capture_source.clone(),
+        }
+    }
+
     pub fn set_template_path_from_str(&mut self, value: &str) {
         if value.trim().is_empty() {
             self.template_path = None;
@@ -57,37 +1183,125 @@ impl AppConfig {
     }
 }
 
+/// Where the configuration file lives, whether or not it has been written yet.
+pub fn config_path() -> Result<PathBuf> {
+    confy::get_configuration_file_path(APP_NAME, None).context("Failed to locate configuration")
+}
+
+/// Where a template made in the setup wizard is saved, next to the configuration file.
+pub fn user_template_path() -> Result<PathBuf> {
+    Ok(config_path()?.with_file_name("accept_button.png"))
+}
+
+/// Folder for template images the user keeps, next to the configuration file.
+pub fn user_templates_dir() -> Result<PathBuf> {
+    Ok(config_path()?.with_file_name("templates"))
+}
+
 pub fn load_or_default() -> Result<AppConfig> {
     let cfg: AppConfig = confy::load(APP_NAME, None).context("Failed to load configuration")?;
     Ok(cfg)
 }
 
+/// Reads the configuration file at `path`. Unlike [`load_or_default`] it never creates or
+/// rewrites the file, so a reload cannot race the editor that is writing it.
+pub fn read(path: &Path) -> Result<AppConfig> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    toml::from_str(&text).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
 pub fn store(config: &AppConfig) -> Result<()> {
     confy::store(APP_NAME, None, config).context("Failed to persist configuration")
 }
 
-fn default_template_search_paths() -> Vec<PathBuf> {
-    let mut candidates = Vec::new();
+/// Named configurations to switch between, e.g. "Home 4K" and "Laptop". The active profile is
+/// also what the main config file holds, so everything else keeps reading that.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Profiles {
+    /// Profile last switched to or saved, remembered across launches.
+    pub active: Option<String>,
+    pub profiles: BTreeMap<String, AppConfig>,
+    /// Global hotkey that switches to each profile, e.g. `Ctrl+Alt+1`, by profile name.
+    pub hotkeys: BTreeMap<String, String>,
+}
+
+impl Profiles {
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.keys().map(String::as_str)
+    }
 
-    if let Ok(exe_path) = std::env::current_exe() {
-        if let Some(dir) = exe_path.parent() {
-            candidates.push(
-                dir.join("resources")
-                    .join("templates")
-                    .join("accept_button.png"),
-            );
-            candidates.push(dir.join("templates").join("accept_button.png"));
+    pub fn get(&self, name: &str) -> Option<&AppConfig> {
+        self.profiles.get(name)
+    }
+
+    /// Stores `config` under `name` (trimmed), replacing a profile of that name, and makes it
+    /// the active profile.
+    pub fn save_as(&mut self, name: &str, config: &AppConfig) -> Result<()> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(anyhow!("Profile name is empty"));
+        }
+        self.profiles.insert(name.to_string(), config.clone());
+        self.active = Some(name.to_string());
+        Ok(())
+    }
+
+    /// Makes `name` active and returns its configuration.
+    pub fn switch(&mut self, name: &str) -> Option<AppConfig> {
+        let config = self.profiles.get(name)?.clone();
+        self.active = Some(name.to_string());
+        Some(config)
+    }
+
+    /// Updates the active profile, if any, to `config`.
+    pub fn update_active(&mut self, config: &AppConfig) {
+        if let Some(active) = self
+            .active
+            .as_ref()
+            .and_then(|name| self.profiles.get_mut(name))
+        {
+            *active = config.clone();
+        }
+    }
+
+    pub fn remove(&mut self, name: &str) -> bool {
+        if self.active.as_deref() == Some(name) {
+            self.active = None;
         }
+        self.hotkeys.remove(name);
+        self.profiles.remove(name).is_some()
     }
 
-    if let Ok(current_dir) = std::env::current_dir() {
-        candidates.push(
-            current_dir
-                .join("resources")
-                .join("templates")
-                .join("accept_button.png"),
-        );
+    /// Hotkey bound to profile `name`, if any.
+    pub fn hotkey(&self, name: &str) -> Option<&str> {
+        self.hotkeys.get(name).map(String::as_str)
     }
 
-    candidates
+    /// Binds `binding` (trimmed) to profile `name`; an empty binding removes it.
+    pub fn set_hotkey(&mut self, name: &str, binding: &str) {
+        let binding = binding.trim();
+        if binding.is_empty() {
+            self.hotkeys.remove(name);
+        } else {
+            self.hotkeys.insert(name.to_string(), binding.to_string());
+        }
+    }
+}
+
+/// Saved profiles; empty until the first one is saved.
+pub fn load_profiles() -> Result<Profiles> {
+    confy::load(APP_NAME, PROFILES_NAME).context("Failed to load profiles")
+}
+
+pub fn store_profiles(profiles: &Profiles) -> Result<()> {
+    confy::store(APP_NAME, PROFILES_NAME, profiles).context("Failed to persist profiles")
+}
+
+fn default_template_search_paths() -> Vec<PathBuf> {
+    crate::templates::bundled_dirs()
+        .into_iter()
+        .map(|dir| dir.join(crate::templates::DEFAULT_TEMPLATE))
+        .collect()
 }
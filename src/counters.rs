@@ -0,0 +1,65 @@
+//! Running counters for the status panel (frames, detections, clicks, errors) and a heartbeat
+//! that tells a live worker from one that went quiet.
+
+use std::time::{Duration, Instant};
+
+/// Without a metrics report for this long a running worker is considered stuck; it normally
+/// reports every second, even while capture is paused.
+pub const STALL_AFTER: Duration = Duration::from_secs(5);
+
+/// Totals since the app started, fed from worker events.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Counters {
+    pub frames: u64,
+    pub detections: u64,
+    pub clicks: u64,
+    pub errors: u64,
+    /// When the worker last reported metrics.
+    last_heartbeat: Option<Instant>,
+}
+
+impl Counters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A metrics report covering `frames` new frames.
+    pub fn on_metrics(&mut self, frames: u32, now: Instant) {
+        self.frames += u64::from(frames);
+        self.last_heartbeat = Some(now);
+    }
+
+    pub fn on_detection(&mut self) {
+        self.detections += 1;
+    }
+
+    pub fn on_click(&mut self) {
+        self.clicks += 1;
+    }
+
+    pub fn on_error(&mut self) {
+        self.errors += 1;
+    }
+
+    /// Monitoring (re)started: the heartbeat is measured from `now` until the first report.
+    pub fn on_start(&mut self, now: Instant) {
+        self.last_heartbeat = Some(now);
+    }
+
+    /// Time since the last report, or since monitoring started.
+    pub fn heartbeat_age(&self, now: Instant) -> Option<Duration> {
+        self.last_heartbeat
+            .map(|heartbeat| now.saturating_duration_since(heartbeat))
+    }
+
+    /// The worker has not reported for [`STALL_AFTER`].
+    pub fn is_stalled(&self, now: Instant) -> bool {
+        self.heartbeat_age(now)
+            .is_some_and(|age| age >= STALL_AFTER)
+    }
+
+    /// Detections per analyzed frame, in percent; `None` before the first frame.
+    pub fn detection_rate(&self) -> Option<f64> {
+        (self.frames > 0).then(|| self.detections as f64 * 100.0 / self.frames as f64)
+    }
+}
@@ -0,0 +1,169 @@
+//! Crash reports: a panic hook that writes the panic message, a backtrace, the saved
+//! configuration (secrets blanked) and the tail of the session log to the config directory, plus
+//! a marker so the next launch can point at the report.
+
+use std::{
+    backtrace::Backtrace,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+
+/// Session log lines kept in a report.
+pub const RECENT_LOG_LINES: usize = 200;
+/// File in the crash directory naming the report the next launch should mention.
+const PENDING_MARKER: &str = "pending";
+/// Configuration values that must not end up in a report users attach to bug reports.
+const SECRET_KEYS: [&[&str]; 3] = [
+    &["notifications", "discord", "webhook_url"],
+    &["notifications", "telegram", "bot_token"],
+    &["kiosk", "pin"],
+];
+/// What a secret is replaced with.
+const REDACTED: &str = "<redacted>";
+
+/// Where reports are written: `crashes/` next to the configuration file.
+pub fn crash_dir() -> Result<PathBuf> {
+    Ok(crate::config::config_path()?.with_file_name("crashes"))
+}
+
+/// What a report is made of; the hook fills it in and [`report_text`] lays it out.
+pub struct CrashReport<'a> {
+    pub time: DateTime<Local>,
+    pub thread: &'a str,
+    pub message: &'a str,
+    pub location: Option<String>,
+    pub backtrace: &'a str,
+    /// The configuration file as last saved, passed through [`redact_config`].
+    pub config: Option<&'a str>,
+    /// The end of the session log.
+    pub recent_log: Option<&'a str>,
+}
+
+/// The report as plain text.
+pub fn report_text(report: &CrashReport) -> String {
+    let section = |body: Option<&str>| body.unwrap_or("(unavailable)").trim_end().to_string();
+    format!(
+        "LoL Auto Accept crash report\n\
+         Version: {version}\n\
+         Time: {time}\n\
+         OS: {os} ({arch})\n\
+         Thread: {thread}\n\
+         Panic: {message}\n\
+         Location: {location}\n\
+         \n==== Backtrace ====\n{backtrace}\n\
+         \n==== Configuration ====\n{config}\n\
+         \n==== Recent log ====\n{log}\n",
+        version = env!("CARGO_PKG_VERSION"),
+        time = report.time.format("%Y-%m-%d %H:%M:%S %:z"),
+        os = std::env::consts::OS,
+        arch = std::env::consts::ARCH,
+        thread = report.thread,
+        message = report.message,
+        location = report.location.as_deref().unwrap_or("unknown"),
+        backtrace = report.backtrace.trim_end(),
+        config = section(report.config),
+        log = section(report.recent_log),
+    )
+}
+
+/// The configuration file `text` with its secrets replaced by `<redacted>`. A file that does not
+/// parse is left out entirely, since its secrets cannot be located.
+pub fn redact_config(text: &str) -> String {
+    let Ok(mut root) = text.parse::<toml::Table>() else {
+        return "(not valid TOML; left out in case it holds secrets)".to_string();
+    };
+    for path in SECRET_KEYS {
+        let (name, tables) = path.split_last().expect("secret paths are not empty");
+        let table = tables.iter().try_fold(&mut root, |table, part| {
+            table.get_mut(*part).and_then(toml::Value::as_table_mut)
+        });
+        if let Some(value) = table.and_then(|table| table.get_mut(*name)) {
+            *value = toml::Value::String(REDACTED.to_string());
+        }
+    }
+    toml::to_string(&root).unwrap_or_else(|_| "(could not be written back)".to_string())
+}
+
+/// The last `lines` lines of `text`.
+pub fn tail(text: &str, lines: usize) -> &str {
+    if lines == 0 {
+        return "";
+    }
+    let mut newlines = text.trim_end_matches('\n').rmatch_indices('\n');
+    match newlines.nth(lines - 1) {
+        Some((index, _)) => &text[index + 1..],
+        None => text,
+    }
+}
+
+/// Writes `report` to a timestamped file in `dir` and marks it as pending for the next launch.
+pub fn write_report(dir: &Path, report: &CrashReport) -> Result<PathBuf> {
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let path = dir.join(format!("crash-{}.txt", report.time.format("%Y%m%d-%H%M%S")));
+    fs::write(&path, report_text(report))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    fs::write(dir.join(PENDING_MARKER), path.to_string_lossy().as_bytes())
+        .context("Failed to mark the crash report")?;
+    Ok(path)
+}
+
+/// The report left by a crash since the last call, if it still exists; clears the marker.
+pub fn take_pending(dir: &Path) -> Option<PathBuf> {
+    let marker = dir.join(PENDING_MARKER);
+    let path = PathBuf::from(fs::read_to_string(&marker).ok()?.trim());
+    let _ = fs::remove_file(&marker);
+    path.exists().then_some(path)
+}
+
+/// Chains a hook in front of the default one that writes a report for every panic, on any thread.
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let thread = std::thread::current();
+        let message = info
+            .payload_as_str()
+            .unwrap_or("(non-string panic payload)");
+        let backtrace = Backtrace::force_capture().to_string();
+        let config = crate::config::config_path()
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|text| redact_config(&text));
+        let log = crate::logpipe::session_log_path()
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok());
+        let report = CrashReport {
+            time: Local::now(),
+            thread: thread.name().unwrap_or("unnamed"),
+            message,
+            location: info.location().map(ToString::to_string),
+            backtrace: &backtrace,
+            config: config.as_deref(),
+            recent_log: log.as_deref().map(|log| tail(log, RECENT_LOG_LINES)),
+        };
+        match crash_dir().and_then(|dir| write_report(&dir, &report)) {
+            Ok(path) => tracing::error!(report = %path.display(), "panic: {message}"),
+            Err(err) => eprintln!("failed to write crash report: {err:#}"),
+        }
+        previous(info);
+    }));
+}
+
+/// Shows `path` in the system's default viewer.
+pub fn open_report(path: &Path) -> Result<()> {
+    let program = if cfg!(windows) {
+        "explorer"
+    } else if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+    Command::new(program)
+        .arg(path)
+        .spawn()
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    Ok(())
+}
@@ -1,22 +1,66 @@
-use std::path::Path;
+use std::{
+    hash::{DefaultHasher, Hasher},
+    path::Path,
+};
 
 use anyhow::{Context, Result};
 use image::{GrayImage, ImageBuffer, Luma, imageops::FilterType};
 use imageproc::template_matching::{MatchTemplateMethod, match_template};
 
-const TEMPLATE_SCALE_FACTORS: &[f32] = &[
-    0.65, 0.7, 0.75, 0.8, 0.85, 0.9, 0.95, 1.0, 1.05, 1.1, 1.15, 1.2, 1.25, 1.3,
-];
+use crate::{
+    config::{ClickAnchor, SearchRegion},
+    scales,
+};
+
+/// Smallest width and height of a template cut from a screenshot.
+pub const MIN_TEMPLATE_SIDE: u32 = 8;
+
+/// Rows skipped between the ones hashed by [`frame_signature`].
+const SIGNATURE_ROW_STEP: usize = 4;
 
 #[derive(Clone)]
 pub struct Template {
     variants: Vec<TemplateVariant>,
+    /// Score a match of this template needs instead of the global threshold.
+    threshold: Option<f32>,
 }
 
 impl Template {
     fn variants(&self) -> &[TemplateVariant] {
         &self.variants
     }
+
+    pub fn with_threshold(mut self, threshold: Option<f32>) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Scales of the variants built for this template.
+    pub fn scales(&self) -> Vec<f32> {
+        self.variants.iter().map(|variant| variant.scale).collect()
+    }
+
+    /// A copy matching only the variants at `scales`; the template unchanged when none of them
+    /// were built.
+    pub fn restricted_to(&self, scales: &[f32]) -> Self {
+        let variants: Vec<TemplateVariant> = self
+            .variants
+            .iter()
+            .filter(|variant| {
+                scales
+                    .iter()
+                    .any(|scale| (scale - variant.scale).abs() < 1e-3)
+            })
+            .cloned()
+            .collect();
+        if variants.is_empty() {
+            return self.clone();
+        }
+        Self {
+            variants,
+            threshold: self.threshold,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -49,16 +93,132 @@ pub struct Detection {
     pub position: (u32, u32),
     pub template_size: (u32, u32),
     pub scale: f32,
+    /// Threshold of the matched template when it overrides the global one.
+    pub threshold: Option<f32>,
+}
+
+impl Detection {
+    /// Whether the score reaches the template's own threshold, or `default_threshold`.
+    pub fn passes(&self, default_threshold: f32) -> bool {
+        self.score >= self.threshold.unwrap_or(default_threshold)
+    }
+
+    /// How far the score is above (or below) the threshold that applies to it.
+    pub fn margin(&self, default_threshold: f32) -> f32 {
+        self.score - self.threshold.unwrap_or(default_threshold)
+    }
+
+    /// Center of the matched region in captured (physical) frame pixels.
+    pub fn center(&self) -> (f32, f32) {
+        (
+            self.position.0 as f32 + self.template_size.0 as f32 / 2.0,
+            self.position.1 as f32 + self.template_size.1 as f32 / 2.0,
+        )
+    }
+
+    /// `anchor` inside the matched region in captured (physical) frame pixels.
+    pub fn anchor_point(&self, anchor: ClickAnchor) -> (f32, f32) {
+        let (fx, fy) = anchor.fractions();
+        (
+            self.position.0 as f32 + self.template_size.0 as f32 * fx,
+            self.position.1 as f32 + self.template_size.1 as f32 * fy,
+        )
+    }
+
+    /// The matched region of `image`, shrunk to at most `max_width` pixels wide.
+    pub fn thumbnail(&self, image: &GrayImage, max_width: u32) -> GrayImage {
+        let x = self.position.0.min(image.width());
+        let y = self.position.1.min(image.height());
+        let width = self.template_size.0.min(image.width() - x);
+        let height = self.template_size.1.min(image.height() - y);
+        let region = image::imageops::crop_imm(image, x, y, width, height).to_image();
+        if width <= max_width || width == 0 {
+            return region;
+        }
+        let scaled_height = ((height as u64 * max_width as u64) / width as u64).max(1) as u32;
+        image::imageops::resize(&region, max_width, scaled_height, FilterType::Triangle)
+    }
 }
 
+/// [`load_template_scaled`] with [`scales::DEFAULT_SCALES`].
 pub fn load_template(path: &Path) -> Result<Template> {
+    load_template_scaled(path, scales::DEFAULT_SCALES)
+}
+
+/// Loads the image at `path` as a grayscale template searched at each of `scales`.
+pub fn load_template_scaled(path: &Path, scales: &[f32]) -> Result<Template> {
     let dyn_img = image::open(path).with_context(|| format!("Failed to load template {path:?}"))?;
     let base = dyn_img.into_luma8();
     Ok(Template {
-        variants: build_variants(&base),
+        variants: build_variants(&base, scales),
+        threshold: None,
     })
 }
 
+/// The part of `image` between corners `a` and `b` (in any order), clamped to the image; `None`
+/// when either side is shorter than [`MIN_TEMPLATE_SIDE`].
+pub fn crop_template(image: &GrayImage, a: (u32, u32), b: (u32, u32)) -> Option<GrayImage> {
+    let span = |a: u32, b: u32, limit: u32| (a.min(b).min(limit), a.max(b).min(limit));
+    let (x0, x1) = span(a.0, b.0, image.width());
+    let (y0, y1) = span(a.1, b.1, image.height());
+    (x1 - x0 >= MIN_TEMPLATE_SIDE && y1 - y0 >= MIN_TEMPLATE_SIDE)
+        .then(|| image::imageops::crop_imm(image, x0, y0, x1 - x0, y1 - y0).to_image())
+}
+
+/// [`detect`] limited to `region` of `frame`, with the match reported in frame coordinates. A
+/// region that lies entirely outside the frame (e.g. after a resolution change) is ignored.
+pub fn detect_in(
+    frame: &GrayImage,
+    template: &Template,
+    region: Option<SearchRegion>,
+) -> Option<Detection> {
+    let Some(region) = region.and_then(|region| region.clamped(frame.width(), frame.height()))
+    else {
+        return detect(frame, template);
+    };
+    let cropped = image::imageops::crop_imm(frame, region.x, region.y, region.width, region.height)
+        .to_image();
+    let mut detection = detect(&cropped, template)?;
+    detection.position.0 += region.x;
+    detection.position.1 += region.y;
+    Some(detection)
+}
+
+/// [`detect_in`] for every template, keeping the match furthest above its own threshold (the
+/// global `default_threshold` unless the template overrides it).
+pub fn detect_any(
+    frame: &GrayImage,
+    templates: &[Template],
+    region: Option<SearchRegion>,
+    default_threshold: f32,
+) -> Option<Detection> {
+    templates
+        .iter()
+        .filter_map(|template| detect_in(frame, template, region))
+        .reduce(|best, detection| {
+            if detection.margin(default_threshold) > best.margin(default_threshold) {
+                detection
+            } else {
+                best
+            }
+        })
+}
+
+/// Cheap fingerprint of a frame from every fourth row, for skipping the match on a screen that
+/// has not changed. Anything the size of a dialog spans several hashed rows.
+pub fn frame_signature(image: &GrayImage) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hasher.write_u32(image.width());
+    hasher.write_u32(image.height());
+    let row = image.width() as usize;
+    if row > 0 {
+        for line in image.as_raw().chunks_exact(row).step_by(SIGNATURE_ROW_STEP) {
+            hasher.write(line);
+        }
+    }
+    hasher.finish()
+}
+
 pub fn detect(frame: &GrayImage, template: &Template) -> Option<Detection> {
     let mut best: Option<Detection> = None;
 
@@ -79,11 +239,12 @@ pub fn detect(frame: &GrayImage, template: &Template) -> Option<Detection> {
                 position: (x, y),
                 template_size: (variant.width(), variant.height()),
                 scale: variant.scale(),
+                threshold: template.threshold,
             };
 
             if best
                 .as_ref()
-                .map_or(true, |current| detection.score > current.score)
+                .is_none_or(|current| detection.score > current.score)
             {
                 best = Some(detection);
             }
@@ -93,18 +254,12 @@ pub fn detect(frame: &GrayImage, template: &Template) -> Option<Detection> {
     best
 }
 
-fn build_variants(base: &GrayImage) -> Vec<TemplateVariant> {
+fn build_variants(base: &GrayImage, scales: &[f32]) -> Vec<TemplateVariant> {
     let mut variants = Vec::new();
-    for &scale in TEMPLATE_SCALE_FACTORS {
-        if scale <= 0.0 {
+    for scale in scales::effective(scales) {
+        let Some((new_w, new_h)) = scales::variant_size(base.dimensions(), scale) else {
             continue;
-        }
-
-        let new_w = ((base.width() as f32 * scale).round() as i32).max(1) as u32;
-        let new_h = ((base.height() as f32 * scale).round() as i32).max(1) as u32;
-        if new_w < 4 || new_h < 4 {
-            continue;
-        }
+        };
 
         let image = if (scale - 1.0).abs() < f32::EPSILON {
             base.clone()
@@ -0,0 +1,186 @@
+//! Elevation mismatch: Windows drops input synthesized by a process that is not elevated when it
+//! targets an elevated window, so a League client started as administrator ignores every click
+//! without an error.
+
+use anyhow::Result;
+
+use crate::config::{AcceptMode, AppConfig, InputBackendKind};
+
+/// Whether this process and the League client run at different elevation in the direction that
+/// blocks input. Unknown states never count as a mismatch.
+pub fn is_mismatch(app_elevated: Option<bool>, client_elevated: Option<bool>) -> bool {
+    app_elevated == Some(false) && client_elevated == Some(true)
+}
+
+/// Whether `config` accepts with synthesized input that a mismatch would block: not in dry runs,
+/// through the client API or through the Interception driver, which sits below the restriction.
+pub fn affects(config: &AppConfig) -> bool {
+    !config.dry_run
+        && config.input.accept_mode != AcceptMode::Lcu
+        && config.input.backend != InputBackendKind::Interception
+}
+
+/// Checks the running League client against this process; `false` when either is unknown, the
+/// client is not running or the platform has no such restriction.
+pub fn client_mismatch() -> bool {
+    is_mismatch(platform::is_elevated(), platform::client_elevated())
+}
+
+/// Command-line parameters for the elevated copy: `args` quoted where needed, following the
+/// rules `CommandLineToArgvW` splits them back by.
+pub fn restart_parameters(args: &[String]) -> String {
+    args.iter()
+        .map(|arg| quote(arg))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `arg` as one command-line argument. Backslashes are literal except in a run that ends at a
+/// `"`, where each pair stands for one; such runs are doubled, including the one before the
+/// closing quote.
+fn quote(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains([' ', '\t', '"']) {
+        return arg.to_owned();
+    }
+    let mut quoted = String::from('"');
+    let mut backslashes = 0;
+    for ch in arg.chars() {
+        match ch {
+            '\\' => backslashes += 1,
+            '"' => {
+                quoted.extend(std::iter::repeat_n('\\', backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.extend(std::iter::repeat_n('\\', backslashes));
+                quoted.push(ch);
+                backslashes = 0;
+            }
+        }
+    }
+    quoted.extend(std::iter::repeat_n('\\', backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+/// Starts this executable again as administrator, with the same arguments, after the UAC prompt.
+/// The caller exits on success.
+pub fn restart_elevated() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    platform::run_elevated(&restart_parameters(&args))
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::{ffi::OsStr, mem, os::windows::ffi::OsStrExt, ptr};
+
+    use anyhow::{Context, Result, bail};
+    use windows_sys::Win32::{
+        Foundation::{CloseHandle, ERROR_ACCESS_DENIED, GetLastError, HANDLE},
+        Security::{GetTokenInformation, TOKEN_ELEVATION, TOKEN_QUERY, TokenElevation},
+        System::Threading::{
+            GetCurrentProcess, OpenProcess, OpenProcessToken, PROCESS_QUERY_LIMITED_INFORMATION,
+        },
+        UI::{Shell::ShellExecuteW, WindowsAndMessaging::SW_SHOWNORMAL},
+    };
+
+    use crate::process::{self, LEAGUE_CLIENT_PROCESS};
+
+    fn wide(text: impl AsRef<OsStr>) -> Vec<u16> {
+        text.as_ref().encode_wide().chain(Some(0)).collect()
+    }
+
+    /// Elevation of the token of `process`, or the error from opening the token.
+    fn token_elevated(process: HANDLE) -> std::result::Result<bool, u32> {
+        let mut token: HANDLE = ptr::null_mut();
+        // SAFETY: `process` is a valid handle and `token` a valid out pointer.
+        if unsafe { OpenProcessToken(process, TOKEN_QUERY, &mut token) } == 0 {
+            // SAFETY: reads the calling thread's last error.
+            return Err(unsafe { GetLastError() });
+        }
+        let mut elevation = TOKEN_ELEVATION { TokenIsElevated: 0 };
+        let mut returned = 0u32;
+        // SAFETY: `elevation` is a writable TOKEN_ELEVATION of the size passed.
+        let ok = unsafe {
+            GetTokenInformation(
+                token,
+                TokenElevation,
+                (&mut elevation as *mut TOKEN_ELEVATION).cast(),
+                mem::size_of::<TOKEN_ELEVATION>() as u32,
+                &mut returned,
+            )
+        };
+        // SAFETY: `token` was opened above.
+        unsafe { CloseHandle(token) };
+        if ok == 0 {
+            // SAFETY: reads the calling thread's last error.
+            return Err(unsafe { GetLastError() });
+        }
+        Ok(elevation.TokenIsElevated != 0)
+    }
+
+    pub fn is_elevated() -> Option<bool> {
+        // SAFETY: the pseudo handle of the current process needs no closing.
+        token_elevated(unsafe { GetCurrentProcess() }).ok()
+    }
+
+    pub fn client_elevated() -> Option<bool> {
+        let pid = process::find_pid(LEAGUE_CLIENT_PROCESS)?;
+        // SAFETY: plain query-only handle, closed below.
+        let handle = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid) };
+        if handle.is_null() {
+            return None;
+        }
+        let elevated = match token_elevated(handle) {
+            Ok(elevated) => Some(elevated),
+            // A process that is not elevated cannot open an elevated one's token.
+            Err(ERROR_ACCESS_DENIED) => Some(true),
+            Err(_) => None,
+        };
+        // SAFETY: `handle` was opened above.
+        unsafe { CloseHandle(handle) };
+        elevated
+    }
+
+    pub fn run_elevated(parameters: &str) -> Result<()> {
+        let exe = std::env::current_exe().context("Failed to locate the executable")?;
+        let (verb, exe, parameters) = (wide("runas"), wide(exe), wide(parameters));
+        // SAFETY: all strings are NUL-terminated and outlive the call.
+        let result = unsafe {
+            ShellExecuteW(
+                ptr::null_mut(),
+                verb.as_ptr(),
+                exe.as_ptr(),
+                parameters.as_ptr(),
+                ptr::null(),
+                SW_SHOWNORMAL,
+            )
+        };
+        // Values up to 32 are errors, including a declined UAC prompt.
+        if result as usize <= 32 {
+            bail!(
+                "Failed to start as administrator (error {})",
+                result as usize
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(windows))]
+mod platform {
+    use anyhow::{Result, bail};
+
+    pub fn is_elevated() -> Option<bool> {
+        None
+    }
+
+    pub fn client_elevated() -> Option<bool> {
+        None
+    }
+
+    pub fn run_elevated(_parameters: &str) -> Result<()> {
+        bail!("Restarting as administrator is only supported on Windows")
+    }
+}
@@ -0,0 +1,210 @@
+//! Gameflow phase and matchmaking state mirrored from the League client.
+
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use serde_json::Value;
+
+use crate::{
+    config::RequeueSettings,
+    lcu::{self, LcuClient},
+};
+
+pub const PHASE_URI: &str = "/lol-gameflow/v1/gameflow-phase";
+pub const PHASE_EVENT: &str = "OnJsonApiEvent_lol-gameflow_v1_gameflow-phase";
+pub const SEARCH_URI: &str = "/lol-matchmaking/v1/search";
+pub const SEARCH_EVENT: &str = "OnJsonApiEvent_lol-matchmaking_v1_search";
+pub const START_SEARCH_PATH: &str = "/lol-lobby/v2/lobby/matchmaking/search";
+/// Seconds a ready check stays open before the client declines it.
+pub const READY_CHECK_SECONDS: f32 = 12.0;
+
+/// A running matchmaking search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueSearch {
+    pub time_in_queue: f32,
+    pub estimated_time: f32,
+}
+
+/// An open ready check; `timer` counts up from the pop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadyCheck {
+    pub timer: f32,
+    /// `None`, `Accepted` or `Declined`.
+    pub response: String,
+}
+
+impl ReadyCheck {
+    pub fn remaining(&self) -> f32 {
+        (READY_CHECK_SECONDS - self.timer).max(0.0)
+    }
+}
+
+/// Latest phase, search and ready check the client reported.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueueStatus {
+    pub phase: String,
+    pub search: Option<QueueSearch>,
+    pub ready_check: Option<ReadyCheck>,
+}
+
+impl QueueStatus {
+    /// Current state queried over REST, used right after connecting since events only carry
+    /// changes. A missing search simply means the player is not queued.
+    pub fn fetch(client: &LcuClient) -> Result<Self> {
+        let mut status = Self::default();
+        status.apply(PHASE_URI, "Update", &client.get(PHASE_URI)?);
+        if let Ok(search) = client.get(SEARCH_URI) {
+            status.apply(SEARCH_URI, "Update", &search);
+        }
+        Ok(status)
+    }
+
+    /// Folds a client event into the status; returns whether anything changed.
+    pub fn apply(&mut self, uri: &str, event_type: &str, data: &Value) -> bool {
+        let deleted = event_type == "Delete";
+        let before = self.clone();
+        match uri {
+            PHASE_URI => {
+                self.phase = data.as_str().unwrap_or("None").to_string();
+                if !matches!(self.phase.as_str(), "Matchmaking" | "ReadyCheck") {
+                    self.search = None;
+                    self.ready_check = None;
+                }
+            }
+            SEARCH_URI if deleted => {
+                self.search = None;
+                self.ready_check = None;
+            }
+            SEARCH_URI => {
+                self.search = parse_search(data);
+                self.ready_check = data.get("readyCheck").and_then(parse_ready_check);
+            }
+            lcu::READY_CHECK_URI if deleted => self.ready_check = None,
+            lcu::READY_CHECK_URI => self.ready_check = parse_ready_check(data),
+            _ => return false,
+        }
+        *self != before
+    }
+}
+
+fn parse_search(data: &Value) -> Option<QueueSearch> {
+    let state = data.get("searchState").and_then(Value::as_str)?;
+    if !matches!(state, "Searching" | "Found") {
+        return None;
+    }
+    let seconds = |key| data.get(key).and_then(Value::as_f64).unwrap_or(0.0) as f32;
+    Some(QueueSearch {
+        time_in_queue: seconds("timeInQueue"),
+        estimated_time: seconds("estimatedQueueTime"),
+    })
+}
+
+fn parse_ready_check(data: &Value) -> Option<ReadyCheck> {
+    if data.get("state").and_then(Value::as_str) != Some("InProgress") {
+        return None;
+    }
+    Some(ReadyCheck {
+        timer: data.get("timer").and_then(Value::as_f64).unwrap_or(0.0) as f32,
+        response: data
+            .get("playerResponse")
+            .and_then(Value::as_str)
+            .unwrap_or("None")
+            .to_string(),
+    })
+}
+
+/// Phases in which a ready check can pop, so the screen is worth watching.
+pub fn capture_needed(phase: &str) -> bool {
+    matches!(phase, "Matchmaking" | "ReadyCheck")
+}
+
+/// Display text for a gameflow phase.
+pub fn phase_label(phase: &str) -> &str {
+    match phase {
+        "" | "None" => "Not in a lobby",
+        "Lobby" => "In lobby",
+        "Matchmaking" => "In queue",
+        "CheckedIntoTournament" => "Checked into tournament",
+        "ReadyCheck" => "Ready check",
+        "ChampSelect" => "Champ select",
+        "GameStart" => "Loading into game",
+        "InProgress" => "In game",
+        "Reconnect" => "Reconnect available",
+        "WaitingForStats" => "Waiting for stats",
+        "PreEndOfGame" | "EndOfGame" => "Post game",
+        other => other,
+    }
+}
+
+/// What the re-queue watcher decided or did.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RequeueOutcome {
+    /// Champ select fell through; searching again after the configured delay.
+    Scheduled {
+        attempt: u32,
+        max: u32,
+    },
+    /// The retry budget is spent; the player has to queue manually.
+    LimitReached {
+        max: u32,
+    },
+    Started {
+        attempt: u32,
+    },
+}
+
+/// Watches phase changes for a champ select that drops back into the lobby.
+#[derive(Debug, Default)]
+pub struct Requeue {
+    phase: String,
+    due: Option<Instant>,
+    attempts: u32,
+}
+
+impl Requeue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn on_phase(
+        &mut self,
+        settings: &RequeueSettings,
+        phase: &str,
+        now: Instant,
+    ) -> Option<RequeueOutcome> {
+        let previous = std::mem::replace(&mut self.phase, phase.to_string());
+        if phase == "InProgress" {
+            self.attempts = 0;
+        }
+        if phase != "Lobby" {
+            // Anything but the lobby (a manual queue, a new champ select) supersedes a pending
+            // re-queue.
+            self.due = None;
+            return None;
+        }
+        if !settings.enabled || previous != "ChampSelect" {
+            return None;
+        }
+        if self.attempts >= settings.max_retries {
+            return Some(RequeueOutcome::LimitReached {
+                max: settings.max_retries,
+            });
+        }
+        self.attempts += 1;
+        self.due = Some(now + Duration::from_millis(settings.delay_ms));
+        Some(RequeueOutcome::Scheduled {
+            attempt: self.attempts,
+            max: settings.max_retries,
+        })
+    }
+
+    /// Returns the attempt number once the scheduled re-queue is due, clearing it.
+    pub fn take_due(&mut self, now: Instant) -> Option<u32> {
+        let due = self.due?;
+        if now < due {
+            return None;
+        }
+        self.due = None;
+        Some(self.attempts)
+    }
+}
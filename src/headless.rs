@@ -0,0 +1,168 @@
+//! Headless mode: the capture → detect → click worker without a window, printing its events to
+//! stdout until Ctrl+C, for mini PCs and remote desktop sessions.
+
+use std::io::{self, Write};
+
+use anyhow::{Context, Result, bail};
+use chrono::Local;
+use crossbeam_channel::{bounded, select};
+use tracing::info;
+
+use crate::{
+    capture,
+    config::AppConfig,
+    hotplug::DisplayWatcher,
+    templates,
+    worker::{self, WorkerEvent},
+};
+
+/// Runs the worker with `config` until Ctrl+C (SIGINT) or the worker stops by itself.
+pub fn run(config: AppConfig) -> Result<()> {
+    let templates = templates::load_enabled(&config).context("Template image lookup failed")?;
+    let backend = capture::open_backend(&config.effective_capture_source())?;
+    let display_watcher = DisplayWatcher::start();
+
+    let (interrupt_tx, interrupt_rx) = bounded(1);
+    ctrlc::set_handler(move || {
+        let _ = interrupt_tx.try_send(());
+    })
+    .context("Failed to install the Ctrl+C handler")?;
+
+    let (mut handle, events) = worker::spawn(
+        config,
+        templates,
+        backend,
+        Some(display_watcher.subscribe()),
+    )?;
+    info!("Monitoring headless; press Ctrl+C to stop");
+
+    let mut stdout = io::stdout().lock();
+    let mut crashed = None;
+    loop {
+        select! {
+            recv(interrupt_rx) -> _ => {
+                // Keep printing until the worker reports that it stopped.
+                info!("Interrupted, stopping the worker");
+                handle.request_stop();
+            }
+            recv(events) -> event => {
+                let Ok(event) = event else { break };
+                if let WorkerEvent::Crashed(message) = &event {
+                    crashed = Some(message.clone());
+                }
+                let stopped = matches!(event, WorkerEvent::Stopped | WorkerEvent::Crashed(_));
+                if let Some(line) = describe(&event) {
+                    // A closed pipe (e.g. `| head`) should not take the worker down.
+                    let _ = writeln!(stdout, "{} {line}", Local::now().format("%H:%M:%S"));
+                }
+                if stopped {
+                    break;
+                }
+            }
+        }
+    }
+    handle.join();
+    let _ = stdout.flush();
+
+    if let Some(message) = crashed {
+        bail!("Worker crashed: {message}");
+    }
+    Ok(())
+}
+
+/// One line of console output for `event`; `None` for per-frame chatter.
+pub fn describe(event: &WorkerEvent) -> Option<String> {
+    Some(match event {
+        WorkerEvent::Detection {
+            score,
+            screen_coords,
+            scale,
+            ..
+        } => format!(
+            "detected at ({}, {}) score {score:.3} scale {scale:.2}",
+            screen_coords.0, screen_coords.1
+        ),
+        WorkerEvent::Clicked { screen_coords } => {
+            format!("clicked at ({}, {})", screen_coords.0, screen_coords.1)
+        }
+        WorkerEvent::WouldClick { screen_coords } => format!(
+            "dry run: would click at ({}, {})",
+            screen_coords.0, screen_coords.1
+        ),
+        WorkerEvent::AcceptAttempt { mode, attempt } => {
+            format!("accept attempt {attempt} ({mode:?})")
+        }
+        WorkerEvent::ClickVerified { attempts } => {
+            format!("ready check gone after {attempts} click(s)")
+        }
+        WorkerEvent::ClickUnconfirmed { attempts } => {
+            format!("ready check still visible after {attempts} click(s)")
+        }
+        WorkerEvent::ReadyCheckAccepted => "ready check accepted through the client API".into(),
+        WorkerEvent::ChampSelect(outcome) => format!("champ select: {outcome:?}"),
+        WorkerEvent::LcuEventsLive(live) => {
+            if *live {
+                "client events connected; screen detection paused".into()
+            } else {
+                "client events lost; watching the screen".into()
+            }
+        }
+        WorkerEvent::QueueStatus(status) => format!("queue: {status:?}"),
+        WorkerEvent::CaptureGated { paused, phase } => {
+            if *paused {
+                format!("capture paused in phase {phase}")
+            } else {
+                format!("capture resumed in phase {phase}")
+            }
+        }
+        WorkerEvent::Honored { player } => format!("honored {player}"),
+        WorkerEvent::Requeue(outcome) => format!("requeue: {outcome:?}"),
+        WorkerEvent::ClickAborted { reason } => format!("click aborted: {reason}"),
+        WorkerEvent::CooldownActive {
+            score,
+            remaining_ms,
+        } => format!("cooldown active ({remaining_ms} ms left), score {score:.3}"),
+        WorkerEvent::UserActive {
+            idle_ms,
+            required_ms,
+        } => format!("held back: user active (idle {idle_ms} of {required_ms} ms)"),
+        WorkerEvent::CaptureDegraded {
+            monitor_index,
+            failures,
+            retry_in_ms,
+        } => format!(
+            "capture of monitor {monitor_index} failing ({failures} in a row), retrying in {retry_in_ms} ms"
+        ),
+        WorkerEvent::CaptureRecovered { monitor_index } => {
+            format!("capture of monitor {monitor_index} recovered")
+        }
+        WorkerEvent::MonitorsChanged {
+            monitor_index,
+            monitor_count,
+        } => format!("displays changed: {monitor_count} monitor(s), capturing {monitor_index}"),
+        WorkerEvent::PossibleMiss(miss) => format!(
+            "possible missed queue pop: {} frames scored up to {:.3}, just under the threshold {:.2}",
+            miss.frames, miss.score, miss.threshold
+        ),
+        WorkerEvent::PowerChanged {
+            on_battery: true,
+            interval_ms,
+            scales,
+        } => format!(
+            "on battery: polling every {interval_ms} ms with {} scale(s)",
+            scales.map_or_else(|| "all".to_string(), |scales| scales.to_string())
+        ),
+        WorkerEvent::PowerChanged { .. } => "plugged in: back to full speed".into(),
+        WorkerEvent::Metrics(_) | WorkerEvent::FrameScored(_) | WorkerEvent::Frame(_) => {
+            return None;
+        }
+        WorkerEvent::ElevationMismatch => {
+            "the League client runs as administrator: clicks will be ignored until this app does too"
+                .into()
+        }
+        WorkerEvent::Crashed(message) => format!("worker crashed: {message}"),
+        WorkerEvent::Error(message) => format!("error: {message}"),
+        WorkerEvent::Info(message) => message.clone(),
+        WorkerEvent::Stopped => "stopped".into(),
+    })
+}
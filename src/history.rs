@@ -0,0 +1,130 @@
+//! Recent detections and what became of them, so an unattended session can be audited later.
+
+use std::{collections::VecDeque, fmt::Write as _, fs, path::Path};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, SecondsFormat};
+use image::GrayImage;
+
+/// Entries kept before the oldest are dropped.
+pub const HISTORY_CAPACITY: usize = 200;
+
+/// What happened after a detection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryOutcome {
+    Detected,
+    Clicked,
+    /// Dry run: the click was only reported.
+    WouldClick,
+    /// The click was called off because the screen changed.
+    Aborted,
+}
+
+impl HistoryOutcome {
+    /// Stable name for exports.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Detected => "detected",
+            Self::Clicked => "clicked",
+            Self::WouldClick => "would_click",
+            Self::Aborted => "aborted",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    /// Unique for the lifetime of the [`History`], for caching textures.
+    pub id: u64,
+    pub at: DateTime<Local>,
+    pub outcome: HistoryOutcome,
+    pub score: f32,
+    pub scale: f32,
+    pub screen_coords: (i32, i32),
+    pub thumbnail: GrayImage,
+}
+
+/// Newest-last list of detections, capped at [`HISTORY_CAPACITY`].
+#[derive(Debug, Default)]
+pub struct History {
+    entries: VecDeque<HistoryEntry>,
+    next_id: u64,
+}
+
+impl History {
+    pub fn record_detection(
+        &mut self,
+        at: DateTime<Local>,
+        score: f32,
+        scale: f32,
+        screen_coords: (i32, i32),
+        thumbnail: GrayImage,
+    ) {
+        if self.entries.len() >= HISTORY_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(HistoryEntry {
+            id: self.next_id,
+            at,
+            outcome: HistoryOutcome::Detected,
+            score,
+            scale,
+            screen_coords,
+            thumbnail,
+        });
+        self.next_id += 1;
+    }
+
+    /// Marks the latest detection with `outcome`; the click may land away from the detection
+    /// point after retries, so `screen_coords` is updated too.
+    pub fn resolve(&mut self, outcome: HistoryOutcome, screen_coords: Option<(i32, i32)>) {
+        if let Some(entry) = self
+            .entries
+            .back_mut()
+            .filter(|entry| entry.outcome == HistoryOutcome::Detected)
+        {
+            entry.outcome = outcome;
+            if let Some(coords) = screen_coords {
+                entry.screen_coords = coords;
+            }
+        }
+    }
+
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &HistoryEntry> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// The entries oldest first as CSV with a header row, for spreadsheets.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("timestamp,outcome,score,scale,screen_x,screen_y\n");
+        for entry in &self.entries {
+            let _ = writeln!(
+                csv,
+                "{},{},{:.4},{:.3},{},{}",
+                entry.at.to_rfc3339_opts(SecondsFormat::Millis, false),
+                entry.outcome.key(),
+                entry.score,
+                entry.scale,
+                entry.screen_coords.0,
+                entry.screen_coords.1,
+            );
+        }
+        csv
+    }
+}
+
+pub fn export_csv(path: &Path, history: &History) -> Result<()> {
+    fs::write(path, history.to_csv()).with_context(|| format!("Failed to write {}", path.display()))
+}
@@ -0,0 +1,129 @@
+//! Post-game honor through the LCU, so the honor screen does not hold up the return to the
+//! lobby.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use rand::seq::SliceRandom;
+use serde_json::{Value, json};
+
+use crate::{
+    config::{HonorSettings, HonorTarget},
+    lcu::LcuClient,
+};
+
+const BALLOT_PATH: &str = "/lol-honor-v2/v1/ballot";
+const HONOR_PATH: &str = "/lol-honor-v2/v1/honor-player";
+const EOG_STATS_PATH: &str = "/lol-end-of-game/v1/eog-stats-block";
+const HONOR_CATEGORY: &str = "HEART";
+
+/// A teammate the ballot lets us honor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Teammate {
+    pub puuid: String,
+    pub summoner_id: i64,
+    pub name: String,
+}
+
+/// Allies listed on the honor ballot (`eligibleAllies`, or `eligiblePlayers` on older clients).
+pub fn eligible_allies(ballot: &Value) -> Vec<Teammate> {
+    ballot
+        .get("eligibleAllies")
+        .or_else(|| ballot.get("eligiblePlayers"))
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|player| {
+            let text = |key| {
+                player
+                    .get(key)
+                    .and_then(Value::as_str)
+                    .filter(|s| !s.is_empty())
+            };
+            Some(Teammate {
+                puuid: text("puuid").unwrap_or_default().to_string(),
+                summoner_id: player
+                    .get("summonerId")
+                    .and_then(Value::as_i64)
+                    .unwrap_or(0),
+                name: text("gameName")
+                    .or_else(|| text("summonerName"))
+                    .or_else(|| text("championName"))?
+                    .to_string(),
+            })
+        })
+        .filter(|teammate| !teammate.puuid.is_empty() || teammate.summoner_id != 0)
+        .collect()
+}
+
+/// `(kills + assists) / max(deaths, 1)` per player puuid from the end-of-game stats block.
+pub fn kda_scores(stats: &Value) -> HashMap<String, f64> {
+    stats
+        .get("teams")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|team| team.get("players").and_then(Value::as_array))
+        .flatten()
+        .filter_map(|player| {
+            let puuid = player.get("puuid")?.as_str()?.to_string();
+            let stat = |key| {
+                player
+                    .pointer(&format!("/stats/{key}"))
+                    .and_then(Value::as_f64)
+                    .unwrap_or(0.0)
+            };
+            let score = (stat("CHAMPIONS_KILLED") + stat("ASSISTS")) / stat("NUM_DEATHS").max(1.0);
+            Some((puuid, score))
+        })
+        .collect()
+}
+
+/// Picks who to honor: the best KDA when scores are known, otherwise (or for
+/// [`HonorTarget::Random`]) anyone.
+pub fn choose_teammate<'a>(
+    allies: &'a [Teammate],
+    target: HonorTarget,
+    scores: &HashMap<String, f64>,
+    rng: &mut impl rand::Rng,
+) -> Option<&'a Teammate> {
+    let best = (target == HonorTarget::BestPerformance)
+        .then(|| {
+            allies
+                .iter()
+                .filter_map(|ally| Some((scores.get(&ally.puuid)?, ally)))
+                .max_by(|(a, _), (b, _)| a.total_cmp(b))
+                .map(|(_, ally)| ally)
+        })
+        .flatten();
+    best.or_else(|| allies.choose(rng))
+}
+
+/// Honors a teammate from the current ballot; returns their name, or `None` when nobody is
+/// eligible.
+pub fn honor_teammate(client: &LcuClient, settings: &HonorSettings) -> Result<Option<String>> {
+    let ballot = client.get(BALLOT_PATH)?;
+    let allies = eligible_allies(&ballot);
+    let scores = match settings.target {
+        HonorTarget::BestPerformance => client
+            .get(EOG_STATS_PATH)
+            .map(|stats| kda_scores(&stats))
+            .unwrap_or_default(),
+        HonorTarget::Random => HashMap::new(),
+    };
+    let Some(teammate) =
+        choose_teammate(&allies, settings.target, &scores, &mut rand::thread_rng())
+    else {
+        return Ok(None);
+    };
+    client.post_json(
+        HONOR_PATH,
+        &json!({
+            "gameId": ballot.get("gameId").cloned().unwrap_or(Value::Null),
+            "honorCategory": HONOR_CATEGORY,
+            "puuid": teammate.puuid,
+            "summonerId": teammate.summoner_id,
+        }),
+    )?;
+    Ok(Some(teammate.name.clone()))
+}
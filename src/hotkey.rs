@@ -0,0 +1,114 @@
+use std::{str::FromStr, sync::OnceLock};
+
+use anyhow::{Context, Result, anyhow};
+use crossbeam_channel::{Receiver, Sender};
+use global_hotkey::{
+    GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState,
+    hotkey::{HotKey, Modifiers},
+};
+use tracing::{info, warn};
+
+/// Things a global hotkey can trigger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HotkeyAction {
+    ToggleMonitoring,
+    ToggleMiniMode,
+    /// Switch to the named settings profile.
+    SwitchProfile(String),
+}
+
+/// System-wide hotkeys that fire even when the window is unfocused or hidden.
+pub struct Hotkeys {
+    manager: GlobalHotKeyManager,
+    bindings: Vec<(HotkeyAction, HotKey)>,
+}
+
+impl Hotkeys {
+    /// `wake` is called from the platform hook whenever a hotkey fires so the UI can repaint
+    /// and drain [`Hotkeys::poll`] even while idle.
+    pub fn new(wake: impl Fn() + Send + Sync + 'static) -> Result<Self> {
+        let manager = GlobalHotKeyManager::new()
+            .map_err(|err| anyhow!("Global hotkeys unavailable: {err}"))?;
+        let tx = events().0.clone();
+        GlobalHotKeyEvent::set_event_handler(Some(move |event: GlobalHotKeyEvent| {
+            let _ = tx.send(event);
+            wake();
+        }));
+        Ok(Self {
+            manager,
+            bindings: Vec::new(),
+        })
+    }
+
+    /// Replaces the binding for `action`. An empty `binding` just unregisters it.
+    pub fn bind(&mut self, action: HotkeyAction, binding: &str) -> Result<()> {
+        if let Some(pos) = self.bindings.iter().position(|(bound, _)| *bound == action) {
+            let (_, old) = self.bindings.remove(pos);
+            if let Err(err) = self.manager.unregister(old) {
+                warn!(error = %err, "failed to unregister hotkey");
+            }
+        }
+        if binding.trim().is_empty() {
+            return Ok(());
+        }
+        let hotkey = parse_hotkey(binding)?;
+        self.manager
+            .register(hotkey)
+            .map_err(|err| anyhow!("Could not register {binding}: {err}"))?;
+        info!(?action, binding, "global hotkey registered");
+        self.bindings.push((action, hotkey));
+        Ok(())
+    }
+
+    /// Unregisters every binding whose action fails `keep`.
+    pub fn retain(&mut self, keep: impl Fn(&HotkeyAction) -> bool) {
+        self.bindings.retain(|(action, hotkey)| {
+            if keep(action) {
+                return true;
+            }
+            if let Err(err) = self.manager.unregister(*hotkey) {
+                warn!(error = %err, "failed to unregister hotkey");
+            }
+            false
+        });
+    }
+
+    /// Actions whose hotkeys were pressed since the previous call.
+    pub fn poll(&self) -> Vec<HotkeyAction> {
+        events()
+            .1
+            .try_iter()
+            .filter(|event| event.state == HotKeyState::Pressed)
+            .filter_map(|event| {
+                self.bindings
+                    .iter()
+                    .find(|(_, hotkey)| hotkey.id() == event.id)
+                    .map(|(action, _)| action.clone())
+            })
+            .collect()
+    }
+}
+
+type EventChannel = (Sender<GlobalHotKeyEvent>, Receiver<GlobalHotKeyEvent>);
+
+/// The event handler in `global_hotkey` is process-wide, so the channel behind it is too.
+fn events() -> &'static EventChannel {
+    static EVENTS: OnceLock<EventChannel> = OnceLock::new();
+    EVENTS.get_or_init(crossbeam_channel::unbounded)
+}
+
+/// Parses bindings such as `Ctrl+Alt+A` or `Shift+F9`. At least one modifier is required for
+/// anything other than function keys, so ordinary typing is never swallowed.
+pub fn parse_hotkey(binding: &str) -> Result<HotKey> {
+    let hotkey =
+        HotKey::from_str(binding.trim()).with_context(|| format!("Invalid hotkey {binding:?}"))?;
+    let key_name = format!("{:?}", hotkey.key);
+    let is_function_key =
+        key_name.len() > 1 && key_name.starts_with('F') && key_name[1..].parse::<u8>().is_ok();
+    if hotkey.mods == Modifiers::empty() && !is_function_key {
+        return Err(anyhow!(
+            "Hotkey {binding:?} needs a modifier such as Ctrl or Alt"
+        ));
+    }
+    Ok(hotkey)
+}
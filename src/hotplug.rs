@@ -0,0 +1,232 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+use tracing::{debug, info, warn};
+
+use crate::capture::{self, MonitorInfo};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Watches for monitors being added, removed, or rearranged. Uses `WM_DISPLAYCHANGE` on Windows
+/// and RandR notifications on X11, falling back to polling the display list elsewhere.
+#[derive(Clone)]
+pub struct DisplayWatcher {
+    generation: Arc<AtomicU64>,
+}
+
+impl DisplayWatcher {
+    pub fn start() -> Self {
+        let generation = Arc::new(AtomicU64::new(0));
+        let listener_generation = generation.clone();
+        let spawned = thread::Builder::new()
+            .name("display-watcher".to_string())
+            .spawn(move || {
+                if let Err(err) = platform::listen(listener_generation.clone()) {
+                    warn!(error = ?err, "display change notifications unavailable, polling instead");
+                    poll(&listener_generation);
+                }
+            });
+        if let Err(err) = spawned {
+            warn!(error = ?err, "failed to start display watcher");
+        }
+        Self { generation }
+    }
+
+    /// A cursor that reports changes that happen after this call.
+    pub fn subscribe(&self) -> DisplayChanges {
+        DisplayChanges {
+            generation: self.generation.clone(),
+            seen: self.generation.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Per-consumer view of a [`DisplayWatcher`].
+pub struct DisplayChanges {
+    generation: Arc<AtomicU64>,
+    seen: u64,
+}
+
+impl DisplayChanges {
+    /// `true` if the display configuration changed since the previous call.
+    pub fn changed(&mut self) -> bool {
+        let current = self.generation.load(Ordering::Relaxed);
+        let changed = current != self.seen;
+        self.seen = current;
+        changed
+    }
+}
+
+fn bump(generation: &AtomicU64) {
+    let value = generation.fetch_add(1, Ordering::Relaxed) + 1;
+    info!(generation = value, "display configuration changed");
+}
+
+fn poll(generation: &AtomicU64) {
+    let mut last = fingerprint(&capture::enumerate_monitors().unwrap_or_default());
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        match capture::enumerate_monitors() {
+            Ok(monitors) => {
+                let current = fingerprint(&monitors);
+                if current != last {
+                    last = current;
+                    bump(generation);
+                }
+            }
+            Err(err) => debug!(error = ?err, "display poll failed"),
+        }
+    }
+}
+
+fn fingerprint(monitors: &[MonitorInfo]) -> Vec<(u32, i32, i32, u32, u32)> {
+    monitors
+        .iter()
+        .map(|m| (m.id, m.origin_x, m.origin_y, m.width, m.height))
+        .collect()
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::{
+        ptr,
+        sync::{Arc, OnceLock, atomic::AtomicU64},
+    };
+
+    use anyhow::{Result, anyhow};
+    use windows_sys::Win32::{
+        Foundation::{HWND, LPARAM, LRESULT, WPARAM},
+        System::LibraryLoader::GetModuleHandleW,
+        UI::WindowsAndMessaging::{
+            CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW, MSG, RegisterClassW,
+            TranslateMessage, WM_DISPLAYCHANGE, WNDCLASSW,
+        },
+    };
+
+    // The window procedure has no user pointer until after creation, so the counter lives here.
+    static GENERATION: OnceLock<Arc<AtomicU64>> = OnceLock::new();
+
+    pub fn listen(generation: Arc<AtomicU64>) -> Result<()> {
+        if GENERATION.set(generation).is_err() {
+            return Err(anyhow!("display watcher already running"));
+        }
+
+        let class_name: Vec<u16> = "LolAutoAcceptDisplayWatcher\0".encode_utf16().collect();
+        // SAFETY: plain Win32 window creation on the thread that pumps its messages. Broadcast
+        // messages such as WM_DISPLAYCHANGE are not delivered to message-only windows, so this is
+        // a regular top-level window that is never shown.
+        unsafe {
+            let instance = GetModuleHandleW(ptr::null());
+            let class = WNDCLASSW {
+                style: 0,
+                lpfnWndProc: Some(window_proc),
+                cbClsExtra: 0,
+                cbWndExtra: 0,
+                hInstance: instance,
+                hIcon: ptr::null_mut(),
+                hCursor: ptr::null_mut(),
+                hbrBackground: ptr::null_mut(),
+                lpszMenuName: ptr::null(),
+                lpszClassName: class_name.as_ptr(),
+            };
+            if RegisterClassW(&class) == 0 {
+                return Err(anyhow!("RegisterClassW failed"));
+            }
+            let hwnd = CreateWindowExW(
+                0,
+                class_name.as_ptr(),
+                class_name.as_ptr(),
+                0,
+                0,
+                0,
+                0,
+                0,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                instance,
+                ptr::null(),
+            );
+            if hwnd.is_null() {
+                return Err(anyhow!("CreateWindowExW failed"));
+            }
+
+            let mut msg: MSG = std::mem::zeroed();
+            while GetMessageW(&mut msg, ptr::null_mut(), 0, 0) > 0 {
+                TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        }
+        Err(anyhow!("display watcher message loop ended"))
+    }
+
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        if msg == WM_DISPLAYCHANGE
+            && let Some(generation) = GENERATION.get()
+        {
+            super::bump(generation);
+        }
+        // SAFETY: forwarding the untouched message to the default handler.
+        unsafe { DefWindowProcW(hwnd, msg, wparam, lparam) }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::sync::{Arc, atomic::AtomicU64};
+
+    use anyhow::{Context, Result};
+    use x11rb::{
+        connection::Connection,
+        protocol::{
+            Event,
+            randr::{ConnectionExt as _, NotifyMask},
+        },
+    };
+
+    pub fn listen(generation: Arc<AtomicU64>) -> Result<()> {
+        let (conn, screen_num) = x11rb::connect(None).context("Failed to connect to X server")?;
+        conn.randr_query_version(1, 2)
+            .context("RandR unavailable")?
+            .reply()
+            .context("RandR version query failed")?;
+        let root = conn.setup().roots[screen_num].root;
+        conn.randr_select_input(
+            root,
+            NotifyMask::SCREEN_CHANGE | NotifyMask::OUTPUT_CHANGE | NotifyMask::CRTC_CHANGE,
+        )?;
+        conn.flush()?;
+
+        loop {
+            match conn.wait_for_event().context("X11 connection lost")? {
+                Event::RandrScreenChangeNotify(_) | Event::RandrNotify(_) => {
+                    super::bump(&generation)
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+mod platform {
+    use std::sync::{Arc, atomic::AtomicU64};
+
+    use anyhow::{Result, anyhow};
+
+    pub fn listen(_generation: Arc<AtomicU64>) -> Result<()> {
+        Err(anyhow!(
+            "no native display change notifications on this platform"
+        ))
+    }
+}
@@ -0,0 +1,133 @@
+//! GUI translations. English strings double as message keys; the Japanese, Korean and Spanish
+//! catalogs in `resources/locales` map them to translations, and anything missing stays English.
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::Path,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use anyhow::{Context, Result};
+use egui::{FontData, FontDefinitions, FontFamily};
+use once_cell::sync::Lazy;
+use tracing::{info, warn};
+
+use crate::config::Language;
+
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+static JAPANESE: Lazy<HashMap<String, String>> =
+    Lazy::new(|| load_embedded("ja", include_str!("../resources/locales/ja.toml")));
+static KOREAN: Lazy<HashMap<String, String>> =
+    Lazy::new(|| load_embedded("ko", include_str!("../resources/locales/ko.toml")));
+static SPANISH: Lazy<HashMap<String, String>> =
+    Lazy::new(|| load_embedded("es", include_str!("../resources/locales/es.toml")));
+
+/// Fonts with CJK glyphs, tried in order; egui's bundled fonts only cover Latin scripts.
+const CJK_FONTS: &[&str] = &[
+    "C:\\Windows\\Fonts\\meiryo.ttc",
+    "C:\\Windows\\Fonts\\YuGothM.ttc",
+    "C:\\Windows\\Fonts\\msgothic.ttc",
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+    "/System/Library/Fonts/Hiragino Sans GB.ttc",
+];
+/// Hangul fonts for systems whose CJK font above lacks it (Windows' Japanese fonts do).
+const HANGUL_FONTS: &[&str] = &[
+    "C:\\Windows\\Fonts\\malgun.ttf",
+    "/System/Library/Fonts/AppleSDGothicNeo.ttc",
+];
+
+/// Switches the language [`tr`] and [`trf`] translate into.
+pub fn set_language(language: Language) {
+    CURRENT.store(language as u8, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    Language::ALL
+        .get(usize::from(CURRENT.load(Ordering::Relaxed)))
+        .copied()
+        .unwrap_or_default()
+}
+
+/// `text` in the current language.
+pub fn tr(text: &str) -> &str {
+    translate(language(), text)
+}
+
+/// `text` in `language`, or `text` itself when English or not translated.
+pub fn translate(language: Language, text: &str) -> &str {
+    catalog(language)
+        .and_then(|catalog| catalog.get(text))
+        .map_or(text, String::as_str)
+}
+
+/// Translates `template` and fills its `{name}` placeholders from `args`.
+pub fn trf(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    fill(tr(template), args)
+}
+
+/// Replaces each `{name}` in `template` with the matching argument; unknown names are kept.
+pub fn fill(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut text = template.to_string();
+    for (name, value) in args {
+        text = text.replace(&format!("{{{name}}}"), &value.to_string());
+    }
+    text
+}
+
+/// The translations for `language`; `None` for English.
+pub fn catalog(language: Language) -> Option<&'static HashMap<String, String>> {
+    match language {
+        Language::English => None,
+        Language::Japanese => Some(&JAPANESE),
+        Language::Korean => Some(&KOREAN),
+        Language::Spanish => Some(&SPANISH),
+    }
+}
+
+/// Parses a catalog: a flat TOML table from English text to translation.
+pub fn parse_catalog(source: &str) -> Result<HashMap<String, String>> {
+    toml::from_str(source).context("invalid translation catalog")
+}
+
+fn load_embedded(code: &str, source: &str) -> HashMap<String, String> {
+    parse_catalog(source).unwrap_or_else(|err| {
+        warn!(language = code, error = ?err, "falling back to English");
+        HashMap::new()
+    })
+}
+
+/// Adds the first installed CJK and Hangul fonts as fallbacks so Japanese and Korean text (and
+/// the language picker) render instead of boxes.
+pub fn install_fonts(ctx: &egui::Context) {
+    let mut fonts = FontDefinitions::default();
+    let mut installed = false;
+    for (name, candidates) in [("cjk", CJK_FONTS), ("hangul", HANGUL_FONTS)] {
+        let Some((path, bytes)) = candidates
+            .iter()
+            .find_map(|path| Some((path, std::fs::read(Path::new(path)).ok()?)))
+        else {
+            continue;
+        };
+        info!(font = path, "loaded fallback font");
+        fonts
+            .font_data
+            .insert(name.to_string(), FontData::from_owned(bytes));
+        for family in [FontFamily::Proportional, FontFamily::Monospace] {
+            fonts
+                .families
+                .entry(family)
+                .or_default()
+                .push(name.to_string());
+        }
+        installed = true;
+    }
+    if installed {
+        ctx.set_fonts(fonts);
+    } else {
+        warn!("no CJK font found, Japanese and Korean text will not render");
+    }
+}
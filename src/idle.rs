@@ -0,0 +1,58 @@
+//! Time since the user last touched the keyboard or mouse, as reported by the OS.
+
+use std::time::Duration;
+
+/// Time since the last user input system-wide, or `None` when this platform cannot tell.
+///
+/// Synthetic input (including our own clicks) resets this counter on most systems.
+pub fn user_idle_time() -> Option<Duration> {
+    platform::user_idle_time()
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::{mem, time::Duration};
+
+    use windows_sys::Win32::{
+        System::SystemInformation::GetTickCount,
+        UI::Input::KeyboardAndMouse::{GetLastInputInfo, LASTINPUTINFO},
+    };
+
+    pub fn user_idle_time() -> Option<Duration> {
+        let mut info = LASTINPUTINFO {
+            cbSize: mem::size_of::<LASTINPUTINFO>() as u32,
+            dwTime: 0,
+        };
+        // SAFETY: `info` is a properly sized, writable LASTINPUTINFO.
+        if unsafe { GetLastInputInfo(&mut info) } == 0 {
+            return None;
+        }
+        // Both are 32-bit tick counts, so wrapping subtraction survives the 49.7-day rollover.
+        // SAFETY: takes no arguments and has no preconditions.
+        let now = unsafe { GetTickCount() };
+        Some(Duration::from_millis(now.wrapping_sub(info.dwTime) as u64))
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::time::Duration;
+
+    use x11rb::{connection::Connection, protocol::screensaver::ConnectionExt as _};
+
+    pub fn user_idle_time() -> Option<Duration> {
+        let (conn, screen_num) = x11rb::connect(None).ok()?;
+        let root = conn.setup().roots[screen_num].root;
+        let info = conn.screensaver_query_info(root).ok()?.reply().ok()?;
+        Some(Duration::from_millis(info.ms_since_user_input as u64))
+    }
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+mod platform {
+    use std::time::Duration;
+
+    pub fn user_idle_time() -> Option<Duration> {
+        None
+    }
+}
@@ -0,0 +1,155 @@
+//! At-a-glance monitoring state, shown as a colored dot in the status panel and mirrored in the
+//! tray and taskbar icons so it can be read from across the room.
+
+use std::time::{Duration, Instant};
+
+use crate::config::IndicatorPalette;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indicator {
+    /// Not monitoring.
+    Idle,
+    Monitoring,
+    /// Monitoring, but holding off after a click until the cooldown ends.
+    Cooldown,
+    /// The last start, accept or capture failed; cleared by the next success or restart.
+    Error,
+}
+
+impl Indicator {
+    /// State to show: an error wins over everything, and the cooldown only counts while running.
+    pub fn current(
+        running: bool,
+        failed: bool,
+        cooldown_until: Option<Instant>,
+        now: Instant,
+    ) -> Self {
+        if failed {
+            Self::Error
+        } else if !running {
+            Self::Idle
+        } else if cooldown_until.is_some_and(|until| until > now) {
+            Self::Cooldown
+        } else {
+            Self::Monitoring
+        }
+    }
+
+    /// Color of the state in `palette`, as RGB: gray, green, yellow or red by default.
+    pub fn color(self, palette: IndicatorPalette) -> [u8; 3] {
+        match (palette, self) {
+            (IndicatorPalette::Standard, Self::Idle) => [0x8c, 0x8c, 0x8c],
+            (IndicatorPalette::Standard, Self::Monitoring) => [0x3c, 0xb3, 0x71],
+            (IndicatorPalette::Standard, Self::Cooldown) => [0xe6, 0xc2, 0x29],
+            (IndicatorPalette::Standard, Self::Error) => [0xd9, 0x43, 0x43],
+            // Okabe–Ito colors.
+            (IndicatorPalette::Colorblind, Self::Idle) => [0x99, 0x99, 0x99],
+            (IndicatorPalette::Colorblind, Self::Monitoring) => [0x00, 0x72, 0xb2],
+            (IndicatorPalette::Colorblind, Self::Cooldown) => [0xf0, 0xe4, 0x42],
+            (IndicatorPalette::Colorblind, Self::Error) => [0xd5, 0x5e, 0x00],
+            (IndicatorPalette::HighContrast, Self::Idle) => [0xc0, 0xc0, 0xc0],
+            (IndicatorPalette::HighContrast, Self::Monitoring) => [0x00, 0xe5, 0xff],
+            (IndicatorPalette::HighContrast, Self::Cooldown) => [0xff, 0xff, 0x00],
+            (IndicatorPalette::HighContrast, Self::Error) => [0xff, 0x00, 0xff],
+        }
+    }
+
+    /// Shape the state is drawn as in `palette`; the standard palette tells states apart by
+    /// color alone.
+    pub fn shape(self, palette: IndicatorPalette) -> IndicatorShape {
+        if palette == IndicatorPalette::Standard {
+            return IndicatorShape::Dot;
+        }
+        match self {
+            Self::Idle => IndicatorShape::Ring,
+            Self::Monitoring => IndicatorShape::Dot,
+            Self::Cooldown => IndicatorShape::Pause,
+            Self::Error => IndicatorShape::Cross,
+        }
+    }
+
+    /// Untranslated name, also used for the tray tooltip.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Idle => "Idle",
+            Self::Monitoring => "Monitoring",
+            Self::Cooldown => "Cooldown",
+            Self::Error => "Error",
+        }
+    }
+}
+
+/// Mark the indicator is drawn as, so states differ by more than color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndicatorShape {
+    /// Filled disc.
+    Dot,
+    /// Disc with a hollow center.
+    Ring,
+    /// Disc with two bars, like a pause button.
+    Pause,
+    /// Disc with an X.
+    Cross,
+}
+
+impl IndicatorShape {
+    /// Whether the point at (`x`, `y`), relative to the center in units of the disc radius,
+    /// belongs to the mark cut into the disc.
+    pub fn marks(self, x: f32, y: f32) -> bool {
+        match self {
+            Self::Dot => false,
+            Self::Ring => x.hypot(y) < 0.5,
+            Self::Pause => y.abs() < 0.5 && (0.12..0.42).contains(&x.abs()),
+            Self::Cross => (x.abs() - y.abs()).abs() < 0.2 && x.abs() < 0.55,
+        }
+    }
+}
+
+/// Black or white, whichever reads better on `color`.
+pub fn contrasting(color: [u8; 3]) -> [u8; 3] {
+    let [r, g, b] = color.map(f32::from);
+    if 0.299 * r + 0.587 * g + 0.114 * b > 140.0 {
+        [0x10, 0x10, 0x10]
+    } else {
+        [0xff, 0xff, 0xff]
+    }
+}
+
+/// A post-click cooldown, counted down by the GUI clock rather than by worker events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cooldown {
+    pub until: Instant,
+    pub total: Duration,
+}
+
+impl Cooldown {
+    /// A cooldown of `total` starting at `now`.
+    pub fn new(now: Instant, total: Duration) -> Self {
+        Self {
+            until: now + total,
+            total,
+        }
+    }
+
+    /// Re-synchronizes with a worker report of `remaining`, keeping the known total so the bar
+    /// does not jump back to empty. A report longer than the total extends it.
+    pub fn sync(previous: Option<Self>, now: Instant, remaining: Duration) -> Self {
+        let total = previous.map_or(remaining, |cooldown| cooldown.total.max(remaining));
+        Self {
+            until: now + remaining,
+            total,
+        }
+    }
+
+    pub fn remaining(&self, now: Instant) -> Duration {
+        self.until.saturating_duration_since(now)
+    }
+
+    /// Share of the cooldown already over, from 0.0 to 1.0.
+    pub fn progress(&self, now: Instant) -> f32 {
+        if self.total.is_zero() {
+            return 1.0;
+        }
+        1.0 - (self.remaining(now).as_secs_f32() / self.total.as_secs_f32()).clamp(0.0, 1.0)
+    }
+}
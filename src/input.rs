@@ -1,12 +1,834 @@
-use std::time::Duration;
+use std::{
+    process::Command,
+    time::{Duration, Instant},
+};
 
-use anyhow::Result;
-use enigo::{Enigo, MouseButton, MouseControllable};
+use anyhow::{Context, Result, bail};
+use enigo::{Enigo, KeyboardControllable, MouseButton, MouseControllable};
+use rand::Rng;
+use tracing::info;
 
-pub fn click_at(x: i32, y: i32) -> Result<()> {
-    let mut enigo = Enigo::new();
-    enigo.mouse_move_to(x, y);
-    std::thread::sleep(Duration::from_millis(30));
-    enigo.mouse_click(MouseButton::Left);
+use crate::config::{AcceptMode, HumanizeSettings, InputBackendKind, InputSettings};
+
+/// Time between intermediate cursor positions of a humanized movement.
+const HUMANIZE_STEP: Duration = Duration::from_millis(8);
+const SETTLE_STEPS: u32 = 3;
+
+/// Keys that can be used for keyboard acceptance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKey {
+    Enter,
+    Space,
+    Tab,
+    Escape,
+    /// Typed through the active keyboard layout.
+    Char(char),
+}
+
+/// Low-level synthetic input. All coordinates are logical desktop coordinates.
+pub trait InputBackend {
+    fn name(&self) -> &str;
+
+    fn cursor_position(&mut self) -> Result<(i32, i32)>;
+
+    fn move_to(&mut self, x: i32, y: i32) -> Result<()>;
+
+    /// Presses (`true`) or releases (`false`) the left mouse button.
+    fn mouse_button(&mut self, pressed: bool) -> Result<()>;
+
+    fn key(&mut self, key: InputKey, pressed: bool) -> Result<()>;
+}
+
+pub fn open_backend(settings: &InputSettings) -> Result<Box<dyn InputBackend>> {
+    let backend: Box<dyn InputBackend> = match settings.backend {
+        InputBackendKind::Enigo => Box::new(EnigoBackend::new()),
+        InputBackendKind::SendInput => open_send_input_backend()?,
+        InputBackendKind::Xdotool => Box::new(XdotoolBackend::open()?),
+        InputBackendKind::Interception => {
+            if !settings.allow_driver_input {
+                bail!("The Interception backend needs `allow_driver_input` to be enabled");
+            }
+            open_interception_backend()?
+        }
+    };
+    info!(backend = backend.name(), "input backend ready");
+    Ok(backend)
+}
+
+#[cfg(windows)]
+fn open_send_input_backend() -> Result<Box<dyn InputBackend>> {
+    Ok(Box::new(win32::SendInputBackend))
+}
+
+#[cfg(not(windows))]
+fn open_send_input_backend() -> Result<Box<dyn InputBackend>> {
+    bail!("SendInput is only supported on Windows")
+}
+
+#[cfg(all(windows, feature = "interception"))]
+fn open_interception_backend() -> Result<Box<dyn InputBackend>> {
+    Ok(Box::new(interception::InterceptionBackend::open()?))
+}
+
+#[cfg(not(all(windows, feature = "interception")))]
+fn open_interception_backend() -> Result<Box<dyn InputBackend>> {
+    bail!("This build does not include the Interception backend (Windows, `interception` feature)")
+}
+
+/// Cross-platform input through `enigo`.
+pub struct EnigoBackend {
+    enigo: Enigo,
+}
+
+impl EnigoBackend {
+    pub fn new() -> Self {
+        Self {
+            enigo: Enigo::new(),
+        }
+    }
+}
+
+impl Default for EnigoBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InputBackend for EnigoBackend {
+    fn name(&self) -> &str {
+        "enigo"
+    }
+
+    fn cursor_position(&mut self) -> Result<(i32, i32)> {
+        Ok(self.enigo.mouse_location())
+    }
+
+    fn move_to(&mut self, x: i32, y: i32) -> Result<()> {
+        self.enigo.mouse_move_to(x, y);
+        Ok(())
+    }
+
+    fn mouse_button(&mut self, pressed: bool) -> Result<()> {
+        if pressed {
+            self.enigo.mouse_down(MouseButton::Left);
+        } else {
+            self.enigo.mouse_up(MouseButton::Left);
+        }
+        Ok(())
+    }
+
+    fn key(&mut self, key: InputKey, pressed: bool) -> Result<()> {
+        let key = match key {
+            InputKey::Enter => enigo::Key::Return,
+            InputKey::Space => enigo::Key::Space,
+            InputKey::Tab => enigo::Key::Tab,
+            InputKey::Escape => enigo::Key::Escape,
+            InputKey::Char(ch) => enigo::Key::Layout(ch),
+        };
+        if pressed {
+            self.enigo.key_down(key);
+        } else {
+            self.enigo.key_up(key);
+        }
+        Ok(())
+    }
+}
+
+/// Drives the `xdotool` command-line tool (X11). Useful when the XTest events sent through
+/// enigo's libxdo binding are ignored.
+pub struct XdotoolBackend;
+
+impl XdotoolBackend {
+    pub fn open() -> Result<Self> {
+        let backend = Self;
+        backend
+            .run(&["version"])
+            .context("xdotool is not installed or not on PATH")?;
+        Ok(backend)
+    }
+
+    fn run(&self, args: &[&str]) -> Result<String> {
+        let output = Command::new("xdotool")
+            .args(args)
+            .output()
+            .context("Failed to run xdotool")?;
+        if !output.status.success() {
+            bail!(
+                "xdotool {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+impl InputBackend for XdotoolBackend {
+    fn name(&self) -> &str {
+        "xdotool"
+    }
+
+    fn cursor_position(&mut self) -> Result<(i32, i32)> {
+        let output = self.run(&["getmouselocation", "--shell"])?;
+        parse_xdotool_location(&output)
+            .with_context(|| format!("Unexpected xdotool output {output:?}"))
+    }
+
+    fn move_to(&mut self, x: i32, y: i32) -> Result<()> {
+        self.run(&["mousemove", &x.to_string(), &y.to_string()])
+            .map(drop)
+    }
+
+    fn mouse_button(&mut self, pressed: bool) -> Result<()> {
+        self.run(&[if pressed { "mousedown" } else { "mouseup" }, "1"])
+            .map(drop)
+    }
+
+    fn key(&mut self, key: InputKey, pressed: bool) -> Result<()> {
+        let name = match key {
+            InputKey::Enter => "Return".to_string(),
+            InputKey::Space => "space".to_string(),
+            InputKey::Tab => "Tab".to_string(),
+            InputKey::Escape => "Escape".to_string(),
+            InputKey::Char(ch) => ch.to_string(),
+        };
+        self.run(&[if pressed { "keydown" } else { "keyup" }, &name])
+            .map(drop)
+    }
+}
+
+/// Parses the `X=..`/`Y=..` lines printed by `xdotool getmouselocation --shell`.
+pub fn parse_xdotool_location(output: &str) -> Option<(i32, i32)> {
+    let field = |name: &str| {
+        output
+            .lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix('='))
+            .and_then(|value| value.trim().parse().ok())
+    };
+    Some((field("X")?, field("Y")?))
+}
+
+/// Accepts the match at `(x, y)` with `mode`: click, key press, or both.
+pub fn accept(
+    backend: &mut dyn InputBackend,
+    x: i32,
+    y: i32,
+    mode: AcceptMode,
+    settings: &InputSettings,
+) -> Result<()> {
+    match mode {
+        AcceptMode::Click => click_at(backend, x, y, settings),
+        AcceptMode::Key => press_key(backend, &settings.accept_key, settings),
+        AcceptMode::ClickAndKey => {
+            click_at(backend, x, y, settings)?;
+            press_key(backend, &settings.accept_key, settings)
+        }
+        AcceptMode::Lcu => {
+            bail!("LCU accepts are sent through the client API, not the input backend")
+        }
+    }
+}
+
+/// Presses and releases the named key (see [`parse_key`]) in the focused window.
+pub fn press_key(
+    backend: &mut dyn InputBackend,
+    name: &str,
+    settings: &InputSettings,
+) -> Result<()> {
+    let key = parse_key(name).with_context(|| format!("Unknown accept key {name:?}"))?;
+    backend.key(key, true)?;
+    std::thread::sleep(jittered(
+        settings.hold_ms,
+        settings.timing_jitter_ms,
+        &mut rand::thread_rng(),
+    ));
+    backend.key(key, false)
+}
+
+/// Maps a configured key name to a key. Named keys are case-insensitive; any other single
+/// character is typed through the active keyboard layout.
+pub fn parse_key(name: &str) -> Option<InputKey> {
+    let name = name.trim();
+    match name.to_ascii_lowercase().as_str() {
+        "enter" | "return" => Some(InputKey::Enter),
+        "space" => Some(InputKey::Space),
+        "tab" => Some(InputKey::Tab),
+        "escape" | "esc" => Some(InputKey::Escape),
+        _ => {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) => Some(InputKey::Char(ch)),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Moves the cursor to `(x, y)` in logical desktop coordinates and left-clicks.
+pub fn click_at(
+    backend: &mut dyn InputBackend,
+    x: i32,
+    y: i32,
+    settings: &InputSettings,
+) -> Result<()> {
+    let origin = backend.cursor_position()?;
+    if settings.humanize.enabled {
+        move_along(backend, origin, (x, y), &settings.humanize)?;
+    } else {
+        backend.move_to(x, y)?;
+    }
+    let mut rng = rand::thread_rng();
+    std::thread::sleep(jittered(
+        settings.click_delay_ms,
+        settings.timing_jitter_ms,
+        &mut rng,
+    ));
+    for press in 0..settings.click_count.presses() {
+        if press > 0 {
+            std::thread::sleep(jittered(
+                settings.double_click_gap_ms,
+                settings.timing_jitter_ms,
+                &mut rng,
+            ));
+        }
+        backend.mouse_button(true)?;
+        std::thread::sleep(jittered(
+            settings.hold_ms,
+            settings.timing_jitter_ms,
+            &mut rng,
+        ));
+        backend.mouse_button(false)?;
+    }
+
+    if settings.restore_cursor && origin != (x, y) {
+        // Always glide back; overshooting the user's own resting position would look odd.
+        let restore = HumanizeSettings {
+            enabled: true,
+            overshoot_px: 0.0,
+            ..settings.humanize.clone()
+        };
+        move_along(backend, (x, y), origin, &restore)?;
+    }
+    Ok(())
+}
+
+/// `base_ms` shifted by a uniformly random amount in `-jitter_ms..=jitter_ms`, never below zero.
+pub fn jittered(base_ms: u64, jitter_ms: u64, rng: &mut impl Rng) -> Duration {
+    let offset = rng.gen_range(-(jitter_ms as i64)..=jitter_ms as i64);
+    Duration::from_millis((base_ms as i64 + offset).max(0) as u64)
+}
+
+fn move_along(
+    backend: &mut dyn InputBackend,
+    from: (i32, i32),
+    to: (i32, i32),
+    settings: &HumanizeSettings,
+) -> Result<()> {
+    for point in humanized_path(from, to, settings, &mut rand::thread_rng()) {
+        backend.move_to(point.x, point.y)?;
+        std::thread::sleep(point.delay);
+    }
     Ok(())
 }
+
+/// Cursor travel (per axis) below which the mouse still counts as resting.
+const ACTIVITY_TOLERANCE_PX: i32 = 2;
+
+/// Remembers when the user last moved the mouse, based on periodic cursor samples.
+#[derive(Debug, Clone)]
+pub struct ActivityTracker {
+    last_position: Option<(i32, i32)>,
+    last_change: Instant,
+}
+
+impl ActivityTracker {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            last_position: None,
+            last_change: now,
+        }
+    }
+
+    pub fn observe(&mut self, position: (i32, i32), now: Instant) {
+        let moved = self.last_position.is_some_and(|(x, y)| {
+            (position.0 - x).abs() > ACTIVITY_TOLERANCE_PX
+                || (position.1 - y).abs() > ACTIVITY_TOLERANCE_PX
+        });
+        if moved {
+            self.last_change = now;
+        }
+        if moved || self.last_position.is_none() {
+            self.last_position = Some(position);
+        }
+    }
+
+    /// Records a cursor position produced by our own input so it is not mistaken for the user.
+    pub fn reset_to(&mut self, position: (i32, i32)) {
+        self.last_position = Some(position);
+    }
+
+    pub fn still_for(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.last_change)
+    }
+}
+
+/// One intermediate cursor position and how long to wait after moving there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathPoint {
+    pub x: i32,
+    pub y: i32,
+    pub delay: Duration,
+}
+
+/// Builds a cursor path from `from` to `to` along a randomly bowed quadratic curve with
+/// ease-in/ease-out timing, a small overshoot past the target, and a short settle back onto it.
+/// The last point is always exactly `to`.
+pub fn humanized_path(
+    from: (i32, i32),
+    to: (i32, i32),
+    settings: &HumanizeSettings,
+    rng: &mut impl Rng,
+) -> Vec<PathPoint> {
+    let (fx, fy) = (from.0 as f32, from.1 as f32);
+    let (tx, ty) = (to.0 as f32, to.1 as f32);
+    let (dx, dy) = (tx - fx, ty - fy);
+    let distance = dx.hypot(dy);
+    if distance < 1.0 {
+        return vec![PathPoint {
+            x: to.0,
+            y: to.1,
+            delay: Duration::ZERO,
+        }];
+    }
+    let (ux, uy) = (dx / distance, dy / distance);
+
+    let overshoot = settings.overshoot_px.max(0.0) * rng.gen_range(0.4..=1.0);
+    let (ex, ey) = (tx + ux * overshoot, ty + uy * overshoot);
+    let bow = distance * settings.curvature.max(0.0) * rng.gen_range(-1.0..=1.0);
+    let (cx, cy) = ((fx + ex) / 2.0 - uy * bow, (fy + ey) / 2.0 + ux * bow);
+
+    let steps = (settings.duration_ms / HUMANIZE_STEP.as_millis() as u64).clamp(5, 200) as u32;
+    let base_delay = Duration::from_millis(settings.duration_ms) / (steps + SETTLE_STEPS);
+    let jittered = |rng: &mut dyn rand::RngCore| base_delay.mul_f32(rng.gen_range(0.7..=1.3));
+
+    let mut path = Vec::with_capacity((steps + SETTLE_STEPS) as usize);
+    for i in 1..=steps {
+        let t = i as f32 / steps as f32;
+        let eased = t * t * (3.0 - 2.0 * t);
+        let inv = 1.0 - eased;
+        let x = inv * inv * fx + 2.0 * inv * eased * cx + eased * eased * ex;
+        let y = inv * inv * fy + 2.0 * inv * eased * cy + eased * eased * ey;
+        path.push(PathPoint {
+            x: x.round() as i32,
+            y: y.round() as i32,
+            delay: jittered(rng),
+        });
+    }
+    for i in 1..=SETTLE_STEPS {
+        let t = i as f32 / SETTLE_STEPS as f32;
+        path.push(PathPoint {
+            x: (ex + (tx - ex) * t).round() as i32,
+            y: (ey + (ty - ey) * t).round() as i32,
+            delay: jittered(rng),
+        });
+    }
+    if let Some(last) = path.last_mut() {
+        last.x = to.0;
+        last.y = to.1;
+    }
+    path
+}
+
+#[cfg(windows)]
+mod win32 {
+    use std::mem;
+
+    use anyhow::{Result, bail};
+    use windows_sys::Win32::{
+        Foundation::POINT,
+        UI::{
+            Input::KeyboardAndMouse::{
+                INPUT, INPUT_0, INPUT_KEYBOARD, INPUT_MOUSE, KEYBDINPUT, KEYEVENTF_KEYUP,
+                KEYEVENTF_UNICODE, MOUSEEVENTF_ABSOLUTE, MOUSEEVENTF_LEFTDOWN, MOUSEEVENTF_LEFTUP,
+                MOUSEEVENTF_MOVE, MOUSEEVENTF_VIRTUALDESK, MOUSEINPUT, SendInput, VIRTUAL_KEY,
+                VK_ESCAPE, VK_RETURN, VK_SPACE, VK_TAB,
+            },
+            WindowsAndMessaging::{
+                GetCursorPos, GetSystemMetrics, SM_CXVIRTUALSCREEN, SM_CYVIRTUALSCREEN,
+                SM_XVIRTUALSCREEN, SM_YVIRTUALSCREEN,
+            },
+        },
+    };
+
+    use super::{InputBackend, InputKey};
+
+    /// Raw `SendInput` events, which some clients accept when enigo's are dropped.
+    pub struct SendInputBackend;
+
+    impl SendInputBackend {
+        fn send(&self, input: INPUT) -> Result<()> {
+            // SAFETY: one initialized `INPUT`, with its size passed as `cbSize`.
+            let sent = unsafe { SendInput(1, &input, mem::size_of::<INPUT>() as i32) };
+            if sent != 1 {
+                bail!("SendInput was blocked: {}", std::io::Error::last_os_error());
+            }
+            Ok(())
+        }
+
+        fn mouse(&self, flags: u32, dx: i32, dy: i32) -> Result<()> {
+            self.send(INPUT {
+                r#type: INPUT_MOUSE,
+                Anonymous: INPUT_0 {
+                    mi: MOUSEINPUT {
+                        dx,
+                        dy,
+                        mouseData: 0,
+                        dwFlags: flags,
+                        time: 0,
+                        dwExtraInfo: 0,
+                    },
+                },
+            })
+        }
+    }
+
+    impl InputBackend for SendInputBackend {
+        fn name(&self) -> &str {
+            "send_input"
+        }
+
+        fn cursor_position(&mut self) -> Result<(i32, i32)> {
+            let mut point = POINT { x: 0, y: 0 };
+            // SAFETY: `point` is a valid, writable `POINT` for the duration of the call.
+            if unsafe { GetCursorPos(&mut point) } == 0 {
+                bail!("GetCursorPos failed: {}", std::io::Error::last_os_error());
+            }
+            Ok((point.x, point.y))
+        }
+
+        fn move_to(&mut self, x: i32, y: i32) -> Result<()> {
+            // Absolute coordinates are normalized to 0..=65535 across the virtual desktop.
+            // SAFETY: `GetSystemMetrics` takes no pointers and has no preconditions.
+            let (left, top, width, height) = unsafe {
+                (
+                    GetSystemMetrics(SM_XVIRTUALSCREEN),
+                    GetSystemMetrics(SM_YVIRTUALSCREEN),
+                    GetSystemMetrics(SM_CXVIRTUALSCREEN).max(2),
+                    GetSystemMetrics(SM_CYVIRTUALSCREEN).max(2),
+                )
+            };
+            let dx = ((x - left) as i64 * 65_535 / (width - 1) as i64) as i32;
+            let dy = ((y - top) as i64 * 65_535 / (height - 1) as i64) as i32;
+            self.mouse(
+                MOUSEEVENTF_MOVE | MOUSEEVENTF_ABSOLUTE | MOUSEEVENTF_VIRTUALDESK,
+                dx,
+                dy,
+            )
+        }
+
+        fn mouse_button(&mut self, pressed: bool) -> Result<()> {
+            let flags = if pressed {
+                MOUSEEVENTF_LEFTDOWN
+            } else {
+                MOUSEEVENTF_LEFTUP
+            };
+            self.mouse(flags, 0, 0)
+        }
+
+        fn key(&mut self, key: InputKey, pressed: bool) -> Result<()> {
+            let (vk, scan, mut flags): (VIRTUAL_KEY, u16, u32) = match key {
+                InputKey::Enter => (VK_RETURN, 0, 0),
+                InputKey::Space => (VK_SPACE, 0, 0),
+                InputKey::Tab => (VK_TAB, 0, 0),
+                InputKey::Escape => (VK_ESCAPE, 0, 0),
+                InputKey::Char(ch) => {
+                    let mut units = [0u16; 2];
+                    if ch.encode_utf16(&mut units).len() != 1 {
+                        bail!("Character {ch:?} cannot be sent as a single key");
+                    }
+                    (0, units[0], KEYEVENTF_UNICODE)
+                }
+            };
+            if !pressed {
+                flags |= KEYEVENTF_KEYUP;
+            }
+            self.send(INPUT {
+                r#type: INPUT_KEYBOARD,
+                Anonymous: INPUT_0 {
+                    ki: KEYBDINPUT {
+                        wVk: vk,
+                        wScan: scan,
+                        dwFlags: flags,
+                        time: 0,
+                        dwExtraInfo: 0,
+                    },
+                },
+            })
+        }
+    }
+}
+
+/// Input through the Interception kernel driver, whose events are indistinguishable from real
+/// hardware to other processes. `interception.dll` is loaded at runtime so the feature builds
+/// without the driver SDK.
+#[cfg(all(windows, feature = "interception"))]
+mod interception {
+    use std::{ffi::c_void, mem};
+
+    use anyhow::{Context, Result, bail};
+    use windows_sys::{
+        Win32::{
+            Foundation::{FreeLibrary, HMODULE},
+            System::LibraryLoader::{GetProcAddress, LoadLibraryW},
+            UI::{
+                Input::KeyboardAndMouse::{MAPVK_VK_TO_VSC, MapVirtualKeyW, VkKeyScanW},
+                WindowsAndMessaging::{
+                    GetCursorPos, GetSystemMetrics, SM_CXVIRTUALSCREEN, SM_CYVIRTUALSCREEN,
+                    SM_XVIRTUALSCREEN, SM_YVIRTUALSCREEN,
+                },
+            },
+        },
+        core::PCSTR,
+    };
+
+    use super::{InputBackend, InputKey};
+
+    type Handle = *mut c_void;
+    type Device = i32;
+
+    const FIRST_KEYBOARD: Device = 1;
+    const FIRST_MOUSE: Device = 11;
+    const DEVICE_SLOTS: Device = 10;
+
+    const MOUSE_LEFT_BUTTON_DOWN: u16 = 0x001;
+    const MOUSE_LEFT_BUTTON_UP: u16 = 0x002;
+    const MOUSE_MOVE_ABSOLUTE: u16 = 0x001;
+    const MOUSE_VIRTUAL_DESKTOP: u16 = 0x002;
+    const KEY_UP: u16 = 0x01;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct MouseStroke {
+        state: u16,
+        flags: u16,
+        rolling: i16,
+        x: i32,
+        y: i32,
+        information: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct KeyStroke {
+        code: u16,
+        state: u16,
+        information: u32,
+    }
+
+    // Prototypes of the interception.dll exports, as declared in interception.h.
+    type CreateContext = unsafe extern "C" fn() -> Handle;
+    type DestroyContext = unsafe extern "C" fn(Handle);
+    type Send = unsafe extern "C" fn(Handle, Device, *const c_void, u32) -> i32;
+    type GetHardwareId = unsafe extern "C" fn(Handle, Device, *mut c_void, u32) -> u32;
+
+    pub struct InterceptionBackend {
+        library: HMODULE,
+        context: Handle,
+        destroy: DestroyContext,
+        send: Send,
+        mouse: Device,
+        keyboard: Device,
+    }
+
+    fn symbol<T>(library: HMODULE, name: &'static [u8]) -> Result<T> {
+        // SAFETY: `name` is NUL-terminated, as `GetProcAddress` requires. Every caller names
+        // `T` as the `unsafe extern "C" fn` type matching that export's prototype in
+        // interception.h, so the transmute only restores the pointer's real signature; `T` is
+        // pointer-sized like the `FARPROC` it is copied from. The returned pointer stays valid
+        // while `library` is loaded, and the backend frees the library only after its last call.
+        unsafe {
+            let proc = GetProcAddress(library, name.as_ptr() as PCSTR).with_context(|| {
+                format!(
+                    "interception.dll lacks {}",
+                    String::from_utf8_lossy(&name[..name.len() - 1])
+                )
+            })?;
+            Ok(mem::transmute_copy(&proc))
+        }
+    }
+
+    impl InterceptionBackend {
+        pub fn open() -> Result<Self> {
+            let dll: Vec<u16> = "interception.dll".encode_utf16().chain(Some(0)).collect();
+            // SAFETY: `dll` is a NUL-terminated wide string that outlives the call.
+            let library = unsafe { LoadLibraryW(dll.as_ptr()) };
+            if library.is_null() {
+                bail!("interception.dll not found; install the Interception driver first");
+            }
+            let loaded = (|| {
+                let create: CreateContext = symbol(library, b"interception_create_context\0")?;
+                let destroy: DestroyContext = symbol(library, b"interception_destroy_context\0")?;
+                let send: Send = symbol(library, b"interception_send\0")?;
+                let hardware_id: GetHardwareId =
+                    symbol(library, b"interception_get_hardware_id\0")?;
+                // SAFETY: `create` has the prototype of `interception_create_context`, which takes
+                // no arguments and returns null when the driver is not running.
+                let context = unsafe { create() };
+                if context.is_null() {
+                    bail!("Interception driver is not running (reboot after installing it)");
+                }
+                let present = |device: Device| {
+                    let mut buffer = [0u16; 256];
+                    // SAFETY: `context` is the live, non-null context created above, and the
+                    // buffer is writable for the size passed, in bytes as the API expects.
+                    unsafe {
+                        hardware_id(
+                            context,
+                            device,
+                            buffer.as_mut_ptr().cast(),
+                            mem::size_of_val(&buffer) as u32,
+                        ) > 0
+                    }
+                };
+                let first = |start: Device| {
+                    (start..start + DEVICE_SLOTS)
+                        .find(|&device| present(device))
+                        .unwrap_or(start)
+                };
+                Ok(Self {
+                    library,
+                    context,
+                    destroy,
+                    send,
+                    mouse: first(FIRST_MOUSE),
+                    keyboard: first(FIRST_KEYBOARD),
+                })
+            })();
+            if loaded.is_err() {
+                // SAFETY: the library was loaded above, and on this path no context was kept and
+                // no function pointer from it outlives this call.
+                unsafe { FreeLibrary(library) };
+            }
+            loaded
+        }
+
+        fn send_mouse(&self, stroke: MouseStroke) -> Result<()> {
+            // SAFETY: `self.context` is live until `drop`, `self.mouse` is a mouse slot, and the
+            // pointer is to exactly one `MouseStroke`, the `#[repr(C)]` layout mouse devices read.
+            let sent = unsafe {
+                (self.send)(
+                    self.context,
+                    self.mouse,
+                    (&stroke as *const MouseStroke).cast(),
+                    1,
+                )
+            };
+            if sent != 1 {
+                bail!("Interception rejected the mouse event");
+            }
+            Ok(())
+        }
+
+        fn send_key(&self, stroke: KeyStroke) -> Result<()> {
+            // SAFETY: `self.context` is live until `drop`, `self.keyboard` is a keyboard slot, and
+            // the pointer is to exactly one `KeyStroke`, the `#[repr(C)]` layout keyboards read.
+            let sent = unsafe {
+                (self.send)(
+                    self.context,
+                    self.keyboard,
+                    (&stroke as *const KeyStroke).cast(),
+                    1,
+                )
+            };
+            if sent != 1 {
+                bail!("Interception rejected the key event");
+            }
+            Ok(())
+        }
+    }
+
+    impl Drop for InterceptionBackend {
+        fn drop(&mut self) {
+            // SAFETY: the context and library were created in `open` and are released once, the
+            // context first, since destroying it calls into the library.
+            unsafe {
+                (self.destroy)(self.context);
+                FreeLibrary(self.library);
+            }
+        }
+    }
+
+    impl InputBackend for InterceptionBackend {
+        fn name(&self) -> &str {
+            "interception"
+        }
+
+        fn cursor_position(&mut self) -> Result<(i32, i32)> {
+            let mut point = windows_sys::Win32::Foundation::POINT { x: 0, y: 0 };
+            // SAFETY: `point` is a valid, writable `POINT` for the duration of the call.
+            if unsafe { GetCursorPos(&mut point) } == 0 {
+                bail!("GetCursorPos failed: {}", std::io::Error::last_os_error());
+            }
+            Ok((point.x, point.y))
+        }
+
+        fn move_to(&mut self, x: i32, y: i32) -> Result<()> {
+            // SAFETY: `GetSystemMetrics` takes no pointers and has no preconditions.
+            let (left, top, width, height) = unsafe {
+                (
+                    GetSystemMetrics(SM_XVIRTUALSCREEN),
+                    GetSystemMetrics(SM_YVIRTUALSCREEN),
+                    GetSystemMetrics(SM_CXVIRTUALSCREEN).max(2),
+                    GetSystemMetrics(SM_CYVIRTUALSCREEN).max(2),
+                )
+            };
+            self.send_mouse(MouseStroke {
+                flags: MOUSE_MOVE_ABSOLUTE | MOUSE_VIRTUAL_DESKTOP,
+                x: ((x - left) as i64 * 65_535 / (width - 1) as i64) as i32,
+                y: ((y - top) as i64 * 65_535 / (height - 1) as i64) as i32,
+                ..MouseStroke::default()
+            })
+        }
+
+        fn mouse_button(&mut self, pressed: bool) -> Result<()> {
+            self.send_mouse(MouseStroke {
+                state: if pressed {
+                    MOUSE_LEFT_BUTTON_DOWN
+                } else {
+                    MOUSE_LEFT_BUTTON_UP
+                },
+                ..MouseStroke::default()
+            })
+        }
+
+        fn key(&mut self, key: InputKey, pressed: bool) -> Result<()> {
+            let code = match key {
+                InputKey::Enter => 0x1C,
+                InputKey::Space => 0x39,
+                InputKey::Tab => 0x0F,
+                InputKey::Escape => 0x01,
+                InputKey::Char(ch) => {
+                    let mut units = [0u16; 2];
+                    if ch.encode_utf16(&mut units).len() != 1 {
+                        bail!("Character {ch:?} cannot be sent as a single key");
+                    }
+                    // SAFETY: both calls take plain values and only read the active keyboard
+                    // layout.
+                    let scan = unsafe {
+                        let vk = VkKeyScanW(units[0]);
+                        if vk == -1 {
+                            bail!("Character {ch:?} is not on the active keyboard layout");
+                        }
+                        MapVirtualKeyW((vk & 0xFF) as u32, MAPVK_VK_TO_VSC)
+                    };
+                    scan as u16
+                }
+            };
+            self.send_key(KeyStroke {
+                code,
+                state: if pressed { 0 } else { KEY_UP },
+                information: 0,
+            })
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Locked settings for shared machines: with `kiosk.enabled` the window only offers Start and
+//! Stop until the PIN from the configuration file is entered.
+
+use std::time::{Duration, Instant};
+
+use crate::config::KioskSettings;
+
+/// Wrong PINs in a row before further attempts are refused for [`LOCKOUT`].
+pub const MAX_ATTEMPTS: u32 = 5;
+pub const LOCKOUT: Duration = Duration::from_secs(30);
+
+/// Outcome of an unlock attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnlockOutcome {
+    Unlocked,
+    WrongPin {
+        /// Attempts left before the lockout.
+        remaining: u32,
+    },
+    /// Too many wrong PINs; try again after `retry_in`.
+    LockedOut {
+        retry_in: Duration,
+    },
+    /// No PIN is configured, so the settings can only be unlocked by editing the file.
+    NoPin,
+}
+
+/// Whether the settings are currently unlocked, and the wrong-PIN count guarding them.
+#[derive(Debug, Clone, Default)]
+pub struct KioskLock {
+    unlocked: bool,
+    failures: u32,
+    locked_out_until: Option<Instant>,
+}
+
+impl KioskLock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the settings are hidden: kiosk mode is on and nobody unlocked them.
+    pub fn is_locked(&self, settings: &KioskSettings) -> bool {
+        settings.enabled && !self.unlocked
+    }
+
+    /// Checks `pin` against the configured one; surrounding whitespace is ignored.
+    pub fn try_unlock(
+        &mut self,
+        settings: &KioskSettings,
+        pin: &str,
+        now: Instant,
+    ) -> UnlockOutcome {
+        let expected = settings.pin.trim();
+        if expected.is_empty() {
+            return UnlockOutcome::NoPin;
+        }
+        if let Some(until) = self.locked_out_until {
+            if now < until {
+                return UnlockOutcome::LockedOut {
+                    retry_in: until - now,
+                };
+            }
+            self.locked_out_until = None;
+            self.failures = 0;
+        }
+        if pin.trim() == expected {
+            self.unlocked = true;
+            self.failures = 0;
+            return UnlockOutcome::Unlocked;
+        }
+        self.failures += 1;
+        if self.failures >= MAX_ATTEMPTS {
+            self.locked_out_until = Some(now + LOCKOUT);
+            return UnlockOutcome::LockedOut { retry_in: LOCKOUT };
+        }
+        UnlockOutcome::WrongPin {
+            remaining: MAX_ATTEMPTS - self.failures,
+        }
+    }
+
+    /// Hides the settings again.
+    pub fn lock(&mut self) {
+        self.unlocked = false;
+    }
+}
@@ -0,0 +1,67 @@
+//! Window position, size and open sections, remembered across launches in `layout.toml` next to
+//! the configuration so the window reappears where it was left.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{capture::MonitorInfo, config::APP_NAME};
+
+const LAYOUT_NAME: &str = "layout";
+
+/// How much of the window's top-left corner must still be on a display to restore the position.
+const VISIBLE_MARGIN: f32 = 40.0;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct WindowLayout {
+    /// Outer top-left corner in logical desktop points.
+    pub position: Option<[f32; 2]>,
+    /// Inner size of the full (not mini-mode) window in points.
+    pub size: Option<[f32; 2]>,
+    pub maximized: bool,
+    /// Whether each collapsible section is open, by section key.
+    pub sections: BTreeMap<String, bool>,
+}
+
+impl WindowLayout {
+    pub fn section_open(&self, key: &str, default: bool) -> bool {
+        self.sections.get(key).copied().unwrap_or(default)
+    }
+
+    pub fn set_section_open(&mut self, key: &str, open: bool) {
+        if self.sections.get(key) != Some(&open) {
+            self.sections.insert(key.to_string(), open);
+        }
+    }
+
+    /// The saved position when its top-left corner still lands on one of `monitors`, so a
+    /// window last seen on a disconnected display opens where the OS puts it instead.
+    pub fn visible_position(&self, monitors: &[MonitorInfo]) -> Option<[f32; 2]> {
+        let [x, y] = self.position?;
+        monitors
+            .iter()
+            .any(|monitor| {
+                let scale = monitor.scale_factor.max(0.1);
+                let left = monitor.origin_x as f32 / scale;
+                let top = monitor.origin_y as f32 / scale;
+                let right = left + monitor.width as f32 / scale;
+                let bottom = top + monitor.height as f32 / scale;
+                x + VISIBLE_MARGIN > left
+                    && x + VISIBLE_MARGIN < right
+                    && y >= top - VISIBLE_MARGIN
+                    && y + VISIBLE_MARGIN < bottom
+            })
+            .then_some([x, y])
+    }
+}
+
+/// The saved layout; empty on first run.
+pub fn load() -> Result<WindowLayout> {
+    confy::load(APP_NAME, LAYOUT_NAME).context("Failed to load window layout")
+}
+
+pub fn store(layout: &WindowLayout) -> Result<()> {
+    confy::store(APP_NAME, LAYOUT_NAME, layout).context("Failed to persist window layout")
+}
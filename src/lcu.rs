@@ -0,0 +1,372 @@
+//! League Client Update (LCU) REST API, authenticated with the credentials from the client's
+//! `lockfile`.
+
+use std::{
+    fs,
+    io::ErrorKind,
+    net::{SocketAddr, TcpStream},
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use anyhow::{Context, Result, anyhow, bail};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use crossbeam_channel::{Receiver, Sender};
+use serde_json::Value;
+use tracing::{debug, info};
+use tungstenite::{
+    Connector, Message, WebSocket, client::IntoClientRequest, http::HeaderValue,
+    stream::MaybeTlsStream,
+};
+
+pub const READY_CHECK_ACCEPT_PATH: &str = "/lol-matchmaking/v1/ready-check/accept";
+pub const READY_CHECK_URI: &str = "/lol-matchmaking/v1/ready-check";
+/// WAMP topic for ready-check updates on the client WebSocket.
+pub const READY_CHECK_EVENT: &str = "OnJsonApiEvent_lol-matchmaking_v1_ready-check";
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(3);
+/// Socket read timeout so the listener notices stop requests.
+const EVENT_POLL: Duration = Duration::from_millis(250);
+const RECONNECT_DELAY: Duration = Duration::from_secs(3);
+/// WAMP 1.0 opcodes used by the client.
+const WAMP_SUBSCRIBE: u64 = 5;
+const WAMP_EVENT: u64 = 8;
+
+/// Contents of the lockfile the client writes while it is running:
+/// `name:pid:port:password:protocol`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lockfile {
+    pub process: String,
+    pub pid: u32,
+    pub port: u16,
+    pub password: String,
+    pub protocol: String,
+}
+
+impl Lockfile {
+    pub fn parse(contents: &str) -> Result<Self> {
+        let fields: Vec<&str> = contents.trim().split(':').collect();
+        let [process, pid, port, password, protocol] = fields[..] else {
+            bail!("Lockfile has {} fields, expected 5", fields.len());
+        };
+        if password.is_empty() {
+            bail!("Lockfile has an empty password");
+        }
+        Ok(Self {
+            process: process.to_string(),
+            pid: pid.parse().context("Invalid pid in lockfile")?,
+            port: port.parse().context("Invalid port in lockfile")?,
+            password: password.to_string(),
+            protocol: protocol.to_string(),
+        })
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read lockfile {path:?}"))?;
+        Self::parse(&contents).with_context(|| format!("Malformed lockfile {path:?}"))
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("{}://127.0.0.1:{}", self.protocol, self.port)
+    }
+
+    pub fn websocket_url(&self) -> String {
+        let scheme = if self.protocol == "https" {
+            "wss"
+        } else {
+            "ws"
+        };
+        format!("{scheme}://127.0.0.1:{}/", self.port)
+    }
+
+    /// `Authorization` header value; the LCU user name is always `riot`.
+    pub fn authorization(&self) -> String {
+        format!(
+            "Basic {}",
+            STANDARD.encode(format!("riot:{}", self.password))
+        )
+    }
+}
+
+/// Standard install locations checked when no lockfile path is configured.
+pub fn default_lockfile_paths() -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    if cfg!(windows) {
+        paths.push(PathBuf::from(r"C:\Riot Games\League of Legends\lockfile"));
+    }
+    if cfg!(target_os = "macos") {
+        paths.push(PathBuf::from(
+            "/Applications/League of Legends.app/Contents/LoL/lockfile",
+        ));
+    }
+    paths
+}
+
+/// The configured lockfile, or the first default location that exists.
+pub fn locate_lockfile(configured: Option<&Path>) -> Result<PathBuf> {
+    if let Some(path) = configured {
+        return Ok(path.to_path_buf());
+    }
+    default_lockfile_paths()
+        .into_iter()
+        .find(|path| path.exists())
+        .ok_or_else(|| anyhow!("League client lockfile not found; is the client running?"))
+}
+
+/// The client serves a certificate signed by Riot's private CA and only listens on loopback,
+/// so certificate validation is skipped rather than bundling that root.
+fn loopback_tls() -> Result<native_tls::TlsConnector> {
+    native_tls::TlsConnector::builder()
+        .danger_accept_invalid_certs(true)
+        .danger_accept_invalid_hostnames(true)
+        .build()
+        .context("Failed to set up TLS for the League client")
+}
+
+/// Blocking HTTPS client for the local LCU API.
+pub struct LcuClient {
+    agent: ureq::Agent,
+    base_url: String,
+    authorization: String,
+}
+
+impl LcuClient {
+    pub fn new(lockfile: &Lockfile) -> Result<Self> {
+        let agent = ureq::AgentBuilder::new()
+            .tls_connector(Arc::new(loopback_tls()?))
+            .timeout(REQUEST_TIMEOUT)
+            .build();
+        Ok(Self {
+            agent,
+            base_url: lockfile.base_url(),
+            authorization: lockfile.authorization(),
+        })
+    }
+
+    /// Reads the lockfile (see [`locate_lockfile`]) and builds a client for that session.
+    pub fn connect(configured_lockfile: Option<&Path>) -> Result<Self> {
+        let path = locate_lockfile(configured_lockfile)?;
+        Self::new(&Lockfile::read(&path)?)
+    }
+
+    pub fn get(&self, path: &str) -> Result<Value> {
+        self.send("GET", path, None)?
+            .into_json()
+            .with_context(|| format!("GET {path} returned malformed JSON"))
+    }
+
+    pub fn post(&self, path: &str) -> Result<()> {
+        self.send("POST", path, None).map(drop)
+    }
+
+    pub fn post_json(&self, path: &str, body: &Value) -> Result<()> {
+        self.send("POST", path, Some(body)).map(drop)
+    }
+
+    pub fn put_json(&self, path: &str, body: &Value) -> Result<()> {
+        self.send("PUT", path, Some(body)).map(drop)
+    }
+
+    pub fn patch(&self, path: &str, body: &Value) -> Result<()> {
+        self.send("PATCH", path, Some(body)).map(drop)
+    }
+
+    fn send(&self, method: &str, path: &str, body: Option<&Value>) -> Result<ureq::Response> {
+        let request = self
+            .agent
+            .request(method, &format!("{}{path}", self.base_url))
+            .set("Authorization", &self.authorization);
+        let outcome = match body {
+            Some(body) => request.send_json(body),
+            None => request.call(),
+        };
+        match outcome {
+            Ok(response) => Ok(response),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                bail!("League client answered {status} for {method} {path}: {body}")
+            }
+            Err(err) => {
+                Err(err).with_context(|| format!("{method} {path} to the League client failed"))
+            }
+        }
+    }
+
+    pub fn accept_ready_check(&self) -> Result<()> {
+        self.post(READY_CHECK_ACCEPT_PATH)
+    }
+}
+
+/// A JSON API change pushed over the client WebSocket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiEvent {
+    pub uri: String,
+    /// `Create`, `Update` or `Delete`.
+    pub event_type: String,
+    pub data: Value,
+}
+
+/// Parses a WAMP event frame: `[8, "<topic>", {"uri", "eventType", "data"}]`.
+pub fn parse_event_message(text: &str) -> Option<ApiEvent> {
+    let frame: Value = serde_json::from_str(text).ok()?;
+    let frame = frame.as_array()?;
+    if frame.first()?.as_u64()? != WAMP_EVENT {
+        return None;
+    }
+    let payload = frame.get(2)?;
+    Some(ApiEvent {
+        uri: payload.get("uri")?.as_str()?.to_string(),
+        event_type: payload.get("eventType")?.as_str()?.to_string(),
+        data: payload.get("data").cloned().unwrap_or(Value::Null),
+    })
+}
+
+/// Whether a ready-check payload describes a pop we have not answered yet.
+pub fn ready_check_pending(data: &Value) -> bool {
+    data.get("state").and_then(Value::as_str) == Some("InProgress")
+        && data.get("playerResponse").and_then(Value::as_str) == Some("None")
+}
+
+/// What the event listener reports to its owner.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LcuMessage {
+    Connected,
+    /// The socket dropped or could not be opened; the listener keeps retrying.
+    Disconnected(String),
+    Event(ApiEvent),
+}
+
+/// Background thread holding a WebSocket to the client, reconnecting whenever the client
+/// restarts. Stops and joins on drop.
+pub struct LcuEventStream {
+    rx: Receiver<LcuMessage>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl LcuEventStream {
+    pub fn spawn(lockfile: Option<PathBuf>, topics: Vec<String>) -> Self {
+        let (tx, rx) = crossbeam_channel::unbounded();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        let handle = thread::Builder::new()
+            .name("lcu-events".to_string())
+            .spawn(move || listen(lockfile, &topics, &tx, &thread_stop))
+            .ok();
+        Self { rx, stop, handle }
+    }
+
+    pub fn receiver(&self) -> &Receiver<LcuMessage> {
+        &self.rx
+    }
+}
+
+impl Drop for LcuEventStream {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+type Socket = WebSocket<MaybeTlsStream<TcpStream>>;
+
+fn listen(
+    lockfile: Option<PathBuf>,
+    topics: &[String],
+    tx: &Sender<LcuMessage>,
+    stop: &AtomicBool,
+) {
+    let mut reported_down = false;
+    while !stop.load(Ordering::Relaxed) {
+        let outcome = open_socket(lockfile.as_deref(), topics).and_then(|mut socket| {
+            info!("connected to League client events");
+            reported_down = false;
+            if tx.send(LcuMessage::Connected).is_err() {
+                return Ok(());
+            }
+            pump(&mut socket, tx, stop)
+        });
+        if let Err(err) = outcome {
+            debug!(error = ?err, "League client events unavailable");
+            if !reported_down {
+                reported_down = true;
+                if tx
+                    .send(LcuMessage::Disconnected(format!("{err:#}")))
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        }
+        let mut waited = Duration::ZERO;
+        while waited < RECONNECT_DELAY && !stop.load(Ordering::Relaxed) {
+            thread::sleep(EVENT_POLL);
+            waited += EVENT_POLL;
+        }
+    }
+}
+
+fn open_socket(lockfile: Option<&Path>, topics: &[String]) -> Result<Socket> {
+    let lockfile = Lockfile::read(&locate_lockfile(lockfile)?)?;
+    let mut request = lockfile
+        .websocket_url()
+        .into_client_request()
+        .context("Invalid client WebSocket URL")?;
+    request.headers_mut().insert(
+        "Authorization",
+        HeaderValue::from_str(&lockfile.authorization())?,
+    );
+    let address = SocketAddr::from(([127, 0, 0, 1], lockfile.port));
+    let stream = TcpStream::connect_timeout(&address, REQUEST_TIMEOUT)
+        .context("League client WebSocket refused the connection")?;
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+    let handle = stream.try_clone()?;
+    let connector = if lockfile.protocol == "https" {
+        Connector::NativeTls(loopback_tls()?)
+    } else {
+        Connector::Plain
+    };
+    let (mut socket, _) =
+        tungstenite::client_tls_with_config(request, stream, None, Some(connector))
+            .map_err(|err| anyhow!("League client WebSocket handshake failed: {err}"))?;
+    // Short reads from here on so the listener can notice stop requests.
+    handle.set_read_timeout(Some(EVENT_POLL))?;
+    for topic in topics {
+        socket
+            .send(Message::text(format!(
+                "[{WAMP_SUBSCRIBE}, {}]",
+                Value::from(topic.as_str())
+            )))
+            .with_context(|| format!("Failed to subscribe to {topic}"))?;
+    }
+    Ok(socket)
+}
+
+fn pump(socket: &mut Socket, tx: &Sender<LcuMessage>, stop: &AtomicBool) -> Result<()> {
+    while !stop.load(Ordering::Relaxed) {
+        match socket.read() {
+            Ok(Message::Text(text)) => {
+                if let Some(event) = parse_event_message(&text)
+                    && tx.send(LcuMessage::Event(event)).is_err()
+                {
+                    return Ok(());
+                }
+            }
+            Ok(Message::Close(_)) => bail!("League client closed the WebSocket"),
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(err))
+                if matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+            Err(err) => return Err(err).context("League client WebSocket failed"),
+        }
+    }
+    let _ = socket.close(None);
+    Ok(())
+}
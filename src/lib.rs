@@ -1,6 +1,53 @@
+pub mod actions;
 pub mod app;
+pub mod autostart;
+pub mod autostop;
+pub mod backup;
 pub mod capture;
+pub mod champ_select;
+pub mod cli;
 pub mod config;
+pub mod counters;
+pub mod crash;
 pub mod detect;
+pub mod elevation;
+pub mod gameflow;
+pub mod headless;
+pub mod history;
+pub mod honor;
+pub mod hotkey;
+pub mod hotplug;
+pub mod i18n;
+pub mod idle;
+pub mod indicator;
 pub mod input;
+pub mod kiosk;
+pub mod layout;
+pub mod lcu;
 pub mod logpipe;
+pub mod metrics;
+pub mod near_miss;
+pub mod notifications;
+pub mod overlay;
+pub mod power;
+pub mod presets;
+pub mod probe;
+pub mod process;
+pub mod reload;
+pub mod runes;
+pub mod scales;
+pub mod schedule;
+pub mod scores;
+pub mod sound;
+pub mod speech;
+pub mod spells;
+pub mod stats;
+pub mod summary;
+pub mod templates;
+pub mod theme;
+pub mod toast;
+pub mod tray;
+pub mod tuning;
+pub mod update;
+pub mod window;
+pub mod worker;
@@ -1,33 +1,140 @@
-use std::io::{Result as IoResult, Write};
+use std::{
+    fs::File,
+    io::{Result as IoResult, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use crossbeam_channel::{Receiver, Sender, unbounded};
+use tracing::{Level, Metadata};
 use tracing_subscriber::{EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt};
 
-pub fn init_logging() -> Result<Receiver<String>> {
+/// A formatted log line for the GUI, with the level and module it was logged at for filtering.
+#[derive(Debug, Clone)]
+pub struct LogLine {
+    pub level: Level,
+    /// Last path segment of the tracing target, e.g. `worker` for `lol_auto_accept_rs::worker`.
+    pub module: String,
+    pub text: String,
+}
+
+impl LogLine {
+    pub fn new(level: Level, target: &str, text: impl Into<String>) -> Self {
+        Self {
+            level,
+            module: target.rsplit("::").next().unwrap_or(target).to_string(),
+            text: text.into(),
+        }
+    }
+}
+
+/// What the Logs panel shows: lines at `level` or more severe, from `module` if set, containing
+/// `query` (case-insensitive) if not empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    pub query: String,
+    pub level: Level,
+    pub module: Option<String>,
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self {
+            query: String::new(),
+            level: Level::DEBUG,
+            module: None,
+        }
+    }
+}
+
+impl LogFilter {
+    /// Levels offered by the filter, most severe first.
+    pub const LEVELS: [Level; 4] = [Level::ERROR, Level::WARN, Level::INFO, Level::DEBUG];
+
+    pub fn matches(&self, line: &LogLine) -> bool {
+        line.level <= self.level
+            && self
+                .module
+                .as_ref()
+                .is_none_or(|module| *module == line.module)
+            && (self.query.is_empty()
+                || line
+                    .text
+                    .to_lowercase()
+                    .contains(&self.query.to_lowercase()))
+    }
+}
+
+/// Where every line of the current session is written, next to the configuration file.
+pub fn session_log_path() -> Result<PathBuf> {
+    Ok(crate::config::config_path()?.with_file_name("session.log"))
+}
+
+pub fn init_logging() -> Result<Receiver<LogLine>> {
     let (tx, rx) = unbounded();
+    init_subscriber(Some(tx))?;
+    Ok(rx)
+}
+
+/// Logging for headless mode: stderr and the session log, without the GUI panel feed.
+pub fn init_console_logging() -> Result<()> {
+    init_subscriber(None)
+}
+
+fn init_subscriber(gui: Option<Sender<LogLine>>) -> Result<()> {
     let env_filter =
         EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info,tracing=warn"));
 
-    let gui_layer = fmt::layer()
-        .with_ansi(false)
-        .with_writer(GuiMakeWriter { sender: tx.clone() })
-        .with_target(false);
+    let gui_layer = gui.map(|sender| {
+        fmt::layer()
+            .with_ansi(false)
+            .with_writer(GuiMakeWriter { sender })
+            .with_target(false)
+    });
 
     let stdout_layer = fmt::layer().with_writer(std::io::stderr).with_target(false);
 
+    // Best effort: without a writable config directory the session just isn't kept on disk.
+    let session_file = session_log_path().ok().and_then(|path| {
+        std::fs::create_dir_all(path.parent()?).ok()?;
+        File::create(path).ok()
+    });
+    let session_layer = session_file.map(|file| {
+        fmt::layer()
+            .with_ansi(false)
+            .with_writer(Arc::new(file))
+            .with_target(true)
+    });
+
     tracing_subscriber::registry()
         .with(env_filter)
         .with(stdout_layer)
         .with(gui_layer)
+        .with(session_layer)
         .try_init()?;
 
-    Ok(rx)
+    Ok(())
+}
+
+/// Writes `lines` to `path`, followed by the whole session log from `session_log` if given, for
+/// attaching to bug reports.
+pub fn export(path: &Path, lines: &[&str], session_log: Option<&Path>) -> Result<()> {
+    let mut text = lines.join("\n");
+    text.push('\n');
+    if let Some(session_log) = session_log {
+        let session = std::fs::read_to_string(session_log)
+            .with_context(|| format!("Failed to read session log {}", session_log.display()))?;
+        text.push_str("\n==== Full session log ====\n");
+        text.push_str(&session);
+    }
+    std::fs::write(path, text)
+        .with_context(|| format!("Failed to write logs to {}", path.display()))
 }
 
 #[derive(Clone)]
 struct GuiMakeWriter {
-    sender: Sender<String>,
+    sender: Sender<LogLine>,
 }
 
 impl<'a> fmt::MakeWriter<'a> for GuiMakeWriter {
@@ -36,13 +143,26 @@ impl<'a> fmt::MakeWriter<'a> for GuiMakeWriter {
     fn make_writer(&'a self) -> Self::Writer {
         GuiWriter {
             sender: self.sender.clone(),
+            level: Level::INFO,
+            target: String::new(),
+            buffer: Vec::new(),
+        }
+    }
+
+    fn make_writer_for(&'a self, meta: &Metadata<'_>) -> Self::Writer {
+        GuiWriter {
+            sender: self.sender.clone(),
+            level: *meta.level(),
+            target: meta.target().to_string(),
             buffer: Vec::new(),
         }
     }
 }
 
 struct GuiWriter {
-    sender: Sender<String>,
+    sender: Sender<LogLine>,
+    level: Level,
+    target: String,
     buffer: Vec<u8>,
 }
 
@@ -59,7 +179,9 @@ impl Write for GuiWriter {
 
         let msg = String::from_utf8_lossy(&self.buffer).trim().to_string();
         if !msg.is_empty() {
-            let _ = self.sender.send(msg);
+            let _ = self
+                .sender
+                .send(LogLine::new(self.level, &self.target, msg));
         }
         self.buffer.clear();
         Ok(())
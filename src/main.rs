@@ -1,12 +1,42 @@
 use anyhow::{Result, anyhow};
-use lol_auto_accept_rs::{app, config, logpipe};
+use clap::Parser;
+use lol_auto_accept_rs::{app, capture, cli::Cli, config, crash, headless, layout, logpipe};
+use tracing::warn;
 
 fn main() -> Result<()> {
+    let cli = Cli::parse();
+    if cli.headless {
+        logpipe::init_console_logging()?;
+        crash::install_panic_hook();
+        let mut config = config::load_or_default()?;
+        cli.apply(&mut config)?;
+        return headless::run(config);
+    }
+
     let log_rx = logpipe::init_logging()?;
+    crash::install_panic_hook();
+    // Loading writes the defaults, so check for a first run before that.
+    let first_run = !config::config_path().is_ok_and(|path| path.exists());
+    // The app applies the overrides itself and never saves them; checked here so a bad one
+    // fails before the window opens.
     let initial_config = config::load_or_default()?;
+    cli.applied(&initial_config)?;
+
+    let layout = layout::load().unwrap_or_else(|err| {
+        warn!(error = ?err, "failed to load window layout");
+        Default::default()
+    });
 
+    let mut viewport = eframe::egui::ViewportBuilder::default()
+        .with_inner_size(layout.size.unwrap_or(app::WINDOW_SIZE))
+        .with_maximized(layout.maximized);
+    if let Some(position) =
+        layout.visible_position(&capture::enumerate_monitors().unwrap_or_default())
+    {
+        viewport = viewport.with_position(position);
+    }
     let native_options = eframe::NativeOptions {
-        viewport: eframe::egui::ViewportBuilder::default().with_inner_size([520.0, 720.0]),
+        viewport,
         ..Default::default()
     };
 
@@ -17,7 +47,10 @@ fn main() -> Result<()> {
             Ok(Box::new(app::LolAutoAcceptApp::new(
                 cc,
                 initial_config.clone(),
+                cli.clone(),
                 log_rx.clone(),
+                first_run,
+                layout.clone(),
             )))
         }),
     )
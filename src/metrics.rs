@@ -0,0 +1,140 @@
+use std::time::{Duration, Instant};
+
+use crate::capture::ArenaStats;
+
+/// Periodic snapshot of worker performance, sent roughly once per second.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WorkerMetrics {
+    pub arena: ArenaStats,
+    /// Frames processed in the window.
+    pub frames: u32,
+    /// Frames captured per second over the last window.
+    pub capture_fps: f32,
+    /// Mean time spent inside the capture backend.
+    pub avg_capture_ms: f32,
+    /// Mean time spent template matching.
+    pub avg_match_ms: f32,
+    /// Mean time from the start of a capture until the detection result is known.
+    pub avg_latency_ms: f32,
+    /// Resident memory of the whole process, where the platform reports it.
+    pub resident_bytes: Option<u64>,
+}
+
+/// Collects per-frame timings between two metrics events.
+pub struct MetricsAccumulator {
+    window_start: Instant,
+    interval: Duration,
+    frames: u32,
+    capture_total: Duration,
+    match_total: Duration,
+    latency_total: Duration,
+}
+
+impl MetricsAccumulator {
+    pub fn new(interval: Duration, now: Instant) -> Self {
+        Self {
+            window_start: now,
+            interval,
+            frames: 0,
+            capture_total: Duration::ZERO,
+            match_total: Duration::ZERO,
+            latency_total: Duration::ZERO,
+        }
+    }
+
+    pub fn record_frame(&mut self, capture: Duration, matching: Duration, latency: Duration) {
+        self.frames += 1;
+        self.capture_total += capture;
+        self.match_total += matching;
+        self.latency_total += latency;
+    }
+
+    pub fn is_due(&self, now: Instant) -> bool {
+        now.duration_since(self.window_start) >= self.interval
+    }
+
+    /// Summarizes the current window and starts a new one.
+    pub fn take(
+        &mut self,
+        now: Instant,
+        arena: ArenaStats,
+        resident_bytes: Option<u64>,
+    ) -> WorkerMetrics {
+        let elapsed = now.duration_since(self.window_start).as_secs_f32();
+        let per_frame = |total: Duration| {
+            if self.frames == 0 {
+                0.0
+            } else {
+                total.as_secs_f32() * 1000.0 / self.frames as f32
+            }
+        };
+        let metrics = WorkerMetrics {
+            arena,
+            frames: self.frames,
+            capture_fps: if elapsed > 0.0 {
+                self.frames as f32 / elapsed
+            } else {
+                0.0
+            },
+            avg_capture_ms: per_frame(self.capture_total),
+            avg_match_ms: per_frame(self.match_total),
+            avg_latency_ms: per_frame(self.latency_total),
+            resident_bytes,
+        };
+        *self = Self::new(self.interval, now);
+        metrics
+    }
+}
+
+/// Resident memory (working set) of this process, or `None` when this platform cannot tell.
+pub fn resident_memory() -> Option<u64> {
+    platform::resident_memory()
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::mem;
+
+    use windows_sys::Win32::System::{
+        ProcessStatus::{K32GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS},
+        Threading::GetCurrentProcess,
+    };
+
+    pub fn resident_memory() -> Option<u64> {
+        // SAFETY: all-zero is a valid PROCESS_MEMORY_COUNTERS; `cb` tells the call its size.
+        let mut counters: PROCESS_MEMORY_COUNTERS = unsafe { mem::zeroed() };
+        counters.cb = mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32;
+        // SAFETY: the pseudo handle of the current process and a writable, sized struct.
+        let ok =
+            unsafe { K32GetProcessMemoryInfo(GetCurrentProcess(), &mut counters, counters.cb) };
+        (ok != 0).then_some(counters.WorkingSetSize as u64)
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    pub fn resident_memory() -> Option<u64> {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        super::parse_vm_rss(&status)
+    }
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+mod platform {
+    pub fn resident_memory() -> Option<u64> {
+        None
+    }
+}
+
+/// Bytes from the `VmRSS:` line of a Linux `/proc/<pid>/status` file.
+pub fn parse_vm_rss(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kib: u64 = line
+        .trim_start_matches("VmRSS:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kib * 1024)
+}
@@ -0,0 +1,58 @@
+//! Warns when the best score keeps landing just under the threshold, so a threshold that is too
+//! strict shows up before a ready check is actually missed.
+
+use crate::config::NearMissSettings;
+
+/// A run of near-miss frames long enough to warn about.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PossibleMiss {
+    /// Best score of the run.
+    pub score: f32,
+    /// Threshold the scores fell short of.
+    pub threshold: f32,
+    pub frames: u32,
+}
+
+/// Consecutive frames whose best score fell within `margin` below the threshold.
+#[derive(Debug, Clone, Default)]
+pub struct NearMissWatch {
+    streak: u32,
+    best: f32,
+    /// The current run was already reported.
+    warned: bool,
+}
+
+impl NearMissWatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds the best `score` of a frame and the `threshold` it applies to (`None` when nothing
+    /// could be matched). Returns the run once, when it reaches `settings.frames`; a frame outside
+    /// the band ends the run.
+    pub fn observe(
+        &mut self,
+        settings: &NearMissSettings,
+        score: Option<f32>,
+        threshold: f32,
+    ) -> Option<PossibleMiss> {
+        let near = score.filter(|&score| {
+            settings.enabled && score < threshold && score >= threshold - settings.margin
+        });
+        let Some(score) = near else {
+            *self = Self::default();
+            return None;
+        };
+        self.streak += 1;
+        self.best = self.best.max(score);
+        if self.warned || self.streak < settings.frames.max(1) {
+            return None;
+        }
+        self.warned = true;
+        Some(PossibleMiss {
+            score: self.best,
+            threshold,
+            frames: self.streak,
+        })
+    }
+}
@@ -0,0 +1,400 @@
+//! Notifications for when nobody is watching the window: an OS toast, a Discord webhook message
+//! and a Telegram bot message.
+
+use std::{io::Cursor, thread, time::Duration};
+
+use anyhow::{Context, Result, bail};
+use chrono::{Local, NaiveTime};
+use crossbeam_channel::Receiver;
+use image::{GrayImage, ImageFormat};
+use serde_json::{Value, json};
+use tracing::{info, warn};
+
+use crate::config::{DiscordSettings, NotificationSettings, TelegramSettings};
+
+const APP_NAME: &str = "LoL Auto Accept";
+const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+const MULTIPART_BOUNDARY: &str = "lol-auto-accept-notification";
+pub const TELEGRAM_API: &str = "https://api.telegram.org";
+
+/// Why a notice was raised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoticeKind {
+    /// The accept button showed up on screen.
+    Found,
+    Accepted,
+    /// The worker hit an error the user has to deal with.
+    Error,
+    /// Monitoring is about to stop, or stopped, because nothing was detected for a long time.
+    AutoStop,
+    /// Scores kept landing just under the threshold; a ready check may be going unnoticed.
+    PossibleMiss,
+}
+
+/// What gets sent to every target that wants its kind.
+#[derive(Debug, Clone)]
+pub struct Notice {
+    pub kind: NoticeKind,
+    pub message: String,
+    /// The frame the accept button was found in, for targets that can attach images.
+    pub screenshot: Option<GrayImage>,
+}
+
+/// A place notices are delivered to.
+pub trait NotificationTarget: Send {
+    fn name(&self) -> &'static str;
+    fn send(&self, notice: &Notice) -> Result<()>;
+
+    /// Whether notices of `kind` go to this target; accepts only unless overridden.
+    fn wants(&self, kind: NoticeKind) -> bool {
+        kind == NoticeKind::Accepted
+    }
+}
+
+/// OS toast (notification center on Windows, D-Bus elsewhere).
+pub struct Desktop;
+
+impl NotificationTarget for Desktop {
+    fn name(&self) -> &'static str {
+        "desktop"
+    }
+
+    fn wants(&self, kind: NoticeKind) -> bool {
+        matches!(
+            kind,
+            NoticeKind::Accepted | NoticeKind::AutoStop | NoticeKind::PossibleMiss
+        )
+    }
+
+    fn send(&self, notice: &Notice) -> Result<()> {
+        notify_rust::Notification::new()
+            .appname(APP_NAME)
+            .summary(APP_NAME)
+            .body(&notice.message)
+            .show()
+            .map(drop)
+            .context("notification service rejected the toast")
+    }
+}
+
+/// Discord channel webhook, optionally attaching the grayscale detection frame as a PNG.
+pub struct DiscordWebhook {
+    pub url: String,
+    pub attach_screenshot: bool,
+}
+
+impl DiscordWebhook {
+    pub fn new(settings: &DiscordSettings) -> Self {
+        Self {
+            url: settings.webhook_url.trim().to_string(),
+            attach_screenshot: settings.attach_screenshot,
+        }
+    }
+}
+
+impl NotificationTarget for DiscordWebhook {
+    fn name(&self) -> &'static str {
+        "Discord"
+    }
+
+    fn send(&self, notice: &Notice) -> Result<()> {
+        if self.url.is_empty() {
+            bail!("no Discord webhook URL configured");
+        }
+        let payload = json!({ "username": APP_NAME, "content": notice.message });
+        let request = ureq::AgentBuilder::new()
+            .timeout(SEND_TIMEOUT)
+            .build()
+            .post(&self.url);
+        let outcome = match notice
+            .screenshot
+            .as_ref()
+            .filter(|_| self.attach_screenshot)
+        {
+            Some(screenshot) => request
+                .set(
+                    "Content-Type",
+                    &format!("multipart/form-data; boundary={MULTIPART_BOUNDARY}"),
+                )
+                .send_bytes(&multipart_body(&payload, &encode_png(screenshot)?)),
+            None => request.send_json(&payload),
+        };
+        match outcome {
+            Ok(_) => Ok(()),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                bail!("Discord answered {status}: {body}")
+            }
+            Err(ureq::Error::Transport(err)) => {
+                // The URL is the webhook's secret, so only the kind of failure is reported.
+                bail!("Discord webhook request failed: {}", err.kind())
+            }
+        }
+    }
+}
+
+/// Telegram bot chat, told about found matches, accepts and worker errors.
+pub struct TelegramBot {
+    /// Bot API root, [`TELEGRAM_API`] outside tests.
+    pub api_base: String,
+    pub bot_token: String,
+    pub chat_id: String,
+}
+
+impl TelegramBot {
+    pub fn new(settings: &TelegramSettings) -> Self {
+        Self {
+            api_base: TELEGRAM_API.to_string(),
+            bot_token: settings.bot_token.trim().to_string(),
+            chat_id: settings.chat_id.trim().to_string(),
+        }
+    }
+}
+
+impl NotificationTarget for TelegramBot {
+    fn name(&self) -> &'static str {
+        "Telegram"
+    }
+
+    fn send(&self, notice: &Notice) -> Result<()> {
+        if self.bot_token.is_empty() || self.chat_id.is_empty() {
+            bail!("Telegram needs both a bot token and a chat ID");
+        }
+        let outcome = ureq::AgentBuilder::new()
+            .timeout(SEND_TIMEOUT)
+            .build()
+            .post(&format!(
+                "{}/bot{}/sendMessage",
+                self.api_base, self.bot_token
+            ))
+            .send_json(json!({ "chat_id": self.chat_id, "text": notice.message }));
+        match outcome {
+            Ok(_) => Ok(()),
+            Err(ureq::Error::Status(status, response)) => {
+                // The token is part of the URL, so only the API's own description is reported.
+                let description = response
+                    .into_json::<Value>()
+                    .ok()
+                    .and_then(|body| body.get("description")?.as_str().map(str::to_string))
+                    .unwrap_or_default();
+                bail!("Telegram answered {status}: {description}")
+            }
+            Err(ureq::Error::Transport(err)) => {
+                bail!("Telegram request failed: {}", err.kind())
+            }
+        }
+    }
+
+    fn wants(&self, _kind: NoticeKind) -> bool {
+        true
+    }
+}
+
+/// Toast text for an accept at `time`; `score` is the template match, absent when the ready
+/// check was accepted through the client API.
+pub fn accept_message(time: NaiveTime, score: Option<f32>) -> String {
+    let time = time.format("%H:%M");
+    match score {
+        Some(score) => format!("Match accepted at {time}, score {score:.2}"),
+        None => format!("Match accepted at {time} through the client API"),
+    }
+}
+
+/// Text for the accept button showing up at `time` with match `score`.
+pub fn found_message(time: NaiveTime, score: f32) -> String {
+    format!("Match found at {}, score {score:.2}", time.format("%H:%M"))
+}
+
+/// Targets switched on in `settings`.
+pub fn targets(settings: &NotificationSettings) -> Vec<Box<dyn NotificationTarget>> {
+    let mut targets: Vec<Box<dyn NotificationTarget>> = Vec::new();
+    // A desktop toast would show up on stream.
+    if settings.desktop && !settings.redact {
+        targets.push(Box::new(Desktop));
+    }
+    if settings.discord.enabled {
+        targets.push(Box::new(DiscordWebhook::new(&settings.discord)));
+    }
+    if settings.telegram.enabled {
+        targets.push(Box::new(TelegramBot::new(&settings.telegram)));
+    }
+    targets
+}
+
+/// Reports an accept to every enabled target. `screenshot` is only copied when a target will
+/// attach it.
+pub fn notify_accepted(
+    settings: &NotificationSettings,
+    score: Option<f32>,
+    screenshot: Option<&GrayImage>,
+) {
+    let wants_screenshot = settings.discord.enabled && settings.discord.attach_screenshot;
+    notify(
+        settings,
+        Notice {
+            kind: NoticeKind::Accepted,
+            message: accept_message(Local::now().time(), score),
+            screenshot: screenshot.filter(|_| wants_screenshot).cloned(),
+        },
+    );
+}
+
+/// Reports the accept button showing up, before it is clicked.
+pub fn notify_found(settings: &NotificationSettings, score: f32) {
+    notify(
+        settings,
+        Notice {
+            kind: NoticeKind::Found,
+            message: found_message(Local::now().time(), score),
+            screenshot: None,
+        },
+    );
+}
+
+/// Reports a worker error.
+pub fn notify_error(settings: &NotificationSettings, message: &str) {
+    notify(
+        settings,
+        Notice {
+            kind: NoticeKind::Error,
+            message: format!("{APP_NAME} error: {message}"),
+            screenshot: None,
+        },
+    );
+}
+
+/// Idle auto-stop text: the pending stop in whole minutes, or the stop itself when `None`.
+pub fn auto_stop_message(remaining: Option<Duration>) -> String {
+    match remaining {
+        Some(remaining) => format!(
+            "Nothing detected for a while, monitoring stops in {} min",
+            remaining.as_secs().div_ceil(60)
+        ),
+        None => "Nothing detected for a while, monitoring stopped".to_string(),
+    }
+}
+
+/// Reports that monitoring stops in `remaining`, or stopped when `None`, for lack of detections.
+pub fn notify_auto_stop(settings: &NotificationSettings, remaining: Option<Duration>) {
+    notify(
+        settings,
+        Notice {
+            kind: NoticeKind::AutoStop,
+            message: auto_stop_message(remaining),
+            screenshot: None,
+        },
+    );
+}
+
+/// Text for `frames` frames in a row scoring just under `threshold`, the best at `score`.
+pub fn possible_miss_message(score: f32, threshold: f32, frames: u32) -> String {
+    format!(
+        "Possible missed queue pop: {frames} frames in a row scored up to {score:.3}, just under the threshold {threshold:.2}. Consider lowering it."
+    )
+}
+
+/// Reports scores stuck just under the threshold.
+pub fn notify_possible_miss(
+    settings: &NotificationSettings,
+    score: f32,
+    threshold: f32,
+    frames: u32,
+) {
+    notify(
+        settings,
+        Notice {
+            kind: NoticeKind::PossibleMiss,
+            message: possible_miss_message(score, threshold, frames),
+            screenshot: None,
+        },
+    );
+}
+
+/// `notice` with only a generic line per kind: no scores, times or screenshot.
+pub fn redacted(notice: Notice) -> Notice {
+    let message = match notice.kind {
+        NoticeKind::Found => "Match found",
+        NoticeKind::Accepted => "Match accepted",
+        NoticeKind::Error => "Something needs attention",
+        NoticeKind::AutoStop => "Monitoring is stopping",
+        NoticeKind::PossibleMiss => "A queue pop may have been missed",
+    };
+    Notice {
+        kind: notice.kind,
+        message: message.to_string(),
+        screenshot: None,
+    }
+}
+
+/// Sends `notice` to the enabled targets that want its kind, redacted when `settings` say so.
+pub fn notify(settings: &NotificationSettings, notice: Notice) {
+    let notice = if settings.redact {
+        redacted(notice)
+    } else {
+        notice
+    };
+    let targets: Vec<_> = targets(settings)
+        .into_iter()
+        .filter(|target| target.wants(notice.kind))
+        .collect();
+    if !targets.is_empty() {
+        dispatch(targets, notice);
+    }
+}
+
+/// Sends `notice` from a short-lived thread so slow services and network round trips never
+/// delay the worker; failures are only logged.
+pub fn dispatch(targets: Vec<Box<dyn NotificationTarget>>, notice: Notice) {
+    thread::spawn(move || {
+        for target in targets {
+            match target.send(&notice) {
+                Ok(()) => info!(target = target.name(), "notification sent"),
+                Err(err) => warn!(target = target.name(), error = ?err, "notification failed"),
+            }
+        }
+    });
+}
+
+/// Sends a test message to `target` in the background; the receiver yields the target name and
+/// outcome once it is done.
+pub fn send_test(target: Box<dyn NotificationTarget>) -> Receiver<(&'static str, Result<()>)> {
+    let (tx, rx) = crossbeam_channel::bounded(1);
+    thread::spawn(move || {
+        let notice = Notice {
+            kind: NoticeKind::Accepted,
+            message: format!("Test notification from {APP_NAME}"),
+            screenshot: None,
+        };
+        let _ = tx.send((target.name(), target.send(&notice)));
+    });
+    rx
+}
+
+/// `multipart/form-data` body (boundary [`MULTIPART_BOUNDARY`]) carrying the JSON payload and
+/// one PNG attachment, as Discord expects for webhook uploads.
+pub fn multipart_body(payload: &Value, png: &[u8]) -> Vec<u8> {
+    let mut body = Vec::with_capacity(png.len() + 512);
+    body.extend_from_slice(
+        format!(
+            "--{MULTIPART_BOUNDARY}\r\n\
+             Content-Disposition: form-data; name=\"payload_json\"\r\n\
+             Content-Type: application/json\r\n\r\n\
+             {payload}\r\n\
+             --{MULTIPART_BOUNDARY}\r\n\
+             Content-Disposition: form-data; name=\"files[0]\"; filename=\"detection_frame.png\"\r\n\
+             Content-Type: image/png\r\n\r\n"
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(png);
+    body.extend_from_slice(format!("\r\n--{MULTIPART_BOUNDARY}--\r\n").as_bytes());
+    body
+}
+
+fn encode_png(image: &GrayImage) -> Result<Vec<u8>> {
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .context("failed to encode screenshot")?;
+    Ok(png)
+}
@@ -0,0 +1,72 @@
+//! Transparent, click-through window drawn over the client for a moment after a detection, to
+//! confirm the right element was found.
+
+use std::time::Duration;
+
+use egui::{Align2, Color32, FontId, Pos2, Rect, Stroke, Vec2, ViewportClass, pos2, vec2};
+
+/// How long the overlay stays up after a detection.
+pub const OVERLAY_DURATION: Duration = Duration::from_secs(2);
+/// Room around the box for its stroke.
+const MARGIN: f32 = 6.0;
+/// Room above the box for the score.
+const LABEL_HEIGHT: f32 = 20.0;
+const BOX_COLOR: Color32 = Color32::from_rgb(60, 220, 90);
+
+/// Overlay window placement in desktop points for a match spanning `min`..`max` (logical desktop
+/// coordinates): the box plus a margin, with the score label above it.
+pub fn window_rect(min: (i32, i32), max: (i32, i32)) -> Rect {
+    let size = box_size(min, max);
+    Rect::from_min_size(
+        pos2(min.0 as f32 - MARGIN, min.1 as f32 - MARGIN - LABEL_HEIGHT),
+        size + vec2(2.0 * MARGIN, 2.0 * MARGIN + LABEL_HEIGHT),
+    )
+}
+
+fn box_size(min: (i32, i32), max: (i32, i32)) -> Vec2 {
+    vec2((max.0 - min.0).max(1) as f32, (max.1 - min.1).max(1) as f32)
+}
+
+/// Shows the overlay for a match spanning `min`..`max` with `score`. Call every frame while it
+/// should stay up; the window closes on the first frame it is not shown.
+pub fn show(ctx: &egui::Context, min: (i32, i32), max: (i32, i32), score: f32) {
+    let rect = window_rect(min, max);
+    let builder = egui::ViewportBuilder::default()
+        .with_title("Detection overlay")
+        .with_position(rect.min)
+        .with_inner_size(rect.size())
+        .with_decorations(false)
+        .with_transparent(true)
+        .with_mouse_passthrough(true)
+        .with_always_on_top()
+        .with_taskbar(false)
+        .with_resizable(false)
+        .with_active(false);
+    ctx.show_viewport_immediate(
+        egui::ViewportId::from_hash_of("detection_overlay"),
+        builder,
+        |ctx, class| {
+            // Without native multi-viewport support this would be drawn inside the main window.
+            if class == ViewportClass::Embedded {
+                return;
+            }
+            egui::CentralPanel::default()
+                .frame(egui::Frame::none())
+                .show(ctx, |ui| {
+                    let painter = ui.painter();
+                    let box_rect = Rect::from_min_size(
+                        Pos2::new(MARGIN, MARGIN + LABEL_HEIGHT),
+                        box_size(min, max),
+                    );
+                    painter.rect_stroke(box_rect, 2.0, Stroke::new(3.0, BOX_COLOR));
+                    painter.text(
+                        pos2(MARGIN, MARGIN),
+                        Align2::LEFT_TOP,
+                        format!("{score:.3}"),
+                        FontId::proportional(16.0),
+                        BOX_COLOR,
+                    );
+                });
+        },
+    );
+}
@@ -0,0 +1,102 @@
+//! Battery-aware pacing: on battery power the worker polls less often, skips unchanged frames
+//! and matches fewer template scales, returning to full speed when plugged in.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use starship_battery::{Manager, State};
+
+use crate::{config::AppConfig, scales};
+
+/// How often the power source is checked while monitoring.
+pub const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerSource {
+    /// Plugged in, or a machine without a battery.
+    Mains,
+    Battery,
+}
+
+/// The current power source: on battery when any battery is discharging.
+pub fn current() -> Result<PowerSource> {
+    let manager = Manager::new().context("failed to open the battery manager")?;
+    let batteries = manager.batteries().context("failed to list batteries")?;
+    for battery in batteries {
+        let battery = battery.context("failed to read a battery")?;
+        if matches!(battery.state(), State::Discharging | State::Empty) {
+            return Ok(PowerSource::Battery);
+        }
+    }
+    Ok(PowerSource::Mains)
+}
+
+/// How the worker runs for a power source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pace {
+    pub interval: Duration,
+    pub skip_unchanged: bool,
+    /// Scales to match; `None` keeps every configured one.
+    pub scales: Option<Vec<f32>>,
+}
+
+/// The configured pace on mains power, or the battery saver's when `source` is the battery and
+/// it is enabled. The saver only ever slows down: a longer configured interval is kept.
+pub fn pace(config: &AppConfig, source: PowerSource) -> Pace {
+    let full = Pace {
+        interval: Duration::from_millis(config.interval_ms.max(10)),
+        skip_unchanged: config.skip_unchanged,
+        scales: None,
+    };
+    let saver = &config.battery;
+    if source == PowerSource::Mains || !saver.enabled {
+        return full;
+    }
+    let configured = scales::effective(&config.template_scales);
+    let thinned = scales::thinned(&configured, saver.max_scales);
+    Pace {
+        interval: full.interval.max(Duration::from_millis(saver.interval_ms)),
+        skip_unchanged: full.skip_unchanged || saver.skip_unchanged,
+        scales: (thinned.len() < configured.len()).then_some(thinned),
+    }
+}
+
+/// Checks the power source every [`POLL_INTERVAL`] and reports changes.
+#[derive(Debug, Clone)]
+pub struct PowerWatch {
+    source: PowerSource,
+    next_check: Instant,
+}
+
+impl PowerWatch {
+    /// Starts on mains power with a check due right away.
+    pub fn new(now: Instant) -> Self {
+        Self {
+            source: PowerSource::Mains,
+            next_check: now,
+        }
+    }
+
+    pub fn source(&self) -> PowerSource {
+        self.source
+    }
+
+    /// Runs `query` when a check is due and returns the new source if it changed. A failed
+    /// query keeps the last known source.
+    pub fn poll(
+        &mut self,
+        now: Instant,
+        query: impl FnOnce() -> Result<PowerSource>,
+    ) -> Result<Option<PowerSource>> {
+        if now < self.next_check {
+            return Ok(None);
+        }
+        self.next_check = now + POLL_INTERVAL;
+        let source = query()?;
+        if source == self.source {
+            return Ok(None);
+        }
+        self.source = source;
+        Ok(Some(source))
+    }
+}
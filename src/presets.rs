@@ -0,0 +1,71 @@
+//! One-click tuning presets that set the detection knobs (threshold, polling interval and
+//! unchanged-frame skipping) together, for users who would rather not tune each one.
+
+use crate::config::AppConfig;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preset {
+    /// Polls often and accepts weaker matches: fastest to react, more false positives.
+    Aggressive,
+    /// The defaults.
+    Balanced,
+    /// Polls slowly and skips matching while the screen is unchanged: lowest CPU use.
+    PowerSaver,
+}
+
+impl Preset {
+    pub const ALL: [Preset; 3] = [Preset::Aggressive, Preset::Balanced, Preset::PowerSaver];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Aggressive => "Aggressive",
+            Self::Balanced => "Balanced",
+            Self::PowerSaver => "Power saver",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Aggressive => {
+                "Checks the screen every 50 ms with a lower threshold; reacts fastest, may misfire"
+            }
+            Self::Balanced => "The default settings: checks every 120 ms at threshold 0.88",
+            Self::PowerSaver => {
+                "Checks every 500 ms and skips matching while the screen is unchanged; least CPU"
+            }
+        }
+    }
+
+    fn values(self) -> (f32, u64, bool) {
+        match self {
+            Self::Aggressive => (0.82, 50, false),
+            Self::Balanced => {
+                let defaults = AppConfig::default();
+                (
+                    defaults.threshold,
+                    defaults.interval_ms,
+                    defaults.skip_unchanged,
+                )
+            }
+            Self::PowerSaver => (0.88, 500, true),
+        }
+    }
+
+    /// Writes the preset's threshold, interval and frame skipping into `config`.
+    pub fn apply(self, config: &mut AppConfig) {
+        let (threshold, interval_ms, skip_unchanged) = self.values();
+        config.threshold = threshold;
+        config.interval_ms = interval_ms;
+        config.skip_unchanged = skip_unchanged;
+    }
+
+    /// The preset `config` currently matches, if any.
+    pub fn current(config: &AppConfig) -> Option<Self> {
+        Self::ALL.into_iter().find(|preset| {
+            let (threshold, interval_ms, skip_unchanged) = preset.values();
+            (config.threshold - threshold).abs() < 1e-4
+                && config.interval_ms == interval_ms
+                && config.skip_unchanged == skip_unchanged
+        })
+    }
+}
@@ -0,0 +1,127 @@
+//! One-off capture and detection outside the worker, so the configuration can be checked
+//! before queueing.
+
+use anyhow::Result;
+use image::{GrayImage, Rgba, RgbaImage};
+use imageproc::{
+    drawing::{draw_hollow_rect_mut, draw_line_segment_mut},
+    rect::Rect,
+};
+
+use crate::{
+    capture::{self, CapturedFrame},
+    config::{AppConfig, SearchRegion},
+    detect::{self, Detection},
+    templates, worker,
+};
+
+/// Outline of the search region.
+pub const REGION_COLOR: Rgba<u8> = Rgba([80, 160, 255, 255]);
+/// Box around a match at or above the threshold.
+pub const MATCH_COLOR: Rgba<u8> = Rgba([60, 220, 90, 255]);
+/// Box around the best match when it stays below the threshold.
+pub const WEAK_MATCH_COLOR: Rgba<u8> = Rgba([250, 200, 40, 255]);
+/// Cross at the computed click point.
+pub const CLICK_COLOR: Rgba<u8> = Rgba([240, 60, 60, 255]);
+
+/// Outcome of a single capture + detect with the current settings.
+pub struct Probe {
+    pub frame: CapturedFrame,
+    /// Best match anywhere in the search region; `None` when the template does not fit.
+    pub detection: Option<Detection>,
+    /// Whether the best match reached the threshold, i.e. the worker would click.
+    pub matched: bool,
+    /// Logical desktop point the worker would click for the best match.
+    pub click: Option<(i32, i32)>,
+}
+
+/// Loads the templates, captures the configured monitor once and runs detection on it.
+pub fn run(config: &AppConfig) -> Result<Probe> {
+    let templates = templates::load_enabled(config)?;
+    let frame = capture::capture_once(&config.effective_capture_source(), config.monitor_index)?;
+    let detection = detect::detect_any(
+        &frame.image,
+        &templates,
+        config.search_region,
+        config.threshold,
+    );
+    Ok(evaluate(config, frame, detection))
+}
+
+/// Judges `detection` on `frame` against the threshold and works out the click point.
+pub fn evaluate(config: &AppConfig, frame: CapturedFrame, detection: Option<Detection>) -> Probe {
+    let matched = detection
+        .as_ref()
+        .is_some_and(|detection| detection.passes(config.threshold));
+    let click = detection
+        .as_ref()
+        .map(|detection| worker::click_point(config, &frame, detection));
+    Probe {
+        frame,
+        detection,
+        matched,
+        click,
+    }
+}
+
+/// The captured frame in color with the search region, the best match and the click point drawn
+/// on top.
+pub fn annotate(probe: &Probe, region: Option<SearchRegion>) -> RgbaImage {
+    let image = &probe.frame.image;
+    let mut annotated = gray_to_rgba(image);
+    if let Some(region) = region.and_then(|region| region.clamped(image.width(), image.height())) {
+        outline(
+            &mut annotated,
+            (region.x, region.y),
+            (region.width, region.height),
+            REGION_COLOR,
+        );
+    }
+    if let Some(detection) = &probe.detection {
+        let color = if probe.matched {
+            MATCH_COLOR
+        } else {
+            WEAK_MATCH_COLOR
+        };
+        outline(
+            &mut annotated,
+            detection.position,
+            detection.template_size,
+            color,
+        );
+    }
+    if let Some((x, y)) = probe.click {
+        let (x, y) = probe.frame.to_physical(x, y);
+        cross(&mut annotated, (x, y), CLICK_COLOR);
+    }
+    annotated
+}
+
+fn gray_to_rgba(image: &GrayImage) -> RgbaImage {
+    RgbaImage::from_fn(image.width(), image.height(), |x, y| {
+        let luma = image.get_pixel(x, y)[0];
+        Rgba([luma, luma, luma, 255])
+    })
+}
+
+/// A box three pixels thick so it stays visible once the screenshot is scaled down.
+fn outline(image: &mut RgbaImage, position: (u32, u32), size: (u32, u32), color: Rgba<u8>) {
+    for inset in 0..3 {
+        let width = size.0.saturating_sub(inset * 2);
+        let height = size.1.saturating_sub(inset * 2);
+        if width == 0 || height == 0 {
+            break;
+        }
+        let rect = Rect::at((position.0 + inset) as i32, (position.1 + inset) as i32)
+            .of_size(width, height);
+        draw_hollow_rect_mut(image, rect, color);
+    }
+}
+
+fn cross(image: &mut RgbaImage, (x, y): (f32, f32), color: Rgba<u8>) {
+    const ARM: f32 = 12.0;
+    for offset in [-1.0, 0.0, 1.0] {
+        draw_line_segment_mut(image, (x - ARM, y + offset), (x + ARM, y + offset), color);
+        draw_line_segment_mut(image, (x + offset, y - ARM), (x + offset, y + ARM), color);
+    }
+}
@@ -0,0 +1,108 @@
+//! Whether the League client process is running.
+
+/// Executable of the League client UI (`LeagueClientUx.exe`).
+pub const LEAGUE_CLIENT_PROCESS: &str = "LeagueClientUx";
+
+/// Whether a process named `name` (without `.exe`) is running, or `None` when this platform
+/// cannot enumerate processes.
+pub fn is_running(name: &str) -> Option<bool> {
+    Some(
+        platform::process_names()?
+            .iter()
+            .any(|candidate| matches_process(candidate, name)),
+    )
+}
+
+/// Id of a running process named `name`, matched like [`is_running`].
+#[cfg(windows)]
+pub fn find_pid(name: &str) -> Option<u32> {
+    platform::processes()?
+        .into_iter()
+        .find(|(_, candidate)| matches_process(candidate, name))
+        .map(|(pid, _)| pid)
+}
+
+/// Case-insensitive executable name match. Prefixes count because Linux truncates command
+/// names to 15 bytes (`LeagueClientUx.` under Wine).
+pub fn matches_process(candidate: &str, name: &str) -> bool {
+    let candidate = candidate.trim().to_ascii_lowercase();
+    let name = name.to_ascii_lowercase();
+    let stem = candidate.strip_suffix(".exe").unwrap_or(&candidate);
+    stem == name || (candidate.len() == 15 && format!("{name}.exe").starts_with(&candidate))
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::mem;
+
+    use windows_sys::Win32::{
+        Foundation::{CloseHandle, INVALID_HANDLE_VALUE},
+        System::Diagnostics::ToolHelp::{
+            CreateToolhelp32Snapshot, PROCESSENTRY32W, Process32FirstW, Process32NextW,
+            TH32CS_SNAPPROCESS,
+        },
+    };
+
+    pub fn process_names() -> Option<Vec<String>> {
+        Some(processes()?.into_iter().map(|(_, name)| name).collect())
+    }
+
+    /// Id and executable name of every process.
+    pub fn processes() -> Option<Vec<(u32, String)>> {
+        // SAFETY: plain snapshot of the process list, closed below.
+        let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) };
+        if snapshot == INVALID_HANDLE_VALUE {
+            return None;
+        }
+        let mut processes = Vec::new();
+        // SAFETY: PROCESSENTRY32W is plain data; the API requires dwSize to be set.
+        let mut entry: PROCESSENTRY32W = unsafe { mem::zeroed() };
+        entry.dwSize = mem::size_of::<PROCESSENTRY32W>() as u32;
+        // SAFETY: `entry` is a properly sized, writable PROCESSENTRY32W.
+        let mut more = unsafe { Process32FirstW(snapshot, &mut entry) } != 0;
+        while more {
+            let len = entry
+                .szExeFile
+                .iter()
+                .position(|&c| c == 0)
+                .unwrap_or(entry.szExeFile.len());
+            processes.push((
+                entry.th32ProcessID,
+                String::from_utf16_lossy(&entry.szExeFile[..len]),
+            ));
+            // SAFETY: same snapshot and entry as above; `dwSize` is still set.
+            more = unsafe { Process32NextW(snapshot, &mut entry) } != 0;
+        }
+        // SAFETY: `snapshot` is a valid handle owned here and closed exactly once.
+        unsafe { CloseHandle(snapshot) };
+        Some(processes)
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::fs;
+
+    pub fn process_names() -> Option<Vec<String>> {
+        let entries = fs::read_dir("/proc").ok()?;
+        Some(
+            entries
+                .flatten()
+                .filter(|entry| {
+                    entry
+                        .file_name()
+                        .to_str()
+                        .is_some_and(|name| name.bytes().all(|b| b.is_ascii_digit()))
+                })
+                .filter_map(|entry| fs::read_to_string(entry.path().join("comm")).ok())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+mod platform {
+    pub fn process_names() -> Option<Vec<String>> {
+        None
+    }
+}
@@ -0,0 +1,120 @@
+//! Hot reload: edits made to the configuration file outside the app — by hand or by a dotfile
+//! sync tool — are picked up while it runs.
+
+use std::{
+    ffi::OsString,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use crossbeam_channel::Receiver;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::{config::AppConfig, worker::LiveSettings};
+
+/// Quiet time after the last change before the file is read, so a save written in several steps
+/// is read once, complete.
+pub const SETTLE: Duration = Duration::from_millis(300);
+
+/// Collapses a burst of change notifications into one reload.
+#[derive(Debug, Clone, Default)]
+pub struct Debounce {
+    changed_at: Option<Instant>,
+}
+
+impl Debounce {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The file changed at `now`; the wait starts over.
+    pub fn on_change(&mut self, now: Instant) {
+        self.changed_at = Some(now);
+    }
+
+    /// `true` once, when [`SETTLE`] has passed since the last change.
+    pub fn is_due(&mut self, now: Instant) -> bool {
+        let due = self
+            .changed_at
+            .is_some_and(|changed_at| now.saturating_duration_since(changed_at) >= SETTLE);
+        if due {
+            self.changed_at = None;
+        }
+        due
+    }
+
+    /// Time left before [`Debounce::is_due`], while a change is waiting.
+    pub fn remaining(&self, now: Instant) -> Option<Duration> {
+        self.changed_at
+            .map(|changed_at| SETTLE.saturating_sub(now.saturating_duration_since(changed_at)))
+    }
+}
+
+/// Watches the configuration file for writes.
+pub struct ConfigWatcher {
+    // Dropping the watcher stops the notifications.
+    _watcher: RecommendedWatcher,
+    changes: Receiver<()>,
+    debounce: Debounce,
+}
+
+impl ConfigWatcher {
+    /// Starts watching `path` and calls `wake` on each change, so a UI that sleeps between
+    /// frames gets to [`ConfigWatcher::poll`]. The folder is watched rather than the file:
+    /// editors and sync tools often replace the file instead of writing to it.
+    pub fn start(path: &Path, wake: impl Fn() + Send + 'static) -> Result<Self> {
+        let dir = path
+            .parent()
+            .context("The configuration file has no parent folder")?;
+        let name = path
+            .file_name()
+            .context("The configuration path has no file name")?
+            .to_os_string();
+        let (tx, changes) = crossbeam_channel::unbounded();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            if event.is_ok_and(|event| touches(&event, &name)) && tx.send(()).is_ok() {
+                wake();
+            }
+        })
+        .context("Failed to create the configuration watcher")?;
+        watcher
+            .watch(dir, RecursiveMode::NonRecursive)
+            .with_context(|| format!("Failed to watch {}", dir.display()))?;
+        Ok(Self {
+            _watcher: watcher,
+            changes,
+            debounce: Debounce::new(),
+        })
+    }
+
+    /// `true` once the file has changed and then stayed quiet for [`SETTLE`].
+    pub fn poll(&mut self, now: Instant) -> bool {
+        if self.changes.try_iter().count() > 0 {
+            self.debounce.on_change(now);
+        }
+        self.debounce.is_due(now)
+    }
+
+    /// Time until a waiting change is due, to schedule the next poll.
+    pub fn remaining(&self, now: Instant) -> Option<Duration> {
+        self.debounce.remaining(now)
+    }
+}
+
+/// Whether `event` creates, writes or renames a file called `name`.
+fn touches(event: &Event, name: &OsString) -> bool {
+    matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+        && event
+            .paths
+            .iter()
+            .any(|path| path.file_name() == Some(name.as_os_str()))
+}
+
+/// Whether going from `old` to `new` changes anything a running worker only reads when it
+/// starts, i.e. anything besides the [`LiveSettings`].
+pub fn needs_restart(old: &AppConfig, new: &AppConfig) -> bool {
+    let mut merged = old.clone();
+    LiveSettings::of(new).apply_to(&mut merged);
+    merged != *new
+}
@@ -0,0 +1,175 @@
+//! Rune pages for the locked champion: local presets, the client's recommendation or a
+//! configurable web source, written through the LCU perks endpoints.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use serde_json::{Value, json};
+use tracing::info;
+
+use crate::{
+    config::{RunePreset, RuneSettings, RuneSource},
+    lcu::LcuClient,
+};
+
+const PAGES_PATH: &str = "/lol-perks/v1/pages";
+const CURRENT_PAGE_PATH: &str = "/lol-perks/v1/currentpage";
+const SOURCE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunePage {
+    pub primary_style_id: i64,
+    pub sub_style_id: i64,
+    pub perk_ids: Vec<i64>,
+}
+
+impl RunePage {
+    fn from_preset(preset: &RunePreset) -> Self {
+        Self {
+            primary_style_id: preset.primary_style_id,
+            sub_style_id: preset.sub_style_id,
+            perk_ids: preset.perk_ids.clone(),
+        }
+    }
+
+    /// Parses the LCU page layout (`primaryStyleId`, `subStyleId`, `selectedPerkIds`).
+    pub fn from_page_json(page: &Value) -> Option<Self> {
+        Some(Self {
+            primary_style_id: page.get("primaryStyleId")?.as_i64()?,
+            sub_style_id: page.get("subStyleId")?.as_i64()?,
+            perk_ids: ids(page.get("selectedPerkIds")?)?,
+        })
+    }
+
+    /// Parses the first entry of `/lol-perks/v1/recommended-pages/...`.
+    pub fn from_recommendation(pages: &Value) -> Option<Self> {
+        let page = pages.as_array()?.first()?;
+        let perks = page
+            .get("perks")?
+            .as_array()?
+            .iter()
+            .filter_map(|perk| perk.get("id")?.as_i64())
+            .collect();
+        Some(Self {
+            primary_style_id: page.get("primaryPerkStyleId")?.as_i64()?,
+            sub_style_id: page.get("secondaryPerkStyleId")?.as_i64()?,
+            perk_ids: perks,
+        })
+    }
+
+    /// A full page has six runes plus three stat shards.
+    pub fn is_complete(&self) -> bool {
+        self.primary_style_id > 0 && self.sub_style_id > 0 && self.perk_ids.len() == 9
+    }
+}
+
+fn ids(list: &Value) -> Option<Vec<i64>> {
+    list.as_array()?.iter().map(Value::as_i64).collect()
+}
+
+/// First preset for `champion` that is either role-less or matches `position`.
+pub fn find_preset<'a>(
+    presets: &'a [RunePreset],
+    champion: &str,
+    alias: &str,
+    position: &str,
+) -> Option<&'a RunePreset> {
+    presets.iter().find(|preset| {
+        let name = preset.champion.trim();
+        (name.eq_ignore_ascii_case(champion) || name.eq_ignore_ascii_case(alias))
+            && (preset.role.is_empty() || preset.role.eq_ignore_ascii_case(position))
+    })
+}
+
+/// Substitutes the placeholders documented on [`RuneSource::Url`].
+pub fn source_url(template: &str, alias: &str, champion_id: i64, position: &str) -> String {
+    template
+        .replace("{champion}", &alias.to_lowercase())
+        .replace("{champion_id}", &champion_id.to_string())
+        .replace("{position}", &position.to_lowercase())
+}
+
+/// The champion being played, as needed to look up a page.
+pub struct LockedChampion<'a> {
+    pub id: i64,
+    pub name: &'a str,
+    pub alias: &'a str,
+    /// Lower-case assigned position, empty in blind queues.
+    pub position: &'a str,
+    pub map_id: i64,
+}
+
+/// Picks the page for `champion` according to `settings`.
+pub fn resolve_page(
+    client: &LcuClient,
+    settings: &RuneSettings,
+    champion: &LockedChampion<'_>,
+) -> Result<RunePage> {
+    if let Some(preset) = find_preset(
+        &settings.presets,
+        champion.name,
+        champion.alias,
+        champion.position,
+    ) {
+        return Ok(RunePage::from_preset(preset));
+    }
+    let page = match &settings.source {
+        RuneSource::Presets => bail!("No rune preset for {}", champion.name),
+        RuneSource::Client => {
+            let position = if champion.position.is_empty() {
+                "NONE".to_string()
+            } else {
+                champion.position.to_uppercase()
+            };
+            let path = format!(
+                "/lol-perks/v1/recommended-pages/champion/{}/position/{position}/map/{}",
+                champion.id, champion.map_id
+            );
+            RunePage::from_recommendation(&client.get(&path)?)
+        }
+        RuneSource::Url { template } => {
+            let url = source_url(template, champion.alias, champion.id, champion.position);
+            let body: Value = ureq::AgentBuilder::new()
+                .timeout(SOURCE_TIMEOUT)
+                .build()
+                .get(&url)
+                .call()
+                .with_context(|| format!("Rune source {url} failed"))?
+                .into_json()
+                .with_context(|| format!("Rune source {url} returned malformed JSON"))?;
+            RunePage::from_page_json(&body)
+        }
+    };
+    page.filter(RunePage::is_complete)
+        .with_context(|| format!("No usable rune page for {}", champion.name))
+}
+
+/// Writes `page` over this tool's page, found by `name`, or creates it, and makes it active.
+/// Pages the user made are never touched: without a free slot creating fails and the user has
+/// to make room.
+pub fn write_page(client: &LcuClient, name: &str, page: &RunePage) -> Result<()> {
+    let body = json!({
+        "name": name,
+        "primaryStyleId": page.primary_style_id,
+        "subStyleId": page.sub_style_id,
+        "selectedPerkIds": page.perk_ids,
+        "current": true,
+    });
+    let pages = client.get(PAGES_PATH)?;
+    let own = pages.as_array().into_iter().flatten().find_map(|existing| {
+        (existing.get("name").and_then(Value::as_str) == Some(name))
+            .then(|| existing.get("id").and_then(Value::as_i64))
+            .flatten()
+    });
+    match own {
+        Some(id) => {
+            client.put_json(&format!("{PAGES_PATH}/{id}"), &body)?;
+            client.put_json(CURRENT_PAGE_PATH, &json!(id))?;
+        }
+        None => client.post_json(PAGES_PATH, &body).context(
+            "Failed to create the rune page; with every slot in use, delete a page to make room",
+        )?,
+    }
+    info!(name, primary = page.primary_style_id, "rune page written");
+    Ok(())
+}
@@ -0,0 +1,130 @@
+//! The template scales matched on every frame: editing helpers for the settings list and an
+//! estimate of what a scale set costs per frame.
+
+/// Scales outside this range are dropped.
+pub const MIN_SCALE: f32 = 0.25;
+pub const MAX_SCALE: f32 = 3.0;
+/// More scales than this are refused; each one is a full template match per frame.
+pub const MAX_SCALES: usize = 40;
+/// Variants narrower or shorter than this are not built.
+pub const MIN_VARIANT_SIDE: u32 = 4;
+
+pub const DEFAULT_SCALES: &[f32] = &[
+    0.65, 0.7, 0.75, 0.8, 0.85, 0.9, 0.95, 1.0, 1.05, 1.1, 1.15, 1.2, 1.25, 1.3,
+];
+
+/// `scales` rounded to hundredths, limited to [`MIN_SCALE`]..=[`MAX_SCALE`], sorted and without
+/// duplicates, at most [`MAX_SCALES`] of them.
+pub fn normalized(scales: &[f32]) -> Vec<f32> {
+    let mut scales: Vec<f32> = scales
+        .iter()
+        .map(|scale| (scale * 100.0).round() / 100.0)
+        .filter(|scale| (MIN_SCALE..=MAX_SCALE).contains(scale))
+        .collect();
+    scales.sort_by(f32::total_cmp);
+    scales.dedup();
+    scales.truncate(MAX_SCALES);
+    scales
+}
+
+/// The scales to build variants for: `scales` normalized, or the template as-is when none are
+/// left.
+pub fn effective(scales: &[f32]) -> Vec<f32> {
+    let scales = normalized(scales);
+    if scales.is_empty() { vec![1.0] } else { scales }
+}
+
+/// `start` to `end` inclusive in steps of `step`, normalized; `None` for an empty range, a
+/// non-positive step or more than [`MAX_SCALES`] entries.
+pub fn range(start: f32, end: f32, step: f32) -> Option<Vec<f32>> {
+    if step <= 0.0 || end < start {
+        return None;
+    }
+    // Half a step of slack so `end` survives rounding.
+    let count = ((end - start) / step + 0.5).floor() as usize + 1;
+    if count > MAX_SCALES {
+        return None;
+    }
+    let scales = normalized(
+        &(0..count)
+            .map(|index| start + step * index as f32)
+            .collect::<Vec<_>>(),
+    );
+    (!scales.is_empty()).then_some(scales)
+}
+
+/// At most `max` of `scales` (sorted), spread evenly from the smallest to the largest and always
+/// including the one closest to 1.0.
+pub fn thinned(scales: &[f32], max: usize) -> Vec<f32> {
+    let max = max.max(1);
+    if scales.len() <= max {
+        return scales.to_vec();
+    }
+    let closest = |scales: &[f32]| {
+        (0..scales.len())
+            .min_by(|&a, &b| (scales[a] - 1.0).abs().total_cmp(&(scales[b] - 1.0).abs()))
+            .unwrap_or(0)
+    };
+    let mut picked: Vec<usize> = if max == 1 {
+        vec![closest(scales)]
+    } else {
+        let last = (scales.len() - 1) as f32;
+        (0..max)
+            .map(|index| (last * index as f32 / (max - 1) as f32).round() as usize)
+            .collect()
+    };
+    let nearest = closest(scales);
+    if !picked.contains(&nearest)
+        && let Some(slot) = picked
+            .iter_mut()
+            .min_by_key(|index| index.abs_diff(nearest))
+    {
+        *slot = nearest;
+    }
+    picked.sort_unstable();
+    picked.dedup();
+    picked.into_iter().map(|index| scales[index]).collect()
+}
+
+/// Size of the variant of a `template`-sized image at `scale`; `None` when it would be smaller
+/// than [`MIN_VARIANT_SIDE`].
+pub fn variant_size(template: (u32, u32), scale: f32) -> Option<(u32, u32)> {
+    let side = |length: u32| ((length as f32 * scale).round() as i64).max(1) as u32;
+    let (width, height) = (side(template.0), side(template.1));
+    (width >= MIN_VARIANT_SIDE && height >= MIN_VARIANT_SIDE).then_some((width, height))
+}
+
+/// Pixel comparisons one frame costs: for every variant that fits in `area`, each placement
+/// times the variant's pixels.
+pub fn match_cost(template: (u32, u32), area: (u32, u32), scales: &[f32]) -> u64 {
+    scales
+        .iter()
+        .filter_map(|&scale| variant_size(template, scale))
+        .filter(|&(width, height)| width <= area.0 && height <= area.1)
+        .map(|(width, height)| {
+            let placements = u64::from(area.0 - width + 1) * u64::from(area.1 - height + 1);
+            placements * u64::from(width) * u64::from(height)
+        })
+        .sum()
+}
+
+/// Matching time for a scale set costing `cost`, extrapolated from `measured_ms` taken by the
+/// running set costing `measured_cost`.
+pub fn estimate_ms(measured_ms: f32, measured_cost: u64, cost: u64) -> Option<f32> {
+    (measured_cost > 0 && measured_ms > 0.0)
+        .then(|| measured_ms * (cost as f64 / measured_cost as f64) as f32)
+}
+
+/// `cost` in compact form for display: `950`, `12.3 k`, `4.5 M` or `1.2 G`.
+pub fn format_cost(cost: u64) -> String {
+    let cost = cost as f64;
+    if cost >= 1e9 {
+        format!("{:.1} G", cost / 1e9)
+    } else if cost >= 1e6 {
+        format!("{:.1} M", cost / 1e6)
+    } else if cost >= 1e3 {
+        format!("{:.1} k", cost / 1e3)
+    } else {
+        format!("{cost}")
+    }
+}
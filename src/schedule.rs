@@ -0,0 +1,28 @@
+//! Whether the monitoring schedule says the worker should be running right now.
+
+use chrono::{Datelike, NaiveDateTime};
+
+use crate::config::{ScheduleSettings, ScheduleWindow};
+
+/// Whether `now` (local time) falls inside any window of an enabled schedule; `None` when the
+/// schedule is off and monitoring is left alone.
+pub fn is_active(settings: &ScheduleSettings, now: NaiveDateTime) -> Option<bool> {
+    settings.enabled.then(|| {
+        settings
+            .windows
+            .iter()
+            .any(|window| window_contains(window, now))
+    })
+}
+
+/// Whether `now` is inside `window`. Windows that cross midnight belong to the day they start.
+pub fn window_contains(window: &ScheduleWindow, now: NaiveDateTime) -> bool {
+    let time = now.time();
+    let today = now.weekday();
+    if window.start < window.end {
+        window.days.contains(&today) && window.start <= time && time < window.end
+    } else {
+        (window.days.contains(&today) && time >= window.start)
+            || (window.days.contains(&today.pred()) && time < window.end)
+    }
+}
@@ -0,0 +1,84 @@
+//! Best match score of every recent frame, for the live score graph.
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// How far back the score graph reaches.
+pub const SCORE_WINDOW: Duration = Duration::from_secs(180);
+
+/// Newest-last frame scores younger than the window.
+#[derive(Debug)]
+pub struct ScoreTrace {
+    window: Duration,
+    samples: VecDeque<(Instant, f32)>,
+}
+
+impl Default for ScoreTrace {
+    fn default() -> Self {
+        Self::new(SCORE_WINDOW)
+    }
+}
+
+impl ScoreTrace {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            samples: VecDeque::new(),
+        }
+    }
+
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Records the best score of a frame captured at `at` and drops samples outside the window.
+    pub fn push(&mut self, at: Instant, score: f32) {
+        self.samples.push_back((at, score));
+        while let Some(&(oldest, _)) = self.samples.front() {
+            if at.saturating_duration_since(oldest) <= self.window {
+                break;
+            }
+            self.samples.pop_front();
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// `[seconds before now, score]` points, oldest first; the x values are negative.
+    pub fn points(&self, now: Instant) -> Vec<[f64; 2]> {
+        self.samples
+            .iter()
+            .map(|&(at, score)| {
+                [
+                    -now.saturating_duration_since(at).as_secs_f64(),
+                    f64::from(score),
+                ]
+            })
+            .collect()
+    }
+
+    /// Score of the most recent frame.
+    pub fn latest(&self) -> Option<f32> {
+        self.samples.back().map(|&(_, score)| score)
+    }
+
+    /// Highest score in the window.
+    pub fn peak(&self) -> Option<f32> {
+        self.samples
+            .iter()
+            .map(|&(_, score)| score)
+            .reduce(f32::max)
+    }
+}
@@ -0,0 +1,74 @@
+//! Audio cues on detection and on click: a built-in chime or a user-selected WAV/OGG file.
+
+use std::{fs::File, io::BufReader, path::Path, thread, time::Duration};
+
+use anyhow::{Context, Result};
+use crossbeam_channel::Sender;
+use rodio::{Decoder, OutputStream, Source, source::SineWave};
+use tracing::warn;
+
+use crate::config::SoundCue;
+
+/// Plays cues on a dedicated audio thread, started on first use because the output stream
+/// cannot leave the thread that opened it.
+#[derive(Default)]
+pub struct SoundPlayer {
+    tx: Option<Sender<SoundCue>>,
+}
+
+impl SoundPlayer {
+    /// Queues `cue` unless it is muted; playback never blocks the caller.
+    pub fn play(&mut self, cue: &SoundCue) {
+        if cue.muted {
+            return;
+        }
+        let tx = self.tx.get_or_insert_with(spawn_audio_thread);
+        let _ = tx.send(cue.clone());
+    }
+}
+
+/// Opens a WAV or OGG file for playback.
+pub fn decode(path: &Path) -> Result<Decoder<BufReader<File>>> {
+    let file = File::open(path).with_context(|| format!("failed to open sound {path:?}"))?;
+    Decoder::new(BufReader::new(file)).with_context(|| format!("unsupported sound file {path:?}"))
+}
+
+/// Built-in two-note chime played when a cue has no file.
+pub fn chime() -> impl Source<Item = f32> + Send + 'static {
+    let note = |frequency: f32, ms: u64| {
+        SineWave::new(frequency)
+            .take_duration(Duration::from_millis(ms))
+            .amplify(0.4)
+    };
+    note(660.0, 140).mix(note(990.0, 220).delay(Duration::from_millis(140)))
+}
+
+fn spawn_audio_thread() -> Sender<SoundCue> {
+    let (tx, rx) = crossbeam_channel::unbounded::<SoundCue>();
+    thread::spawn(move || {
+        let (_stream, handle) = match OutputStream::try_default() {
+            Ok(output) => output,
+            Err(err) => {
+                warn!(error = %err, "no audio output, sound cues disabled");
+                return;
+            }
+        };
+        for cue in rx {
+            let volume = cue.volume.clamp(0.0, 1.0);
+            let played = match &cue.path {
+                Some(path) => decode(path).and_then(|source| {
+                    handle
+                        .play_raw(source.convert_samples().amplify(volume))
+                        .context("audio output rejected the sound")
+                }),
+                None => handle
+                    .play_raw(chime().amplify(volume))
+                    .context("audio output rejected the chime"),
+            };
+            if let Err(err) = played {
+                warn!(error = ?err, "failed to play sound cue");
+            }
+        }
+    });
+    tx
+}
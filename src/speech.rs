@@ -0,0 +1,135 @@
+//! Spoken announcements through the OS speech engine: SAPI/WinRT and AVFoundation via `tts`,
+//! speech-dispatcher's `spd-say` elsewhere.
+
+use std::thread;
+
+use crossbeam_channel::Sender;
+use tracing::warn;
+
+use crate::config::SpeechSettings;
+
+/// What happened, each with its own phrase in [`SpeechSettings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Announcement {
+    Found,
+    Accepted,
+}
+
+/// The phrase to speak for `announcement`; `None` while announcements are off or the phrase is
+/// empty.
+pub fn phrase(settings: &SpeechSettings, announcement: Announcement) -> Option<&str> {
+    if !settings.enabled {
+        return None;
+    }
+    let text = match announcement {
+        Announcement::Found => &settings.found,
+        Announcement::Accepted => &settings.accepted,
+    };
+    Some(text.trim()).filter(|text| !text.is_empty())
+}
+
+struct Utterance {
+    text: String,
+    volume: f32,
+}
+
+/// Speaks on a dedicated thread, started on first use because some engines must stay on the
+/// thread that opened them.
+#[derive(Default)]
+pub struct Announcer {
+    tx: Option<Sender<Utterance>>,
+}
+
+impl Announcer {
+    /// Queues the phrase for `announcement`, if any; speaking never blocks the caller.
+    pub fn announce(&mut self, settings: &SpeechSettings, announcement: Announcement) {
+        if let Some(text) = phrase(settings, announcement) {
+            self.say(text, settings.volume);
+        }
+    }
+
+    /// Queues `text` regardless of the settings, for the *Test* button.
+    pub fn say(&mut self, text: &str, volume: f32) {
+        let tx = self.tx.get_or_insert_with(spawn_speech_thread);
+        let _ = tx.send(Utterance {
+            text: text.to_string(),
+            volume: volume.clamp(0.0, 1.0),
+        });
+    }
+}
+
+fn spawn_speech_thread() -> Sender<Utterance> {
+    let (tx, rx) = crossbeam_channel::unbounded::<Utterance>();
+    thread::spawn(move || {
+        let mut voice = match platform::Voice::open() {
+            Ok(voice) => voice,
+            Err(err) => {
+                warn!(error = ?err, "no speech engine, voice announcements disabled");
+                return;
+            }
+        };
+        for utterance in rx {
+            if let Err(err) = voice.speak(&utterance.text, utterance.volume) {
+                warn!(error = ?err, "failed to speak announcement");
+            }
+        }
+    });
+    tx
+}
+
+#[cfg(any(windows, target_os = "macos"))]
+mod platform {
+    use anyhow::{Context, Result};
+    use tts::Tts;
+
+    pub struct Voice(Tts);
+
+    impl Voice {
+        pub fn open() -> Result<Self> {
+            Ok(Self(
+                Tts::default().context("failed to open the speech engine")?,
+            ))
+        }
+
+        /// Queues `text` behind anything still being spoken.
+        pub fn speak(&mut self, text: &str, volume: f32) -> Result<()> {
+            if self.0.supported_features().volume {
+                let (min, max) = (self.0.min_volume(), self.0.max_volume());
+                self.0
+                    .set_volume(min + (max - min) * volume)
+                    .context("failed to set the speech volume")?;
+            }
+            self.0.speak(text, false).context("failed to speak")?;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(not(any(windows, target_os = "macos")))]
+mod platform {
+    use std::process::Command;
+
+    use anyhow::{Context, Result, bail};
+
+    pub struct Voice;
+
+    impl Voice {
+        pub fn open() -> Result<Self> {
+            Ok(Self)
+        }
+
+        /// Runs `spd-say`, which returns once the text is handed to speech-dispatcher.
+        pub fn speak(&mut self, text: &str, volume: f32) -> Result<()> {
+            // spd-say takes -100..=100.
+            let volume = ((volume * 200.0).round() as i32 - 100).to_string();
+            let status = Command::new("spd-say")
+                .args(["--volume", &volume, "--", text])
+                .status()
+                .context("failed to run spd-say; is speech-dispatcher installed?")?;
+            if !status.success() {
+                bail!("spd-say exited with {status}");
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Summoner spell presets applied in champ select.
+
+use crate::config::{FlashKey, SpellPreset};
+
+pub const FLASH: i64 = 4;
+
+/// Summoner spell ids by English name (`summoner-spells.json` in the client game data).
+const SPELLS: &[(&str, i64)] = &[
+    ("Cleanse", 1),
+    ("Exhaust", 3),
+    ("Flash", 4),
+    ("Ghost", 6),
+    ("Heal", 7),
+    ("Smite", 11),
+    ("Teleport", 12),
+    ("Clarity", 13),
+    ("Ignite", 14),
+    ("Barrier", 21),
+    ("Mark", 32),
+];
+
+pub fn spell_names() -> impl Iterator<Item = &'static str> {
+    SPELLS.iter().map(|&(name, _)| name)
+}
+
+pub fn spell_id(name: &str) -> Option<i64> {
+    let name = name.trim();
+    SPELLS
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(name))
+        .map(|&(_, id)| id)
+}
+
+pub fn spell_name(id: i64) -> &'static str {
+    SPELLS
+        .iter()
+        .find(|&&(_, known)| known == id)
+        .map_or("?", |&(name, _)| name)
+}
+
+/// Most specific preset for the champion (name or alias) and role: champion and role, then
+/// champion only, then role only, then a catch-all.
+pub fn find_preset<'a>(
+    presets: &'a [SpellPreset],
+    champion: &str,
+    alias: &str,
+    position: &str,
+) -> Option<&'a SpellPreset> {
+    let champion_matches = |preset: &SpellPreset| {
+        let name = preset.champion.trim();
+        name.eq_ignore_ascii_case(champion) || name.eq_ignore_ascii_case(alias)
+    };
+    let role_matches = |preset: &SpellPreset| preset.role.eq_ignore_ascii_case(position);
+    let specificity =
+        |preset: &SpellPreset| match (preset.champion.trim().is_empty(), preset.role.is_empty()) {
+            (false, false) if champion_matches(preset) && role_matches(preset) => Some(0),
+            (false, true) if champion_matches(preset) => Some(1),
+            (true, false) if role_matches(preset) => Some(2),
+            (true, true) => Some(3),
+            _ => None,
+        };
+    presets
+        .iter()
+        .filter_map(|preset| Some((specificity(preset)?, preset)))
+        .min_by_key(|&(rank, _)| rank)
+        .map(|(_, preset)| preset)
+}
+
+/// Resolves a preset to `[D, F]` spell ids with Flash moved onto `flash_key`.
+pub fn arrange(preset: &SpellPreset, flash_key: FlashKey) -> Option<[i64; 2]> {
+    let [first, second] = preset.spells.as_slice() else {
+        return None;
+    };
+    let mut spells = [spell_id(first)?, spell_id(second)?];
+    if spells[0] == spells[1] {
+        return None;
+    }
+    let flash_slot = match flash_key {
+        FlashKey::D => 0,
+        FlashKey::F => 1,
+    };
+    if spells[1 - flash_slot] == FLASH {
+        spells.swap(0, 1);
+    }
+    Some(spells)
+}
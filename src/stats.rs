@@ -0,0 +1,160 @@
+//! Session statistics (accepts, detection scores, detection-to-click time, frames, uptime) and
+//! lifetime totals stored next to the config file.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::config::APP_NAME;
+
+/// Confy name of the lifetime totals file (`stats.toml` in the config directory).
+const STATS_NAME: &str = "stats";
+
+/// Counters shared by the session and lifetime views.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct StatsTotals {
+    pub accepted: u64,
+    pub detections: u64,
+    pub score_sum: f64,
+    pub score_min: Option<f32>,
+    pub score_max: Option<f32>,
+    /// Accepts with a measured detection-to-click time.
+    pub reactions: u64,
+    pub reaction_ms_sum: u64,
+    pub frames: u64,
+    /// Time spent monitoring.
+    pub uptime_ms: u64,
+}
+
+impl StatsTotals {
+    pub fn record_score(&mut self, score: f32) {
+        self.detections += 1;
+        self.score_sum += f64::from(score);
+        self.score_min = Some(self.score_min.map_or(score, |min| min.min(score)));
+        self.score_max = Some(self.score_max.map_or(score, |max| max.max(score)));
+    }
+
+    pub fn average_score(&self) -> Option<f32> {
+        (self.detections > 0).then(|| (self.score_sum / self.detections as f64) as f32)
+    }
+
+    pub fn average_reaction(&self) -> Option<Duration> {
+        (self.reactions > 0).then(|| Duration::from_millis(self.reaction_ms_sum / self.reactions))
+    }
+
+    pub fn uptime(&self) -> Duration {
+        Duration::from_millis(self.uptime_ms)
+    }
+
+    /// Sum of both totals, as if they had been recorded together.
+    pub fn merged(&self, other: &StatsTotals) -> StatsTotals {
+        let extreme = |a: Option<f32>, b: Option<f32>, pick: fn(f32, f32) -> f32| match (a, b) {
+            (Some(a), Some(b)) => Some(pick(a, b)),
+            (a, b) => a.or(b),
+        };
+        StatsTotals {
+            accepted: self.accepted + other.accepted,
+            detections: self.detections + other.detections,
+            score_sum: self.score_sum + other.score_sum,
+            score_min: extreme(self.score_min, other.score_min, f32::min),
+            score_max: extreme(self.score_max, other.score_max, f32::max),
+            reactions: self.reactions + other.reactions,
+            reaction_ms_sum: self.reaction_ms_sum + other.reaction_ms_sum,
+            frames: self.frames + other.frames,
+            uptime_ms: self.uptime_ms + other.uptime_ms,
+        }
+    }
+}
+
+/// Statistics for this run of the app, fed from worker events, on top of the lifetime totals
+/// loaded at startup.
+#[derive(Debug, Clone, Default)]
+pub struct SessionStats {
+    previous: StatsTotals,
+    session: StatsTotals,
+    running_since: Option<Instant>,
+    /// Latest detection not yet followed by an accept attempt.
+    detected_at: Option<Instant>,
+    /// A ready check is being accepted; `Some` holds its detection time when screen-driven.
+    pending: Option<Option<Instant>>,
+}
+
+impl SessionStats {
+    /// Starts a session on top of `lifetime`, the totals of earlier sessions.
+    pub fn new(lifetime: StatsTotals) -> Self {
+        Self {
+            previous: lifetime,
+            ..Self::default()
+        }
+    }
+
+    pub fn on_detection(&mut self, score: f32, now: Instant) {
+        self.session.record_score(score);
+        self.detected_at = Some(now);
+    }
+
+    /// Detection that will not be clicked (dry run or aborted click).
+    pub fn on_detection_dropped(&mut self) {
+        self.detected_at = None;
+    }
+
+    /// An accept is about to be sent; `attempt` 1 starts a new ready check, retries are ignored.
+    pub fn on_accept_attempt(&mut self, attempt: u32) {
+        if attempt == 1 {
+            self.pending = Some(self.detected_at.take());
+        }
+    }
+
+    /// Click or API accept went through. Only the first per ready check counts as a match.
+    pub fn on_accepted(&mut self, now: Instant) {
+        let Some(detected_at) = self.pending.take() else {
+            return;
+        };
+        self.session.accepted += 1;
+        if let Some(detected_at) = detected_at {
+            self.session.reactions += 1;
+            self.session.reaction_ms_sum += now.duration_since(detected_at).as_millis() as u64;
+        }
+    }
+
+    pub fn on_frames(&mut self, frames: u32) {
+        self.session.frames += u64::from(frames);
+    }
+
+    /// Starts or stops the uptime clock.
+    pub fn set_running(&mut self, running: bool, now: Instant) {
+        match (running, self.running_since) {
+            (true, None) => self.running_since = Some(now),
+            (false, Some(since)) => {
+                self.session.uptime_ms += now.duration_since(since).as_millis() as u64;
+                self.running_since = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// This session's totals, counting the current monitoring run up to `now`.
+    pub fn session(&self, now: Instant) -> StatsTotals {
+        let mut session = self.session.clone();
+        if let Some(since) = self.running_since {
+            session.uptime_ms += now.duration_since(since).as_millis() as u64;
+        }
+        session
+    }
+
+    /// Earlier sessions plus this one.
+    pub fn lifetime(&self, now: Instant) -> StatsTotals {
+        self.previous.merged(&self.session(now))
+    }
+}
+
+/// Lifetime totals from the config directory; empty on first run.
+pub fn load_lifetime() -> Result<StatsTotals> {
+    confy::load(APP_NAME, STATS_NAME).context("Failed to load statistics")
+}
+
+pub fn store_lifetime(totals: &StatsTotals) -> Result<()> {
+    confy::store(APP_NAME, STATS_NAME, totals).context("Failed to persist statistics")
+}
@@ -0,0 +1,124 @@
+//! Summary of one monitoring run (start to stop), shown as a card when monitoring stops so an
+//! unattended session can be checked afterwards.
+
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
+
+use crate::metrics::WorkerMetrics;
+
+/// Scores this far below the threshold count as near misses.
+pub const NEAR_MISS_MARGIN: f32 = 0.05;
+
+/// Counters for the monitoring run in progress, fed from worker events.
+#[derive(Debug, Clone)]
+pub struct RunTracker {
+    started: DateTime<Local>,
+    started_at: Instant,
+    threshold: f32,
+    frames: u64,
+    detections: u64,
+    clicks: u64,
+    near_misses: u64,
+    /// Sum of per-frame capture-to-decision latency, for the average.
+    latency_ms_sum: f64,
+}
+
+impl RunTracker {
+    /// A run started at `started`, matching against `threshold`.
+    pub fn new(started: DateTime<Local>, now: Instant, threshold: f32) -> Self {
+        Self {
+            started,
+            started_at: now,
+            threshold,
+            frames: 0,
+            detections: 0,
+            clicks: 0,
+            near_misses: 0,
+            latency_ms_sum: 0.0,
+        }
+    }
+
+    pub fn on_metrics(&mut self, metrics: &WorkerMetrics) {
+        self.frames += u64::from(metrics.frames);
+        self.latency_ms_sum += f64::from(metrics.avg_latency_ms) * f64::from(metrics.frames);
+    }
+
+    /// Best score of a frame; one just under the threshold is a near miss.
+    pub fn on_score(&mut self, score: f32) {
+        if score < self.threshold && score >= self.threshold - NEAR_MISS_MARGIN {
+            self.near_misses += 1;
+        }
+    }
+
+    pub fn on_detection(&mut self) {
+        self.detections += 1;
+    }
+
+    pub fn on_click(&mut self) {
+        self.clicks += 1;
+    }
+
+    pub fn finish(&self, now: Instant) -> RunSummary {
+        RunSummary {
+            started: self.started,
+            duration: now.duration_since(self.started_at),
+            frames: self.frames,
+            detections: self.detections,
+            clicks: self.clicks,
+            near_misses: self.near_misses,
+            average_latency_ms: (self.frames > 0)
+                .then(|| (self.latency_ms_sum / self.frames as f64) as f32),
+        }
+    }
+}
+
+/// What a finished monitoring run did.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunSummary {
+    pub started: DateTime<Local>,
+    pub duration: Duration,
+    pub frames: u64,
+    pub detections: u64,
+    pub clicks: u64,
+    /// Frames whose best score fell within [`NEAR_MISS_MARGIN`] below the threshold.
+    pub near_misses: u64,
+    /// Mean capture-to-decision time per frame; `None` before the first metrics report.
+    pub average_latency_ms: Option<f32>,
+}
+
+impl RunSummary {
+    /// Label and value rows, labels untranslated, for the card and for copying as text.
+    pub fn rows(&self) -> Vec<(&'static str, String)> {
+        vec![
+            (
+                "Started",
+                self.started.format("%Y-%m-%d %H:%M:%S").to_string(),
+            ),
+            ("Duration", format_duration(self.duration)),
+            ("Frames processed", self.frames.to_string()),
+            ("Detections", self.detections.to_string()),
+            ("Clicks", self.clicks.to_string()),
+            ("Near misses", self.near_misses.to_string()),
+            (
+                "Average latency",
+                self.average_latency_ms
+                    .map_or_else(|| "–".to_string(), |ms| format!("{ms:.1} ms")),
+            ),
+        ]
+    }
+}
+
+/// Compact duration for display: `1h 05m`, `3m 07s`, `42s` or `350ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 3600 {
+        format!("{}h {:02}m", secs / 3600, secs % 3600 / 60)
+    } else if secs > 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else if secs > 0 {
+        format!("{secs}s")
+    } else {
+        format!("{}ms", duration.as_millis())
+    }
+}
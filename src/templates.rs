@@ -0,0 +1,297 @@
+//! Template registry: the bundled templates next to the executable (per-locale ones live in a
+//! sub-folder named after the locale) plus the user's own, each enabled or not and with an
+//! optional threshold of its own. Choices are stored as [`TemplateEntry`]s in the config.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use tracing::warn;
+
+use crate::{
+    config::{AppConfig, TemplateEntry},
+    detect::{self, Template},
+};
+
+/// File name of the stock Accept button template, the only one enabled out of the box.
+pub const DEFAULT_TEMPLATE: &str = "accept_button.png";
+/// The stock [`DEFAULT_TEMPLATE`], built into the executable for when the bundled copy is gone.
+pub const EMBEDDED_TEMPLATE: &[u8] = include_bytes!("../resources/templates/accept_button.png");
+
+/// Where a known template comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateKind {
+    Bundled,
+    /// Bundled for one client language, e.g. `ja` for `templates/ja/`.
+    Locale(String),
+    User,
+}
+
+/// A template as listed in the manager.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnownTemplate {
+    pub path: PathBuf,
+    pub kind: TemplateKind,
+    pub enabled: bool,
+    pub threshold: Option<f32>,
+}
+
+impl KnownTemplate {
+    pub fn name(&self) -> String {
+        self.path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+
+    fn entry(&self) -> TemplateEntry {
+        TemplateEntry {
+            path: self.path.clone(),
+            enabled: self.enabled,
+            threshold: self.threshold,
+        }
+    }
+}
+
+/// Folders searched for bundled templates, in priority order.
+pub fn bundled_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Ok(exe_path) = std::env::current_exe()
+        && let Some(dir) = exe_path.parent()
+    {
+        dirs.push(dir.join("resources").join("templates"));
+        dirs.push(dir.join("templates"));
+    }
+    if let Ok(current_dir) = std::env::current_dir() {
+        dirs.push(current_dir.join("resources").join("templates"));
+    }
+    dirs
+}
+
+fn is_image(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("png"))
+}
+
+fn images_in(dir: &Path) -> Vec<PathBuf> {
+    let mut images: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && is_image(path))
+        .collect();
+    images.sort();
+    images
+}
+
+/// PNGs directly in `dirs` (bundled) and one folder down (per-locale). A file found under the
+/// same relative path in an earlier folder hides later ones.
+pub fn discover(dirs: &[PathBuf]) -> Vec<(PathBuf, TemplateKind)> {
+    let mut found: Vec<(PathBuf, PathBuf, TemplateKind)> = Vec::new();
+    let mut add = |relative: PathBuf, path: PathBuf, kind: TemplateKind| {
+        if !found.iter().any(|(seen, _, _)| *seen == relative) {
+            found.push((relative, path, kind));
+        }
+    };
+    for dir in dirs {
+        for path in images_in(dir) {
+            add(
+                path.file_name().map(PathBuf::from).unwrap_or_default(),
+                path,
+                TemplateKind::Bundled,
+            );
+        }
+        let mut locales: Vec<PathBuf> = fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect();
+        locales.sort();
+        for locale_dir in locales {
+            let locale = locale_dir
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            for path in images_in(&locale_dir) {
+                let relative = Path::new(&locale).join(path.file_name().unwrap_or_default());
+                add(relative, path, TemplateKind::Locale(locale.clone()));
+            }
+        }
+    }
+    found
+        .into_iter()
+        .map(|(_, path, kind)| (path, kind))
+        .collect()
+}
+
+/// The `discovered` templates with the choices in `registry` applied, followed by the user's
+/// own. Untouched bundled templates are enabled only if they are the stock [`DEFAULT_TEMPLATE`].
+pub fn known(
+    registry: &[TemplateEntry],
+    discovered: &[(PathBuf, TemplateKind)],
+) -> Vec<KnownTemplate> {
+    let mut templates: Vec<KnownTemplate> = discovered
+        .iter()
+        .map(|(path, kind)| {
+            let entry = registry.iter().find(|entry| entry.path == *path);
+            KnownTemplate {
+                path: path.clone(),
+                kind: kind.clone(),
+                enabled: entry.map_or_else(
+                    || {
+                        *kind == TemplateKind::Bundled
+                            && path
+                                .file_name()
+                                .is_some_and(|name| name == DEFAULT_TEMPLATE)
+                    },
+                    |entry| entry.enabled,
+                ),
+                threshold: entry.and_then(|entry| entry.threshold),
+            }
+        })
+        .collect();
+    templates.extend(
+        registry
+            .iter()
+            .filter(|entry| !discovered.iter().any(|(path, _)| *path == entry.path))
+            .map(|entry| KnownTemplate {
+                path: entry.path.clone(),
+                kind: TemplateKind::User,
+                enabled: entry.enabled,
+                threshold: entry.threshold,
+            }),
+    );
+    templates
+}
+
+/// Stores the choices made for `template` in `registry`.
+pub fn update(registry: &mut Vec<TemplateEntry>, template: &KnownTemplate) {
+    match registry
+        .iter_mut()
+        .find(|entry| entry.path == template.path)
+    {
+        Some(entry) => *entry = template.entry(),
+        None => registry.push(template.entry()),
+    }
+}
+
+/// Adds a user template, enabled; `false` if it is already listed.
+pub fn add(registry: &mut Vec<TemplateEntry>, path: PathBuf) -> bool {
+    if registry.iter().any(|entry| entry.path == path) {
+        return false;
+    }
+    registry.push(TemplateEntry {
+        path,
+        ..TemplateEntry::default()
+    });
+    true
+}
+
+/// Copies the image at `source` into `dir`, numbering the name (`name-2.png`, …) instead of
+/// overwriting a different file already there, and returns the copy's path.
+pub fn copy_into(source: &Path, dir: &Path) -> Result<PathBuf> {
+    if !is_image(source) {
+        bail!("{} is not a PNG image", source.display());
+    }
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let stem = source
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "template".to_string());
+    let target = free_target(dir, &stem, |target| {
+        target.canonicalize().ok() == source.canonicalize().ok()
+    });
+    if !target.exists() {
+        fs::copy(source, &target)
+            .with_context(|| format!("Failed to copy template to {}", target.display()))?;
+    }
+    Ok(target)
+}
+
+/// Writes [`EMBEDDED_TEMPLATE`] into `dir` under the [`DEFAULT_TEMPLATE`] name, numbered like
+/// [`copy_into`] when a different file has it, and returns its path.
+pub fn write_embedded(dir: &Path) -> Result<PathBuf> {
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let stem = DEFAULT_TEMPLATE.trim_end_matches(".png");
+    let target = free_target(dir, stem, |target| {
+        fs::read(target).is_ok_and(|bytes| bytes == EMBEDDED_TEMPLATE)
+    });
+    if !target.exists() {
+        fs::write(&target, EMBEDDED_TEMPLATE)
+            .with_context(|| format!("Failed to write template {}", target.display()))?;
+    }
+    Ok(target)
+}
+
+/// `dir/stem.png`, or the first numbered name (`stem-2.png`, …) that is free or holds a file
+/// `same` accepts.
+fn free_target(dir: &Path, stem: &str, same: impl Fn(&Path) -> bool) -> PathBuf {
+    let mut target = dir.join(format!("{stem}.png"));
+    let mut counter = 2;
+    while target.exists() && !same(&target) {
+        target = dir.join(format!("{stem}-{counter}.png"));
+        counter += 1;
+    }
+    target
+}
+
+/// Forgets the entry for `path`: a user template disappears, a bundled one returns to defaults.
+pub fn remove(registry: &mut Vec<TemplateEntry>, path: &Path) {
+    registry.retain(|entry| entry.path != path);
+}
+
+/// Pixel sizes of the templates [`load_enabled`] would match with; unreadable ones are skipped.
+pub fn enabled_sizes(config: &AppConfig) -> Vec<(u32, u32)> {
+    let paths: Vec<PathBuf> = if config.template_path.is_some() {
+        config.resolve_template_path().into_iter().collect()
+    } else {
+        known(&config.templates, &discover(&bundled_dirs()))
+            .into_iter()
+            .filter(|template| template.enabled)
+            .map(|template| template.path)
+            .collect()
+    };
+    paths
+        .iter()
+        .filter_map(|path| image::image_dimensions(path).ok())
+        .collect()
+}
+
+/// Templates to match with: the configured template path alone when set, otherwise every
+/// enabled template in the registry. Templates that fail to load are skipped with a warning.
+pub fn load_enabled(config: &AppConfig) -> Result<Vec<Template>> {
+    if config.template_path.is_some() {
+        return Ok(vec![detect::load_template_scaled(
+            &config.resolve_template_path()?,
+            &config.template_scales,
+        )?]);
+    }
+    let enabled: Vec<KnownTemplate> = known(&config.templates, &discover(&bundled_dirs()))
+        .into_iter()
+        .filter(|template| template.enabled)
+        .collect();
+    if enabled.is_empty() {
+        bail!("No template is enabled; enable one under Manage templates");
+    }
+    let templates: Vec<Template> = enabled
+        .iter()
+        .filter_map(|known| {
+            match detect::load_template_scaled(&known.path, &config.template_scales) {
+                Ok(template) => Some(template.with_threshold(known.threshold)),
+                Err(err) => {
+                    warn!(path = %known.path.display(), error = ?err, "skipping template");
+                    None
+                }
+            }
+        })
+        .collect();
+    if templates.is_empty() {
+        bail!("None of the enabled templates could be loaded");
+    }
+    Ok(templates)
+}
@@ -0,0 +1,56 @@
+//! GUI theme: system, dark or light visuals with an optional accent color.
+
+use std::ops::RangeInclusive;
+
+use egui::{Color32, Visuals};
+
+use crate::config::{ThemeMode, ThemeSettings};
+
+/// Zoom range offered by the *UI scale* slider.
+pub const UI_SCALE_RANGE: RangeInclusive<f32> = 0.5..=3.0;
+
+/// Pixels per point for the display's `native` scaling zoomed by `ui_scale`, which is clamped to
+/// [`UI_SCALE_RANGE`] so a hand-edited config cannot make the window unusable.
+pub fn pixels_per_point(native: f32, ui_scale: f32) -> f32 {
+    let scale = if ui_scale.is_finite() {
+        ui_scale.clamp(*UI_SCALE_RANGE.start(), *UI_SCALE_RANGE.end())
+    } else {
+        1.0
+    };
+    native * scale
+}
+
+/// Visuals for `settings`; `system_dark` is the OS preference used by [`ThemeMode::System`].
+pub fn visuals(settings: &ThemeSettings, system_dark: bool) -> Visuals {
+    let dark = match settings.mode {
+        ThemeMode::System => system_dark,
+        ThemeMode::Dark => true,
+        ThemeMode::Light => false,
+    };
+    let mut visuals = if dark {
+        Visuals::dark()
+    } else {
+        Visuals::light()
+    };
+    if let Some([r, g, b]) = settings.accent {
+        let accent = Color32::from_rgb(r, g, b);
+        visuals.selection.bg_fill = accent;
+        visuals.selection.stroke.color = contrasting_text(accent);
+        visuals.hyperlink_color = accent;
+        visuals.widgets.hovered.bg_stroke.color = accent;
+        visuals.widgets.active.bg_fill = accent;
+        visuals.widgets.active.weak_bg_fill = accent;
+    }
+    visuals
+}
+
+/// Black or white, whichever reads better on `background`.
+pub fn contrasting_text(background: Color32) -> Color32 {
+    let [r, g, b, _] = background.to_array();
+    let luma = 0.299 * f32::from(r) + 0.587 * f32::from(g) + 0.114 * f32::from(b);
+    if luma > 150.0 {
+        Color32::BLACK
+    } else {
+        Color32::WHITE
+    }
+}
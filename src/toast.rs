@@ -0,0 +1,115 @@
+//! Short-lived pop-up notifications stacked in a corner of the window, so transient events
+//! (configuration saved, detection, worker errors) stay readable after the status line has moved on.
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// More than this many toasts drops the oldest.
+pub const MAX_TOASTS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastKind {
+    /// How long a toast of this kind stays up; problems linger so they can be read.
+    pub fn duration(self) -> Duration {
+        match self {
+            Self::Info | Self::Success => Duration::from_secs(4),
+            Self::Warning => Duration::from_secs(6),
+            Self::Error => Duration::from_secs(10),
+        }
+    }
+
+    /// Blue, green, yellow or red, as RGB.
+    pub fn color(self) -> [u8; 3] {
+        match self {
+            Self::Info => [0x5a, 0x9b, 0xd5],
+            Self::Success => [0x3c, 0xb3, 0x71],
+            Self::Warning => [0xe6, 0xb4, 0x22],
+            Self::Error => [0xd9, 0x48, 0x3b],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    pub kind: ToastKind,
+    pub text: String,
+    pub shown_at: Instant,
+    /// How many times the same message arrived while this toast was up.
+    pub repeats: u32,
+}
+
+impl Toast {
+    pub fn expires_at(&self) -> Instant {
+        self.shown_at + self.kind.duration()
+    }
+
+    /// Share of the display time still left, from 1.0 down to 0.0.
+    pub fn remaining(&self, now: Instant) -> f32 {
+        let left = self.expires_at().saturating_duration_since(now);
+        left.as_secs_f32() / self.kind.duration().as_secs_f32()
+    }
+}
+
+/// The visible toasts, oldest first.
+#[derive(Debug, Default)]
+pub struct Toasts {
+    items: VecDeque<Toast>,
+}
+
+impl Toasts {
+    /// Shows `text`; a repeat of the newest toast restarts its timer instead of stacking.
+    pub fn push(&mut self, kind: ToastKind, text: impl Into<String>, now: Instant) {
+        let text = text.into();
+        if let Some(last) = self.items.back_mut()
+            && last.kind == kind
+            && last.text == text
+        {
+            last.shown_at = now;
+            last.repeats += 1;
+            return;
+        }
+        if self.items.len() >= MAX_TOASTS {
+            self.items.pop_front();
+        }
+        self.items.push_back(Toast {
+            kind,
+            text,
+            shown_at: now,
+            repeats: 0,
+        });
+    }
+
+    /// Drops expired toasts.
+    pub fn prune(&mut self, now: Instant) {
+        self.items.retain(|toast| toast.expires_at() > now);
+    }
+
+    pub fn dismiss(&mut self, index: usize) {
+        self.items.remove(index);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Toast> {
+        self.items.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Time until the next toast expires, for scheduling a repaint.
+    pub fn next_expiry(&self, now: Instant) -> Option<Duration> {
+        self.items
+            .iter()
+            .map(|toast| toast.expires_at().saturating_duration_since(now))
+            .min()
+    }
+}
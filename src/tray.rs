@@ -0,0 +1,228 @@
+//! System tray icon with Start/Stop/Show/Exit, so the window can be hidden while monitoring.
+//! Only Windows has a tray here; elsewhere [`Tray::create`] returns `None`.
+
+use crate::{
+    config::IndicatorPalette,
+    indicator::{self, Indicator},
+};
+
+/// A tray menu choice (or a click on the icon, which maps to `Show`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayCommand {
+    Start,
+    Stop,
+    Show,
+    Exit,
+}
+
+pub struct Tray {
+    inner: platform::Tray,
+}
+
+impl Tray {
+    /// Creates the icon. Tray events wake `ctx` so they are handled while the window is hidden.
+    pub fn create(ctx: &egui::Context) -> Option<Self> {
+        platform::Tray::create(ctx).map(|inner| Self { inner })
+    }
+
+    /// Next pending command, if any.
+    pub fn poll(&self) -> Option<TrayCommand> {
+        self.inner.poll()
+    }
+
+    /// Enables Start or Stop to match the worker state.
+    pub fn set_running(&self, running: bool) {
+        self.inner.set_running(running);
+    }
+
+    /// Redraws the icon for `indicator` in `palette` and updates the tooltip.
+    pub fn set_indicator(&self, indicator: Indicator, palette: IndicatorPalette) {
+        self.inner.set_indicator(indicator, palette);
+    }
+}
+
+/// Round badge used as the tray and taskbar icon, `size`×`size` RGBA: a gold rim around a
+/// center in the [`Indicator`] color, with the state's shape cut into it unless `palette` is the
+/// standard one.
+pub fn icon_rgba(size: u32, indicator: Indicator, palette: IndicatorPalette) -> Vec<u8> {
+    let color = indicator.color(palette);
+    let [r, g, b] = color;
+    let [mr, mg, mb] = indicator::contrasting(color);
+    let shape = indicator.shape(palette);
+    let center = (size as f32 - 1.0) / 2.0;
+    let radius = size as f32 / 2.0;
+    let inner = radius * 0.75;
+    let mut rgba = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let distance = ((x as f32 - center).powi(2) + (y as f32 - center).powi(2)).sqrt();
+            let pixel = if distance > radius {
+                [0, 0, 0, 0]
+            } else if distance > inner {
+                [0x78, 0x5a, 0x28, 0xff]
+            } else if shape.marks((x as f32 - center) / inner, (y as f32 - center) / inner) {
+                [mr, mg, mb, 0xff]
+            } else {
+                [r, g, b, 0xff]
+            };
+            rgba.extend_from_slice(&pixel);
+        }
+    }
+    rgba
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::cell::Cell;
+
+    use crossbeam_channel::{Receiver, Sender};
+    use tracing::warn;
+    use tray_icon::{
+        Icon, MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent,
+        menu::{Menu, MenuEvent, MenuId, MenuItem, PredefinedMenuItem},
+    };
+
+    use super::{TrayCommand, icon_rgba};
+    use crate::{config::IndicatorPalette, indicator::Indicator};
+
+    const ICON_SIZE: u32 = 32;
+
+    pub struct Tray {
+        icon: TrayIcon,
+        start: MenuItem,
+        stop: MenuItem,
+        rx: Receiver<TrayCommand>,
+        running: Cell<Option<bool>>,
+        indicator: Cell<Option<(Indicator, IndicatorPalette)>>,
+    }
+
+    impl Tray {
+        pub fn create(ctx: &egui::Context) -> Option<Self> {
+            match build(ctx) {
+                Ok(tray) => Some(tray),
+                Err(err) => {
+                    warn!(error = %err, "tray icon unavailable");
+                    None
+                }
+            }
+        }
+
+        pub fn poll(&self) -> Option<TrayCommand> {
+            self.rx.try_recv().ok()
+        }
+
+        pub fn set_running(&self, running: bool) {
+            if self.running.replace(Some(running)) == Some(running) {
+                return;
+            }
+            self.start.set_enabled(!running);
+            self.stop.set_enabled(running);
+        }
+
+        pub fn set_indicator(&self, indicator: Indicator, palette: IndicatorPalette) {
+            let shown = Some((indicator, palette));
+            if self.indicator.replace(shown) == shown {
+                return;
+            }
+            let rgba = icon_rgba(ICON_SIZE, indicator, palette);
+            match Icon::from_rgba(rgba, ICON_SIZE, ICON_SIZE) {
+                Ok(icon) => {
+                    let _ = self.icon.set_icon(Some(icon));
+                }
+                Err(err) => warn!(error = %err, "failed to build tray icon"),
+            }
+            let tooltip = format!("LoL Auto Accept – {}", indicator.label().to_lowercase());
+            let _ = self.icon.set_tooltip(Some(tooltip));
+        }
+    }
+
+    fn build(ctx: &egui::Context) -> Result<Tray, Box<dyn std::error::Error>> {
+        let start = MenuItem::new("Start", true, None);
+        let stop = MenuItem::new("Stop", false, None);
+        let show = MenuItem::new("Show window", true, None);
+        let exit = MenuItem::new("Exit", true, None);
+        let menu = Menu::new();
+        menu.append_items(&[
+            &start,
+            &stop,
+            &PredefinedMenuItem::separator(),
+            &show,
+            &exit,
+        ])?;
+        let icon = TrayIconBuilder::new()
+            .with_menu(Box::new(menu))
+            .with_tooltip("LoL Auto Accept")
+            .with_icon(Icon::from_rgba(
+                icon_rgba(ICON_SIZE, Indicator::Idle, IndicatorPalette::Standard),
+                ICON_SIZE,
+                ICON_SIZE,
+            )?)
+            .build()?;
+
+        let (tx, rx) = crossbeam_channel::unbounded();
+        let ids: [(MenuId, TrayCommand); 4] = [
+            (start.id().clone(), TrayCommand::Start),
+            (stop.id().clone(), TrayCommand::Stop),
+            (show.id().clone(), TrayCommand::Show),
+            (exit.id().clone(), TrayCommand::Exit),
+        ];
+        let menu_tx = tx.clone();
+        let menu_ctx = ctx.clone();
+        MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
+            if let Some((_, command)) = ids.iter().find(|(id, _)| *id == event.id) {
+                forward(&menu_tx, &menu_ctx, *command);
+            }
+        }));
+        let icon_ctx = ctx.clone();
+        TrayIconEvent::set_event_handler(Some(move |event: TrayIconEvent| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                forward(&tx, &icon_ctx, TrayCommand::Show);
+            }
+        }));
+
+        Ok(Tray {
+            icon,
+            start,
+            stop,
+            rx,
+            running: Cell::new(None),
+            indicator: Cell::new(None),
+        })
+    }
+
+    fn forward(tx: &Sender<TrayCommand>, ctx: &egui::Context, command: TrayCommand) {
+        let _ = tx.send(command);
+        ctx.request_repaint();
+    }
+}
+
+#[cfg(not(windows))]
+mod platform {
+    use super::TrayCommand;
+    use crate::{config::IndicatorPalette, indicator::Indicator};
+
+    pub enum Tray {}
+
+    impl Tray {
+        pub fn create(_ctx: &egui::Context) -> Option<Self> {
+            None
+        }
+
+        pub fn poll(&self) -> Option<TrayCommand> {
+            match *self {}
+        }
+
+        pub fn set_running(&self, _running: bool) {
+            match *self {}
+        }
+
+        pub fn set_indicator(&self, _indicator: Indicator, _palette: IndicatorPalette) {
+            match *self {}
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Threshold tuning from live captures: match scores with the ready check on screen and without
+//! it, and the threshold that separates the two.
+
+use std::{thread, time::Duration};
+
+use anyhow::{Context, Result};
+use crossbeam_channel::Sender;
+
+use crate::{capture, config::AppConfig, detect, templates};
+
+/// Frames captured each time the user presses *Capture*.
+pub const SAMPLES_PER_CAPTURE: usize = 5;
+/// Pause between those frames; five of them fit well inside the ten seconds a ready check stays.
+pub const SAMPLE_SPACING: Duration = Duration::from_millis(400);
+/// Range of the threshold slider; recommendations outside it are not offered.
+pub const MIN_THRESHOLD: f32 = 0.5;
+pub const MAX_THRESHOLD: f32 = 0.99;
+
+/// Whether the ready check was on screen while a frame was captured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Absent,
+    Present,
+}
+
+/// Best scores collected so far, per phase.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Samples {
+    pub absent: Vec<f32>,
+    pub present: Vec<f32>,
+}
+
+impl Samples {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, phase: Phase, score: f32) {
+        match phase {
+            Phase::Absent => self.absent.push(score),
+            Phase::Present => self.present.push(score),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.absent.clear();
+        self.present.clear();
+    }
+
+    /// The closest scores of the two phases; `None` until both have at least one sample.
+    pub fn separation(&self) -> Option<Separation> {
+        let absent_max = self.absent.iter().copied().reduce(f32::max)?;
+        let present_min = self.present.iter().copied().reduce(f32::min)?;
+        Some(Separation {
+            absent_max,
+            present_min,
+        })
+    }
+}
+
+/// Highest score without the dialog against the lowest one with it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Separation {
+    pub absent_max: f32,
+    pub present_min: f32,
+}
+
+impl Separation {
+    /// Margin between the phases; zero or negative when a single threshold cannot tell them
+    /// apart.
+    pub fn gap(&self) -> f32 {
+        self.present_min - self.absent_max
+    }
+
+    /// The midpoint of the gap, so both kinds of frame keep the same margin. `None` when the
+    /// phases overlap or the midpoint falls outside the slider range.
+    pub fn recommended(&self) -> Option<f32> {
+        if self.gap() <= 0.0 {
+            return None;
+        }
+        let midpoint = (self.absent_max + self.present_min) / 2.0;
+        (MIN_THRESHOLD..=MAX_THRESHOLD)
+            .contains(&midpoint)
+            .then_some(midpoint)
+    }
+}
+
+/// Captures `count` frames [`SAMPLE_SPACING`] apart and sends the best match score of each.
+/// Stops at the first error, after sending it.
+pub fn sample(config: &AppConfig, count: usize, tx: &Sender<Result<f32>>) {
+    let templates = match templates::load_enabled(config) {
+        Ok(templates) => templates,
+        Err(err) => {
+            let _ = tx.send(Err(err));
+            return;
+        }
+    };
+    let source = config.effective_capture_source();
+    for index in 0..count {
+        if index > 0 {
+            thread::sleep(SAMPLE_SPACING);
+        }
+        let score = capture::capture_once(&source, config.monitor_index).and_then(|frame| {
+            detect::detect_any(
+                &frame.image,
+                &templates,
+                config.search_region,
+                config.threshold,
+            )
+            .map(|detection| detection.score)
+            .context("the template is larger than the captured screen")
+        });
+        let failed = score.is_err();
+        if tx.send(score).is_err() || failed {
+            return;
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Update checks against the project's GitHub releases and in-place replacement of the running
+//! executable (`self_update`). Nothing is downloaded until the user confirms in the GUI.
+
+use anyhow::{Context, Result, bail};
+use self_update::{
+    VerifyingKey,
+    backends::github::{ReleaseList, Update},
+    update::Release,
+    version::bump_is_greater,
+};
+
+pub const REPO_OWNER: &str = "mrdeadlift";
+pub const REPO_NAME: &str = "HextechReady";
+/// Version of the running build.
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Executable name inside the release archives.
+pub const BIN_NAME: &str = env!("CARGO_PKG_NAME");
+/// Public halves of the ed25519 keys release archives are signed with (`zipsign sign zip`). A
+/// download without a valid signature from one of them is never installed; while the list is
+/// empty this build only checks for updates and links to the release.
+pub static VERIFYING_KEYS: &[VerifyingKey] = &[];
+
+/// Whether this build can verify downloads, and so install updates itself.
+pub fn can_install() -> bool {
+    !VERIFYING_KEYS.is_empty()
+}
+
+/// A newer release the user can install.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvailableUpdate {
+    pub version: String,
+    pub name: String,
+    pub date: String,
+    /// Release notes as written on GitHub (Markdown).
+    pub changelog: String,
+    pub url: Option<String>,
+}
+
+impl AvailableUpdate {
+    fn from_release(release: &Release) -> Self {
+        Self {
+            version: release.version().to_string(),
+            name: release.name().to_string(),
+            date: release.date().to_string(),
+            changelog: release.body().unwrap_or_default().trim().to_string(),
+            url: release.release_notes_url().map(str::to_string),
+        }
+    }
+}
+
+/// The newest of `releases` that is newer than `current` and ships a build for `target`.
+/// Releases with an unparsable version are skipped.
+pub fn newest_update(current: &str, target: &str, releases: &[Release]) -> Option<AvailableUpdate> {
+    releases
+        .iter()
+        .filter(|release| release.asset_for(target, None).is_some())
+        .filter(|release| bump_is_greater(current, release.version()).unwrap_or(false))
+        .reduce(|best, release| {
+            if bump_is_greater(best.version(), release.version()).unwrap_or(false) {
+                release
+            } else {
+                best
+            }
+        })
+        .map(AvailableUpdate::from_release)
+}
+
+/// Asks GitHub for the release list; `None` when this build is the newest.
+pub fn check() -> Result<Option<AvailableUpdate>> {
+    let releases = ReleaseList::configure()
+        .repo_owner(REPO_OWNER)
+        .repo_name(REPO_NAME)
+        .build()
+        .context("Failed to configure the release check")?
+        .fetch()
+        .context("Failed to fetch the release list")?;
+    Ok(newest_update(
+        CURRENT_VERSION,
+        self_update::get_target(),
+        releases.all(),
+    ))
+}
+
+/// Downloads release `version`, checks its signature against [`VERIFYING_KEYS`] and swaps it in
+/// for the running executable; the new version runs after a restart.
+pub fn install(version: &str) -> Result<()> {
+    // `self_update` skips the signature check when it has no keys, so refuse here instead.
+    if !can_install() {
+        bail!("This build cannot verify downloads; get version {version} from the release page");
+    }
+    let status = Update::configure()
+        .repo_owner(REPO_OWNER)
+        .repo_name(REPO_NAME)
+        .bin_name(BIN_NAME)
+        .current_version(CURRENT_VERSION)
+        .release_tag(format!("v{version}"))
+        .show_download_progress(false)
+        .verifying_keys(VERIFYING_KEYS)
+        // The GUI already asked; without this `update` would block on a stdin prompt.
+        .unattended()
+        .build()
+        .context("Failed to configure the update")?
+        .update()
+        .with_context(|| format!("Failed to install version {version}"))?;
+    tracing::info!(version = status.version(), "update installed");
+    Ok(())
+}
@@ -0,0 +1,229 @@
+//! Top-level window queries used to guard synthetic input.
+
+use anyhow::Result;
+
+/// Opaque native window handle (`HWND` on Windows, X11 window id on Linux).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowId(pub u64);
+
+/// The window that currently has keyboard focus, or `None` when it cannot be determined on
+/// this platform.
+pub fn foreground_window() -> Option<WindowId> {
+    platform::foreground_window()
+}
+
+/// Finds a top-level window by its exact title.
+pub fn find_window(title: &str) -> Option<WindowId> {
+    platform::find_window(title)
+}
+
+/// Brings `window` to the foreground and gives it keyboard focus.
+pub fn focus(window: WindowId) -> Result<()> {
+    platform::focus(window)
+}
+
+/// Top-level windows of this process, such as the main window and the detection overlay.
+pub fn own_windows() -> Vec<WindowId> {
+    platform::own_windows()
+}
+
+/// Keeps `window` out of screenshots, recordings and screen sharing (`excluded`), or lets it be
+/// captured again.
+pub fn set_capture_excluded(window: WindowId, excluded: bool) -> Result<()> {
+    platform::set_capture_excluded(window, excluded)
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::ptr;
+
+    use anyhow::{Result, bail};
+    use windows_sys::Win32::{
+        Foundation::{BOOL, HWND, LPARAM},
+        System::Threading::{AttachThreadInput, GetCurrentProcessId, GetCurrentThreadId},
+        UI::WindowsAndMessaging::{
+            BringWindowToTop, EnumWindows, FindWindowW, GetForegroundWindow,
+            GetWindowThreadProcessId, IsIconic, SW_RESTORE, SetForegroundWindow,
+            SetWindowDisplayAffinity, ShowWindow, WDA_EXCLUDEFROMCAPTURE, WDA_MONITOR, WDA_NONE,
+        },
+    };
+
+    use super::WindowId;
+
+    pub fn foreground_window() -> Option<WindowId> {
+        // SAFETY: no arguments; returns null when no window is focused.
+        let hwnd = unsafe { GetForegroundWindow() };
+        (!hwnd.is_null()).then_some(WindowId(hwnd as u64))
+    }
+
+    pub fn find_window(title: &str) -> Option<WindowId> {
+        let wide: Vec<u16> = title.encode_utf16().chain(Some(0)).collect();
+        // SAFETY: `wide` is a NUL-terminated UTF-16 string that outlives the call.
+        let hwnd = unsafe { FindWindowW(ptr::null(), wide.as_ptr()) };
+        (!hwnd.is_null()).then_some(WindowId(hwnd as u64))
+    }
+
+    pub fn focus(window: WindowId) -> Result<()> {
+        let hwnd = window.0 as HWND;
+        // SAFETY: plain Win32 calls on a window handle; a stale handle just makes them fail.
+        unsafe {
+            if IsIconic(hwnd) != 0 {
+                ShowWindow(hwnd, SW_RESTORE);
+            }
+            // Windows only lets the foreground thread hand out focus, so temporarily share its
+            // input state.
+            let foreground = GetForegroundWindow();
+            let foreground_thread = GetWindowThreadProcessId(foreground, ptr::null_mut());
+            let current_thread = GetCurrentThreadId();
+            let attached = foreground_thread != 0
+                && foreground_thread != current_thread
+                && AttachThreadInput(current_thread, foreground_thread, 1) != 0;
+            BringWindowToTop(hwnd);
+            let focused = SetForegroundWindow(hwnd) != 0;
+            if attached {
+                AttachThreadInput(current_thread, foreground_thread, 0);
+            }
+            if !focused {
+                bail!("SetForegroundWindow was refused");
+            }
+        }
+        Ok(())
+    }
+
+    pub fn own_windows() -> Vec<WindowId> {
+        unsafe extern "system" fn collect(hwnd: HWND, windows: LPARAM) -> BOOL {
+            let mut process = 0;
+            // SAFETY: `windows` is the `Vec` passed to `EnumWindows` below, alive for the call.
+            unsafe {
+                GetWindowThreadProcessId(hwnd, &mut process);
+                if process == GetCurrentProcessId() {
+                    (*(windows as *mut Vec<WindowId>)).push(WindowId(hwnd as u64));
+                }
+            }
+            1
+        }
+        let mut windows: Vec<WindowId> = Vec::new();
+        // SAFETY: the callback only runs during the call and only touches `windows`.
+        unsafe { EnumWindows(Some(collect), &mut windows as *mut Vec<WindowId> as LPARAM) };
+        windows
+    }
+
+    pub fn set_capture_excluded(window: WindowId, excluded: bool) -> Result<()> {
+        let hwnd = window.0 as HWND;
+        // SAFETY: plain Win32 calls on a window handle; a stale handle just makes them fail.
+        let applied = unsafe {
+            if excluded {
+                // Before Windows 10 2004 only blacking the window out is available.
+                SetWindowDisplayAffinity(hwnd, WDA_EXCLUDEFROMCAPTURE) != 0
+                    || SetWindowDisplayAffinity(hwnd, WDA_MONITOR) != 0
+            } else {
+                SetWindowDisplayAffinity(hwnd, WDA_NONE) != 0
+            }
+        };
+        if !applied {
+            bail!("SetWindowDisplayAffinity was refused");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use anyhow::{Context, Result, bail};
+    use x11rb::{
+        connection::Connection,
+        protocol::xproto::{AtomEnum, ClientMessageEvent, ConnectionExt as _, EventMask, Window},
+        rust_connection::RustConnection,
+    };
+
+    use super::WindowId;
+
+    fn atom(conn: &RustConnection, name: &[u8]) -> Option<u32> {
+        Some(conn.intern_atom(false, name).ok()?.reply().ok()?.atom)
+    }
+
+    fn root_windows(conn: &RustConnection, root: Window, property: &[u8]) -> Option<Vec<u32>> {
+        let property = atom(conn, property)?;
+        let reply = conn
+            .get_property(false, root, property, AtomEnum::WINDOW, 0, u32::MAX / 4)
+            .ok()?
+            .reply()
+            .ok()?;
+        Some(reply.value32()?.collect())
+    }
+
+    pub fn foreground_window() -> Option<WindowId> {
+        let (conn, screen_num) = x11rb::connect(None).ok()?;
+        let root = conn.setup().roots[screen_num].root;
+        root_windows(&conn, root, b"_NET_ACTIVE_WINDOW")?
+            .first()
+            .filter(|&&window| window != 0)
+            .map(|&window| WindowId(window as u64))
+    }
+
+    pub fn find_window(title: &str) -> Option<WindowId> {
+        let (conn, screen_num) = x11rb::connect(None).ok()?;
+        let root = conn.setup().roots[screen_num].root;
+        let name = atom(&conn, b"_NET_WM_NAME")?;
+        let utf8 = atom(&conn, b"UTF8_STRING")?;
+        root_windows(&conn, root, b"_NET_CLIENT_LIST")?
+            .into_iter()
+            .find(|&window| {
+                conn.get_property(false, window, name, utf8, 0, 1024)
+                    .ok()
+                    .and_then(|cookie| cookie.reply().ok())
+                    .is_some_and(|reply| reply.value == title.as_bytes())
+            })
+            .map(|window| WindowId(window as u64))
+    }
+
+    pub fn focus(window: WindowId) -> Result<()> {
+        let (conn, screen_num) = x11rb::connect(None).context("Failed to connect to X server")?;
+        let root = conn.setup().roots[screen_num].root;
+        let active = atom(&conn, b"_NET_ACTIVE_WINDOW").context("_NET_ACTIVE_WINDOW missing")?;
+        // Source indication 2 = pager, which window managers honour without focus-stealing checks.
+        let event = ClientMessageEvent::new(32, window.0 as Window, active, [2, 0, 0, 0, 0]);
+        conn.send_event(
+            false,
+            root,
+            EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY,
+            event,
+        )?;
+        conn.flush()?;
+        Ok(())
+    }
+    // X11 has no per-window capture exclusion.
+    pub fn own_windows() -> Vec<WindowId> {
+        Vec::new()
+    }
+
+    pub fn set_capture_excluded(_window: WindowId, _excluded: bool) -> Result<()> {
+        bail!("Hiding windows from capture is not supported on X11")
+    }
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+mod platform {
+    use anyhow::{Result, bail};
+
+    use super::WindowId;
+
+    pub fn foreground_window() -> Option<WindowId> {
+        None
+    }
+
+    pub fn find_window(_title: &str) -> Option<WindowId> {
+        None
+    }
+
+    pub fn focus(_window: WindowId) -> Result<()> {
+        bail!("Window focus is not supported on this platform")
+    }
+    pub fn own_windows() -> Vec<WindowId> {
+        Vec::new()
+    }
+
+    pub fn set_capture_excluded(_window: WindowId, _excluded: bool) -> Result<()> {
+        bail!("Hiding windows from capture is not supported on this platform")
+    }
+}
@@ -0,0 +1,1374 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use crossbeam_channel::{Receiver, Sender};
+use image::GrayImage;
+use rand::Rng;
+use tracing::{error, info, warn};
+
+use crate::{
+    actions::{Dispatcher, InputAction},
+    capture::{self, CaptureBackend, CapturedFrame, FrameArena},
+    champ_select::{self, ChampSelectAutomation, ChampSelectOutcome, ChampSelectReport},
+    config::{AcceptMode, AppConfig, InputSettings},
+    detect::{self, Detection, Template},
+    elevation,
+    gameflow::{self, QueueStatus, Requeue, RequeueOutcome},
+    honor,
+    hotplug::DisplayChanges,
+    idle,
+    input::{self, ActivityTracker, InputBackend},
+    lcu::{self, LcuClient, LcuEventStream, LcuMessage},
+    metrics::{self, MetricsAccumulator, WorkerMetrics},
+    near_miss::{NearMissWatch, PossibleMiss},
+    notifications,
+    power::{self, PowerSource, PowerWatch},
+    sound::SoundPlayer,
+    speech::{Announcement, Announcer},
+    window::{self, WindowId},
+};
+
+const METRICS_INTERVAL: Duration = Duration::from_secs(1);
+/// Width detection thumbnails are shrunk to.
+const THUMBNAIL_WIDTH: u32 = 96;
+/// Longest an accept is held back waiting for the user's mouse to settle; the dialog only
+/// stays up for a few seconds.
+const MAX_IDLE_WAIT: Duration = Duration::from_secs(4);
+const IDLE_POLL: Duration = Duration::from_millis(25);
+/// How far (physical px) a re-captured match may drift and still count as the same button.
+const REVERIFY_TOLERANCE_PX: u32 = 6;
+const FOCUS_SETTLE: Duration = Duration::from_millis(60);
+/// The honor ballot is filled in shortly after the post-game phase starts.
+const HONOR_DELAY: Duration = Duration::from_millis(1_500);
+
+/// Settings a running worker picks up between frames; everything else applies from the next
+/// start.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LiveSettings {
+    pub threshold: f32,
+    pub interval_ms: u64,
+    pub cooldown_ms: u64,
+    pub dry_run: bool,
+    pub skip_unchanged: bool,
+}
+
+impl LiveSettings {
+    pub fn of(config: &AppConfig) -> Self {
+        Self {
+            threshold: config.threshold,
+            interval_ms: config.interval_ms,
+            cooldown_ms: config.cooldown_ms,
+            dry_run: config.dry_run,
+            skip_unchanged: config.skip_unchanged,
+        }
+    }
+
+    pub fn apply_to(&self, config: &mut AppConfig) {
+        config.threshold = self.threshold;
+        config.interval_ms = self.interval_ms;
+        config.cooldown_ms = self.cooldown_ms;
+        config.dry_run = self.dry_run;
+        config.skip_unchanged = self.skip_unchanged;
+    }
+}
+
+/// Requests shared between a worker and whoever started it.
+#[derive(Debug, Clone, Default)]
+pub struct WorkerControl {
+    pub stop: Arc<AtomicBool>,
+    /// Set to have the next captured frame sent back as [`WorkerEvent::Frame`].
+    pub frame_request: Arc<AtomicBool>,
+    /// Settings to switch to before the next frame.
+    pub live: Arc<Mutex<Option<LiveSettings>>>,
+}
+
+pub struct WorkerHandle {
+    control: WorkerControl,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl WorkerHandle {
+    pub fn request_stop(&self) {
+        self.control.stop.store(true, Ordering::Relaxed);
+    }
+
+    /// Asks for the next captured frame to be sent back as [`WorkerEvent::Frame`].
+    pub fn request_frame(&self) {
+        self.control.frame_request.store(true, Ordering::Relaxed);
+    }
+
+    /// Switches the running worker to `settings` before its next frame.
+    pub fn update(&self, settings: LiveSettings) {
+        *self
+            .control
+            .live
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(settings);
+    }
+
+    pub fn join(&mut self) {
+        if let Some(handle) = self.thread.take()
+            && let Err(err) = handle.join()
+        {
+            error!("Worker thread join error: {err:?}");
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(&self.thread, Some(handle) if handle.is_finished())
+    }
+}
+
+impl Drop for WorkerHandle {
+    fn drop(&mut self) {
+        self.request_stop();
+        self.join();
+    }
+}
+
+pub enum WorkerEvent {
+    Detection {
+        score: f32,
+        image_coords: (u32, u32),
+        screen_coords: (i32, i32),
+        template_size: (u32, u32),
+        /// Top-left and bottom-right corners of the match in logical desktop coordinates.
+        screen_rect: ((i32, i32), (i32, i32)),
+        scale: f32,
+        /// The matched region, for the detection history.
+        thumbnail: GrayImage,
+    },
+    Clicked {
+        screen_coords: (i32, i32),
+    },
+    /// Dry-run counterpart of `Clicked`: the click that would have been issued.
+    WouldClick {
+        screen_coords: (i32, i32),
+    },
+    /// An accept is about to be sent with `mode`; `attempt` counts from 1 per ready check.
+    AcceptAttempt {
+        mode: AcceptMode,
+        attempt: u32,
+    },
+    /// The ready-check dialog disappeared after `attempts` clicks.
+    ClickVerified {
+        attempts: u32,
+    },
+    /// The dialog was still visible after the last retry.
+    ClickUnconfirmed {
+        attempts: u32,
+    },
+    /// A ready check pushed over the client WebSocket was accepted through the API.
+    ReadyCheckAccepted,
+    /// Champ-select automation progress.
+    ChampSelect(ChampSelectOutcome),
+    /// The client WebSocket came up (`true`, screen detection paused) or went away.
+    LcuEventsLive(bool),
+    /// Gameflow phase, queue timer or ready check changed.
+    QueueStatus(QueueStatus),
+    /// Phase gating paused capture (`paused`) or let it run again, entering `phase`.
+    CaptureGated {
+        paused: bool,
+        phase: String,
+    },
+    /// A teammate was honored after the game.
+    Honored {
+        player: String,
+    },
+    /// Auto re-queue progress after a dodge.
+    Requeue(RequeueOutcome),
+    /// A click was cancelled at the last moment and will be retried on a later frame.
+    ClickAborted {
+        reason: String,
+    },
+    CooldownActive {
+        score: f32,
+        remaining_ms: u64,
+    },
+    /// A match was held back because the user is still at the keyboard.
+    UserActive {
+        idle_ms: u64,
+        required_ms: u64,
+    },
+    CaptureDegraded {
+        monitor_index: usize,
+        failures: u32,
+        retry_in_ms: u64,
+    },
+    CaptureRecovered {
+        monitor_index: usize,
+    },
+    MonitorsChanged {
+        monitor_index: usize,
+        monitor_count: usize,
+    },
+    Metrics(WorkerMetrics),
+    /// The frame asked for with [`WorkerHandle::request_frame`].
+    Frame(FrameSnapshot),
+    /// Best match score of a frame, whether or not it reached the threshold.
+    FrameScored(f32),
+    /// Several frames in a row scored just under the threshold.
+    PossibleMiss(PossibleMiss),
+    /// The machine switched between battery and mains power, and the pace with it.
+    PowerChanged {
+        on_battery: bool,
+        interval_ms: u64,
+        /// Template scales matched; `None` for all configured ones.
+        scales: Option<usize>,
+    },
+    /// The League client runs as administrator and this app does not, so clicks are ignored.
+    ElevationMismatch,
+    /// The worker thread panicked and is gone.
+    Crashed(String),
+    Error(String),
+    Info(String),
+    Stopped,
+}
+
+/// A captured frame with what the detector made of it, for the last-frame viewer.
+#[derive(Debug, Clone)]
+pub struct FrameSnapshot {
+    pub image: GrayImage,
+    /// Best match in the frame, whether or not it reached the threshold.
+    pub detection: Option<Detection>,
+    pub threshold: f32,
+}
+
+impl WorkerEvent {
+    /// Per-frame score, frame or periodic metrics: nothing a hidden window has to react to right
+    /// away.
+    pub fn is_routine(&self) -> bool {
+        matches!(
+            self,
+            Self::FrameScored(_) | Self::Frame(_) | Self::Metrics(_)
+        )
+    }
+}
+
+/// Where a click on `detection` lands in logical desktop coordinates: the configured anchor plus
+/// the click offset, before any retry jitter. Detection runs on physical pixels, so the anchor is
+/// converted through `frame`.
+pub fn click_point(config: &AppConfig, frame: &CapturedFrame, detection: &Detection) -> (i32, i32) {
+    let (anchor_x, anchor_y) = detection.anchor_point(config.click_anchor);
+    let (x, y) = frame.to_logical(anchor_x, anchor_y);
+    (x + config.click_offset_x, y + config.click_offset_y)
+}
+
+/// Forwards `events` to a new channel and calls `wake` after each one, passing
+/// [`WorkerEvent::is_routine`], so the GUI can sleep until an event arrives instead of polling.
+/// The relay ends with the worker.
+pub fn relay(
+    events: Receiver<WorkerEvent>,
+    wake: impl Fn(bool) + Send + 'static,
+) -> Result<Receiver<WorkerEvent>> {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    thread::Builder::new()
+        .name("lol-auto-accept-events".to_string())
+        .spawn(move || {
+            for event in events {
+                let routine = event.is_routine();
+                if tx.send(event).is_err() {
+                    break;
+                }
+                wake(routine);
+            }
+        })
+        .context("Failed to spawn worker event relay")?;
+    Ok(rx)
+}
+
+/// Starts the capture → detect → click loop on a background thread.
+pub fn spawn(
+    config: AppConfig,
+    templates: Vec<Template>,
+    backend: Box<dyn CaptureBackend>,
+    display_changes: Option<DisplayChanges>,
+) -> Result<(WorkerHandle, Receiver<WorkerEvent>)> {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    let control = WorkerControl::default();
+    let worker_control = control.clone();
+
+    let handle = thread::Builder::new()
+        .name("lol-auto-accept-worker".to_string())
+        .spawn(move || {
+            let events = tx.clone();
+            let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+                run_worker(
+                    config,
+                    templates,
+                    backend,
+                    display_changes,
+                    tx,
+                    worker_control,
+                )
+            }));
+            if let Err(payload) = outcome {
+                // The panic hook has already written the crash report.
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown panic".to_string());
+                let _ = events.send(WorkerEvent::Crashed(message));
+            }
+        })
+        .context("Failed to spawn worker thread")?;
+
+    Ok((
+        WorkerHandle {
+            control,
+            thread: Some(handle),
+        },
+        rx,
+    ))
+}
+
+pub fn run_worker(
+    config: AppConfig,
+    templates: Vec<Template>,
+    mut backend: Box<dyn CaptureBackend>,
+    mut display_changes: Option<DisplayChanges>,
+    events_tx: Sender<WorkerEvent>,
+    control: WorkerControl,
+) {
+    let mut config = config;
+    config.notifications = config.effective_notifications();
+    let mut clicks = ClickState::new(Instant::now());
+    let mut pace = power::pace(&config, PowerSource::Mains);
+    info!(
+        monitor = config.monitor_index,
+        backend = backend.name(),
+        input = ?config.input.backend,
+        threshold = config.threshold,
+        dry_run = config.dry_run,
+        cooldown_ms = config.cooldown_ms,
+        interval_ms = config.interval_ms,
+        "worker started"
+    );
+
+    if events_tx
+        .send(WorkerEvent::Info("Monitoring active".to_string()))
+        .is_err()
+    {
+        return;
+    }
+
+    if elevation::affects(&config) && elevation::client_mismatch() {
+        warn!(
+            "the League client runs as administrator and this app does not; clicks will be ignored"
+        );
+        let _ = events_tx.send(WorkerEvent::ElevationMismatch);
+    }
+
+    let mut dispatcher = match input::open_backend(&config.input) {
+        Ok(backend) => Dispatcher::new(
+            backend,
+            Duration::from_millis(config.input.min_action_gap_ms),
+        ),
+        Err(err) => {
+            error!(error = ?err, "failed to open input backend");
+            let message = format!("Input backend: {err:#}");
+            notifications::notify_error(&config.notifications, &message);
+            let _ = events_tx.send(WorkerEvent::Error(message));
+            let _ = events_tx.send(WorkerEvent::Stopped);
+            return;
+        }
+    };
+
+    const FAILURES_BEFORE_REENUMERATE: u32 = 3;
+    let initial_monitors = backend.monitors().unwrap_or_default();
+    if let Some(id) = config.monitor_id
+        && !initial_monitors.iter().any(|monitor| monitor.id == id)
+    {
+        info!(
+            monitor_id = id,
+            monitor = config.monitor_index,
+            "configured monitor id not found, using index"
+        );
+    }
+    let mut monitor_index =
+        capture::remap_monitor_index(&initial_monitors, config.monitor_id, config.monitor_index)
+            .unwrap_or(config.monitor_index);
+    // Follow the physical display rather than its position in the list across hotplug events.
+    let mut target_id = initial_monitors
+        .get(monitor_index)
+        .map(|monitor| monitor.id);
+    let mut arena = FrameArena::default();
+    let mut metrics = MetricsAccumulator::new(METRICS_INTERVAL, Instant::now());
+    let mut failures = 0u32;
+    let mut degraded = false;
+    // Signature of the last frame and its match result, kept when `skip_unchanged` is on.
+    let mut last_match: Option<(u64, Option<Detection>)> = None;
+    let mut near_miss = NearMissWatch::new();
+    let mut power_watch = config
+        .battery
+        .enabled
+        .then(|| PowerWatch::new(Instant::now()));
+    // Templates limited to the battery saver's scales, while it is active.
+    let mut paced_templates: Option<Vec<Template>> = None;
+    let topics: Vec<String> = [
+        (
+            config.lcu.listen_events || config.lcu.queue_status,
+            lcu::READY_CHECK_EVENT,
+        ),
+        (
+            config.lcu.queue_status
+                || config.lcu.gate_capture
+                || config.requeue.enabled
+                || config.honor.enabled,
+            gameflow::PHASE_EVENT,
+        ),
+        (config.lcu.queue_status, gameflow::SEARCH_EVENT),
+        (
+            config.champ_select_automation(),
+            champ_select::SESSION_EVENT,
+        ),
+    ]
+    .into_iter()
+    .filter(|(enabled, _)| *enabled)
+    .map(|(_, topic)| topic.to_string())
+    .collect();
+    let lcu_events =
+        (!topics.is_empty()).then(|| LcuEventStream::spawn(config.lcu.lockfile.clone(), topics));
+    let mut lcu_state = LcuState::default();
+    let mut champ_select = ChampSelectAutomation::new();
+
+    while !control.stop.load(Ordering::Relaxed) {
+        let now = Instant::now();
+        let live = control
+            .live
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        if let Some(settings) = live {
+            settings.apply_to(&mut config);
+            let source = power_watch
+                .as_ref()
+                .map_or(PowerSource::Mains, PowerWatch::source);
+            pace = power::pace(&config, source);
+            last_match = None;
+            info!(
+                threshold = config.threshold,
+                dry_run = config.dry_run,
+                cooldown_ms = config.cooldown_ms,
+                interval_ms = pace.interval.as_millis() as u64,
+                skip_unchanged = pace.skip_unchanged,
+                "settings updated"
+            );
+        }
+        if metrics.is_due(now) {
+            let _ = events_tx.send(WorkerEvent::Metrics(metrics.take(
+                now,
+                arena.stats(),
+                metrics::resident_memory(),
+            )));
+        }
+
+        if let Some(watch) = &mut power_watch {
+            match watch.poll(now, power::current) {
+                Ok(Some(source)) => {
+                    pace = power::pace(&config, source);
+                    paced_templates = pace.scales.as_ref().map(|scales| {
+                        templates
+                            .iter()
+                            .map(|template| template.restricted_to(scales))
+                            .collect()
+                    });
+                    last_match = None;
+                    let interval_ms = pace.interval.as_millis() as u64;
+                    let scales = pace.scales.as_ref().map(Vec::len);
+                    info!(
+                        ?source,
+                        interval_ms,
+                        skip_unchanged = pace.skip_unchanged,
+                        ?scales,
+                        "power source changed"
+                    );
+                    let _ = events_tx.send(WorkerEvent::PowerChanged {
+                        on_battery: source == PowerSource::Battery,
+                        interval_ms,
+                        scales,
+                    });
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    warn!(error = ?err, "power source unavailable, battery saver disabled");
+                    power_watch = None;
+                }
+            }
+        }
+
+        if display_changes
+            .as_mut()
+            .is_some_and(DisplayChanges::changed)
+        {
+            match backend.monitors() {
+                Ok(monitors) => {
+                    if let Some(index) = capture::remap_monitor_index(
+                        &monitors,
+                        target_id.or(config.monitor_id),
+                        config.monitor_index,
+                    ) {
+                        if index != monitor_index {
+                            info!(from = monitor_index, to = index, "monitor index remapped");
+                        }
+                        monitor_index = index;
+                        target_id = Some(monitors[index].id);
+                    }
+                    let _ = events_tx.send(WorkerEvent::MonitorsChanged {
+                        monitor_index,
+                        monitor_count: monitors.len(),
+                    });
+                }
+                Err(err) => warn!(error = ?err, "monitor enumeration after display change failed"),
+            }
+        }
+
+        if config.input.idle_grace_ms > 0
+            && let Ok(position) = dispatcher.backend().cursor_position()
+        {
+            clicks.activity.observe(position, now);
+        }
+
+        if let Some(stream) = &lcu_events {
+            // While the socket is up the client pushes ready checks, so capture is skipped and
+            // the wait for the next event replaces the polling interval. The same goes for
+            // phases where no ready check can appear when capture is gated on the phase.
+            let skip_capture =
+                (lcu_state.live && config.lcu.listen_events) || lcu_state.capture_paused(&config);
+            let wait = if skip_capture {
+                pace.interval
+            } else {
+                Duration::ZERO
+            };
+            pump_lcu_events(
+                &config,
+                stream,
+                wait,
+                &mut lcu_state,
+                &mut clicks,
+                &mut champ_select,
+                &events_tx,
+            );
+            let paused = lcu_state.capture_paused(&config);
+            if paused != lcu_state.gated {
+                lcu_state.gated = paused;
+                info!(paused, phase = %lcu_state.queue.phase, "capture gate changed");
+                let _ = events_tx.send(WorkerEvent::CaptureGated {
+                    paused,
+                    phase: lcu_state.queue.phase.clone(),
+                });
+            }
+            if paused || (lcu_state.live && config.lcu.listen_events) {
+                continue;
+            }
+        }
+
+        let active_templates = paced_templates.as_deref().unwrap_or(&templates);
+        let capture_started = Instant::now();
+        match arena.capture(backend.as_mut(), monitor_index) {
+            Ok(mut frame) => {
+                if config.mask_cursor {
+                    match dispatcher.backend().cursor_position() {
+                        Ok(cursor) => frame.mask_cursor(cursor),
+                        Err(err) => warn!(error = ?err, "cursor position unavailable"),
+                    }
+                }
+                let capture_time = capture_started.elapsed();
+                if failures > 0 {
+                    info!(
+                        monitor = monitor_index,
+                        failures, "screen capture recovered"
+                    );
+                    let _ = events_tx.send(WorkerEvent::CaptureRecovered { monitor_index });
+                    failures = 0;
+                    degraded = false;
+                }
+                let match_started = Instant::now();
+                let signature = pace
+                    .skip_unchanged
+                    .then(|| detect::frame_signature(&frame.image));
+                let detection = match &last_match {
+                    // Same pixels, same result: skip the template match.
+                    Some((previous, detection)) if signature == Some(*previous) => {
+                        detection.clone()
+                    }
+                    _ => detect::detect_any(
+                        &frame.image,
+                        active_templates,
+                        config.search_region,
+                        config.threshold,
+                    ),
+                };
+                last_match = signature.map(|signature| (signature, detection.clone()));
+                let match_time = match_started.elapsed();
+                if let Some(detection) = &detection {
+                    let _ = events_tx.send(WorkerEvent::FrameScored(detection.score));
+                }
+                let threshold = detection
+                    .as_ref()
+                    .and_then(|detection| detection.threshold)
+                    .unwrap_or(config.threshold);
+                if let Some(miss) = near_miss.observe(
+                    &config.near_miss,
+                    detection.as_ref().map(|detection| detection.score),
+                    threshold,
+                ) {
+                    warn!(
+                        score = miss.score,
+                        threshold = miss.threshold,
+                        frames = miss.frames,
+                        "possible missed queue pop: scores just under the threshold"
+                    );
+                    notifications::notify_possible_miss(
+                        &config.notifications,
+                        miss.score,
+                        miss.threshold,
+                        miss.frames,
+                    );
+                    let _ = events_tx.send(WorkerEvent::PossibleMiss(miss));
+                }
+                if control.frame_request.swap(false, Ordering::Relaxed) {
+                    let _ = events_tx.send(WorkerEvent::Frame(FrameSnapshot {
+                        image: frame.image.clone(),
+                        detection: detection.clone(),
+                        threshold: config.threshold,
+                    }));
+                }
+                metrics.record_frame(capture_time, match_time, capture_started.elapsed());
+                handle_detection(
+                    &config,
+                    &mut dispatcher,
+                    &events_tx,
+                    &frame,
+                    detection,
+                    &mut clicks,
+                    &mut || {
+                        let recheck = arena.capture(backend.as_mut(), monitor_index).ok()?;
+                        let detection = detect::detect_any(
+                            &recheck.image,
+                            active_templates,
+                            config.search_region,
+                            config.threshold,
+                        );
+                        arena.recycle(recheck);
+                        detection
+                    },
+                );
+                arena.recycle(frame);
+
+                if backend.is_exhausted() {
+                    info!(backend = backend.name(), "capture source exhausted");
+                    let _ =
+                        events_tx.send(WorkerEvent::Info("Capture source exhausted".to_string()));
+                    break;
+                }
+            }
+            Err(err) => {
+                failures += 1;
+                let delay = capture::backoff_delay(failures);
+                error!(error = ?err, failures, retry_ms = delay.as_millis() as u64, "screen capture failed");
+                if !degraded {
+                    let message = format!("Capture failed: {err:#}");
+                    notifications::notify_error(&config.notifications, &message);
+                    let _ = events_tx.send(WorkerEvent::Error(message));
+                }
+
+                if failures.is_multiple_of(FAILURES_BEFORE_REENUMERATE) {
+                    match backend.monitors() {
+                        Ok(monitors) => {
+                            match capture::remap_monitor_index(
+                                &monitors,
+                                config.monitor_id,
+                                config.monitor_index,
+                            ) {
+                                Some(index) => {
+                                    if index != monitor_index {
+                                        warn!(
+                                            from = monitor_index,
+                                            to = index,
+                                            "configured monitor unavailable, falling back"
+                                        );
+                                    }
+                                    monitor_index = index;
+                                    target_id = Some(monitors[index].id);
+                                }
+                                None => warn!("no monitors available"),
+                            }
+                        }
+                        Err(err) => warn!(error = ?err, "monitor re-enumeration failed"),
+                    }
+                    degraded = true;
+                    let _ = events_tx.send(WorkerEvent::CaptureDegraded {
+                        monitor_index,
+                        failures,
+                        retry_in_ms: delay.as_millis() as u64,
+                    });
+                }
+
+                sleep_unless_stopped(delay, &control.stop);
+                continue;
+            }
+        }
+
+        if control.stop.load(Ordering::Relaxed) {
+            break;
+        }
+
+        thread::sleep(pace.interval);
+    }
+
+    let _ = events_tx.send(WorkerEvent::Stopped);
+    info!("worker stopped");
+}
+
+fn sleep_unless_stopped(duration: Duration, stop_flag: &AtomicBool) {
+    let deadline = Instant::now() + duration;
+    while !stop_flag.load(Ordering::Relaxed) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        thread::sleep(remaining.min(Duration::from_millis(50)));
+    }
+}
+
+/// Click bookkeeping carried across frames.
+struct ClickState {
+    last_click: Option<Instant>,
+    verify: Option<PendingVerify>,
+    activity: ActivityTracker,
+    /// Client API session, opened on the first LCU accept.
+    lcu: Option<LcuClient>,
+    sound: SoundPlayer,
+    speech: Announcer,
+}
+
+impl ClickState {
+    fn new(now: Instant) -> Self {
+        Self {
+            last_click: None,
+            verify: None,
+            activity: ActivityTracker::new(now),
+            lcu: None,
+            sound: SoundPlayer::default(),
+            speech: Announcer::default(),
+        }
+    }
+}
+
+/// An accept that still has to be confirmed by the dialog disappearing.
+#[derive(Clone, Copy)]
+struct PendingVerify {
+    /// Attempts so far across all stages.
+    attempts: u32,
+    /// Index into [`AppConfig::accept_stages`].
+    stage: usize,
+    stage_attempts: u32,
+    due: Instant,
+}
+
+fn handle_detection(
+    config: &AppConfig,
+    dispatcher: &mut Dispatcher,
+    events_tx: &Sender<WorkerEvent>,
+    frame: &CapturedFrame,
+    detection: Option<Detection>,
+    clicks: &mut ClickState,
+    recheck: &mut dyn FnMut() -> Option<Detection>,
+) {
+    let now = Instant::now();
+    let matched = detection.filter(|result| result.passes(config.threshold));
+    let verify = &config.click_verify;
+
+    let stages = config.accept_stages();
+    let attempt = match (clicks.verify, &matched) {
+        (Some(pending), _) if now < pending.due => return,
+        (Some(pending), None) => {
+            info!(attempts = pending.attempts, "accept confirmed");
+            let _ = events_tx.send(WorkerEvent::ClickVerified {
+                attempts: pending.attempts,
+            });
+            clicks.verify = None;
+            return;
+        }
+        (Some(pending), Some(_)) => {
+            let next = if pending.stage_attempts < verify.max_attempts {
+                PendingVerify {
+                    attempts: pending.attempts + 1,
+                    stage_attempts: pending.stage_attempts + 1,
+                    ..pending
+                }
+            } else {
+                PendingVerify {
+                    attempts: pending.attempts + 1,
+                    stage: pending.stage + 1,
+                    stage_attempts: 1,
+                    ..pending
+                }
+            };
+            let Some(mode) = stages.get(next.stage) else {
+                warn!(
+                    attempts = pending.attempts,
+                    "dialog still visible after all accept attempts"
+                );
+                let _ = events_tx.send(WorkerEvent::ClickUnconfirmed {
+                    attempts: pending.attempts,
+                });
+                clicks.verify = None;
+                return;
+            };
+            let message = if next.stage != pending.stage {
+                format!("Accept not registered, escalating to {mode:?}")
+            } else {
+                format!(
+                    "Accept not registered, retrying ({}/{})",
+                    next.stage_attempts, verify.max_attempts
+                )
+            };
+            let _ = events_tx.send(WorkerEvent::Info(message));
+            next
+        }
+        (None, None) => return,
+        (None, Some(result)) => {
+            let cooldown = Duration::from_millis(config.cooldown_ms);
+            if let Some(last) = clicks.last_click {
+                let elapsed = now.duration_since(last);
+                if elapsed < cooldown {
+                    let remaining = cooldown.saturating_sub(elapsed);
+                    let _ = events_tx.send(WorkerEvent::CooldownActive {
+                        score: result.score,
+                        remaining_ms: remaining.as_millis() as u64,
+                    });
+                    return;
+                }
+            }
+            // Only gates the first attempt: our own click resets the OS idle timer, so retries
+            // for the same ready check would otherwise never fire.
+            if let Some(idle_ms) = user_active_idle_ms(&config.input) {
+                let _ = events_tx.send(WorkerEvent::UserActive {
+                    idle_ms,
+                    required_ms: config.input.require_user_idle_secs * 1000,
+                });
+                return;
+            }
+            PendingVerify {
+                attempts: 1,
+                stage: 0,
+                stage_attempts: 1,
+                due: now,
+            }
+        }
+    };
+    let mut mode = stages[attempt.stage];
+    let Some(result) = matched else {
+        return;
+    };
+    // Queried only for a match: on X11 each query opens a new connection.
+    let foreground = config
+        .click_guard
+        .check_foreground
+        .then(window::foreground_window)
+        .flatten();
+    if attempt.attempts == 1 {
+        clicks.sound.play(&config.sound.detection);
+        clicks.speech.announce(&config.speech, Announcement::Found);
+        notifications::notify_found(&config.notifications, result.score);
+    }
+
+    let (mut screen_x, mut screen_y) = click_point(config, frame, &result);
+    if attempt.stage_attempts > 1 && verify.retry_jitter_px > 0 {
+        // Nudge retries slightly in case the previous point hit a dead spot in the button.
+        let spread = verify.retry_jitter_px as i32;
+        let mut rng = rand::thread_rng();
+        screen_x += rng.gen_range(-spread..=spread);
+        screen_y += rng.gen_range(-spread..=spread);
+    }
+
+    let _ = events_tx.send(WorkerEvent::Detection {
+        score: result.score,
+        image_coords: result.position,
+        screen_coords: (screen_x, screen_y),
+        template_size: result.template_size,
+        screen_rect: (
+            frame.to_logical(result.position.0 as f32, result.position.1 as f32),
+            frame.to_logical(
+                (result.position.0 + result.template_size.0) as f32,
+                (result.position.1 + result.template_size.1) as f32,
+            ),
+        ),
+        scale: result.scale,
+        thumbnail: result.thumbnail(&frame.image, THUMBNAIL_WIDTH),
+    });
+
+    if config.dry_run {
+        info!(
+            score = result.score,
+            screen_x, screen_y, "dry run, click skipped"
+        );
+        let _ = events_tx.send(WorkerEvent::WouldClick {
+            screen_coords: (screen_x, screen_y),
+        });
+        clicks.last_click = Some(now);
+        return;
+    }
+
+    let api_outcome = if mode == AcceptMode::Lcu {
+        let _ = events_tx.send(WorkerEvent::AcceptAttempt {
+            mode,
+            attempt: attempt.attempts,
+        });
+        match accept_via_lcu(config, &mut clicks.lcu) {
+            Err(err) if config.lcu.listen_events => {
+                // Hybrid mode: the screen only drives accepts while the client is unreachable,
+                // so click instead of reporting the API failure.
+                warn!(error = ?err, "client API unreachable, clicking instead");
+                mode = AcceptMode::Click;
+                None
+            }
+            outcome => Some(outcome),
+        }
+    } else {
+        None
+    };
+    let outcome = if let Some(outcome) = api_outcome {
+        outcome
+    } else {
+        let grace = Duration::from_millis(config.input.idle_grace_ms);
+        if !grace.is_zero() {
+            wait_for_mouse_idle(dispatcher.backend(), &mut clicks.activity, grace, events_tx);
+        }
+
+        if let Some(reason) = click_blocked(config, foreground, &result, recheck) {
+            // Leave the cooldown untouched so the next matching frame schedules a fresh click.
+            info!(reason, "accept aborted, screen changed");
+            let _ = events_tx.send(WorkerEvent::ClickAborted {
+                reason: reason.to_string(),
+            });
+            return;
+        }
+
+        let restore_foreground = if config.input.focus_client {
+            focus_client_window(config)
+        } else {
+            None
+        };
+        let _ = events_tx.send(WorkerEvent::AcceptAttempt {
+            mode,
+            attempt: attempt.attempts,
+        });
+        dispatcher.submit(InputAction::Accept {
+            x: screen_x,
+            y: screen_y,
+            mode,
+        });
+        let outcome = dispatcher.run_pending(&config.input);
+        if let Some(previous) = restore_foreground
+            && let Err(err) = window::focus(previous)
+        {
+            warn!(error = ?err, "failed to restore previous foreground window");
+        }
+        if let Ok(position) = dispatcher.backend().cursor_position() {
+            clicks.activity.reset_to(position);
+        }
+        outcome
+    };
+    if let Err(err) = outcome {
+        error!(error = ?err, ?mode, "failed to accept");
+        let message = format!("Accept failed: {err:#}");
+        notifications::notify_error(&config.notifications, &message);
+        let _ = events_tx.send(WorkerEvent::Error(message));
+        clicks.verify = None;
+        return;
+    }
+
+    info!(
+        score = result.score,
+        scale = result.scale,
+        template_width = result.template_size.0,
+        template_height = result.template_size.1,
+        frame_scale = frame.scale_factor,
+        screen_x,
+        screen_y,
+        attempt = attempt.attempts,
+        ?mode,
+        "accept button clicked"
+    );
+    let _ = events_tx.send(WorkerEvent::Clicked {
+        screen_coords: (screen_x, screen_y),
+    });
+    clicks.sound.play(&config.sound.click);
+    clicks
+        .speech
+        .announce(&config.speech, Announcement::Accepted);
+    // Re-clicks of the same ready check are not news; only the first one is reported.
+    if attempt.attempts == 1 {
+        notifications::notify_accepted(
+            &config.notifications,
+            Some(result.score),
+            Some(&frame.image),
+        );
+    }
+    let clicked_at = Instant::now();
+    clicks.last_click = Some(clicked_at);
+    clicks.verify = verify.enabled.then(|| PendingVerify {
+        due: clicked_at + Duration::from_millis(verify.delay_ms),
+        ..attempt
+    });
+}
+
+/// What the worker knows about the client from its event stream.
+#[derive(Default)]
+struct LcuState {
+    live: bool,
+    queue: QueueStatus,
+    requeue: Requeue,
+    /// Capture is currently paused by the gameflow phase.
+    gated: bool,
+    /// When to honor a teammate in the current post-game.
+    honor_due: Option<Instant>,
+    /// When the socket last came up or went down.
+    switched_at: Option<Instant>,
+}
+
+impl LcuState {
+    /// With phase gating on, capture only runs while a ready check can actually appear. An
+    /// unknown phase (socket down, phase not fetched yet) keeps capture running.
+    fn capture_paused(&self, config: &AppConfig) -> bool {
+        config.lcu.gate_capture
+            && self.live
+            && !self.queue.phase.is_empty()
+            && !gameflow::capture_needed(&self.queue.phase)
+    }
+}
+
+/// Handles client WebSocket messages, waiting up to `wait` for the first one.
+fn pump_lcu_events(
+    config: &AppConfig,
+    stream: &LcuEventStream,
+    wait: Duration,
+    state: &mut LcuState,
+    clicks: &mut ClickState,
+    champ_select: &mut ChampSelectAutomation,
+    events_tx: &Sender<WorkerEvent>,
+) {
+    let rx = stream.receiver();
+    let first = if wait.is_zero() {
+        rx.try_recv().ok()
+    } else {
+        rx.recv_timeout(wait).ok()
+    };
+    for message in first.into_iter().chain(rx.try_iter()) {
+        match message {
+            LcuMessage::Connected => {
+                if config.lcu.listen_events {
+                    info!(
+                        screen_secs = state.switched_at.map(|at| at.elapsed().as_secs()),
+                        "client API reachable, handing ready checks to client events"
+                    );
+                }
+                state.live = true;
+                state.switched_at = Some(Instant::now());
+                let _ = events_tx.send(WorkerEvent::LcuEventsLive(true));
+                if config.lcu.queue_status || config.lcu.gate_capture {
+                    match fetch_queue_status(config, &mut clicks.lcu) {
+                        Ok(queue) => {
+                            state.queue = queue;
+                            if config.lcu.queue_status {
+                                let _ =
+                                    events_tx.send(WorkerEvent::QueueStatus(state.queue.clone()));
+                            }
+                        }
+                        Err(err) => warn!(error = ?err, "queue status unavailable"),
+                    }
+                }
+            }
+            LcuMessage::Disconnected(reason) => {
+                warn!(
+                    reason,
+                    api_secs = state.switched_at.map(|at| at.elapsed().as_secs()),
+                    "League client events unavailable, using screen detection"
+                );
+                state.live = false;
+                state.switched_at = Some(Instant::now());
+                state.queue = QueueStatus::default();
+                let _ = events_tx.send(WorkerEvent::LcuEventsLive(false));
+            }
+            LcuMessage::Event(event) => {
+                if state
+                    .queue
+                    .apply(&event.uri, &event.event_type, &event.data)
+                    && config.lcu.queue_status
+                {
+                    let _ = events_tx.send(WorkerEvent::QueueStatus(state.queue.clone()));
+                }
+                if event.uri == lcu::READY_CHECK_URI {
+                    if config.lcu.listen_events && lcu::ready_check_pending(&event.data) {
+                        accept_ready_check_event(config, clicks, events_tx);
+                    }
+                } else if event.uri == champ_select::SESSION_URI {
+                    report_champ_select(
+                        champ_select.on_session(config, &event.event_type, &event.data),
+                        events_tx,
+                    );
+                } else if event.uri == gameflow::PHASE_URI {
+                    let phase = event.data.as_str().unwrap_or("None");
+                    state.honor_due = (config.honor.enabled && phase == "PreEndOfGame")
+                        .then(|| Instant::now() + HONOR_DELAY);
+                    if let Some(outcome) =
+                        state
+                            .requeue
+                            .on_phase(&config.requeue, phase, Instant::now())
+                    {
+                        info!(?outcome, "champ select ended in the lobby");
+                        let _ = events_tx.send(WorkerEvent::Requeue(outcome));
+                    }
+                }
+            }
+        }
+    }
+    let now = Instant::now();
+    if let Some(attempt) = state.requeue.take_due(now) {
+        requeue(config, attempt, &mut clicks.lcu, events_tx);
+    }
+    if state.honor_due.is_some_and(|due| now >= due) {
+        state.honor_due = None;
+        honor_teammate(config, &mut clicks.lcu, events_tx);
+    }
+    report_champ_select(champ_select.tick(config, Instant::now()), events_tx);
+}
+
+fn requeue(
+    config: &AppConfig,
+    attempt: u32,
+    client: &mut Option<LcuClient>,
+    events_tx: &Sender<WorkerEvent>,
+) {
+    if config.dry_run {
+        info!(attempt, "dry run, not searching again");
+        let _ = events_tx.send(WorkerEvent::Info(
+            "Dry run: would restart matchmaking".to_string(),
+        ));
+        return;
+    }
+    match with_lcu_client(config, client, |client| {
+        client.post(gameflow::START_SEARCH_PATH)
+    }) {
+        Ok(()) => {
+            info!(attempt, "matchmaking restarted");
+            let _ = events_tx.send(WorkerEvent::Requeue(RequeueOutcome::Started { attempt }));
+        }
+        Err(err) => {
+            warn!(error = ?err, "re-queue failed");
+            let _ = events_tx.send(WorkerEvent::Error(format!("Re-queue failed: {err:#}")));
+        }
+    }
+}
+
+fn honor_teammate(
+    config: &AppConfig,
+    client: &mut Option<LcuClient>,
+    events_tx: &Sender<WorkerEvent>,
+) {
+    if config.dry_run {
+        info!("dry run, not honoring");
+        let _ = events_tx.send(WorkerEvent::Info(
+            "Dry run: would honor a teammate".to_string(),
+        ));
+        return;
+    }
+    match with_lcu_client(config, client, |client| {
+        honor::honor_teammate(client, &config.honor)
+    }) {
+        Ok(Some(player)) => {
+            info!(player, "teammate honored");
+            let _ = events_tx.send(WorkerEvent::Honored { player });
+        }
+        Ok(None) => info!("no teammate eligible for honor"),
+        Err(err) => {
+            warn!(error = ?err, "honor failed");
+            let _ = events_tx.send(WorkerEvent::Error(format!("Honor failed: {err:#}")));
+        }
+    }
+}
+
+fn report_champ_select(report: ChampSelectReport, events_tx: &Sender<WorkerEvent>) {
+    for outcome in report.outcomes {
+        let _ = events_tx.send(WorkerEvent::ChampSelect(outcome));
+    }
+    for err in report.errors {
+        warn!(error = ?err, "champ select automation failed");
+        let _ = events_tx.send(WorkerEvent::Error(format!("Champ select: {err:#}")));
+    }
+}
+
+/// Answers a ready check announced over the client WebSocket.
+fn accept_ready_check_event(
+    config: &AppConfig,
+    clicks: &mut ClickState,
+    events_tx: &Sender<WorkerEvent>,
+) {
+    if let Some(idle_ms) = user_active_idle_ms(&config.input) {
+        let _ = events_tx.send(WorkerEvent::UserActive {
+            idle_ms,
+            required_ms: config.input.require_user_idle_secs * 1000,
+        });
+        return;
+    }
+    if config.dry_run {
+        info!("dry run, ready check left unanswered");
+        let _ = events_tx.send(WorkerEvent::Info(
+            "Dry run: would accept the ready check through the client API".to_string(),
+        ));
+        return;
+    }
+    let _ = events_tx.send(WorkerEvent::AcceptAttempt {
+        mode: AcceptMode::Lcu,
+        attempt: 1,
+    });
+    match accept_via_lcu(config, &mut clicks.lcu) {
+        Ok(()) => {
+            info!("ready check accepted from client event");
+            clicks.last_click = Some(Instant::now());
+            let _ = events_tx.send(WorkerEvent::ReadyCheckAccepted);
+            clicks.sound.play(&config.sound.click);
+            clicks
+                .speech
+                .announce(&config.speech, Announcement::Accepted);
+            notifications::notify_accepted(&config.notifications, None, None);
+        }
+        Err(err) => {
+            error!(error = ?err, "failed to accept ready check from client event");
+            let message = format!("Accept failed: {err:#}");
+            notifications::notify_error(&config.notifications, &message);
+            let _ = events_tx.send(WorkerEvent::Error(message));
+        }
+    }
+}
+
+/// Accepts through the client API, reconnecting when the cached session was rejected (the
+/// client writes a new lockfile whenever it restarts).
+fn accept_via_lcu(config: &AppConfig, client: &mut Option<LcuClient>) -> Result<()> {
+    with_lcu_client(config, client, LcuClient::accept_ready_check)
+}
+
+fn fetch_queue_status(config: &AppConfig, client: &mut Option<LcuClient>) -> Result<QueueStatus> {
+    with_lcu_client(config, client, QueueStatus::fetch)
+}
+
+/// Runs `request` on the cached client, connecting first if needed. The client is dropped on
+/// failure so the next call re-reads the lockfile.
+fn with_lcu_client<T>(
+    config: &AppConfig,
+    client: &mut Option<LcuClient>,
+    request: impl FnOnce(&LcuClient) -> Result<T>,
+) -> Result<T> {
+    let session = match client.take() {
+        Some(session) => session,
+        None => LcuClient::connect(config.lcu.lockfile.as_deref())?,
+    };
+    let result = request(&session);
+    if result.is_ok() {
+        *client = Some(session);
+    }
+    result
+}
+
+/// Raises the League client and returns the window that had focus before, if it changed.
+fn focus_client_window(config: &AppConfig) -> Option<WindowId> {
+    let title = config.client_window_title();
+    let Some(client) = window::find_window(title) else {
+        warn!(title, "client window not found, clicking without focusing");
+        return None;
+    };
+    let previous = window::foreground_window();
+    if previous == Some(client) {
+        return None;
+    }
+    if let Err(err) = window::focus(client) {
+        warn!(error = ?err, "failed to focus client window");
+        return None;
+    }
+    // Give the window manager a moment to bring the client on top before the click lands.
+    thread::sleep(FOCUS_SETTLE);
+    previous
+}
+
+/// Re-checks the screen right before clicking. Returns why the click must not happen.
+fn click_blocked(
+    config: &AppConfig,
+    foreground: Option<WindowId>,
+    result: &Detection,
+    recheck: &mut dyn FnMut() -> Option<Detection>,
+) -> Option<&'static str> {
+    if let Some(before) = foreground
+        && window::foreground_window().is_some_and(|now| now != before)
+    {
+        return Some("foreground window changed");
+    }
+    if config.click_guard.reverify_pixels {
+        let still_there = recheck().is_some_and(|again| {
+            again.passes(config.threshold)
+                && again.position.0.abs_diff(result.position.0) <= REVERIFY_TOLERANCE_PX
+                && again.position.1.abs_diff(result.position.1) <= REVERIFY_TOLERANCE_PX
+        });
+        if !still_there {
+            return Some("button no longer at the detected position");
+        }
+    }
+    None
+}
+
+/// Idle time in ms when the user has touched the keyboard/mouse more recently than
+/// `require_user_idle_secs`; `None` when the accept may go ahead.
+fn user_active_idle_ms(settings: &InputSettings) -> Option<u64> {
+    if settings.require_user_idle_secs == 0 {
+        return None;
+    }
+    // Platforms without an idle query never block accepting.
+    let idle = idle::user_idle_time()?;
+    (idle < Duration::from_secs(settings.require_user_idle_secs)).then_some(idle.as_millis() as u64)
+}
+
+/// Blocks until the cursor has rested for `grace`, giving up after [`MAX_IDLE_WAIT`].
+fn wait_for_mouse_idle(
+    input_backend: &mut dyn InputBackend,
+    activity: &mut ActivityTracker,
+    grace: Duration,
+    events_tx: &Sender<WorkerEvent>,
+) {
+    let started = Instant::now();
+    let mut announced = false;
+    loop {
+        let now = Instant::now();
+        if let Ok(position) = input_backend.cursor_position() {
+            activity.observe(position, now);
+        }
+        if activity.still_for(now) >= grace {
+            return;
+        }
+        if now.duration_since(started) >= MAX_IDLE_WAIT {
+            warn!("mouse still active, accepting anyway");
+            return;
+        }
+        if !announced {
+            info!(
+                grace_ms = grace.as_millis() as u64,
+                "deferring accept while mouse is active"
+            );
+            let _ = events_tx.send(WorkerEvent::Info(
+                "Waiting for the mouse to settle before accepting".to_string(),
+            ));
+            announced = true;
+        }
+        thread::sleep(IDLE_POLL);
+    }
+}
@@ -0,0 +1,39 @@
+use std::time::{Duration, Instant};
+
+use lol_auto_accept_rs::{
+    actions::{ActionQueue, InputAction},
+    config::AcceptMode,
+};
+
+fn accept(x: i32, y: i32) -> InputAction {
+    InputAction::Accept {
+        x,
+        y,
+        mode: AcceptMode::Click,
+    }
+}
+
+#[test]
+fn duplicate_actions_are_merged() {
+    let mut queue = ActionQueue::new(Duration::ZERO);
+    assert!(queue.push(accept(1, 1)));
+    assert!(!queue.push(accept(5, 5)));
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.pop_ready(Instant::now()), Some(accept(5, 5)));
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn dispatches_respect_minimum_gap() {
+    let gap = Duration::from_millis(250);
+    let start = Instant::now();
+    let mut queue = ActionQueue::new(gap);
+    queue.push(accept(0, 0));
+    assert!(queue.pop_ready(start).is_some());
+
+    queue.push(accept(1, 1));
+    let later = start + Duration::from_millis(100);
+    assert_eq!(queue.pop_ready(later), None);
+    assert_eq!(queue.wait_time(later), Duration::from_millis(150));
+    assert!(queue.pop_ready(start + gap).is_some());
+}
@@ -0,0 +1,35 @@
+use std::path::Path;
+
+use lol_auto_accept_rs::autostart::{
+    ENTRY_NAME, MINIMIZED_FLAG, desktop_entry, launch_agent, run_command,
+};
+
+#[test]
+fn run_command_quotes_paths_with_spaces() {
+    let exe = Path::new("C:\\Program Files\\LoL Auto Accept\\lol-auto-accept-rs.exe");
+    assert_eq!(
+        run_command(exe),
+        "\"C:\\Program Files\\LoL Auto Accept\\lol-auto-accept-rs.exe\" --minimized"
+    );
+}
+
+#[test]
+fn desktop_entry_execs_the_binary() {
+    let entry = desktop_entry(Path::new("/opt/lol auto accept/lol-auto-accept-rs"));
+    assert!(entry.starts_with("[Desktop Entry]\n"));
+    assert!(entry.contains("Type=Application\n"));
+    assert!(entry.contains("Exec=\"/opt/lol auto accept/lol-auto-accept-rs\" --minimized\n"));
+}
+
+#[test]
+fn launch_agent_runs_at_load() {
+    let plist = launch_agent(Path::new(
+        "/Applications/LoL Auto Accept.app/Contents/MacOS/app",
+    ));
+    assert!(plist.contains(&format!("<string>{ENTRY_NAME}</string>")));
+    assert!(
+        plist.contains("<string>/Applications/LoL Auto Accept.app/Contents/MacOS/app</string>")
+    );
+    assert!(plist.contains(&format!("<string>{MINIMIZED_FLAG}</string>")));
+    assert!(plist.contains("<key>RunAtLoad</key>\n    <true/>"));
+}
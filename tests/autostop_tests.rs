@@ -0,0 +1,80 @@
+use std::time::{Duration, Instant};
+
+use lol_auto_accept_rs::{
+    autostop::{AutoStopAction, AutoStopTimer},
+    config::AutoStopSettings,
+};
+
+fn minutes(n: u64) -> Duration {
+    Duration::from_secs(n * 60)
+}
+
+fn enabled(after_minutes: u64, warn_minutes: u64) -> AutoStopSettings {
+    AutoStopSettings {
+        enabled: true,
+        after_minutes,
+        warn_minutes,
+    }
+}
+
+#[test]
+fn disabled_by_default() {
+    let start = Instant::now();
+    let mut timer = AutoStopTimer::new(start);
+    assert_eq!(
+        timer.poll(&AutoStopSettings::default(), start + minutes(600)),
+        None
+    );
+}
+
+#[test]
+fn warns_once_then_stops() {
+    let settings = enabled(60, 5);
+    let start = Instant::now();
+    let mut timer = AutoStopTimer::new(start);
+    assert_eq!(timer.poll(&settings, start + minutes(54)), None);
+    assert_eq!(
+        timer.poll(&settings, start + minutes(56)),
+        Some(AutoStopAction::Warn {
+            remaining: minutes(4)
+        })
+    );
+    assert_eq!(timer.poll(&settings, start + minutes(57)), None);
+    assert_eq!(
+        timer.poll(&settings, start + minutes(60)),
+        Some(AutoStopAction::Stop)
+    );
+}
+
+#[test]
+fn activity_restarts_the_countdown() {
+    let settings = enabled(30, 5);
+    let start = Instant::now();
+    let mut timer = AutoStopTimer::new(start);
+    assert!(matches!(
+        timer.poll(&settings, start + minutes(26)),
+        Some(AutoStopAction::Warn { .. })
+    ));
+    timer.on_activity(start + minutes(27));
+    assert_eq!(timer.poll(&settings, start + minutes(50)), None);
+    assert!(matches!(
+        timer.poll(&settings, start + minutes(53)),
+        Some(AutoStopAction::Warn { .. })
+    ));
+    assert_eq!(
+        timer.poll(&settings, start + minutes(57)),
+        Some(AutoStopAction::Stop)
+    );
+}
+
+#[test]
+fn zero_warning_stops_without_warning() {
+    let settings = enabled(10, 0);
+    let start = Instant::now();
+    let mut timer = AutoStopTimer::new(start);
+    assert_eq!(timer.poll(&settings, start + minutes(9)), None);
+    assert_eq!(
+        timer.poll(&settings, start + minutes(10)),
+        Some(AutoStopAction::Stop)
+    );
+}
@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+
+use lol_auto_accept_rs::{
+    backup::{self, FORMAT_VERSION, SettingsBundle},
+    config::{AppConfig, Profiles, TemplateEntry},
+};
+
+fn sample() -> SettingsBundle {
+    let config = AppConfig {
+        threshold: 0.91,
+        ..AppConfig::default()
+    };
+    let mut profiles = Profiles::default();
+    profiles.save_as("SoloQ", &config).unwrap();
+    profiles.set_hotkey("SoloQ", "Ctrl+Alt+1");
+    SettingsBundle::new(&config, &profiles)
+}
+
+#[test]
+fn bundle_round_trips_through_json() {
+    let bundle = sample();
+    let text = bundle.to_json().expect("serializes");
+    assert!(text.contains("\"version\": 1"));
+    assert_eq!(SettingsBundle::from_json(&text).expect("parses"), bundle);
+}
+
+#[test]
+fn export_and_import_use_one_file() {
+    let path = std::env::temp_dir().join(format!("backup-tests-{}.json", std::process::id()));
+    let bundle = sample();
+    backup::export(&path, &bundle).expect("export");
+    assert_eq!(backup::import(&path).expect("import"), bundle);
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn newer_formats_and_other_files_are_rejected() {
+    let mut bundle = sample();
+    bundle.version = FORMAT_VERSION + 1;
+    let text = bundle.to_json().expect("serializes");
+    assert!(SettingsBundle::from_json(&text).is_err());
+    assert!(SettingsBundle::from_json("{\"threshold\": 0.9}").is_err());
+}
+
+#[test]
+fn missing_templates_lists_paths_absent_on_this_machine() {
+    let existing = std::env::temp_dir();
+    let gone = PathBuf::from("/definitely/not/here/accept.png");
+    let mut bundle = sample();
+    bundle.config.template_path = Some(gone.clone());
+    bundle.config.templates = vec![
+        TemplateEntry {
+            path: existing,
+            ..TemplateEntry::default()
+        },
+        TemplateEntry {
+            path: gone.clone(),
+            ..TemplateEntry::default()
+        },
+    ];
+    assert_eq!(bundle.missing_templates(), vec![gone]);
+}
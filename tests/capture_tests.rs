@@ -0,0 +1,155 @@
+use std::{path::PathBuf, time::Duration};
+
+use image::GrayImage;
+use lol_auto_accept_rs::capture::{self, CapturedFrame, FrameArena, MonitorInfo};
+
+fn frame(origin: (i32, i32), scale_factor: f32) -> CapturedFrame {
+    CapturedFrame {
+        image: GrayImage::new(1, 1),
+        origin,
+        scale_factor,
+    }
+}
+
+#[test]
+fn physical_pixels_are_divided_by_scale() {
+    let frame = frame((1920, 0), 1.5);
+    assert_eq!(frame.to_logical(300.0, 150.0), (2120, 100));
+}
+
+#[test]
+fn logical_round_trips_through_physical() {
+    let frame = frame((-1280, 200), 1.25);
+    let (px, py) = frame.to_physical(-1000, 400);
+    assert_eq!(frame.to_logical(px, py), (-1000, 400));
+}
+
+#[test]
+fn invalid_scale_falls_back_to_identity() {
+    let frame = frame((10, 20), 0.0);
+    assert_eq!(frame.to_logical(5.0, 5.0), (15, 25));
+}
+
+fn monitor(id: u32, is_primary: bool) -> MonitorInfo {
+    MonitorInfo {
+        id,
+        name: format!("Display {id}"),
+        width: 1920,
+        height: 1080,
+        scale_factor: 1.0,
+        is_primary,
+        origin_x: 0,
+        origin_y: 0,
+    }
+}
+
+#[test]
+fn backoff_doubles_and_caps() {
+    assert_eq!(capture::backoff_delay(0), Duration::ZERO);
+    assert_eq!(capture::backoff_delay(1), Duration::from_millis(250));
+    assert_eq!(capture::backoff_delay(3), Duration::from_millis(1000));
+    assert_eq!(capture::backoff_delay(30), Duration::from_secs(8));
+}
+
+#[test]
+fn missing_monitor_falls_back_to_primary() {
+    let monitors = vec![monitor(1, false), monitor(2, true)];
+    assert_eq!(capture::resolve_monitor_index(&monitors, 1), Some(1));
+    assert_eq!(capture::resolve_monitor_index(&monitors, 5), Some(1));
+    assert_eq!(capture::resolve_monitor_index(&[], 0), None);
+}
+
+#[test]
+fn direct_luma_matches_image_conversion() {
+    let sample = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("resources")
+        .join("samples")
+        .join("positive_mock.png");
+    let rgba = image::open(sample).expect("sample loads").into_rgba8();
+    let expected = image::DynamicImage::ImageRgba8(rgba.clone()).into_luma8();
+
+    let mut out = GrayImage::new(0, 0);
+    capture::rgba_to_luma_into(rgba.as_raw(), rgba.width(), rgba.height(), &mut out)
+        .expect("conversion succeeds");
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn direct_luma_rejects_short_buffers() {
+    let mut out = GrayImage::new(0, 0);
+    assert!(capture::rgba_to_luma_into(&[0; 7], 1, 2, &mut out).is_err());
+}
+
+fn fill_gray(frame: &mut CapturedFrame, width: u32, height: u32) -> anyhow::Result<()> {
+    let raw = vec![128u8; (width * height * 4) as usize];
+    capture::rgba_to_luma_into(&raw, width, height, &mut frame.image)
+}
+
+#[test]
+fn arena_reuses_buffers_of_same_size() {
+    let mut arena = FrameArena::new(1);
+    for _ in 0..3 {
+        let frame = arena.fill(|f| fill_gray(f, 64, 32)).expect("fill succeeds");
+        arena.recycle(frame);
+    }
+    let stats = arena.stats();
+    assert_eq!(stats.allocations, 1);
+    assert_eq!(stats.reuses, 2);
+    assert_eq!(stats.bytes_held, 64 * 32);
+}
+
+#[test]
+fn arena_reallocates_on_resolution_change() {
+    let mut arena = FrameArena::new(1);
+    let frame = arena.fill(|f| fill_gray(f, 64, 32)).expect("fill succeeds");
+    arena.recycle(frame);
+    let frame = arena.fill(|f| fill_gray(f, 32, 32)).expect("fill succeeds");
+    arena.recycle(frame);
+    let stats = arena.stats();
+    assert_eq!(stats.allocations, 2);
+    assert_eq!(stats.bytes_held, 32 * 32);
+}
+
+#[test]
+fn remap_follows_monitor_id_after_hotplug() {
+    let monitors = vec![monitor(7, true), monitor(3, false), monitor(9, false)];
+    assert_eq!(capture::remap_monitor_index(&monitors, Some(3), 0), Some(1));
+    assert_eq!(
+        capture::remap_monitor_index(&monitors, Some(42), 2),
+        Some(2)
+    );
+    assert_eq!(
+        capture::remap_monitor_index(&monitors, Some(42), 5),
+        Some(0)
+    );
+}
+
+#[test]
+fn bgra_and_rgba_conversions_agree() {
+    let rgba = [10u8, 200, 30, 255, 250, 5, 90, 0];
+    let bgra = [30u8, 200, 10, 255, 90, 5, 250, 0];
+    let (mut from_rgba, mut from_bgra) = (GrayImage::new(0, 0), GrayImage::new(0, 0));
+    capture::rgba_to_luma_into(&rgba, 2, 1, &mut from_rgba).expect("rgba converts");
+    capture::bgra_to_luma_into(&bgra, 2, 1, &mut from_bgra).expect("bgra converts");
+    assert_eq!(from_rgba, from_bgra);
+}
+
+#[test]
+fn cursor_mask_copies_row_above() {
+    let mut frame = frame((100, 100), 1.0);
+    frame.image = GrayImage::from_fn(64, 64, |_, y| image::Luma([if y < 10 { 50 } else { 200 }]));
+    frame.mask_cursor((110, 110));
+
+    assert_eq!(frame.image.get_pixel(20, 20)[0], 50);
+    assert_eq!(frame.image.get_pixel(41, 41)[0], 50);
+    assert_eq!(frame.image.get_pixel(42, 42)[0], 200);
+    assert_eq!(frame.image.get_pixel(5, 20)[0], 200);
+}
+
+#[test]
+fn cursor_mask_ignores_cursor_off_frame() {
+    let mut frame = frame((0, 0), 1.0);
+    frame.image = GrayImage::from_pixel(16, 16, image::Luma([7]));
+    frame.mask_cursor((-500, 40));
+    assert!(frame.image.pixels().all(|p| p[0] == 7));
+}
@@ -0,0 +1,310 @@
+use std::{
+    collections::HashSet,
+    fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    path::PathBuf,
+    thread::{self, JoinHandle},
+};
+
+use lol_auto_accept_rs::{
+    champ_select::{
+        ChampSelectAutomation, ChampSelectOutcome, ChampionCatalog, LocalAction, assigned_position, bench_champions, choose_bench_swap,
+        choose_champion, locked_champion, pending_actions, received_requests, taken_champions,
+        teammate_intents,
+    },
+    config::{AppConfig, ChampionPriorities},
+};
+use serde_json::{Value, json};
+
+fn session() -> Value {
+    json!({
+        "gameId": 42,
+        "localPlayerCellId": 2,
+        "myTeam": [
+            {"cellId": 0, "assignedPosition": "TOP", "championId": 0},
+            {"cellId": 1, "assignedPosition": "JUNGLE", "championId": 64},
+            {"cellId": 2, "assignedPosition": "MIDDLE", "championId": 0}
+        ],
+        "theirTeam": [
+            {"cellId": 5, "championId": 0}
+        ],
+        "actions": [
+            [
+                {"id": 1, "actorCellId": 0, "type": "ban", "championId": 157, "completed": true, "isInProgress": false},
+                {"id": 2, "actorCellId": 2, "type": "ban", "championId": 0, "completed": true, "isInProgress": false}
+            ],
+            [
+                {"id": 7, "actorCellId": 5, "type": "pick", "championId": 103, "completed": true, "isInProgress": false},
+                {"id": 8, "actorCellId": 2, "type": "pick", "championId": 0, "completed": false, "isInProgress": true}
+            ]
+        ],
+        "bans": {"myTeamBans": [], "theirTeamBans": [238]}
+    })
+}
+
+/// Serves `count` LCU requests, one connection each, with the status and JSON `respond` returns
+/// for the request line. Returns a lockfile pointing at it and the request lines it saw.
+fn fake_client(
+    count: usize,
+    respond: fn(&str) -> (u16, Value),
+) -> (PathBuf, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let port = listener.local_addr().unwrap().port();
+    let lockfile = std::env::temp_dir().join(format!(
+        "champ-select-tests-{}-{port}.lock",
+        std::process::id()
+    ));
+    fs::write(&lockfile, format!("LeagueClient:1:{port}:pw:http")).unwrap();
+    let server = thread::spawn(move || {
+        (0..count)
+            .map(|_| {
+                let (mut stream, _) = listener.accept().expect("accept");
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                reader.read_line(&mut request).unwrap();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                reader.read_exact(&mut vec![0; length]).unwrap();
+                let (status, body) = respond(&request);
+                let body = body.to_string();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Status\r\nContent-Type: application/json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+                request.trim().to_string()
+            })
+            .collect()
+    });
+    (lockfile, server)
+}
+
+#[test]
+fn local_pick_action_is_pending() {
+    assert_eq!(
+        pending_actions(&session()),
+        vec![LocalAction {
+            id: 8,
+            kind: "pick".to_string(),
+            champion_id: 0,
+            in_progress: true,
+        }]
+    );
+    assert_eq!(assigned_position(&session()), "middle");
+}
+
+#[test]
+fn bans_and_other_picks_are_taken() {
+    let taken = taken_champions(&session());
+    assert_eq!(taken, HashSet::from([157, 238, 103, 64]));
+}
+
+#[test]
+fn first_available_candidate_wins() {
+    let taken = HashSet::from([103, 157]);
+    let pickable = HashSet::from([7, 134]);
+    assert_eq!(
+        choose_champion(&[103, 7, 134], &taken, Some(&pickable)),
+        Some(7)
+    );
+    assert_eq!(choose_champion(&[103, 134], &taken, None), Some(134));
+    assert_eq!(choose_champion(&[103, 99], &taken, Some(&pickable)), None);
+}
+
+#[test]
+fn catalog_resolves_names_and_aliases() {
+    let catalog = ChampionCatalog::from_summary(&json!([
+        {"id": -1, "name": "None", "alias": "None"},
+        {"id": 62, "name": "Wukong", "alias": "MonkeyKing"},
+        {"id": 103, "name": "Ahri", "alias": "Ahri"}
+    ]));
+    assert_eq!(catalog.id("wukong"), Some(62));
+    assert_eq!(catalog.id(" MonkeyKing "), Some(62));
+    assert_eq!(catalog.id("None"), None);
+    assert_eq!(catalog.name(103), "Ahri");
+}
+
+#[test]
+fn role_list_falls_back_to_fill() {
+    let champions = ChampionPriorities {
+        middle: vec!["Ahri".to_string(), "Syndra".to_string()],
+        fill: vec!["ahri".to_string(), "Lux".to_string()],
+        ..ChampionPriorities::default()
+    };
+    assert_eq!(
+        champions.candidates("MIDDLE"),
+        vec!["Ahri", "Syndra", "Lux"]
+    );
+    assert_eq!(champions.candidates(""), vec!["ahri", "Lux"]);
+}
+
+#[test]
+fn teammate_hovers_are_not_banned() {
+    let session = json!({
+        "localPlayerCellId": 0,
+        "myTeam": [
+            {"cellId": 0, "championPickIntent": 55, "championId": 0},
+            {"cellId": 1, "championPickIntent": 103, "championId": 0},
+            {"cellId": 2, "championPickIntent": 0, "championId": 0}
+        ],
+        "actions": [[
+            {"id": 1, "actorCellId": 2, "type": "pick", "championId": 7, "completed": false},
+            {"id": 2, "actorCellId": 0, "type": "ban", "championId": 0, "completed": false, "isInProgress": true}
+        ]]
+    });
+    assert_eq!(teammate_intents(&session), HashSet::from([103, 7]));
+}
+
+#[test]
+fn champion_counts_as_locked_once_no_pick_is_pending() {
+    assert_eq!(locked_champion(&session()), None);
+
+    let aram = json!({
+        "localPlayerCellId": 3,
+        "myTeam": [{"cellId": 3, "championId": 22}],
+        "actions": []
+    });
+    assert_eq!(locked_champion(&aram), Some(22));
+}
+
+#[test]
+fn bench_is_read_only_when_enabled() {
+    let session = json!({
+        "benchEnabled": true,
+        "benchChampions": [{"championId": 222, "isPriority": false}, {"championId": 99}]
+    });
+    assert_eq!(bench_champions(&session), vec![222, 99]);
+    let legacy = json!({"benchEnabled": true, "benchChampionIds": [51, 21]});
+    assert_eq!(bench_champions(&legacy), vec![51, 21]);
+    let draft = json!({"benchEnabled": false, "benchChampions": [{"championId": 222}]});
+    assert!(bench_champions(&draft).is_empty());
+}
+
+#[test]
+fn bench_swap_only_upgrades_along_the_wishlist() {
+    let wishlist = [222, 99, 51];
+    // Anything on the wishlist beats an unlisted champion; the best one wins.
+    assert_eq!(
+        choose_bench_swap(&wishlist, Some(1), &[51, 99, 7]),
+        Some(99)
+    );
+    // Already holding a better champion.
+    assert_eq!(choose_bench_swap(&wishlist, Some(99), &[51]), None);
+    assert_eq!(
+        choose_bench_swap(&wishlist, Some(99), &[222, 51]),
+        Some(222)
+    );
+    assert_eq!(choose_bench_swap(&wishlist, None, &[7, 8]), None);
+    assert_eq!(choose_bench_swap(&[], Some(1), &[222]), None);
+}
+
+#[test]
+fn only_received_requests_are_answered() {
+    let session = json!({
+        "trades": [
+            {"id": 1, "cellId": 0, "state": "AVAILABLE"},
+            {"id": 2, "cellId": 3, "state": "RECEIVED"},
+            {"id": 3, "cellId": 4, "state": "SENT"}
+        ],
+        "pickOrderSwaps": [{"id": 9, "cellId": 1, "state": "RECEIVED"}]
+    });
+    let trades = received_requests(&session, "trades");
+    assert_eq!(trades.len(), 1);
+    assert_eq!((trades[0].id, trades[0].cell_id), (2, 3));
+    assert_eq!(received_requests(&session, "pickOrderSwaps")[0].id, 9);
+    assert!(received_requests(&json!({}), "trades").is_empty());
+}
+
+#[test]
+fn custom_games_are_not_a_configured_queue() {
+    let (lockfile, server) = fake_client(2, |request| {
+        if request.contains("/lol-gameflow/v1/session") {
+            // Custom games and the practice tool have no matchmade queue.
+            (200, json!({"gameData": {"queue": {"id": -1}}, "map": {"id": 11}}))
+        } else {
+            (200, json!([{"id": 103, "name": "Ahri", "alias": "Ahri"}]))
+        }
+    });
+    let mut config = AppConfig::default();
+    config.lcu.lockfile = Some(lockfile.clone());
+    config.auto_pick.enabled = true;
+    config.auto_pick.champions.fill = vec!["Ahri".to_string()];
+
+    let mut automation = ChampSelectAutomation::new();
+    let report = automation.on_session(&config, "Update", &session());
+    assert!(report.outcomes.is_empty() && report.errors.is_empty());
+    let requests = server.join().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests.iter().all(|request| request.starts_with("GET ")));
+    // The client was kept and the queue remembered: the server is gone, yet nothing fails.
+    let report = automation.on_session(&config, "Update", &session());
+    assert!(report.outcomes.is_empty() && report.errors.is_empty());
+    let _ = fs::remove_file(lockfile);
+}
+
+#[test]
+fn failed_pick_does_not_hold_up_the_ban() {
+    let (lockfile, server) = fake_client(7, |request| {
+        if request.contains("/lol-gameflow/v1/session") {
+            (200, json!({"gameData": {"queue": {"id": 420}}, "map": {"id": 11}}))
+        } else if request.contains("champion-summary") {
+            (
+                200,
+                json!([
+                    {"id": 103, "name": "Ahri", "alias": "Ahri"},
+                    {"id": 134, "name": "Syndra", "alias": "Syndra"}
+                ]),
+            )
+        } else if request.contains("-champion-ids") {
+            (200, json!([103, 134]))
+        } else if request.starts_with("PATCH /lol-champ-select/v1/session/actions/2 ") {
+            // The hover is refused, e.g. because the champion was just taken.
+            (500, json!({"message": "champion unavailable"}))
+        } else {
+            (200, json!({}))
+        }
+    });
+    let mut config = AppConfig::default();
+    config.lcu.lockfile = Some(lockfile.clone());
+    config.auto_pick.enabled = true;
+    config.auto_pick.champions.fill = vec!["Ahri".to_string()];
+    config.auto_ban.enabled = true;
+    config.auto_ban.delay_ms = 0;
+    config.auto_ban.champions.fill = vec!["Syndra".to_string()];
+    let session = json!({
+        "gameId": 7,
+        "localPlayerCellId": 0,
+        "myTeam": [{"cellId": 0, "assignedPosition": "", "championId": 0}],
+        "actions": [
+            [{"id": 1, "actorCellId": 0, "type": "ban", "championId": 0, "completed": false, "isInProgress": true}],
+            [{"id": 2, "actorCellId": 0, "type": "pick", "championId": 0, "completed": false, "isInProgress": false}]
+        ]
+    });
+
+    let report = ChampSelectAutomation::new().on_session(&config, "Update", &session);
+    assert_eq!(
+        report.outcomes,
+        vec![ChampSelectOutcome::Banned {
+            champion: "Syndra".to_string()
+        }]
+    );
+    assert_eq!(report.errors.len(), 1);
+    assert!(format!("{:#}", report.errors[0]).starts_with("Auto pick failed"));
+    let complete = "POST /lol-champ-select/v1/session/actions/1/complete HTTP/1.1";
+    assert!(server.join().unwrap().iter().any(|request| request == complete));
+    let _ = fs::remove_file(lockfile);
+}
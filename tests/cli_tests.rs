@@ -0,0 +1,132 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use lol_auto_accept_rs::{
+    autostart::MINIMIZED_FLAG,
+    cli::{self, Cli},
+    config::{AcceptMode, AppConfig},
+};
+
+fn parse(args: &[&str]) -> Cli {
+    Cli::try_parse_from(std::iter::once("lol-auto-accept-rs").chain(args.iter().copied()))
+        .expect("parses")
+}
+
+#[test]
+fn no_arguments_leave_the_config_alone() {
+    let mut config = AppConfig::default();
+    parse(&[]).apply(&mut config).expect("applies");
+    assert_eq!(config, AppConfig::default());
+}
+
+#[test]
+fn named_options_override_the_config_file() {
+    let mut config = AppConfig {
+        monitor_id: Some(7),
+        ..AppConfig::default()
+    };
+    parse(&[
+        "--threshold",
+        "0.9",
+        "--monitor",
+        "1",
+        "--interval-ms",
+        "200",
+        "--cooldown-ms",
+        "5000",
+        "--template",
+        "path.png",
+        "--dry-run",
+    ])
+    .apply(&mut config)
+    .expect("applies");
+    assert_eq!(config.threshold, 0.9);
+    assert_eq!(config.monitor_index, 1);
+    assert_eq!(config.monitor_id, None);
+    assert_eq!(config.interval_ms, 200);
+    assert_eq!(config.cooldown_ms, 5000);
+    assert_eq!(config.template_path, Some(PathBuf::from("path.png")));
+    assert!(config.dry_run);
+}
+
+#[test]
+fn launch_switches_parse() {
+    let cli = parse(&["--headless", MINIMIZED_FLAG, "--auto-start"]);
+    assert!(cli.headless && cli.minimized && cli.auto_start);
+    assert!(Cli::try_parse_from(["lol-auto-accept-rs", "--no-such-flag"]).is_err());
+}
+
+#[test]
+fn set_reaches_nested_fields() {
+    let mut config = AppConfig::default();
+    cli::set(&mut config, "lcu.listen_events=true").expect("bool");
+    cli::set(&mut config, "input.accept_mode=lcu").expect("bare enum text");
+    cli::set(
+        &mut config,
+        "notifications.discord.webhook_url=https://example.com/hook",
+    )
+    .expect("bare url");
+    cli::set(&mut config, "toggle_hotkey = \"Ctrl+Alt+B\"").expect("quoted string");
+    assert!(config.lcu.listen_events);
+    assert_eq!(config.input.accept_mode, AcceptMode::Lcu);
+    assert_eq!(
+        config.notifications.discord.webhook_url,
+        "https://example.com/hook"
+    );
+    assert_eq!(config.toggle_hotkey, "Ctrl+Alt+B");
+}
+
+#[test]
+fn named_options_win_over_set() {
+    let mut config = AppConfig::default();
+    parse(&["--set", "threshold=0.7", "--threshold", "0.95"])
+        .apply(&mut config)
+        .expect("applies");
+    assert_eq!(config.threshold, 0.95);
+}
+
+#[test]
+fn set_rejects_unknown_keys_and_bad_values() {
+    let mut config = AppConfig::default();
+    assert!(cli::set(&mut config, "thresold=0.9").is_err());
+    assert!(cli::set(&mut config, "lcu.no_such_field=1").is_err());
+    assert!(cli::set(&mut config, "interval_ms=fast").is_err());
+    assert!(cli::set(&mut config, "threshold.value=1").is_err());
+    assert!(cli::set(&mut config, "threshold").is_err());
+    assert!(cli::set(&mut config, ".threshold=1").is_err());
+    assert_eq!(config, AppConfig::default());
+}
+
+#[test]
+fn overrides_are_not_written_back() {
+    let file = AppConfig {
+        threshold: 0.7,
+        monitor_id: Some(5),
+        ..AppConfig::default()
+    };
+    let cli = parse(&["--threshold", "0.9", "--monitor", "1", "--dry-run"]);
+    let mut edited = cli.applied(&file).expect("applies");
+    assert_eq!(edited.threshold, 0.9);
+    edited.interval_ms = 250;
+
+    let restored = cli.without_overrides(&edited, &file).expect("restores");
+    assert_eq!(
+        restored,
+        AppConfig {
+            interval_ms: 250,
+            ..file
+        }
+    );
+}
+
+#[test]
+fn edits_to_overridden_settings_are_kept() {
+    let file = AppConfig::default();
+    let cli = parse(&["--threshold", "0.9", "--set", "lcu.queue_status=true"]);
+    let mut edited = cli.applied(&file).expect("applies");
+    edited.threshold = 0.8;
+
+    let restored = cli.without_overrides(&edited, &file).expect("restores");
+    assert_eq!(restored.threshold, 0.8);
+    assert_eq!(restored.lcu.queue_status, file.lcu.queue_status);
+}
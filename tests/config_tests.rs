@@ -0,0 +1,232 @@
+use std::path::PathBuf;
+
+use lol_auto_accept_rs::config::{
+    self, AcceptMode, AppConfig, CaptureSource, ClickCount, ClientSlot, Profiles, SearchRegion,
+};
+
+#[test]
+fn config_round_trips_through_toml() {
+    let config = AppConfig {
+        capture_source: CaptureSource::Directory {
+            path: PathBuf::from("frames"),
+            looping: true,
+        },
+        ..AppConfig::default()
+    };
+    let text = toml::to_string(&config).expect("serializes");
+    let parsed: AppConfig = toml::from_str(&text).expect("parses");
+    assert_eq!(parsed, config);
+}
+
+#[test]
+fn missing_fields_fall_back_to_defaults() {
+    let parsed: AppConfig = toml::from_str("threshold = 0.9\n").expect("parses");
+    assert_eq!(parsed.threshold, 0.9);
+    assert_eq!(parsed.capture_source, CaptureSource::Screen);
+    assert_eq!(parsed.interval_ms, AppConfig::default().interval_ms);
+    assert!(!parsed.auto_start);
+    assert!(!parsed.start_minimized);
+    assert!(parsed.reload_on_change);
+    assert!(parsed.confirm_exit);
+    assert!(parsed.toasts);
+}
+
+#[test]
+fn input_settings_missing_from_file_use_defaults() {
+    let config: AppConfig = toml::from_str("threshold = 0.9\n[input.humanize]\nenabled = true\n")
+        .expect("partial config parses");
+    assert!(config.input.humanize.enabled);
+    assert!(config.input.restore_cursor);
+    assert_eq!(config.input.require_user_idle_secs, 0);
+    assert!(!config.input.allow_driver_input);
+}
+
+#[test]
+fn click_count_serializes_as_snake_case() {
+    let mut config = AppConfig::default();
+    config.input.click_count = ClickCount::Double;
+    let text = toml::to_string(&config).expect("serialize");
+    assert!(text.contains("click_count = \"double\""));
+    let parsed: AppConfig = toml::from_str(&text).expect("parse");
+    assert_eq!(parsed.input.click_count.presses(), 2);
+}
+
+#[test]
+fn client_window_title_follows_window_capture_source() {
+    let mut config = AppConfig::default();
+    assert_eq!(config.client_window_title(), "League of Legends");
+    config.capture_source = CaptureSource::Window {
+        title: "League of Legends (TM) Client".to_string(),
+    };
+    assert_eq!(
+        config.client_window_title(),
+        "League of Legends (TM) Client"
+    );
+}
+
+#[test]
+fn accept_stages_follow_escalation_toggles() {
+    let mut config = AppConfig::default();
+    assert_eq!(config.accept_stages(), vec![AcceptMode::Click]);
+
+    config.escalation.enabled = true;
+    assert_eq!(
+        config.accept_stages(),
+        vec![AcceptMode::Click, AcceptMode::Key]
+    );
+
+    config.escalation.click = false;
+    assert_eq!(config.accept_stages(), vec![AcceptMode::Key]);
+
+    config.escalation.lcu = true;
+    assert_eq!(
+        config.accept_stages(),
+        vec![AcceptMode::Key, AcceptMode::Lcu]
+    );
+
+    config.escalation.key = false;
+    config.escalation.lcu = false;
+    config.input.accept_mode = AcceptMode::ClickAndKey;
+    assert_eq!(config.accept_stages(), vec![AcceptMode::ClickAndKey]);
+}
+
+#[test]
+fn search_region_round_trips_and_clamps() {
+    let region = SearchRegion::from_corners((300, 40), (100, 240));
+    assert_eq!(
+        region,
+        SearchRegion {
+            x: 100,
+            y: 40,
+            width: 200,
+            height: 200
+        }
+    );
+    let config = AppConfig {
+        search_region: Some(region),
+        ..AppConfig::default()
+    };
+    let text = toml::to_string(&config).expect("serialize");
+    let parsed: AppConfig = toml::from_str(&text).expect("parse");
+    assert_eq!(parsed.search_region, Some(region));
+
+    let clamped = region.clamped(250, 120).unwrap();
+    assert_eq!((clamped.width, clamped.height), (150, 80));
+    assert!(region.clamped(80, 1000).is_none());
+}
+
+#[test]
+fn profiles_switch_update_and_round_trip() {
+    let mut profiles = Profiles::default();
+    let laptop = AppConfig {
+        threshold: 0.8,
+        ..AppConfig::default()
+    };
+    assert!(profiles.save_as("  ", &laptop).is_err());
+    profiles.save_as(" Laptop ", &laptop).unwrap();
+    profiles.save_as("Home 4K", &AppConfig::default()).unwrap();
+    assert_eq!(profiles.active.as_deref(), Some("Home 4K"));
+    assert_eq!(profiles.names().collect::<Vec<_>>(), ["Home 4K", "Laptop"]);
+
+    assert_eq!(profiles.switch("Laptop"), Some(laptop.clone()));
+    assert_eq!(profiles.active.as_deref(), Some("Laptop"));
+    assert_eq!(profiles.switch("Missing"), None);
+    assert_eq!(profiles.active.as_deref(), Some("Laptop"));
+
+    let tuned = AppConfig {
+        threshold: 0.95,
+        ..laptop
+    };
+    profiles.update_active(&tuned);
+    assert_eq!(profiles.get("Laptop"), Some(&tuned));
+
+    let text = toml::to_string(&profiles).expect("serializes");
+    let parsed: Profiles = toml::from_str(&text).expect("parses");
+    assert_eq!(parsed, profiles);
+
+    assert!(profiles.remove("Laptop"));
+    assert_eq!(profiles.active, None);
+    assert!(!profiles.remove("Laptop"));
+}
+
+#[test]
+fn profile_hotkeys_are_set_cleared_and_dropped_with_the_profile() {
+    let mut profiles = Profiles::default();
+    profiles.save_as("SoloQ", &AppConfig::default()).unwrap();
+    profiles.save_as("ARAM", &AppConfig::default()).unwrap();
+    profiles.set_hotkey("SoloQ", " Ctrl+Alt+1 ");
+    profiles.set_hotkey("ARAM", "Ctrl+Alt+2");
+    assert_eq!(profiles.hotkey("SoloQ"), Some("Ctrl+Alt+1"));
+
+    let text = toml::to_string(&profiles).expect("serializes");
+    let parsed: Profiles = toml::from_str(&text).expect("parses");
+    assert_eq!(parsed, profiles);
+
+    profiles.set_hotkey("ARAM", "  ");
+    assert_eq!(profiles.hotkey("ARAM"), None);
+    profiles.remove("SoloQ");
+    assert_eq!(profiles.hotkey("SoloQ"), None);
+    assert!(profiles.hotkeys.is_empty());
+}
+
+#[test]
+fn extra_clients_get_their_own_monitor_template_and_cooldown() {
+    let mut config = AppConfig {
+        capture_source: CaptureSource::Window {
+            title: "League of Legends".to_string(),
+        },
+        search_region: Some(SearchRegion::from_corners((0, 0), (100, 50))),
+        ..AppConfig::default()
+    };
+    config.input.accept_mode = AcceptMode::Lcu;
+    config.lcu.listen_events = true;
+    config.auto_pick.enabled = true;
+    let slot = ClientSlot {
+        monitor_index: 1,
+        monitor_id: Some(7),
+        template_path: Some(PathBuf::from("duo.png")),
+        cooldown_ms: 9_000,
+        ..ClientSlot::default()
+    };
+    config.extra_clients.push(slot.clone());
+
+    let client = config.for_client(&slot);
+    assert_eq!(client.monitor_index, 1);
+    assert_eq!(client.monitor_id, Some(7));
+    assert_eq!(client.template_path, Some(PathBuf::from("duo.png")));
+    assert_eq!(client.cooldown_ms, 9_000);
+    assert_eq!(client.search_region, None);
+    assert_eq!(client.capture_source, CaptureSource::Screen);
+    assert_eq!(client.input.accept_mode, AcceptMode::Click);
+    assert!(!client.lcu.listen_events);
+    assert!(!client.champ_select_automation());
+    assert!(client.extra_clients.is_empty());
+    // Everything else follows the main settings.
+    assert_eq!(client.threshold, config.threshold);
+}
+
+#[test]
+fn extra_client_without_template_keeps_the_main_templates() {
+    let config = AppConfig {
+        template_path: Some(PathBuf::from("main.png")),
+        ..AppConfig::default()
+    };
+    let client = config.for_client(&ClientSlot::default());
+    assert_eq!(client.template_path, Some(PathBuf::from("main.png")));
+}
+
+#[test]
+fn read_parses_the_file_without_creating_it() {
+    let dir = std::env::temp_dir().join(format!("config-read-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("config.toml");
+    assert!(config::read(&path).is_err(), "a missing file is an error");
+    assert!(!path.exists());
+
+    std::fs::write(&path, "threshold = 0.9\n").unwrap();
+    assert_eq!(config::read(&path).unwrap().threshold, 0.9);
+
+    std::fs::write(&path, "threshold = \"high\"\n").unwrap();
+    assert!(config::read(&path).is_err(), "a malformed file is an error");
+    std::fs::remove_dir_all(&dir).unwrap();
+}
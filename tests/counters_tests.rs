@@ -0,0 +1,44 @@
+use std::time::{Duration, Instant};
+
+use lol_auto_accept_rs::counters::{Counters, STALL_AFTER};
+
+#[test]
+fn counts_frames_detections_clicks_and_errors() {
+    let now = Instant::now();
+    let mut counters = Counters::new();
+    assert_eq!(counters.detection_rate(), None);
+    counters.on_metrics(150, now);
+    counters.on_metrics(50, now);
+    counters.on_detection();
+    counters.on_click();
+    counters.on_error();
+    assert_eq!(
+        (
+            counters.frames,
+            counters.detections,
+            counters.clicks,
+            counters.errors
+        ),
+        (200, 1, 1, 1)
+    );
+    assert_eq!(counters.detection_rate(), Some(0.5));
+}
+
+#[test]
+fn a_quiet_worker_is_reported_as_stalled() {
+    let start = Instant::now();
+    let mut counters = Counters::new();
+    assert!(!counters.is_stalled(start + STALL_AFTER * 2));
+
+    counters.on_start(start);
+    assert!(!counters.is_stalled(start + Duration::from_secs(1)));
+    assert!(counters.is_stalled(start + STALL_AFTER));
+
+    // A report with no frames (capture paused) still counts as a heartbeat.
+    counters.on_metrics(0, start + STALL_AFTER);
+    assert!(!counters.is_stalled(start + STALL_AFTER + Duration::from_secs(1)));
+    assert_eq!(
+        counters.heartbeat_age(start + STALL_AFTER + Duration::from_secs(2)),
+        Some(Duration::from_secs(2))
+    );
+}
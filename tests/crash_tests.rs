@@ -0,0 +1,83 @@
+use chrono::{Local, TimeZone};
+use lol_auto_accept_rs::crash::{
+    CrashReport, redact_config, report_text, tail, take_pending, write_report,
+};
+
+fn report<'a>(config: Option<&'a str>, recent_log: Option<&'a str>) -> CrashReport<'a> {
+    CrashReport {
+        time: Local.with_ymd_and_hms(2024, 3, 9, 21, 4, 5).unwrap(),
+        thread: "lol-auto-accept-worker",
+        message: "index out of bounds",
+        location: Some("src/worker.rs:10:5".to_string()),
+        backtrace: "   0: std::backtrace\n",
+        config,
+        recent_log,
+    }
+}
+
+#[test]
+fn tail_keeps_the_last_lines() {
+    let text = "one\ntwo\nthree\nfour\n";
+    assert_eq!(tail(text, 2), "three\nfour\n");
+    assert_eq!(tail(text, 4), text);
+    assert_eq!(tail(text, 10), text);
+    assert_eq!(tail("a\nb", 1), "b");
+    assert_eq!(tail(text, 0), "");
+}
+
+#[test]
+fn report_lists_panic_config_and_log() {
+    let text = report_text(&report(Some("threshold = 0.9\n"), Some("INFO started\n")));
+    assert!(text.contains("Thread: lol-auto-accept-worker"));
+    assert!(text.contains("Panic: index out of bounds"));
+    assert!(text.contains("Location: src/worker.rs:10:5"));
+    assert!(text.contains("==== Backtrace ====\n   0: std::backtrace\n"));
+    assert!(text.contains("==== Configuration ====\nthreshold = 0.9\n"));
+    assert!(text.contains("==== Recent log ====\nINFO started\n"));
+    assert!(text.contains("Time: 2024-03-09 21:04:05"));
+
+    let bare = report_text(&report(None, None));
+    assert!(bare.contains("==== Configuration ====\n(unavailable)"));
+}
+
+#[test]
+fn report_leaves_out_secrets() {
+    let config = redact_config(
+        "threshold = 0.9\n\
+         [notifications.discord]\n\
+         enabled = true\n\
+         webhook_url = \"https://discord.com/api/webhooks/123/secret-token\"\n\
+         [notifications.telegram]\n\
+         bot_token = \"123456:telegram-secret\"\n\
+         chat_id = \"42\"\n\
+         [kiosk]\n\
+         pin = \"8642\"\n",
+    );
+    let text = report_text(&report(Some(&config), None));
+    assert!(!text.contains("secret-token"));
+    assert!(!text.contains("telegram-secret"));
+    assert!(!text.contains("8642"));
+    assert!(text.contains("webhook_url = \"<redacted>\""));
+    assert!(text.contains("threshold = 0.9"));
+    assert!(text.contains("chat_id = \"42\""));
+
+    assert!(!redact_config("pin = \"8642").contains("8642"));
+}
+
+#[test]
+fn pending_report_is_taken_once() {
+    let dir = std::env::temp_dir().join(format!("crash-tests-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    assert_eq!(take_pending(&dir), None);
+
+    let path = write_report(&dir, &report(None, None)).unwrap();
+    assert_eq!(path.file_name().unwrap(), "crash-20240309-210405.txt");
+    assert!(
+        std::fs::read_to_string(&path)
+            .unwrap()
+            .contains("index out of bounds")
+    );
+    assert_eq!(take_pending(&dir), Some(path));
+    assert_eq!(take_pending(&dir), None);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
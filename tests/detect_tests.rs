@@ -1,6 +1,9 @@
 use std::path::PathBuf;
 
-use lol_auto_accept_rs::detect;
+use lol_auto_accept_rs::{
+    config::{ClickAnchor, SearchRegion},
+    detect::{self, Detection},
+};
 
 fn template_path() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR"))
@@ -63,3 +66,102 @@ fn negative_sample_is_below_threshold() {
         );
     }
 }
+
+#[test]
+fn click_anchor_maps_into_matched_region() {
+    let detection = Detection {
+        score: 1.0,
+        position: (100, 50),
+        template_size: (200, 80),
+        scale: 1.0,
+        threshold: None,
+    };
+    assert_eq!(
+        detection.anchor_point(ClickAnchor::Center),
+        detection.center()
+    );
+    assert_eq!(detection.anchor_point(ClickAnchor::TopLeft), (100.0, 50.0));
+    assert_eq!(
+        detection.anchor_point(ClickAnchor::Percent { x: 25.0, y: 150.0 }),
+        (150.0, 130.0)
+    );
+}
+
+#[test]
+fn crop_template_orders_and_clamps_corners() {
+    let image = image::GrayImage::from_fn(100, 60, |x, y| image::Luma([(x + y) as u8]));
+
+    let cropped = detect::crop_template(&image, (40, 50), (10, 20)).unwrap();
+    assert_eq!(cropped.dimensions(), (30, 30));
+    assert_eq!(cropped.get_pixel(0, 0).0, [30]);
+
+    let clamped = detect::crop_template(&image, (90, 0), (500, 500)).unwrap();
+    assert_eq!(clamped.dimensions(), (10, 60));
+
+    assert!(detect::crop_template(&image, (10, 10), (15, 40)).is_none());
+}
+
+#[test]
+fn detect_in_reports_frame_coordinates() {
+    let button = image::GrayImage::from_fn(24, 16, |x, y| {
+        image::Luma([((x * 37 + y * 91) % 251) as u8])
+    });
+    let path = std::env::temp_dir().join(format!("detect_in_{}.png", std::process::id()));
+    button.save(&path).unwrap();
+    let template = detect::load_template(&path).expect("template loads");
+    std::fs::remove_file(&path).ok();
+    let mut frame = image::GrayImage::new(72, 48);
+    image::imageops::replace(&mut frame, &button, 30, 20);
+    let region = SearchRegion {
+        x: 20,
+        y: 10,
+        width: 44,
+        height: 34,
+    };
+
+    let detection = detect::detect_in(&frame, &template, Some(region)).expect("match");
+    assert!(detection.score > 0.99, "score {}", detection.score);
+    assert_eq!(detection.position, (30, 20));
+
+    let outside = SearchRegion { x: 100, ..region };
+    let fallback = detect::detect_in(&frame, &template, Some(outside)).expect("match");
+    assert_eq!(fallback.position, detection.position);
+}
+
+#[test]
+fn frame_signature_tracks_screen_changes() {
+    let frame = image::GrayImage::from_fn(64, 48, |x, y| image::Luma([(x * 3 + y) as u8]));
+    let same = frame.clone();
+    assert_eq!(
+        detect::frame_signature(&frame),
+        detect::frame_signature(&same)
+    );
+
+    // A dialog-sized change is always seen.
+    let mut changed = frame.clone();
+    for y in 10..30 {
+        for x in 10..40 {
+            changed.put_pixel(x, y, image::Luma([255]));
+        }
+    }
+    assert_ne!(
+        detect::frame_signature(&frame),
+        detect::frame_signature(&changed)
+    );
+
+    let resized = image::GrayImage::new(48, 64);
+    assert_ne!(
+        detect::frame_signature(&image::GrayImage::new(64, 48)),
+        detect::frame_signature(&resized)
+    );
+}
+
+#[test]
+fn restricted_template_keeps_only_the_given_scales() {
+    let template =
+        detect::load_template_scaled(&template_path(), &[0.8, 1.0, 1.2]).expect("template loads");
+    assert_eq!(template.scales(), vec![0.8, 1.0, 1.2]);
+    assert_eq!(template.restricted_to(&[1.0, 1.2]).scales(), vec![1.0, 1.2]);
+    // Nothing left would disable the template, so it stays as it was.
+    assert_eq!(template.restricted_to(&[2.0]).scales(), vec![0.8, 1.0, 1.2]);
+}
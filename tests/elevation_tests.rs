@@ -0,0 +1,58 @@
+use lol_auto_accept_rs::{
+    config::{AcceptMode, AppConfig, InputBackendKind},
+    elevation::{affects, is_mismatch, restart_parameters},
+};
+
+#[test]
+fn only_an_elevated_client_with_a_normal_app_is_a_mismatch() {
+    assert!(is_mismatch(Some(false), Some(true)));
+    assert!(!is_mismatch(Some(true), Some(true)));
+    assert!(!is_mismatch(Some(true), Some(false)));
+    assert!(!is_mismatch(Some(false), Some(false)));
+}
+
+#[test]
+fn unknown_elevation_is_not_a_mismatch() {
+    assert!(!is_mismatch(None, Some(true)));
+    assert!(!is_mismatch(Some(false), None));
+    assert!(!is_mismatch(None, None));
+}
+
+#[test]
+fn only_synthesized_input_is_affected() {
+    let config = AppConfig::default();
+    assert!(affects(&config));
+    assert!(!affects(&AppConfig {
+        dry_run: true,
+        ..config.clone()
+    }));
+    let mut api = config.clone();
+    api.input.accept_mode = AcceptMode::Lcu;
+    assert!(!affects(&api));
+    let mut driver = config;
+    driver.input.backend = InputBackendKind::Interception;
+    assert!(!affects(&driver));
+}
+
+#[test]
+fn restart_parameters_quote_arguments_with_spaces() {
+    assert_eq!(restart_parameters(&[]), "");
+    assert_eq!(
+        restart_parameters(&["--minimized".into(), "C:\\My Files\\a.png".into()]),
+        "--minimized \"C:\\My Files\\a.png\""
+    );
+    assert_eq!(restart_parameters(&["".into()]), "\"\"");
+}
+
+#[test]
+fn restart_parameters_double_backslashes_before_quotes() {
+    assert_eq!(
+        restart_parameters(&["C:\\My Dir\\".into()]),
+        "\"C:\\My Dir\\\\\""
+    );
+    assert_eq!(
+        restart_parameters(&["say \\\"hi\"".into()]),
+        "\"say \\\\\\\"hi\\\"\""
+    );
+    assert_eq!(restart_parameters(&["C:\\Dir\\".into()]), "C:\\Dir\\");
+}
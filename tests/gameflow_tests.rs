@@ -0,0 +1,141 @@
+use std::time::{Duration, Instant};
+
+use lol_auto_accept_rs::{
+    config::RequeueSettings,
+    gameflow::{self, PHASE_URI, QueueStatus, Requeue, RequeueOutcome, SEARCH_URI},
+    lcu::READY_CHECK_URI,
+};
+use serde_json::json;
+
+#[test]
+fn search_events_track_queue_and_ready_check() {
+    let mut status = QueueStatus::default();
+    assert!(status.apply(PHASE_URI, "Update", &json!("Matchmaking")));
+    assert!(status.apply(
+        SEARCH_URI,
+        "Update",
+        &json!({"searchState": "Searching", "timeInQueue": 42.5, "estimatedQueueTime": 120.0})
+    ));
+    let search = status.search.clone().expect("search");
+    assert_eq!(search.time_in_queue, 42.5);
+    assert_eq!(search.estimated_time, 120.0);
+    assert!(status.ready_check.is_none());
+
+    assert!(status.apply(
+        SEARCH_URI,
+        "Update",
+        &json!({
+            "searchState": "Found",
+            "timeInQueue": 60.0,
+            "readyCheck": {"state": "InProgress", "playerResponse": "None", "timer": 3.0}
+        })
+    ));
+    let check = status.ready_check.clone().expect("ready check");
+    assert_eq!(check.response, "None");
+    assert_eq!(check.remaining(), gameflow::READY_CHECK_SECONDS - 3.0);
+
+    assert!(status.apply(
+        READY_CHECK_URI,
+        "Update",
+        &json!({"state": "InProgress", "playerResponse": "Accepted", "timer": 5.0})
+    ));
+    assert_eq!(status.ready_check.as_ref().unwrap().response, "Accepted");
+}
+
+#[test]
+fn leaving_matchmaking_clears_timers() {
+    let mut status = QueueStatus::default();
+    status.apply(PHASE_URI, "Update", &json!("Matchmaking"));
+    status.apply(
+        SEARCH_URI,
+        "Update",
+        &json!({"searchState": "Searching", "timeInQueue": 5.0}),
+    );
+    assert!(status.apply(PHASE_URI, "Update", &json!("ChampSelect")));
+    assert!(status.search.is_none());
+    assert_eq!(gameflow::phase_label(&status.phase), "Champ select");
+
+    status.apply(PHASE_URI, "Update", &json!("Matchmaking"));
+    status.apply(
+        SEARCH_URI,
+        "Update",
+        &json!({"searchState": "Searching", "timeInQueue": 1.0}),
+    );
+    assert!(status.apply(SEARCH_URI, "Delete", &json!(null)));
+    assert!(status.search.is_none());
+}
+
+#[test]
+fn unrelated_or_repeated_events_report_no_change() {
+    let mut status = QueueStatus::default();
+    status.apply(PHASE_URI, "Update", &json!("Lobby"));
+    assert!(!status.apply(PHASE_URI, "Update", &json!("Lobby")));
+    assert!(!status.apply("/lol-champ-select/v1/session", "Update", &json!({})));
+    assert_eq!(gameflow::phase_label("None"), "Not in a lobby");
+}
+
+#[test]
+fn dodge_schedules_requeue_until_the_limit() {
+    let settings = RequeueSettings {
+        enabled: true,
+        max_retries: 2,
+        delay_ms: 1_000,
+    };
+    let start = Instant::now();
+    let mut requeue = Requeue::new();
+    let dodge = |requeue: &mut Requeue| {
+        requeue.on_phase(&settings, "ChampSelect", start);
+        requeue.on_phase(&settings, "Lobby", start)
+    };
+    assert_eq!(
+        dodge(&mut requeue),
+        Some(RequeueOutcome::Scheduled { attempt: 1, max: 2 })
+    );
+    assert_eq!(requeue.take_due(start), None);
+    assert_eq!(requeue.take_due(start + Duration::from_secs(1)), Some(1));
+    assert_eq!(requeue.take_due(start + Duration::from_secs(2)), None);
+
+    assert_eq!(
+        dodge(&mut requeue),
+        Some(RequeueOutcome::Scheduled { attempt: 2, max: 2 })
+    );
+    assert_eq!(
+        dodge(&mut requeue),
+        Some(RequeueOutcome::LimitReached { max: 2 })
+    );
+
+    requeue.on_phase(&settings, "InProgress", start);
+    requeue.on_phase(&settings, "EndOfGame", start);
+    assert_eq!(requeue.on_phase(&settings, "Lobby", start), None);
+    assert_eq!(
+        dodge(&mut requeue),
+        Some(RequeueOutcome::Scheduled { attempt: 1, max: 2 })
+    );
+}
+
+#[test]
+fn manual_queue_cancels_pending_requeue() {
+    let settings = RequeueSettings {
+        enabled: true,
+        ..RequeueSettings::default()
+    };
+    let start = Instant::now();
+    let mut requeue = Requeue::new();
+    requeue.on_phase(&settings, "ChampSelect", start);
+    assert!(requeue.on_phase(&settings, "Lobby", start).is_some());
+    requeue.on_phase(&settings, "Matchmaking", start);
+    assert_eq!(requeue.take_due(start + Duration::from_secs(60)), None);
+
+    let disabled = RequeueSettings::default();
+    requeue.on_phase(&disabled, "ChampSelect", start);
+    assert_eq!(requeue.on_phase(&disabled, "Lobby", start), None);
+}
+
+#[test]
+fn capture_only_runs_while_a_ready_check_can_pop() {
+    assert!(gameflow::capture_needed("Matchmaking"));
+    assert!(gameflow::capture_needed("ReadyCheck"));
+    for phase in ["None", "Lobby", "ChampSelect", "InProgress", "EndOfGame"] {
+        assert!(!gameflow::capture_needed(phase), "{phase}");
+    }
+}
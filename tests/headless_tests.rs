@@ -0,0 +1,33 @@
+use lol_auto_accept_rs::{headless, worker::WorkerEvent};
+
+#[test]
+fn describes_accept_events() {
+    assert_eq!(
+        headless::describe(&WorkerEvent::Clicked {
+            screen_coords: (960, 720)
+        })
+        .as_deref(),
+        Some("clicked at (960, 720)")
+    );
+    assert_eq!(
+        headless::describe(&WorkerEvent::Error("capture failed".into())).as_deref(),
+        Some("error: capture failed")
+    );
+    assert_eq!(
+        headless::describe(&WorkerEvent::Stopped).as_deref(),
+        Some("stopped")
+    );
+}
+
+#[test]
+fn skips_per_frame_chatter() {
+    assert_eq!(headless::describe(&WorkerEvent::FrameScored(0.42)), None);
+}
+
+#[test]
+fn describes_an_elevation_mismatch() {
+    assert!(
+        headless::describe(&WorkerEvent::ElevationMismatch)
+            .is_some_and(|line| line.contains("administrator"))
+    );
+}
@@ -0,0 +1,92 @@
+use chrono::Local;
+use image::{GrayImage, Luma};
+use lol_auto_accept_rs::{
+    detect::Detection,
+    history::{HISTORY_CAPACITY, History, HistoryOutcome},
+};
+
+#[test]
+fn thumbnail_crops_the_matched_region() {
+    let mut frame = GrayImage::new(40, 30);
+    frame.put_pixel(10, 5, Luma([255]));
+    let detection = Detection {
+        score: 0.95,
+        position: (10, 5),
+        template_size: (8, 4),
+        scale: 1.0,
+        threshold: None,
+    };
+    let thumbnail = detection.thumbnail(&frame, 96);
+    assert_eq!(thumbnail.dimensions(), (8, 4));
+    assert_eq!(thumbnail.get_pixel(0, 0).0, [255]);
+}
+
+#[test]
+fn thumbnail_is_shrunk_and_clamped_to_the_frame() {
+    let frame = GrayImage::new(300, 100);
+    let wide = Detection {
+        score: 0.9,
+        position: (0, 0),
+        template_size: (200, 50),
+        scale: 1.0,
+        threshold: None,
+    };
+    assert_eq!(wide.thumbnail(&frame, 100).dimensions(), (100, 25));
+
+    let overhanging = Detection {
+        position: (290, 90),
+        template_size: (20, 20),
+        ..wide
+    };
+    assert_eq!(overhanging.thumbnail(&frame, 100).dimensions(), (10, 10));
+}
+
+#[test]
+fn outcome_is_attached_to_the_latest_detection() {
+    let mut history = History::default();
+    history.record_detection(Local::now(), 0.91, 1.0, (100, 200), GrayImage::new(2, 2));
+    history.resolve(HistoryOutcome::Clicked, Some((102, 199)));
+    // A second outcome for the same detection is ignored.
+    history.resolve(HistoryOutcome::Aborted, None);
+
+    let entry = history.entries().next().unwrap();
+    assert_eq!(entry.outcome, HistoryOutcome::Clicked);
+    assert_eq!(entry.screen_coords, (102, 199));
+}
+
+#[test]
+fn oldest_entries_are_dropped_at_capacity() {
+    let mut history = History::default();
+    for _ in 0..HISTORY_CAPACITY + 5 {
+        history.record_detection(Local::now(), 0.9, 1.0, (0, 0), GrayImage::new(1, 1));
+    }
+    assert_eq!(history.len(), HISTORY_CAPACITY);
+    assert_eq!(history.entries().next().unwrap().id, 5);
+    assert_eq!(
+        history.entries().last().unwrap().id,
+        HISTORY_CAPACITY as u64 + 4
+    );
+}
+
+#[test]
+fn csv_lists_entries_oldest_first() {
+    let mut history = History::default();
+    let at = chrono::DateTime::parse_from_rfc3339("2026-03-01T21:15:02.250+09:00")
+        .expect("timestamp")
+        .with_timezone(&Local);
+    history.record_detection(at, 0.9312, 1.05, (960, 720), GrayImage::new(1, 1));
+    history.resolve(HistoryOutcome::Clicked, Some((962, 721)));
+    history.record_detection(at, 0.885, 1.0, (10, -20), GrayImage::new(1, 1));
+
+    let csv = history.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "timestamp,outcome,score,scale,screen_x,screen_y");
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].ends_with(",clicked,0.9312,1.050,962,721"));
+    assert!(lines[2].ends_with(",detected,0.8850,1.000,10,-20"));
+    let timestamp = lines[1].split(',').next().unwrap();
+    assert_eq!(
+        chrono::DateTime::parse_from_rfc3339(timestamp).expect("rfc3339"),
+        at
+    );
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use lol_auto_accept_rs::{
+    config::HonorTarget,
+    honor::{choose_teammate, eligible_allies, kda_scores},
+};
+use rand::{SeedableRng, rngs::StdRng};
+use serde_json::json;
+
+fn ballot() -> serde_json::Value {
+    json!({
+        "gameId": 7,
+        "eligibleAllies": [
+            {"puuid": "a", "summonerId": 1, "gameName": "Alpha", "championName": "Ahri"},
+            {"puuid": "b", "summonerId": 2, "summonerName": "Bravo"},
+            {"puuid": "c", "summonerId": 3, "championName": "Garen"},
+            {"puuid": "", "summonerId": 0, "gameName": "Ghost"}
+        ]
+    })
+}
+
+#[test]
+fn ballot_names_fall_back_through_known_fields() {
+    let allies = eligible_allies(&ballot());
+    let names: Vec<&str> = allies.iter().map(|ally| ally.name.as_str()).collect();
+    assert_eq!(names, ["Alpha", "Bravo", "Garen"]);
+    let legacy = json!({"eligiblePlayers": [{"summonerId": 9, "summonerName": "Old"}]});
+    assert_eq!(eligible_allies(&legacy)[0].summoner_id, 9);
+    assert!(eligible_allies(&json!({})).is_empty());
+}
+
+#[test]
+fn best_performance_uses_kda() {
+    let stats = json!({"teams": [{"players": [
+        {"puuid": "a", "stats": {"CHAMPIONS_KILLED": 2, "ASSISTS": 4, "NUM_DEATHS": 6}},
+        {"puuid": "b", "stats": {"CHAMPIONS_KILLED": 5, "ASSISTS": 10, "NUM_DEATHS": 0}},
+        {"puuid": "c", "stats": {"CHAMPIONS_KILLED": 8, "ASSISTS": 1, "NUM_DEATHS": 3}}
+    ]}]});
+    let scores = kda_scores(&stats);
+    assert_eq!(scores["b"], 15.0);
+    assert_eq!(scores["a"], 1.0);
+
+    let allies = eligible_allies(&ballot());
+    let mut rng = StdRng::seed_from_u64(1);
+    let best = choose_teammate(&allies, HonorTarget::BestPerformance, &scores, &mut rng);
+    assert_eq!(best.map(|ally| ally.name.as_str()), Some("Bravo"));
+}
+
+#[test]
+fn random_or_missing_stats_still_pick_someone() {
+    let allies = eligible_allies(&ballot());
+    let mut rng = StdRng::seed_from_u64(3);
+    let empty = HashMap::new();
+    assert!(choose_teammate(&allies, HonorTarget::BestPerformance, &empty, &mut rng).is_some());
+    assert!(choose_teammate(&allies, HonorTarget::Random, &empty, &mut rng).is_some());
+    assert!(choose_teammate(&[], HonorTarget::Random, &empty, &mut rng).is_none());
+}
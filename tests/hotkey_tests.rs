@@ -0,0 +1,23 @@
+use global_hotkey::hotkey::{Code, Modifiers};
+use lol_auto_accept_rs::{config::DEFAULT_TOGGLE_HOTKEY, hotkey::parse_hotkey};
+
+#[test]
+fn default_toggle_hotkey_parses() {
+    let hotkey = parse_hotkey(DEFAULT_TOGGLE_HOTKEY).expect("default binding");
+    assert_eq!(hotkey.mods, Modifiers::CONTROL | Modifiers::ALT);
+    assert_eq!(hotkey.key, Code::KeyA);
+}
+
+#[test]
+fn bare_letter_is_rejected_but_function_keys_are_allowed() {
+    assert!(parse_hotkey("A").is_err());
+    assert!(parse_hotkey("Ctrl+").is_err());
+    assert_eq!(parse_hotkey("F9").expect("function key").key, Code::F9);
+}
+
+#[test]
+fn profile_hotkey_bindings_parse() {
+    let hotkey = parse_hotkey("Ctrl+Alt+1").expect("profile binding");
+    assert_eq!(hotkey.mods, Modifiers::CONTROL | Modifiers::ALT);
+    assert_eq!(hotkey.key, Code::Digit1);
+}
@@ -0,0 +1,97 @@
+use lol_auto_accept_rs::{
+    config::Language,
+    i18n::{catalog, fill, parse_catalog, translate},
+};
+
+const TRANSLATED: [Language; 3] = [Language::Japanese, Language::Korean, Language::Spanish];
+
+/// String literals passed directly to `tr(` or `trf(` in the GUI source.
+fn wrapped_literals(source: &str) -> Vec<String> {
+    let mut literals = Vec::new();
+    for call in ["tr(", "trf("] {
+        for (start, _) in source.match_indices(call) {
+            let preceded_by_ident = source[..start]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+            let rest = source[start + call.len()..].trim_start();
+            if preceded_by_ident || !rest.starts_with('"') {
+                continue;
+            }
+            let mut literal = String::new();
+            let mut chars = rest[1..].chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' => break,
+                    '\\' => literal.extend(chars.next()),
+                    c => literal.push(c),
+                }
+            }
+            literals.push(literal);
+        }
+    }
+    literals
+}
+
+#[test]
+fn catalogs_cover_every_gui_string() {
+    let literals = wrapped_literals(include_str!("../src/app.rs"));
+    assert!(
+        literals.len() > 100,
+        "found only {} strings",
+        literals.len()
+    );
+    for language in TRANSLATED {
+        let catalog = catalog(language).expect("translated language has a catalog");
+        let missing: Vec<_> = literals
+            .iter()
+            .filter(|text| !catalog.contains_key(text.as_str()))
+            .collect();
+        assert!(missing.is_empty(), "{language:?} is missing {missing:?}");
+    }
+}
+
+#[test]
+fn translations_keep_placeholders() {
+    for language in TRANSLATED {
+        for (english, translated) in catalog(language).unwrap() {
+            for placeholder in english.split('{').skip(1).filter_map(|s| s.split_once('}')) {
+                let placeholder = format!("{{{}}}", placeholder.0);
+                assert!(
+                    translated.contains(&placeholder),
+                    "{language:?} {translated:?} lacks {placeholder}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn translates_known_strings() {
+    assert_eq!(translate(Language::Japanese, "Start"), "開始");
+    assert_eq!(translate(Language::Korean, "Stop"), "중지");
+    assert_eq!(translate(Language::Spanish, "Language"), "Idioma");
+    assert_eq!(translate(Language::English, "Start"), "Start");
+}
+
+#[test]
+fn unknown_strings_stay_english() {
+    for language in TRANSLATED {
+        assert_eq!(translate(language, "Not a GUI string"), "Not a GUI string");
+    }
+}
+
+#[test]
+fn fill_replaces_named_placeholders() {
+    let text = fill(
+        "Clicked at ({x}, {y}), {unknown}",
+        &[("x", &12), ("y", &"34")],
+    );
+    assert_eq!(text, "Clicked at (12, 34), {unknown}");
+}
+
+#[test]
+fn parse_catalog_rejects_non_string_values() {
+    assert!(parse_catalog("\"Start\" = \"開始\"").is_ok());
+    assert!(parse_catalog("\"Start\" = 1").is_err());
+}
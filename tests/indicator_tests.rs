@@ -0,0 +1,134 @@
+use std::time::{Duration, Instant};
+
+use lol_auto_accept_rs::{
+    config::IndicatorPalette,
+    indicator::{Cooldown, Indicator, IndicatorShape, contrasting},
+};
+
+const STATES: [Indicator; 4] = [
+    Indicator::Idle,
+    Indicator::Monitoring,
+    Indicator::Cooldown,
+    Indicator::Error,
+];
+
+#[test]
+fn idle_and_monitoring_follow_the_worker() {
+    let now = Instant::now();
+    assert_eq!(Indicator::current(false, false, None, now), Indicator::Idle);
+    assert_eq!(
+        Indicator::current(true, false, None, now),
+        Indicator::Monitoring
+    );
+}
+
+#[test]
+fn cooldown_lasts_until_its_end_while_running() {
+    let now = Instant::now();
+    let until = Some(now + Duration::from_secs(2));
+    assert_eq!(
+        Indicator::current(true, false, until, now),
+        Indicator::Cooldown
+    );
+    assert_eq!(
+        Indicator::current(true, false, until, now + Duration::from_secs(2)),
+        Indicator::Monitoring
+    );
+    assert_eq!(
+        Indicator::current(false, false, until, now),
+        Indicator::Idle
+    );
+}
+
+#[test]
+fn errors_win_over_every_other_state() {
+    let now = Instant::now();
+    let until = Some(now + Duration::from_secs(2));
+    assert_eq!(Indicator::current(true, true, until, now), Indicator::Error);
+    assert_eq!(Indicator::current(false, true, None, now), Indicator::Error);
+}
+
+#[test]
+fn states_have_distinct_colors() {
+    for palette in IndicatorPalette::ALL {
+        let colors = STATES.map(|state| state.color(palette));
+        for (index, color) in colors.iter().enumerate() {
+            assert!(!colors[index + 1..].contains(color));
+        }
+    }
+}
+
+#[test]
+fn accessible_palettes_give_each_state_a_shape() {
+    for palette in [IndicatorPalette::Colorblind, IndicatorPalette::HighContrast] {
+        let shapes = STATES.map(|state| state.shape(palette));
+        for (index, shape) in shapes.iter().enumerate() {
+            assert!(!shapes[index + 1..].contains(shape));
+        }
+    }
+    assert!(
+        STATES
+            .iter()
+            .all(|state| state.shape(IndicatorPalette::Standard) == IndicatorShape::Dot)
+    );
+}
+
+#[test]
+fn colorblind_palette_avoids_green_for_monitoring() {
+    let [r, g, b] = Indicator::Monitoring.color(IndicatorPalette::Colorblind);
+    assert!(b > g && b > r);
+}
+
+#[test]
+fn marks_cut_into_the_disc() {
+    assert!(!IndicatorShape::Dot.marks(0.0, 0.0));
+    assert!(IndicatorShape::Ring.marks(0.0, 0.0));
+    assert!(!IndicatorShape::Ring.marks(0.8, 0.0));
+    assert!(IndicatorShape::Pause.marks(0.3, 0.0));
+    assert!(!IndicatorShape::Pause.marks(0.0, 0.0));
+    assert!(IndicatorShape::Cross.marks(0.3, 0.3));
+    assert!(!IndicatorShape::Cross.marks(0.3, 0.0));
+}
+
+#[test]
+fn mark_color_contrasts_with_the_fill() {
+    assert_eq!(contrasting([0xff, 0xff, 0x00]), [0x10, 0x10, 0x10]);
+    assert_eq!(contrasting([0x00, 0x72, 0xb2]), [0xff, 0xff, 0xff]);
+}
+
+#[test]
+fn cooldown_progress_follows_the_clock() {
+    let now = Instant::now();
+    let cooldown = Cooldown::new(now, Duration::from_secs(4));
+    assert_eq!(cooldown.progress(now), 0.0);
+    assert!((cooldown.progress(now + Duration::from_secs(1)) - 0.25).abs() < 1e-6);
+    assert_eq!(
+        cooldown.remaining(now + Duration::from_secs(3)),
+        Duration::from_secs(1)
+    );
+    assert_eq!(cooldown.progress(now + Duration::from_secs(5)), 1.0);
+    assert_eq!(
+        cooldown.remaining(now + Duration::from_secs(5)),
+        Duration::ZERO
+    );
+}
+
+#[test]
+fn worker_reports_resync_without_resetting_the_total() {
+    let now = Instant::now();
+    let started = Cooldown::new(now, Duration::from_secs(4));
+    let later = now + Duration::from_secs(1);
+    let synced = Cooldown::sync(Some(started), later, Duration::from_millis(2500));
+    assert_eq!(synced.total, Duration::from_secs(4));
+    assert_eq!(synced.until, later + Duration::from_millis(2500));
+
+    let fresh = Cooldown::sync(None, now, Duration::from_secs(2));
+    assert_eq!(fresh.total, Duration::from_secs(2));
+    assert_eq!(fresh.progress(now), 0.0);
+}
+
+#[test]
+fn empty_cooldown_counts_as_over() {
+    let now = Instant::now();
+    assert_eq!(Cooldown::new(now, Duration::ZERO).progress(now), 1.0);
+}
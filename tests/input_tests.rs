@@ -0,0 +1,102 @@
+use std::time::{Duration, Instant};
+
+use lol_auto_accept_rs::{
+    config::HumanizeSettings,
+    input::{self, ActivityTracker, InputKey},
+};
+use rand::{SeedableRng, rngs::StdRng};
+
+#[test]
+fn humanized_path_ends_exactly_on_target() {
+    let settings = HumanizeSettings::default();
+    for seed in 0..20 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let path = input::humanized_path((0, 0), (800, 450), &settings, &mut rng);
+        let last = path.last().expect("path has points");
+        assert_eq!((last.x, last.y), (800, 450));
+        assert!(path.len() > 5, "path should have intermediate points");
+    }
+}
+
+#[test]
+fn humanized_path_stays_near_the_straight_line() {
+    let settings = HumanizeSettings {
+        curvature: 0.1,
+        overshoot_px: 5.0,
+        ..HumanizeSettings::default()
+    };
+    let mut rng = StdRng::seed_from_u64(7);
+    let path = input::humanized_path((0, 0), (1000, 0), &settings, &mut rng);
+    for point in &path {
+        assert!(point.y.abs() <= 100, "bow too large: {:?}", point);
+        assert!(point.x <= 1006, "overshoot too large: {:?}", point);
+    }
+}
+
+#[test]
+fn zero_distance_is_a_single_step() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let path = input::humanized_path((5, 5), (5, 5), &HumanizeSettings::default(), &mut rng);
+    assert_eq!(path.len(), 1);
+}
+
+#[test]
+fn jittered_delay_stays_within_spread() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let samples: Vec<_> = (0..200)
+        .map(|_| input::jittered(30, 15, &mut rng))
+        .collect();
+    assert!(
+        samples
+            .iter()
+            .all(|d| (15..=45).contains(&(d.as_millis() as u64)))
+    );
+    assert!(
+        samples.iter().any(|d| *d != samples[0]),
+        "delay should vary"
+    );
+    assert!(input::jittered(5, 20, &mut rng).as_millis() <= 25);
+    assert_eq!(input::jittered(40, 0, &mut rng).as_millis(), 40);
+}
+
+#[test]
+fn parse_key_accepts_named_and_single_character_keys() {
+    assert_eq!(input::parse_key("Enter"), Some(InputKey::Enter));
+    assert_eq!(input::parse_key(" return "), Some(InputKey::Enter));
+    assert_eq!(input::parse_key("SPACE"), Some(InputKey::Space));
+    assert_eq!(input::parse_key("a"), Some(InputKey::Char('a')));
+    assert_eq!(input::parse_key(""), None);
+    assert_eq!(input::parse_key("Enterr"), None);
+}
+
+#[test]
+fn parses_xdotool_shell_location() {
+    let output = "X=1280\nY=-40\nSCREEN=0\nWINDOW=12345\n";
+    assert_eq!(input::parse_xdotool_location(output), Some((1280, -40)));
+    assert_eq!(input::parse_xdotool_location("SCREEN=0\n"), None);
+}
+
+#[test]
+fn activity_tracker_measures_time_since_last_move() {
+    let start = Instant::now();
+    let mut tracker = ActivityTracker::new(start);
+    tracker.observe((100, 100), start);
+    tracker.observe((101, 99), start + Duration::from_millis(100));
+    assert_eq!(
+        tracker.still_for(start + Duration::from_millis(300)),
+        Duration::from_millis(300)
+    );
+
+    tracker.observe((140, 100), start + Duration::from_millis(400));
+    assert_eq!(
+        tracker.still_for(start + Duration::from_millis(500)),
+        Duration::from_millis(100)
+    );
+
+    tracker.reset_to((900, 500));
+    tracker.observe((900, 500), start + Duration::from_millis(600));
+    assert_eq!(
+        tracker.still_for(start + Duration::from_millis(600)),
+        Duration::from_millis(200)
+    );
+}
@@ -0,0 +1,75 @@
+use std::time::{Duration, Instant};
+
+use lol_auto_accept_rs::{
+    config::KioskSettings,
+    kiosk::{KioskLock, LOCKOUT, MAX_ATTEMPTS, UnlockOutcome},
+};
+
+fn settings(pin: &str) -> KioskSettings {
+    KioskSettings {
+        enabled: true,
+        pin: pin.to_string(),
+    }
+}
+
+#[test]
+fn locked_only_while_kiosk_mode_is_on() {
+    let lock = KioskLock::new();
+    assert!(lock.is_locked(&settings("1234")));
+    assert!(!lock.is_locked(&KioskSettings::default()));
+}
+
+#[test]
+fn the_right_pin_unlocks_until_locked_again() {
+    let settings = settings("1234");
+    let mut lock = KioskLock::new();
+    assert_eq!(
+        lock.try_unlock(&settings, " 1234 ", Instant::now()),
+        UnlockOutcome::Unlocked
+    );
+    assert!(!lock.is_locked(&settings));
+    lock.lock();
+    assert!(lock.is_locked(&settings));
+}
+
+#[test]
+fn wrong_pins_lead_to_a_lockout() {
+    let settings = settings("1234");
+    let mut lock = KioskLock::new();
+    let now = Instant::now();
+    assert_eq!(
+        lock.try_unlock(&settings, "0000", now),
+        UnlockOutcome::WrongPin {
+            remaining: MAX_ATTEMPTS - 1
+        }
+    );
+    for _ in 1..MAX_ATTEMPTS - 1 {
+        lock.try_unlock(&settings, "0000", now);
+    }
+    assert_eq!(
+        lock.try_unlock(&settings, "0000", now),
+        UnlockOutcome::LockedOut { retry_in: LOCKOUT }
+    );
+    // Even the right PIN is refused until the lockout ends.
+    assert_eq!(
+        lock.try_unlock(&settings, "1234", now + Duration::from_secs(10)),
+        UnlockOutcome::LockedOut {
+            retry_in: LOCKOUT - Duration::from_secs(10)
+        }
+    );
+    assert_eq!(
+        lock.try_unlock(&settings, "1234", now + LOCKOUT),
+        UnlockOutcome::Unlocked
+    );
+}
+
+#[test]
+fn without_a_pin_the_settings_stay_locked() {
+    let settings = settings("  ");
+    let mut lock = KioskLock::new();
+    assert_eq!(
+        lock.try_unlock(&settings, "", Instant::now()),
+        UnlockOutcome::NoPin
+    );
+    assert!(lock.is_locked(&settings));
+}
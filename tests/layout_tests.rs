@@ -0,0 +1,71 @@
+use lol_auto_accept_rs::{capture::MonitorInfo, layout::WindowLayout};
+
+fn monitor(origin_x: i32, width: u32, scale_factor: f32) -> MonitorInfo {
+    MonitorInfo {
+        id: 1,
+        name: "Display 1".into(),
+        width,
+        height: 1080,
+        scale_factor,
+        is_primary: origin_x == 0,
+        origin_x,
+        origin_y: 0,
+    }
+}
+
+#[test]
+fn sections_fall_back_to_their_default() {
+    let mut layout = WindowLayout::default();
+    assert!(layout.section_open("logs", true));
+    assert!(!layout.section_open("statistics", false));
+    layout.set_section_open("logs", false);
+    assert!(!layout.section_open("logs", true));
+}
+
+#[test]
+fn position_on_a_connected_display_is_kept() {
+    let layout = WindowLayout {
+        position: Some([2100.0, 100.0]),
+        ..WindowLayout::default()
+    };
+    let monitors = [monitor(0, 1920, 1.0), monitor(1920, 1920, 1.0)];
+    assert_eq!(layout.visible_position(&monitors), Some([2100.0, 100.0]));
+}
+
+#[test]
+fn position_on_a_disconnected_display_is_dropped() {
+    let layout = WindowLayout {
+        position: Some([2100.0, 100.0]),
+        ..WindowLayout::default()
+    };
+    assert_eq!(layout.visible_position(&[monitor(0, 1920, 1.0)]), None);
+    assert_eq!(
+        WindowLayout::default().visible_position(&[monitor(0, 1920, 1.0)]),
+        None
+    );
+}
+
+#[test]
+fn display_bounds_are_compared_in_logical_points() {
+    let layout = WindowLayout {
+        position: Some([1500.0, 100.0]),
+        ..WindowLayout::default()
+    };
+    // 3840 physical pixels at 200 % are 1920 points wide.
+    assert!(layout.visible_position(&[monitor(0, 3840, 2.0)]).is_some());
+    assert!(layout.visible_position(&[monitor(0, 2560, 2.0)]).is_none());
+}
+
+#[test]
+fn layout_round_trips_through_toml() {
+    let mut layout = WindowLayout {
+        position: Some([10.0, 20.0]),
+        size: Some([640.0, 800.0]),
+        maximized: true,
+        ..WindowLayout::default()
+    };
+    layout.set_section_open("history", true);
+    let text = toml::to_string(&layout).expect("serializes");
+    let parsed: WindowLayout = toml::from_str(&text).expect("parses");
+    assert_eq!(parsed, layout);
+}
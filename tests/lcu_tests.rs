@@ -0,0 +1,135 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    path::PathBuf,
+    thread,
+    time::Duration,
+};
+
+use lol_auto_accept_rs::lcu::{
+    LcuClient, LcuEventStream, LcuMessage, Lockfile, READY_CHECK_ACCEPT_PATH, READY_CHECK_EVENT,
+    READY_CHECK_URI, parse_event_message, ready_check_pending,
+};
+use serde_json::json;
+use tungstenite::Message;
+
+#[test]
+fn lockfile_parses_all_fields() {
+    let lockfile = Lockfile::parse("LeagueClient:12345:54321:s3cr3t:https\n").expect("parses");
+    assert_eq!(lockfile.process, "LeagueClient");
+    assert_eq!(lockfile.pid, 12345);
+    assert_eq!(lockfile.port, 54321);
+    assert_eq!(lockfile.password, "s3cr3t");
+    assert_eq!(lockfile.base_url(), "https://127.0.0.1:54321");
+    // base64("riot:s3cr3t")
+    assert_eq!(lockfile.authorization(), "Basic cmlvdDpzM2NyM3Q=");
+}
+
+#[test]
+fn malformed_lockfile_is_rejected() {
+    assert!(Lockfile::parse("LeagueClient:12345:54321").is_err());
+    assert!(Lockfile::parse("LeagueClient:pid:54321:pw:https").is_err());
+    assert!(Lockfile::parse("LeagueClient:1:54321::https").is_err());
+}
+
+#[test]
+fn accept_posts_authenticated_request() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let port = listener.local_addr().unwrap().port();
+    let server = thread::spawn(move || {
+        let (stream, _) = listener.accept().expect("accept");
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut head = Vec::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line.trim().is_empty() {
+                break;
+            }
+            head.push(line.trim().to_string());
+        }
+        let mut stream = stream;
+        stream
+            .write_all(b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n")
+            .unwrap();
+        head
+    });
+
+    let lockfile = Lockfile::parse(&format!("LeagueClient:1:{port}:pw:http")).unwrap();
+    LcuClient::new(&lockfile)
+        .expect("client")
+        .accept_ready_check()
+        .expect("accept succeeds");
+
+    let head = server.join().unwrap();
+    assert_eq!(head[0], format!("POST {READY_CHECK_ACCEPT_PATH} HTTP/1.1"));
+    assert!(head.contains(&format!("Authorization: {}", lockfile.authorization())));
+}
+
+#[test]
+fn event_frames_are_parsed() {
+    let text = r#"[8,"OnJsonApiEvent_lol-matchmaking_v1_ready-check",{"data":{"state":"InProgress","playerResponse":"None"},"eventType":"Update","uri":"/lol-matchmaking/v1/ready-check"}]"#;
+    let event = parse_event_message(text).expect("event frame");
+    assert_eq!(event.uri, READY_CHECK_URI);
+    assert_eq!(event.event_type, "Update");
+    assert!(ready_check_pending(&event.data));
+
+    assert!(parse_event_message(r#"[0,"session","1",""]"#).is_none());
+    assert!(parse_event_message("not json").is_none());
+}
+
+#[test]
+fn answered_ready_check_is_not_pending() {
+    assert!(!ready_check_pending(
+        &json!({"state": "InProgress", "playerResponse": "Accepted"})
+    ));
+    assert!(!ready_check_pending(&json!({"state": "Invalid"})));
+}
+
+#[test]
+fn event_stream_subscribes_and_forwards_events() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let port = listener.local_addr().unwrap().port();
+    let dir = tempdir();
+    let lockfile = dir.join("lockfile");
+    fs::write(&lockfile, format!("LeagueClient:1:{port}:pw:http")).unwrap();
+
+    let server = thread::spawn(move || {
+        let (stream, _) = listener.accept().expect("accept");
+        let mut socket = tungstenite::accept(stream).expect("handshake");
+        let subscribe = socket.read().expect("subscribe").into_text().unwrap();
+        socket
+            .send(Message::text(format!(
+                r#"[8,"{READY_CHECK_EVENT}",{{"data":{{"state":"InProgress","playerResponse":"None"}},"eventType":"Update","uri":"{READY_CHECK_URI}"}}]"#
+            )))
+            .unwrap();
+        // Keep the socket open until the client hangs up.
+        while socket.read().is_ok() {}
+        subscribe
+    });
+
+    let stream = LcuEventStream::spawn(Some(lockfile), vec![READY_CHECK_EVENT.to_string()]);
+    let rx = stream.receiver();
+    assert_eq!(
+        rx.recv_timeout(Duration::from_secs(5)).unwrap(),
+        LcuMessage::Connected
+    );
+    match rx.recv_timeout(Duration::from_secs(5)).unwrap() {
+        LcuMessage::Event(event) => assert!(ready_check_pending(&event.data)),
+        other => panic!("unexpected message {other:?}"),
+    }
+    drop(stream);
+
+    assert_eq!(
+        server.join().unwrap(),
+        format!(r#"[5, "{READY_CHECK_EVENT}"]"#)
+    );
+    let _ = fs::remove_dir_all(dir);
+}
+
+fn tempdir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("lcu-tests-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
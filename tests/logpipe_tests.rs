@@ -0,0 +1,65 @@
+use lol_auto_accept_rs::logpipe::{LogFilter, LogLine, export};
+use tracing::Level;
+
+fn line(level: Level, target: &str, text: &str) -> LogLine {
+    LogLine::new(level, target, text)
+}
+
+#[test]
+fn module_is_the_last_target_segment() {
+    assert_eq!(
+        line(Level::INFO, "lol_auto_accept_rs::worker", "x").module,
+        "worker"
+    );
+    assert_eq!(line(Level::INFO, "main", "x").module, "main");
+}
+
+#[test]
+fn default_filter_shows_everything() {
+    let filter = LogFilter::default();
+    assert!(filter.matches(&line(Level::DEBUG, "a::worker", "frame")));
+    assert!(filter.matches(&line(Level::ERROR, "a::lcu", "down")));
+}
+
+#[test]
+fn level_filter_keeps_more_severe_lines() {
+    let filter = LogFilter {
+        level: Level::WARN,
+        ..LogFilter::default()
+    };
+    assert!(filter.matches(&line(Level::ERROR, "a::worker", "failed")));
+    assert!(filter.matches(&line(Level::WARN, "a::worker", "slow")));
+    assert!(!filter.matches(&line(Level::INFO, "a::worker", "started")));
+}
+
+#[test]
+fn module_and_query_filters_combine() {
+    let filter = LogFilter {
+        query: "CLICK".to_string(),
+        module: Some("worker".to_string()),
+        ..LogFilter::default()
+    };
+    assert!(filter.matches(&line(Level::INFO, "a::worker", "Clicked at (1, 2)")));
+    assert!(!filter.matches(&line(Level::INFO, "a::worker", "Template loaded")));
+    assert!(!filter.matches(&line(Level::INFO, "a::input", "Clicked at (1, 2)")));
+}
+
+#[test]
+fn export_writes_buffer_and_session_log() {
+    let dir = std::env::temp_dir().join(format!("logpipe-export-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let session = dir.join("session.log");
+    std::fs::write(&session, "early line\nlate line\n").unwrap();
+    let out = dir.join("export.log");
+
+    export(&out, &["late line"], None).unwrap();
+    assert_eq!(std::fs::read_to_string(&out).unwrap(), "late line\n");
+
+    export(&out, &["late line"], Some(&session)).unwrap();
+    let text = std::fs::read_to_string(&out).unwrap();
+    assert!(text.starts_with("late line\n"));
+    assert!(text.ends_with("early line\nlate line\n"));
+
+    assert!(export(&out, &[], Some(&dir.join("missing.log"))).is_err());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
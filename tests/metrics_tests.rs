@@ -0,0 +1,50 @@
+use std::time::{Duration, Instant};
+
+use lol_auto_accept_rs::{
+    capture::ArenaStats,
+    metrics::{self, MetricsAccumulator},
+};
+
+#[test]
+fn accumulator_averages_frames_over_window() {
+    let start = Instant::now();
+    let mut acc = MetricsAccumulator::new(Duration::from_secs(1), start);
+    acc.record_frame(
+        Duration::from_millis(20),
+        Duration::from_millis(10),
+        Duration::from_millis(50),
+    );
+    acc.record_frame(
+        Duration::from_millis(40),
+        Duration::from_millis(20),
+        Duration::from_millis(70),
+    );
+
+    let later = start + Duration::from_secs(2);
+    assert!(acc.is_due(later));
+    let metrics = acc.take(later, ArenaStats::default(), Some(1024));
+    assert!((metrics.capture_fps - 1.0).abs() < 1e-3);
+    assert!((metrics.avg_capture_ms - 30.0).abs() < 1e-3);
+    assert!((metrics.avg_match_ms - 15.0).abs() < 1e-3);
+    assert!((metrics.avg_latency_ms - 60.0).abs() < 1e-3);
+    assert_eq!(metrics.resident_bytes, Some(1024));
+
+    assert!(!acc.is_due(later));
+    let empty = acc.take(later + Duration::from_secs(1), ArenaStats::default(), None);
+    assert_eq!(empty.capture_fps, 0.0);
+    assert_eq!(empty.avg_capture_ms, 0.0);
+    assert_eq!(empty.avg_match_ms, 0.0);
+}
+
+#[test]
+fn parses_resident_set_from_proc_status() {
+    let status = "Name:\tlol\nVmPeak:\t  90000 kB\nVmRSS:\t   51200 kB\nThreads:\t4\n";
+    assert_eq!(metrics::parse_vm_rss(status), Some(51200 * 1024));
+    assert_eq!(metrics::parse_vm_rss("Name:\tlol\n"), None);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn reports_resident_memory_on_linux() {
+    assert!(metrics::resident_memory().is_some_and(|bytes| bytes > 0));
+}
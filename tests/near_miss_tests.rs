@@ -0,0 +1,51 @@
+use lol_auto_accept_rs::{
+    config::NearMissSettings,
+    near_miss::{NearMissWatch, PossibleMiss},
+};
+
+fn settings(frames: u32) -> NearMissSettings {
+    NearMissSettings {
+        enabled: true,
+        margin: 0.03,
+        frames,
+    }
+}
+
+#[test]
+fn warns_once_after_enough_near_misses_in_a_row() {
+    let settings = settings(3);
+    let mut watch = NearMissWatch::new();
+    assert_eq!(watch.observe(&settings, Some(0.86), 0.88), None);
+    assert_eq!(watch.observe(&settings, Some(0.87), 0.88), None);
+    assert_eq!(
+        watch.observe(&settings, Some(0.865), 0.88),
+        Some(PossibleMiss {
+            score: 0.87,
+            threshold: 0.88,
+            frames: 3,
+        })
+    );
+    assert_eq!(watch.observe(&settings, Some(0.86), 0.88), None);
+}
+
+#[test]
+fn a_frame_outside_the_band_ends_the_run() {
+    let settings = settings(2);
+    for interruption in [Some(0.5), Some(0.9), None] {
+        let mut watch = NearMissWatch::new();
+        assert_eq!(watch.observe(&settings, Some(0.86), 0.88), None);
+        assert_eq!(watch.observe(&settings, interruption, 0.88), None);
+        assert_eq!(watch.observe(&settings, Some(0.86), 0.88), None);
+        assert!(watch.observe(&settings, Some(0.86), 0.88).is_some());
+    }
+}
+
+#[test]
+fn disabled_never_warns() {
+    let settings = NearMissSettings {
+        enabled: false,
+        ..settings(1)
+    };
+    let mut watch = NearMissWatch::new();
+    assert_eq!(watch.observe(&settings, Some(0.87), 0.88), None);
+}
@@ -0,0 +1,325 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    thread,
+};
+
+use chrono::NaiveTime;
+use image::GrayImage;
+use lol_auto_accept_rs::{
+    config::{AppConfig, DiscordSettings, NotificationSettings, TelegramSettings},
+    notifications::{
+        DiscordWebhook, Notice, NoticeKind, NotificationTarget, TelegramBot, accept_message,
+        auto_stop_message, found_message, multipart_body, possible_miss_message, redacted, targets,
+    },
+};
+use serde_json::{Value, json};
+
+/// Answers one request with `status`, returning its head lines and body.
+fn serve_once(
+    listener: TcpListener,
+    status: &'static str,
+) -> thread::JoinHandle<(Vec<String>, Vec<u8>)> {
+    thread::spawn(move || {
+        let (stream, _) = listener.accept().expect("accept");
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut head = Vec::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line.trim().is_empty() {
+                break;
+            }
+            head.push(line.trim().to_string());
+        }
+        let length = head
+            .iter()
+            .find_map(|line| line.strip_prefix("Content-Length: "))
+            .map_or(0, |value| value.parse().unwrap());
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        let mut stream = stream;
+        stream
+            .write_all(format!("HTTP/1.1 {status}\r\nContent-Length: 0\r\n\r\n").as_bytes())
+            .unwrap();
+        (head, body)
+    })
+}
+
+fn webhook(port: u16, attach_screenshot: bool) -> DiscordWebhook {
+    DiscordWebhook::new(&DiscordSettings {
+        enabled: true,
+        webhook_url: format!(" http://127.0.0.1:{port}/api/webhooks/1/token "),
+        attach_screenshot,
+    })
+}
+
+#[test]
+fn screen_accept_reports_time_and_score() {
+    let time = NaiveTime::from_hms_opt(18, 42, 7).unwrap();
+    assert_eq!(
+        accept_message(time, Some(0.9312)),
+        "Match accepted at 18:42, score 0.93"
+    );
+}
+
+#[test]
+fn api_accept_has_no_score() {
+    let time = NaiveTime::from_hms_opt(9, 5, 0).unwrap();
+    assert_eq!(
+        accept_message(time, None),
+        "Match accepted at 09:05 through the client API"
+    );
+}
+
+#[test]
+fn only_enabled_targets_are_built() {
+    assert!(targets(&NotificationSettings::default()).is_empty());
+    let settings = NotificationSettings {
+        desktop: true,
+        discord: DiscordSettings {
+            enabled: true,
+            ..DiscordSettings::default()
+        },
+        telegram: TelegramSettings {
+            enabled: true,
+            ..TelegramSettings::default()
+        },
+        redact: false,
+    };
+    let targets = targets(&settings);
+    let names: Vec<_> = targets.iter().map(|target| target.name()).collect();
+    assert_eq!(names, ["desktop", "Discord", "Telegram"]);
+
+    let wanting_errors: Vec<_> = targets
+        .iter()
+        .filter(|target| target.wants(NoticeKind::Error))
+        .map(|target| target.name())
+        .collect();
+    assert_eq!(wanting_errors, ["Telegram"]);
+    assert!(
+        targets
+            .iter()
+            .all(|target| target.wants(NoticeKind::Accepted))
+    );
+}
+
+#[test]
+fn found_message_reports_score() {
+    let time = NaiveTime::from_hms_opt(18, 41, 59).unwrap();
+    assert_eq!(
+        found_message(time, 0.914),
+        "Match found at 18:41, score 0.91"
+    );
+}
+
+fn telegram(port: u16) -> TelegramBot {
+    TelegramBot {
+        api_base: format!("http://127.0.0.1:{port}"),
+        ..TelegramBot::new(&TelegramSettings {
+            enabled: true,
+            bot_token: " 123:abc ".to_string(),
+            chat_id: "-42".to_string(),
+        })
+    }
+}
+
+#[test]
+fn telegram_sends_message_to_chat() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let port = listener.local_addr().unwrap().port();
+    let server = serve_once(listener, "200 OK");
+
+    let notice = Notice {
+        kind: NoticeKind::Error,
+        message: "Capture failed".to_string(),
+        screenshot: None,
+    };
+    telegram(port).send(&notice).expect("sent");
+
+    let (head, body) = server.join().unwrap();
+    assert_eq!(head[0], "POST /bot123:abc/sendMessage HTTP/1.1");
+    let payload: Value = serde_json::from_slice(&body).expect("json body");
+    assert_eq!(payload, json!({"chat_id": "-42", "text": "Capture failed"}));
+}
+
+#[test]
+fn telegram_errors_leave_out_the_token() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let port = listener.local_addr().unwrap().port();
+    let server = serve_once(listener, "404 Not Found");
+
+    let notice = Notice {
+        kind: NoticeKind::Found,
+        message: "found".to_string(),
+        screenshot: None,
+    };
+    let err = telegram(port).send(&notice).unwrap_err();
+    assert!(format!("{err:#}").contains("404"), "{err:#}");
+    assert!(!format!("{err:#}").contains("123:abc"), "{err:#}");
+    server.join().unwrap();
+
+    let unset = TelegramBot::new(&TelegramSettings::default());
+    assert!(unset.send(&notice).is_err());
+}
+
+#[test]
+fn webhook_posts_message_as_json() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let port = listener.local_addr().unwrap().port();
+    let server = serve_once(listener, "204 No Content");
+
+    let notice = Notice {
+        kind: NoticeKind::Accepted,
+        message: "Match accepted at 18:42, score 0.93".to_string(),
+        screenshot: Some(GrayImage::new(4, 4)),
+    };
+    webhook(port, false).send(&notice).expect("sent");
+
+    let (head, body) = server.join().unwrap();
+    assert_eq!(head[0], "POST /api/webhooks/1/token HTTP/1.1");
+    let payload: Value = serde_json::from_slice(&body).expect("json body");
+    assert_eq!(payload["content"], notice.message);
+}
+
+#[test]
+fn webhook_attaches_screenshot_as_multipart() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let port = listener.local_addr().unwrap().port();
+    let server = serve_once(listener, "200 OK");
+
+    let notice = Notice {
+        kind: NoticeKind::Accepted,
+        message: "accepted".to_string(),
+        screenshot: Some(GrayImage::new(4, 4)),
+    };
+    webhook(port, true).send(&notice).expect("sent");
+
+    let (head, body) = server.join().unwrap();
+    assert!(
+        head.iter()
+            .any(|line| line.starts_with("Content-Type: multipart/form-data; boundary="))
+    );
+    let body = String::from_utf8_lossy(&body);
+    assert!(body.contains(r#"name="payload_json""#));
+    assert!(body.contains(r#"filename="detection_frame.png""#));
+    assert!(body.contains("PNG"));
+}
+
+#[test]
+fn webhook_reports_rejections() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let port = listener.local_addr().unwrap().port();
+    let server = serve_once(listener, "401 Unauthorized");
+
+    let notice = Notice {
+        kind: NoticeKind::Accepted,
+        message: "accepted".to_string(),
+        screenshot: None,
+    };
+    let err = webhook(port, false).send(&notice).unwrap_err();
+    assert!(format!("{err:#}").contains("401"), "{err:#}");
+    server.join().unwrap();
+
+    let unset = DiscordWebhook::new(&DiscordSettings::default());
+    assert!(unset.send(&notice).is_err());
+}
+
+#[test]
+fn webhook_errors_leave_out_the_url() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let port = listener.local_addr().unwrap().port();
+    // Nothing listens there any more, so the request fails before any answer.
+    drop(listener);
+
+    let notice = Notice {
+        kind: NoticeKind::Accepted,
+        message: "accepted".to_string(),
+        screenshot: None,
+    };
+    let err = webhook(port, false).send(&notice).unwrap_err();
+    assert!(
+        !format!("{err:#}").contains("/api/webhooks/1/token"),
+        "{err:#}"
+    );
+    assert!(
+        !format!("{err:?}").contains("/api/webhooks/1/token"),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn multipart_body_wraps_payload_and_png() {
+    let body = multipart_body(&json!({"content": "hi"}), b"\x89PNG");
+    let text = String::from_utf8_lossy(&body);
+    assert!(text.starts_with("--lol-auto-accept-notification\r\n"));
+    assert!(text.contains("Content-Type: application/json\r\n\r\n{\"content\":\"hi\"}\r\n"));
+    assert!(text.contains("Content-Type: image/png\r\n\r\n\u{FFFD}PNG\r\n"));
+    assert!(text.ends_with("--lol-auto-accept-notification--\r\n"));
+}
+
+#[test]
+fn auto_stop_message_rounds_minutes_up() {
+    assert_eq!(
+        auto_stop_message(Some(std::time::Duration::from_secs(241))),
+        "Nothing detected for a while, monitoring stops in 5 min"
+    );
+    assert_eq!(
+        auto_stop_message(None),
+        "Nothing detected for a while, monitoring stopped"
+    );
+}
+
+#[test]
+fn desktop_takes_auto_stop_notices() {
+    let settings = NotificationSettings {
+        desktop: true,
+        ..NotificationSettings::default()
+    };
+    let targets = targets(&settings);
+    assert!(targets[0].wants(NoticeKind::AutoStop));
+    assert!(!targets[0].wants(NoticeKind::Error));
+}
+
+#[test]
+fn possible_miss_message_names_score_and_threshold() {
+    assert_eq!(
+        possible_miss_message(0.8712, 0.88, 5),
+        "Possible missed queue pop: 5 frames in a row scored up to 0.871, just under the threshold 0.88. Consider lowering it."
+    );
+    let settings = NotificationSettings {
+        desktop: true,
+        ..NotificationSettings::default()
+    };
+    assert!(targets(&settings)[0].wants(NoticeKind::PossibleMiss));
+}
+
+#[test]
+fn streamer_mode_drops_the_desktop_toast() {
+    let mut config = AppConfig::default();
+    config.notifications.desktop = true;
+    config.notifications.telegram.enabled = true;
+    config.streamer.enabled = true;
+    let settings = config.effective_notifications();
+    assert!(settings.redact);
+    let names: Vec<_> = targets(&settings)
+        .iter()
+        .map(|target| target.name())
+        .collect();
+    assert_eq!(names, ["Telegram"]);
+
+    config.streamer.hide_notifications = false;
+    assert!(!config.effective_notifications().redact);
+}
+
+#[test]
+fn redacted_notices_keep_only_a_generic_line() {
+    let notice = redacted(Notice {
+        kind: NoticeKind::Accepted,
+        message: "Match accepted at 18:41, score 0.93".to_string(),
+        screenshot: Some(GrayImage::new(2, 2)),
+    });
+    assert_eq!(notice.kind, NoticeKind::Accepted);
+    assert_eq!(notice.message, "Match accepted");
+    assert!(notice.screenshot.is_none());
+}
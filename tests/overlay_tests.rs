@@ -0,0 +1,16 @@
+use lol_auto_accept_rs::overlay::window_rect;
+
+#[test]
+fn window_surrounds_the_match_with_room_for_the_label() {
+    let rect = window_rect((100, 200), (180, 230));
+    assert!(rect.min.x < 100.0 && rect.min.y < 200.0);
+    assert!(rect.max.x > 180.0 && rect.max.y > 230.0);
+    // The score label sits above the box, so there is more room on top than below.
+    assert!(200.0 - rect.min.y > rect.max.y - 230.0);
+}
+
+#[test]
+fn degenerate_matches_still_get_a_window() {
+    let rect = window_rect((50, 50), (50, 50));
+    assert!(rect.width() > 0.0 && rect.height() > 0.0);
+}
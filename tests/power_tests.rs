@@ -0,0 +1,80 @@
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use lol_auto_accept_rs::{
+    config::AppConfig,
+    power::{self, POLL_INTERVAL, PowerSource, PowerWatch},
+};
+
+fn config() -> AppConfig {
+    AppConfig {
+        interval_ms: 120,
+        skip_unchanged: false,
+        template_scales: vec![0.8, 0.9, 1.0, 1.1, 1.2, 1.3],
+        ..AppConfig::default()
+    }
+}
+
+#[test]
+fn mains_power_keeps_the_configured_pace() {
+    let pace = power::pace(&config(), PowerSource::Mains);
+    assert_eq!(pace.interval, Duration::from_millis(120));
+    assert!(!pace.skip_unchanged);
+    assert_eq!(pace.scales, None);
+}
+
+#[test]
+fn battery_slows_down_and_thins_the_scales() {
+    let mut config = config();
+    config.battery.max_scales = 3;
+    let pace = power::pace(&config, PowerSource::Battery);
+    assert_eq!(
+        pace.interval,
+        Duration::from_millis(config.battery.interval_ms)
+    );
+    assert!(pace.skip_unchanged);
+    assert_eq!(pace.scales, Some(vec![0.8, 1.0, 1.3]));
+}
+
+#[test]
+fn battery_never_speeds_up_or_runs_when_disabled() {
+    let mut config = config();
+    config.interval_ms = 2_000;
+    let pace = power::pace(&config, PowerSource::Battery);
+    assert_eq!(pace.interval, Duration::from_millis(2_000));
+
+    config.battery.enabled = false;
+    assert_eq!(
+        power::pace(&config, PowerSource::Battery),
+        power::pace(&config, PowerSource::Mains)
+    );
+}
+
+#[test]
+fn watch_reports_changes_only_when_a_check_is_due() {
+    let start = Instant::now();
+    let mut watch = PowerWatch::new(start);
+    assert_eq!(watch.poll(start, || Ok(PowerSource::Mains)).unwrap(), None);
+    // Not due yet: the query is not even run.
+    assert_eq!(
+        watch
+            .poll(start + Duration::from_secs(1), || unreachable!())
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        watch
+            .poll(start + POLL_INTERVAL, || Ok(PowerSource::Battery))
+            .unwrap(),
+        Some(PowerSource::Battery)
+    );
+    assert_eq!(watch.source(), PowerSource::Battery);
+    assert!(
+        watch
+            .poll(start + POLL_INTERVAL * 2, || Err(anyhow!(
+                "no battery driver"
+            )))
+            .is_err()
+    );
+    assert_eq!(watch.source(), PowerSource::Battery);
+}
@@ -0,0 +1,40 @@
+use lol_auto_accept_rs::{config::AppConfig, presets::Preset};
+
+#[test]
+fn default_config_is_the_balanced_preset() {
+    assert_eq!(
+        Preset::current(&AppConfig::default()),
+        Some(Preset::Balanced)
+    );
+}
+
+#[test]
+fn applying_a_preset_selects_it() {
+    for preset in Preset::ALL {
+        let mut config = AppConfig::default();
+        preset.apply(&mut config);
+        assert_eq!(Preset::current(&config), Some(preset));
+    }
+}
+
+#[test]
+fn power_saver_polls_slower_and_skips_unchanged_frames() {
+    let mut config = AppConfig::default();
+    Preset::PowerSaver.apply(&mut config);
+    assert!(config.interval_ms > AppConfig::default().interval_ms);
+    assert!(config.skip_unchanged);
+
+    Preset::Aggressive.apply(&mut config);
+    assert!(config.interval_ms < AppConfig::default().interval_ms);
+    assert!(config.threshold < AppConfig::default().threshold);
+    assert!(!config.skip_unchanged);
+}
+
+#[test]
+fn hand_tuned_settings_match_no_preset() {
+    let config = AppConfig {
+        interval_ms: 333,
+        ..AppConfig::default()
+    };
+    assert_eq!(Preset::current(&config), None);
+}
@@ -0,0 +1,77 @@
+use image::GrayImage;
+use lol_auto_accept_rs::{
+    capture::CapturedFrame,
+    config::{AppConfig, SearchRegion},
+    detect::Detection,
+    probe::{CLICK_COLOR, MATCH_COLOR, REGION_COLOR, WEAK_MATCH_COLOR, annotate, evaluate},
+};
+
+fn frame(scale_factor: f32) -> CapturedFrame {
+    CapturedFrame {
+        image: GrayImage::new(300, 200),
+        origin: (1920, 0),
+        scale_factor,
+    }
+}
+
+fn detection(score: f32) -> Detection {
+    Detection {
+        score,
+        position: (60, 40),
+        template_size: (120, 60),
+        scale: 1.0,
+        threshold: None,
+    }
+}
+
+#[test]
+fn click_point_uses_logical_coordinates_and_offset() {
+    let config = AppConfig {
+        click_offset_x: 5,
+        click_offset_y: -3,
+        ..AppConfig::default()
+    };
+    let probe = evaluate(&config, frame(2.0), Some(detection(0.95)));
+    // Center (120, 70) physical on a 200% monitor at x = 1920.
+    assert_eq!(probe.click, Some((1920 + 60 + 5, 35 - 3)));
+}
+
+#[test]
+fn threshold_decides_whether_it_would_click() {
+    let config = AppConfig {
+        threshold: 0.9,
+        ..AppConfig::default()
+    };
+    assert!(evaluate(&config, frame(1.0), Some(detection(0.95))).matched);
+    assert!(!evaluate(&config, frame(1.0), Some(detection(0.5))).matched);
+    let none = evaluate(&config, frame(1.0), None);
+    assert!(!none.matched);
+    assert_eq!(none.click, None);
+}
+
+#[test]
+fn annotation_marks_region_match_and_click() {
+    let config = AppConfig {
+        threshold: 0.9,
+        ..AppConfig::default()
+    };
+    let region = SearchRegion {
+        x: 10,
+        y: 10,
+        width: 280,
+        height: 180,
+    };
+    let annotated = annotate(
+        &evaluate(&config, frame(1.0), Some(detection(0.95))),
+        Some(region),
+    );
+    assert_eq!(annotated.dimensions(), (300, 200));
+    assert_eq!(*annotated.get_pixel(10, 100), REGION_COLOR);
+    assert_eq!(*annotated.get_pixel(60, 70), MATCH_COLOR);
+    assert_eq!(*annotated.get_pixel(120, 70), CLICK_COLOR);
+    assert_eq!(annotated.get_pixel(0, 0).0, [0, 0, 0, 255]);
+
+    let weak = annotate(&evaluate(&config, frame(1.0), Some(detection(0.5))), None);
+    assert_eq!(*weak.get_pixel(60, 70), WEAK_MATCH_COLOR);
+    assert_eq!(weak.get_pixel(10, 100).0, [0, 0, 0, 255]);
+}
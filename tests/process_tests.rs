@@ -0,0 +1,33 @@
+use lol_auto_accept_rs::process::{LEAGUE_CLIENT_PROCESS, matches_process};
+
+#[test]
+fn windows_and_truncated_linux_names_match() {
+    assert!(matches_process("LeagueClientUx.exe", LEAGUE_CLIENT_PROCESS));
+    assert!(matches_process("leagueclientux.EXE", LEAGUE_CLIENT_PROCESS));
+    assert!(matches_process("LeagueClientUx.\n", LEAGUE_CLIENT_PROCESS));
+    assert!(matches_process("LeagueClientUx", LEAGUE_CLIENT_PROCESS));
+}
+
+#[test]
+fn other_league_processes_do_not_match() {
+    assert!(!matches_process("LeagueClient.exe", LEAGUE_CLIENT_PROCESS));
+    assert!(!matches_process(
+        "LeagueClientUxRender.exe",
+        LEAGUE_CLIENT_PROCESS
+    ));
+    assert!(!matches_process(
+        "League of Legends.exe",
+        LEAGUE_CLIENT_PROCESS
+    ));
+    assert!(!matches_process("LeagueClientUxR", LEAGUE_CLIENT_PROCESS));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn own_process_is_found() {
+    use lol_auto_accept_rs::process;
+
+    let own = std::fs::read_to_string("/proc/self/comm").expect("comm");
+    assert_eq!(process::is_running(own.trim()), Some(true));
+    assert_eq!(process::is_running("no-such-process-name"), Some(false));
+}
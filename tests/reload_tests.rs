@@ -0,0 +1,111 @@
+use std::{
+    fs,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use clap::Parser;
+use lol_auto_accept_rs::{
+    cli::Cli,
+    config::AppConfig,
+    reload::{self, ConfigWatcher, Debounce, SETTLE},
+    worker::LiveSettings,
+};
+
+#[test]
+fn debounce_waits_for_the_changes_to_settle() {
+    let start = Instant::now();
+    let mut debounce = Debounce::new();
+    assert!(!debounce.is_due(start));
+    assert_eq!(debounce.remaining(start), None);
+
+    debounce.on_change(start);
+    debounce.on_change(start + Duration::from_millis(200));
+    assert!(!debounce.is_due(start + SETTLE));
+    assert_eq!(
+        debounce.remaining(start + SETTLE),
+        Some(Duration::from_millis(200))
+    );
+    assert!(debounce.is_due(start + SETTLE + Duration::from_millis(200)));
+    assert!(
+        !debounce.is_due(start + SETTLE * 2),
+        "one burst reloads once"
+    );
+}
+
+#[test]
+fn live_settings_alone_need_no_restart() {
+    let old = AppConfig::default();
+    let live = AppConfig {
+        threshold: 0.9,
+        interval_ms: 500,
+        cooldown_ms: 1_000,
+        dry_run: true,
+        skip_unchanged: true,
+        ..AppConfig::default()
+    };
+    assert!(!reload::needs_restart(&old, &live));
+
+    let moved = AppConfig {
+        monitor_index: 1,
+        ..live.clone()
+    };
+    assert!(reload::needs_restart(&old, &moved));
+}
+
+#[test]
+fn watcher_reports_writes_to_the_file_only() {
+    let dir = std::env::temp_dir().join(format!("reload-tests-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("config.toml");
+    fs::write(&path, "threshold = 0.8\n").unwrap();
+    let wakes = Arc::new(AtomicUsize::new(0));
+    let counter = wakes.clone();
+    let mut watcher = ConfigWatcher::start(&path, move || {
+        counter.fetch_add(1, Ordering::Relaxed);
+    })
+    .expect("watcher starts");
+
+    fs::write(dir.join("other.toml"), "x = 1\n").unwrap();
+    fs::write(&path, "threshold = 0.9\n").unwrap();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while wakes.load(Ordering::Relaxed) == 0 && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(20));
+    }
+    assert!(wakes.load(Ordering::Relaxed) > 0, "the write wakes the UI");
+
+    let now = Instant::now();
+    assert!(!watcher.poll(now), "not before the write settles");
+    assert!(watcher.poll(now + SETTLE));
+    assert!(!watcher.poll(now + SETTLE * 2));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn reloads_keep_the_command_line_overrides() {
+    let cli = Cli::try_parse_from(["lol-auto-accept-rs", "--dry-run"]).expect("parses");
+    let file = AppConfig::default();
+    let running = cli.applied(&file).expect("applies");
+    let edited = AppConfig {
+        threshold: 0.9,
+        ..file
+    };
+
+    let reloaded = cli.applied(&edited).expect("applies");
+    assert!(
+        reloaded.dry_run,
+        "an edit to the file cannot start clicking"
+    );
+    assert_eq!(
+        LiveSettings::of(&reloaded),
+        LiveSettings {
+            threshold: 0.9,
+            ..LiveSettings::of(&running)
+        }
+    );
+    assert!(!reload::needs_restart(&running, &reloaded));
+}
@@ -0,0 +1,68 @@
+use lol_auto_accept_rs::{
+    config::RunePreset,
+    runes::{RunePage, find_preset, source_url},
+};
+use serde_json::json;
+
+#[test]
+fn recommendation_is_converted_to_a_page() {
+    let pages = json!([{
+        "primaryPerkStyleId": 8100,
+        "secondaryPerkStyleId": 8300,
+        "perks": [
+            {"id": 8112}, {"id": 8139}, {"id": 8138}, {"id": 8135},
+            {"id": 8345}, {"id": 8347}, {"id": 5008}, {"id": 5008}, {"id": 5002}
+        ]
+    }]);
+    let page = RunePage::from_recommendation(&pages).expect("page");
+    assert_eq!(page.primary_style_id, 8100);
+    assert_eq!(page.sub_style_id, 8300);
+    assert!(page.is_complete());
+    assert!(RunePage::from_recommendation(&json!([])).is_none());
+}
+
+#[test]
+fn page_json_must_be_complete() {
+    let page = RunePage::from_page_json(&json!({
+        "primaryStyleId": 8000,
+        "subStyleId": 8400,
+        "selectedPerkIds": [8010, 9111, 9104, 8299]
+    }))
+    .expect("parses");
+    assert!(!page.is_complete());
+}
+
+#[test]
+fn presets_match_champion_and_role() {
+    let presets = vec![
+        RunePreset {
+            champion: "Wukong".to_string(),
+            role: "top".to_string(),
+            primary_style_id: 8000,
+            ..RunePreset::default()
+        },
+        RunePreset {
+            champion: "MonkeyKing".to_string(),
+            primary_style_id: 8100,
+            ..RunePreset::default()
+        },
+    ];
+    let top = find_preset(&presets, "Wukong", "MonkeyKing", "top").unwrap();
+    assert_eq!(top.primary_style_id, 8000);
+    let jungle = find_preset(&presets, "Wukong", "MonkeyKing", "jungle").unwrap();
+    assert_eq!(jungle.primary_style_id, 8100);
+    assert!(find_preset(&presets, "Ahri", "Ahri", "middle").is_none());
+}
+
+#[test]
+fn source_url_substitutes_placeholders() {
+    assert_eq!(
+        source_url(
+            "https://runes.example/{champion}/{position}?id={champion_id}",
+            "MonkeyKing",
+            62,
+            "JUNGLE"
+        ),
+        "https://runes.example/monkeyking/jungle?id=62"
+    );
+}
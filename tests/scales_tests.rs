@@ -0,0 +1,70 @@
+use lol_auto_accept_rs::scales;
+
+#[test]
+fn normalized_rounds_sorts_dedups_and_drops_out_of_range() {
+    assert_eq!(
+        scales::normalized(&[1.2, 0.9, 1.004, 1.0, 0.1, 5.0]),
+        vec![0.9, 1.0, 1.2]
+    );
+}
+
+#[test]
+fn effective_falls_back_to_the_template_size() {
+    assert_eq!(scales::effective(&[]), vec![1.0]);
+    assert_eq!(scales::effective(&[9.0]), vec![1.0]);
+    assert_eq!(scales::effective(&[0.8]), vec![0.8]);
+}
+
+#[test]
+fn range_includes_both_ends() {
+    assert_eq!(
+        scales::range(0.8, 1.2, 0.1),
+        Some(vec![0.8, 0.9, 1.0, 1.1, 1.2])
+    );
+    assert_eq!(scales::range(1.0, 1.0, 0.05), Some(vec![1.0]));
+}
+
+#[test]
+fn range_rejects_empty_and_oversized_ranges() {
+    assert_eq!(scales::range(1.2, 0.8, 0.1), None);
+    assert_eq!(scales::range(0.8, 1.2, 0.0), None);
+    assert_eq!(scales::range(0.25, 3.0, 0.01), None);
+}
+
+#[test]
+fn match_cost_counts_placements_times_template_pixels() {
+    // A 10x10 template in a 20x20 area: 11 * 11 placements of 100 pixels.
+    assert_eq!(scales::match_cost((10, 10), (20, 20), &[1.0]), 12_100);
+    // Variants that do not fit or fall below the minimum side cost nothing.
+    assert_eq!(scales::match_cost((10, 10), (20, 20), &[3.0, 0.3]), 0);
+    assert!(
+        scales::match_cost((10, 10), (20, 20), &[0.8, 1.0])
+            > scales::match_cost((10, 10), (20, 20), &[1.0])
+    );
+}
+
+#[test]
+fn estimate_scales_the_measured_time_by_cost() {
+    assert_eq!(scales::estimate_ms(4.0, 100, 250), Some(10.0));
+    assert_eq!(scales::estimate_ms(4.0, 0, 250), None);
+}
+
+#[test]
+fn format_cost_uses_metric_prefixes() {
+    assert_eq!(scales::format_cost(950), "950");
+    assert_eq!(scales::format_cost(12_300), "12.3 k");
+    assert_eq!(scales::format_cost(4_500_000), "4.5 M");
+    assert_eq!(scales::format_cost(1_200_000_000), "1.2 G");
+}
+
+#[test]
+fn thinned_spreads_the_picks_and_keeps_the_native_size() {
+    let all = scales::normalized(scales::DEFAULT_SCALES);
+    assert_eq!(scales::thinned(&all, 40), all);
+    let five = scales::thinned(&all, 5);
+    assert_eq!(five.len(), 5);
+    assert_eq!(five.first(), all.first());
+    assert_eq!(five.last(), all.last());
+    assert!(five.contains(&1.0));
+    assert_eq!(scales::thinned(&all, 1), vec![1.0]);
+}
@@ -0,0 +1,69 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use lol_auto_accept_rs::{
+    config::{ScheduleSettings, ScheduleWindow},
+    schedule::{is_active, window_contains},
+};
+
+/// 2024-01-01 was a Monday.
+fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2024, 1, day)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+}
+
+fn time(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+}
+
+#[test]
+fn default_window_covers_weekday_evenings() {
+    let window = ScheduleWindow::default();
+    assert!(window_contains(&window, at(1, 19, 0)));
+    assert!(window_contains(&window, at(5, 22, 59)));
+    assert!(!window_contains(&window, at(1, 23, 0)));
+    assert!(!window_contains(&window, at(1, 18, 59)));
+    // Saturday.
+    assert!(!window_contains(&window, at(6, 20, 0)));
+}
+
+#[test]
+fn overnight_windows_belong_to_their_start_day() {
+    let window = ScheduleWindow {
+        days: vec![Weekday::Fri],
+        start: time(22, 0),
+        end: time(2, 0),
+    };
+    assert!(window_contains(&window, at(5, 23, 30)));
+    assert!(window_contains(&window, at(6, 1, 59)));
+    assert!(!window_contains(&window, at(6, 2, 0)));
+    assert!(!window_contains(&window, at(6, 23, 0)));
+    // Thursday night into Friday is not scheduled.
+    assert!(!window_contains(&window, at(5, 1, 0)));
+}
+
+#[test]
+fn disabled_schedule_leaves_monitoring_alone() {
+    let mut settings = ScheduleSettings {
+        enabled: false,
+        windows: vec![ScheduleWindow::default()],
+    };
+    assert_eq!(is_active(&settings, at(1, 20, 0)), None);
+    settings.enabled = true;
+    assert_eq!(is_active(&settings, at(1, 20, 0)), Some(true));
+    assert_eq!(is_active(&settings, at(1, 9, 0)), Some(false));
+    settings.windows.clear();
+    assert_eq!(is_active(&settings, at(1, 20, 0)), Some(false));
+}
+
+#[test]
+fn schedule_reads_short_times_from_toml() {
+    let settings: ScheduleSettings = toml::from_str(
+        "enabled = true\n[[windows]]\ndays = [\"Sat\", \"Sun\"]\nstart = \"10:30\"\nend = \"18:00\"\n",
+    )
+    .expect("parses");
+    assert_eq!(settings.windows[0].days, [Weekday::Sat, Weekday::Sun]);
+    assert_eq!(settings.windows[0].start, time(10, 30));
+    let text = toml::to_string(&settings).expect("serializes");
+    assert_eq!(toml::from_str::<ScheduleSettings>(&text).unwrap(), settings);
+}
@@ -0,0 +1,38 @@
+use std::time::{Duration, Instant};
+
+use lol_auto_accept_rs::scores::ScoreTrace;
+
+#[test]
+fn drops_samples_older_than_the_window() {
+    let start = Instant::now();
+    let mut trace = ScoreTrace::new(Duration::from_secs(10));
+    trace.push(start, 0.2);
+    trace.push(start + Duration::from_secs(5), 0.4);
+    trace.push(start + Duration::from_secs(12), 0.6);
+    assert_eq!(trace.len(), 2);
+    assert_eq!(trace.peak(), Some(0.6));
+    assert_eq!(trace.latest(), Some(0.6));
+}
+
+#[test]
+fn points_count_seconds_back_from_now() {
+    let start = Instant::now();
+    let mut trace = ScoreTrace::new(Duration::from_secs(60));
+    trace.push(start, 0.5);
+    trace.push(start + Duration::from_secs(3), 0.9);
+    let points = trace.points(start + Duration::from_secs(4));
+    assert_eq!(points.len(), 2);
+    assert!((points[0][0] + 4.0).abs() < 1e-6);
+    assert!((points[1][0] + 1.0).abs() < 1e-6);
+    assert!((points[1][1] - 0.9).abs() < 1e-6);
+}
+
+#[test]
+fn empty_trace_has_no_peak() {
+    let mut trace = ScoreTrace::default();
+    assert!(trace.is_empty());
+    assert_eq!(trace.peak(), None);
+    trace.push(Instant::now(), 0.3);
+    trace.clear();
+    assert!(trace.is_empty());
+}
@@ -0,0 +1,48 @@
+use std::{fs, time::Duration};
+
+use lol_auto_accept_rs::sound::{chime, decode};
+use rodio::Source;
+
+/// 16-bit mono PCM WAV holding `samples` of silence.
+fn silent_wav(samples: u32) -> Vec<u8> {
+    let data_len = samples * 2;
+    let mut wav = Vec::new();
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&8_000u32.to_le_bytes());
+    wav.extend_from_slice(&16_000u32.to_le_bytes());
+    wav.extend_from_slice(&2u16.to_le_bytes());
+    wav.extend_from_slice(&16u16.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+    wav.resize(wav.len() + data_len as usize, 0);
+    wav
+}
+
+#[test]
+fn wav_files_decode() {
+    let path = std::env::temp_dir().join(format!("lol-auto-accept-{}.wav", std::process::id()));
+    fs::write(&path, silent_wav(800)).unwrap();
+    let source = decode(&path).expect("wav decodes");
+    assert_eq!(source.sample_rate(), 8_000);
+    assert_eq!(source.count(), 800);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn missing_and_non_audio_files_are_rejected() {
+    assert!(decode("does/not/exist.ogg".as_ref()).is_err());
+    assert!(decode("resources/templates/accept_button.png".as_ref()).is_err());
+}
+
+#[test]
+fn chime_is_short() {
+    let samples = chime().count() as u64;
+    let length = Duration::from_millis(samples * 1000 / 48_000);
+    assert!(length >= Duration::from_millis(300), "{length:?}");
+    assert!(length <= Duration::from_millis(400), "{length:?}");
+}
@@ -0,0 +1,33 @@
+use lol_auto_accept_rs::{
+    config::SpeechSettings,
+    speech::{Announcement, phrase},
+};
+
+#[test]
+fn silent_until_enabled() {
+    let settings = SpeechSettings::default();
+    assert_eq!(phrase(&settings, Announcement::Found), None);
+    assert_eq!(phrase(&settings, Announcement::Accepted), None);
+}
+
+#[test]
+fn each_announcement_speaks_its_own_phrase() {
+    let settings = SpeechSettings {
+        enabled: true,
+        ..SpeechSettings::default()
+    };
+    assert_eq!(phrase(&settings, Announcement::Found), Some("Match found"));
+    assert_eq!(phrase(&settings, Announcement::Accepted), Some("Accepted"));
+}
+
+#[test]
+fn an_empty_phrase_stays_silent() {
+    let settings = SpeechSettings {
+        enabled: true,
+        found: "  ".to_string(),
+        accepted: " Go! ".to_string(),
+        volume: 1.0,
+    };
+    assert_eq!(phrase(&settings, Announcement::Found), None);
+    assert_eq!(phrase(&settings, Announcement::Accepted), Some("Go!"));
+}
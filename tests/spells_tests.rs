@@ -0,0 +1,72 @@
+use lol_auto_accept_rs::{
+    config::{FlashKey, SpellPreset},
+    spells::{arrange, find_preset, spell_id, spell_name},
+};
+
+fn preset(champion: &str, role: &str, spells: [&str; 2]) -> SpellPreset {
+    SpellPreset {
+        champion: champion.to_string(),
+        role: role.to_string(),
+        spells: spells.iter().map(|spell| spell.to_string()).collect(),
+    }
+}
+
+#[test]
+fn spell_names_resolve_case_insensitively() {
+    assert_eq!(spell_id("Flash"), Some(4));
+    assert_eq!(spell_id(" ignite "), Some(14));
+    assert_eq!(spell_id("Snowball"), None);
+    assert_eq!(spell_name(11), "Smite");
+}
+
+#[test]
+fn most_specific_preset_wins() {
+    let presets = vec![
+        preset("", "", ["Flash", "Heal"]),
+        preset("", "jungle", ["Flash", "Smite"]),
+        preset("Lee Sin", "", ["Flash", "Ignite"]),
+        preset("LeeSin", "jungle", ["Ghost", "Smite"]),
+    ];
+    let pick = |champion, alias, position| {
+        find_preset(&presets, champion, alias, position).map(|preset| preset.spells[0].as_str())
+    };
+    assert_eq!(pick("Lee Sin", "LeeSin", "jungle"), Some("Ghost"));
+    assert_eq!(pick("Lee Sin", "LeeSin", "top"), Some("Flash"));
+    assert_eq!(
+        find_preset(&presets, "Lee Sin", "LeeSin", "top").map(|p| p.spells[1].as_str()),
+        Some("Ignite")
+    );
+    assert_eq!(
+        find_preset(&presets, "Amumu", "Amumu", "jungle").map(|p| p.spells[1].as_str()),
+        Some("Smite")
+    );
+    assert_eq!(
+        find_preset(&presets, "Ahri", "Ahri", "middle").map(|p| p.spells[1].as_str()),
+        Some("Heal")
+    );
+    assert!(find_preset(&presets[1..3], "Ahri", "Ahri", "middle").is_none());
+}
+
+#[test]
+fn flash_moves_to_the_preferred_key() {
+    let flash_first = preset("", "", ["Flash", "Ignite"]);
+    let flash_second = preset("", "", ["Ignite", "Flash"]);
+    assert_eq!(arrange(&flash_first, FlashKey::F), Some([14, 4]));
+    assert_eq!(arrange(&flash_second, FlashKey::F), Some([14, 4]));
+    assert_eq!(arrange(&flash_second, FlashKey::D), Some([4, 14]));
+    assert_eq!(
+        arrange(&preset("", "", ["Teleport", "Ignite"]), FlashKey::D),
+        Some([12, 14])
+    );
+}
+
+#[test]
+fn invalid_presets_are_rejected() {
+    assert!(arrange(&preset("", "", ["Flash", "Flash"]), FlashKey::F).is_none());
+    assert!(arrange(&preset("", "", ["Flash", "Snowball"]), FlashKey::F).is_none());
+    let single = SpellPreset {
+        spells: vec!["Flash".to_string()],
+        ..SpellPreset::default()
+    };
+    assert!(arrange(&single, FlashKey::F).is_none());
+}
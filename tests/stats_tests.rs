@@ -0,0 +1,83 @@
+use std::time::{Duration, Instant};
+
+use lol_auto_accept_rs::stats::{SessionStats, StatsTotals};
+
+#[test]
+fn scores_track_average_and_range() {
+    let mut totals = StatsTotals::default();
+    assert_eq!(totals.average_score(), None);
+    for score in [0.9, 0.95, 0.92] {
+        totals.record_score(score);
+    }
+    assert!((totals.average_score().unwrap() - 0.9233).abs() < 1e-3);
+    assert_eq!(totals.score_min, Some(0.9));
+    assert_eq!(totals.score_max, Some(0.95));
+}
+
+#[test]
+fn retries_count_as_one_accept() {
+    let start = Instant::now();
+    let mut stats = SessionStats::default();
+    stats.on_detection(0.93, start);
+    stats.on_accept_attempt(1);
+    stats.on_accepted(start + Duration::from_millis(300));
+    stats.on_detection(0.94, start + Duration::from_millis(1000));
+    stats.on_accept_attempt(2);
+    stats.on_accepted(start + Duration::from_millis(1100));
+
+    let session = stats.session(start);
+    assert_eq!(session.accepted, 1);
+    assert_eq!(session.detections, 2);
+    assert_eq!(session.average_reaction(), Some(Duration::from_millis(300)));
+}
+
+#[test]
+fn api_accepts_have_no_reaction_time() {
+    let start = Instant::now();
+    let mut stats = SessionStats::default();
+    stats.on_detection(0.9, start);
+    stats.on_detection_dropped();
+    stats.on_accept_attempt(1);
+    stats.on_accepted(start + Duration::from_secs(5));
+
+    let session = stats.session(start);
+    assert_eq!(session.accepted, 1);
+    assert_eq!(session.average_reaction(), None);
+}
+
+#[test]
+fn uptime_only_runs_while_monitoring() {
+    let start = Instant::now();
+    let mut stats = SessionStats::default();
+    stats.set_running(true, start);
+    stats.set_running(false, start + Duration::from_secs(10));
+    stats.set_running(true, start + Duration::from_secs(60));
+    let session = stats.session(start + Duration::from_secs(65));
+    assert_eq!(session.uptime(), Duration::from_secs(15));
+}
+
+#[test]
+fn lifetime_adds_the_session_to_earlier_totals() {
+    let start = Instant::now();
+    let earlier = StatsTotals {
+        accepted: 4,
+        frames: 1_000,
+        score_min: Some(0.89),
+        score_max: Some(0.97),
+        uptime_ms: 60_000,
+        ..StatsTotals::default()
+    };
+    let mut stats = SessionStats::new(earlier);
+    stats.on_frames(25);
+    stats.on_detection(0.99, start);
+    stats.on_accept_attempt(1);
+    stats.on_accepted(start);
+
+    let lifetime = stats.lifetime(start);
+    assert_eq!(lifetime.accepted, 5);
+    assert_eq!(lifetime.frames, 1_025);
+    assert_eq!(lifetime.score_min, Some(0.89));
+    assert_eq!(lifetime.score_max, Some(0.99));
+    // Repeated saves must not count the session twice.
+    assert_eq!(stats.lifetime(start), lifetime);
+}
@@ -0,0 +1,64 @@
+use std::time::{Duration, Instant};
+
+use chrono::Local;
+use lol_auto_accept_rs::{
+    metrics::WorkerMetrics,
+    summary::{self, RunTracker},
+};
+
+fn metrics(frames: u32, avg_latency_ms: f32) -> WorkerMetrics {
+    WorkerMetrics {
+        frames,
+        avg_latency_ms,
+        ..WorkerMetrics::default()
+    }
+}
+
+#[test]
+fn run_tracker_sums_up_the_run() {
+    let start = Instant::now();
+    let mut run = RunTracker::new(Local::now(), start, 0.88);
+    run.on_metrics(&metrics(10, 20.0));
+    run.on_metrics(&metrics(30, 40.0));
+    for score in [0.5, 0.84, 0.87, 0.9] {
+        run.on_score(score);
+    }
+    run.on_detection();
+    run.on_click();
+
+    let summary = run.finish(start + Duration::from_secs(3_725));
+    assert_eq!(summary.duration, Duration::from_secs(3_725));
+    assert_eq!(summary.frames, 40);
+    assert_eq!(summary.detections, 1);
+    assert_eq!(summary.clicks, 1);
+    // 0.84 and 0.87 are within the margin; 0.9 passed and 0.5 is noise.
+    assert_eq!(summary.near_misses, 2);
+    assert!((summary.average_latency_ms.unwrap() - 35.0).abs() < 1e-3);
+
+    let rows = summary.rows();
+    assert!(rows.contains(&("Duration", "1h 02m".to_string())));
+    assert!(rows.contains(&("Average latency", "35.0 ms".to_string())));
+}
+
+#[test]
+fn empty_run_has_no_latency() {
+    let start = Instant::now();
+    let summary = RunTracker::new(Local::now(), start, 0.88).finish(start);
+    assert_eq!(summary.frames, 0);
+    assert_eq!(summary.average_latency_ms, None);
+    assert!(
+        summary
+            .rows()
+            .contains(&("Average latency", "–".to_string()))
+    );
+}
+
+#[test]
+fn durations_are_compact() {
+    assert_eq!(
+        summary::format_duration(Duration::from_millis(350)),
+        "350ms"
+    );
+    assert_eq!(summary::format_duration(Duration::from_secs(42)), "42s");
+    assert_eq!(summary::format_duration(Duration::from_secs(187)), "3m 07s");
+}
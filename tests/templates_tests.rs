@@ -0,0 +1,201 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use lol_auto_accept_rs::{
+    config::TemplateEntry,
+    detect,
+    detect::Detection,
+    templates::{self, DEFAULT_TEMPLATE, EMBEDDED_TEMPLATE, KnownTemplate, TemplateKind},
+};
+
+fn tempdir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("templates-tests-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("create temp dir");
+    dir
+}
+
+fn touch(path: &Path) {
+    fs::create_dir_all(path.parent().expect("parent")).expect("create parent");
+    fs::write(path, b"").expect("write file");
+}
+
+#[test]
+fn discover_finds_bundled_and_locale_templates() {
+    let dir = tempdir("discover");
+    touch(&dir.join(DEFAULT_TEMPLATE));
+    touch(&dir.join("ja").join(DEFAULT_TEMPLATE));
+    touch(&dir.join("notes.txt"));
+
+    let found = templates::discover(std::slice::from_ref(&dir));
+    assert_eq!(
+        found,
+        vec![
+            (dir.join(DEFAULT_TEMPLATE), TemplateKind::Bundled),
+            (
+                dir.join("ja").join(DEFAULT_TEMPLATE),
+                TemplateKind::Locale("ja".into())
+            ),
+        ]
+    );
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn discover_lets_earlier_folders_shadow_later_ones() {
+    let first = tempdir("shadow-first");
+    let second = tempdir("shadow-second");
+    touch(&first.join(DEFAULT_TEMPLATE));
+    touch(&second.join(DEFAULT_TEMPLATE));
+    touch(&second.join("ko_accept.png"));
+
+    let found = templates::discover(&[first.clone(), second.clone(), first.join("missing")]);
+    assert_eq!(
+        found,
+        vec![
+            (first.join(DEFAULT_TEMPLATE), TemplateKind::Bundled),
+            (second.join("ko_accept.png"), TemplateKind::Bundled),
+        ]
+    );
+    let _ = fs::remove_dir_all(first);
+    let _ = fs::remove_dir_all(second);
+}
+
+#[test]
+fn known_enables_only_the_stock_template_by_default() {
+    let discovered = vec![
+        (PathBuf::from("t/accept_button.png"), TemplateKind::Bundled),
+        (PathBuf::from("t/other.png"), TemplateKind::Bundled),
+        (
+            PathBuf::from("t/ja/accept_button.png"),
+            TemplateKind::Locale("ja".into()),
+        ),
+    ];
+    let enabled: Vec<bool> = templates::known(&[], &discovered)
+        .iter()
+        .map(|template| template.enabled)
+        .collect();
+    assert_eq!(enabled, vec![true, false, false]);
+}
+
+#[test]
+fn known_applies_registry_choices_and_appends_user_templates() {
+    let discovered = vec![(PathBuf::from("t/accept_button.png"), TemplateKind::Bundled)];
+    let registry = vec![
+        TemplateEntry {
+            path: PathBuf::from("t/accept_button.png"),
+            enabled: false,
+            threshold: Some(0.9),
+        },
+        TemplateEntry {
+            path: PathBuf::from("mine/custom.png"),
+            ..TemplateEntry::default()
+        },
+    ];
+    let known = templates::known(&registry, &discovered);
+    assert_eq!(
+        known,
+        vec![
+            KnownTemplate {
+                path: PathBuf::from("t/accept_button.png"),
+                kind: TemplateKind::Bundled,
+                enabled: false,
+                threshold: Some(0.9),
+            },
+            KnownTemplate {
+                path: PathBuf::from("mine/custom.png"),
+                kind: TemplateKind::User,
+                enabled: true,
+                threshold: None,
+            },
+        ]
+    );
+    assert_eq!(known[1].name(), "custom");
+}
+
+#[test]
+fn add_update_and_remove_edit_the_registry() {
+    let mut registry = Vec::new();
+    assert!(templates::add(&mut registry, PathBuf::from("mine/a.png")));
+    assert!(!templates::add(&mut registry, PathBuf::from("mine/a.png")));
+
+    let mut template = templates::known(&registry, &[]).remove(0);
+    template.threshold = Some(0.7);
+    templates::update(&mut registry, &template);
+    assert_eq!(registry.len(), 1);
+    assert_eq!(registry[0].threshold, Some(0.7));
+
+    let bundled = KnownTemplate {
+        path: PathBuf::from("t/other.png"),
+        kind: TemplateKind::Bundled,
+        enabled: true,
+        threshold: None,
+    };
+    templates::update(&mut registry, &bundled);
+    assert_eq!(registry.len(), 2);
+
+    templates::remove(&mut registry, Path::new("mine/a.png"));
+    assert_eq!(registry.len(), 1);
+    assert_eq!(registry[0].path, PathBuf::from("t/other.png"));
+}
+
+#[test]
+fn detection_uses_its_own_threshold_when_set() {
+    let mut detection = Detection {
+        score: 0.85,
+        position: (0, 0),
+        template_size: (10, 10),
+        scale: 1.0,
+        threshold: None,
+    };
+    assert!(!detection.passes(0.9));
+    detection.threshold = Some(0.8);
+    assert!(detection.passes(0.9));
+    assert!((detection.margin(0.9) - 0.05).abs() < 1e-6);
+}
+
+#[test]
+fn copy_into_numbers_clashing_names() {
+    let dir = tempdir("copy");
+    let source = dir.join("dropped").join("accept.png");
+    fs::create_dir_all(source.parent().expect("parent")).expect("create parent");
+    fs::write(&source, b"first").expect("write source");
+    let target = dir.join("templates");
+
+    let first = templates::copy_into(&source, &target).expect("copies");
+    assert_eq!(first, target.join("accept.png"));
+    assert_eq!(fs::read(&first).expect("read copy"), b"first");
+
+    let second = templates::copy_into(&source, &target).expect("copies again");
+    assert_eq!(second, target.join("accept-2.png"));
+
+    // Copying a file already in the folder onto itself is a no-op.
+    assert_eq!(
+        templates::copy_into(&first, &target).expect("same file"),
+        first
+    );
+
+    let text = dir.join("notes.txt");
+    touch(&text);
+    assert!(templates::copy_into(&text, &target).is_err());
+}
+
+#[test]
+fn embedded_template_is_written_once_and_loads() {
+    let dir = tempdir("embedded");
+    let written = templates::write_embedded(&dir).expect("writes");
+    assert_eq!(written, dir.join(DEFAULT_TEMPLATE));
+    assert_eq!(fs::read(&written).expect("read"), EMBEDDED_TEMPLATE);
+    assert!(detect::load_template(&written).is_ok());
+
+    // The same image already there is reused; a different file keeps its name.
+    assert_eq!(templates::write_embedded(&dir).expect("reuses"), written);
+    fs::write(&written, b"mine").expect("overwrite");
+    assert_eq!(
+        templates::write_embedded(&dir).expect("writes beside"),
+        dir.join("accept_button-2.png")
+    );
+    assert_eq!(fs::read(&written).expect("read"), b"mine");
+}
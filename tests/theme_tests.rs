@@ -0,0 +1,62 @@
+use egui::Color32;
+use lol_auto_accept_rs::{
+    config::{ThemeMode, ThemeSettings},
+    theme::{contrasting_text, pixels_per_point, visuals},
+};
+
+#[test]
+fn mode_picks_dark_or_light() {
+    let system = ThemeSettings::default();
+    assert!(visuals(&system, true).dark_mode);
+    assert!(!visuals(&system, false).dark_mode);
+
+    let light = ThemeSettings {
+        mode: ThemeMode::Light,
+        ..ThemeSettings::default()
+    };
+    assert!(!visuals(&light, true).dark_mode);
+    let dark = ThemeSettings {
+        mode: ThemeMode::Dark,
+        ..ThemeSettings::default()
+    };
+    assert!(visuals(&dark, false).dark_mode);
+}
+
+#[test]
+fn accent_colors_selection_and_links() {
+    let settings = ThemeSettings {
+        mode: ThemeMode::Dark,
+        accent: Some([0xc8, 0xaa, 0x6e]),
+        ..ThemeSettings::default()
+    };
+    let visuals = visuals(&settings, true);
+    let accent = Color32::from_rgb(0xc8, 0xaa, 0x6e);
+    assert_eq!(visuals.selection.bg_fill, accent);
+    assert_eq!(visuals.hyperlink_color, accent);
+    assert_eq!(visuals.selection.stroke.color, Color32::BLACK);
+}
+
+#[test]
+fn text_contrasts_with_accent() {
+    assert_eq!(
+        contrasting_text(Color32::from_rgb(250, 240, 200)),
+        Color32::BLACK
+    );
+    assert_eq!(
+        contrasting_text(Color32::from_rgb(20, 40, 120)),
+        Color32::WHITE
+    );
+}
+
+#[test]
+fn ui_scale_multiplies_native_scaling() {
+    assert_eq!(pixels_per_point(2.0, 1.0), 2.0);
+    assert_eq!(pixels_per_point(1.5, 1.5), 2.25);
+}
+
+#[test]
+fn ui_scale_is_clamped() {
+    assert_eq!(pixels_per_point(1.0, 10.0), 3.0);
+    assert_eq!(pixels_per_point(1.0, 0.0), 0.5);
+    assert_eq!(pixels_per_point(2.0, f32::NAN), 2.0);
+}
@@ -0,0 +1,62 @@
+use std::time::{Duration, Instant};
+
+use lol_auto_accept_rs::toast::{MAX_TOASTS, ToastKind, Toasts};
+
+#[test]
+fn toasts_expire_after_their_duration() {
+    let start = Instant::now();
+    let mut toasts = Toasts::default();
+    toasts.push(ToastKind::Success, "Configuration saved", start);
+    toasts.push(ToastKind::Error, "Worker error: boom", start);
+
+    toasts.prune(start + ToastKind::Success.duration() + Duration::from_millis(1));
+    let left: Vec<_> = toasts.iter().map(|toast| toast.kind).collect();
+    assert_eq!(left, vec![ToastKind::Error]);
+
+    toasts.prune(start + ToastKind::Error.duration());
+    assert!(toasts.is_empty());
+}
+
+#[test]
+fn repeated_message_restarts_the_newest_toast() {
+    let start = Instant::now();
+    let later = start + Duration::from_secs(2);
+    let mut toasts = Toasts::default();
+    toasts.push(ToastKind::Info, "Detected", start);
+    toasts.push(ToastKind::Info, "Detected", later);
+
+    let all: Vec<_> = toasts.iter().collect();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].repeats, 1);
+    assert_eq!(all[0].shown_at, later);
+    assert_eq!(toasts.next_expiry(later), Some(ToastKind::Info.duration()));
+}
+
+#[test]
+fn oldest_toast_is_dropped_when_full() {
+    let now = Instant::now();
+    let mut toasts = Toasts::default();
+    for index in 0..=MAX_TOASTS {
+        toasts.push(ToastKind::Info, format!("toast {index}"), now);
+    }
+    let texts: Vec<_> = toasts.iter().map(|toast| toast.text.as_str()).collect();
+    assert_eq!(texts.len(), MAX_TOASTS);
+    assert_eq!(texts[0], "toast 1");
+
+    toasts.dismiss(0);
+    assert_eq!(toasts.iter().next().unwrap().text, "toast 2");
+}
+
+#[test]
+fn remaining_counts_down_to_zero() {
+    let now = Instant::now();
+    let mut toasts = Toasts::default();
+    toasts.push(ToastKind::Warning, "Capture failing", now);
+    let toast = toasts.iter().next().unwrap();
+    assert!((toast.remaining(now) - 1.0).abs() < 1e-3);
+    assert!((toast.remaining(now + ToastKind::Warning.duration() / 2) - 0.5).abs() < 1e-3);
+    assert_eq!(
+        toast.remaining(now + ToastKind::Warning.duration() * 2),
+        0.0
+    );
+}
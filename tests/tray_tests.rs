@@ -0,0 +1,50 @@
+use lol_auto_accept_rs::{
+    config::IndicatorPalette,
+    indicator::{Indicator, contrasting},
+    tray::icon_rgba,
+};
+
+#[test]
+fn icon_is_an_opaque_badge_with_transparent_corners() {
+    let size = 32;
+    let rgba = icon_rgba(size, Indicator::Idle, IndicatorPalette::Standard);
+    assert_eq!(rgba.len(), (size * size * 4) as usize);
+    let alpha = |x: u32, y: u32| rgba[((y * size + x) * 4 + 3) as usize];
+    assert_eq!(alpha(0, 0), 0);
+    assert_eq!(alpha(size - 1, size - 1), 0);
+    assert_eq!(alpha(size / 2, size / 2), 0xff);
+}
+
+#[test]
+fn icon_center_takes_the_indicator_color() {
+    let size = 32;
+    for indicator in [
+        Indicator::Idle,
+        Indicator::Monitoring,
+        Indicator::Cooldown,
+        Indicator::Error,
+    ] {
+        let rgba = icon_rgba(size, indicator, IndicatorPalette::Standard);
+        let center = ((size / 2 * size + size / 2) * 4) as usize;
+        assert_eq!(
+            rgba[center..center + 3],
+            indicator.color(IndicatorPalette::Standard)
+        );
+    }
+}
+
+#[test]
+fn colorblind_icons_carry_the_state_shape() {
+    let size = 32;
+    let palette = IndicatorPalette::Colorblind;
+    let center = ((size / 2 * size + size / 2) * 4) as usize;
+    // A ring is hollow in the middle, a dot is not.
+    let idle = icon_rgba(size, Indicator::Idle, palette);
+    let color = Indicator::Idle.color(palette);
+    assert_eq!(idle[center..center + 3], contrasting(color));
+    let monitoring = icon_rgba(size, Indicator::Monitoring, palette);
+    assert_eq!(
+        monitoring[center..center + 3],
+        Indicator::Monitoring.color(palette)
+    );
+}
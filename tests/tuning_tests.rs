@@ -0,0 +1,70 @@
+use lol_auto_accept_rs::tuning::{MAX_THRESHOLD, Phase, Samples, Separation};
+
+#[test]
+fn separation_needs_both_phases() {
+    let mut samples = Samples::new();
+    assert_eq!(samples.separation(), None);
+    samples.push(Phase::Absent, 0.42);
+    assert_eq!(samples.separation(), None);
+    samples.push(Phase::Present, 0.95);
+    assert!(samples.separation().is_some());
+}
+
+#[test]
+fn separation_compares_the_closest_scores() {
+    let mut samples = Samples::new();
+    for score in [0.40, 0.61, 0.55] {
+        samples.push(Phase::Absent, score);
+    }
+    for score in [0.97, 0.91, 0.95] {
+        samples.push(Phase::Present, score);
+    }
+    let separation = samples.separation().unwrap();
+    assert_eq!(separation.absent_max, 0.61);
+    assert_eq!(separation.present_min, 0.91);
+    assert!((separation.gap() - 0.30).abs() < 1e-6);
+}
+
+#[test]
+fn recommends_the_midpoint_of_the_gap() {
+    let separation = Separation {
+        absent_max: 0.6,
+        present_min: 0.9,
+    };
+    assert!((separation.recommended().unwrap() - 0.75).abs() < 1e-6);
+}
+
+#[test]
+fn overlapping_scores_have_no_recommendation() {
+    let separation = Separation {
+        absent_max: 0.9,
+        present_min: 0.85,
+    };
+    assert!(separation.gap() < 0.0);
+    assert_eq!(separation.recommended(), None);
+}
+
+#[test]
+fn recommendation_stays_within_the_slider_range() {
+    let separation = Separation {
+        absent_max: 0.995,
+        present_min: 1.0,
+    };
+    assert!(separation.gap() > 0.0);
+    assert_eq!(separation.recommended(), None);
+
+    let separation = Separation {
+        absent_max: 0.97,
+        present_min: 1.0,
+    };
+    assert!(separation.recommended().unwrap() <= MAX_THRESHOLD);
+}
+
+#[test]
+fn clearing_drops_every_sample() {
+    let mut samples = Samples::new();
+    samples.push(Phase::Absent, 0.3);
+    samples.push(Phase::Present, 0.9);
+    samples.clear();
+    assert_eq!(samples, Samples::new());
+}
@@ -0,0 +1,56 @@
+use lol_auto_accept_rs::update::{AvailableUpdate, newest_update};
+use self_update::update::{Release, ReleaseAsset};
+
+const TARGET: &str = "x86_64-pc-windows-msvc";
+
+fn release(version: &str, target: &str) -> Release {
+    Release::builder()
+        .version(version)
+        .name(format!("v{version}"))
+        .date("2024-05-01T12:00:00Z")
+        .body(format!("\n- Changes in {version}\n"))
+        .asset(ReleaseAsset::new(
+            format!("lol-auto-accept-rs-{version}-{target}.zip"),
+            format!("https://example.invalid/{version}.zip"),
+        ))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn picks_the_newest_release_above_the_current_version() {
+    let releases = [
+        release("0.3.0", TARGET),
+        release("0.2.0", TARGET),
+        release("0.4.1", TARGET),
+        release("0.1.0", TARGET),
+    ];
+    let update = newest_update("0.2.0", TARGET, &releases).unwrap();
+    assert_eq!(
+        update,
+        AvailableUpdate {
+            version: "0.4.1".into(),
+            name: "v0.4.1".into(),
+            date: "2024-05-01T12:00:00Z".into(),
+            changelog: "- Changes in 0.4.1".into(),
+            url: None,
+        }
+    );
+}
+
+#[test]
+fn no_update_when_current_is_newest() {
+    let releases = [release("0.1.0", TARGET), release("0.2.0", TARGET)];
+    assert_eq!(newest_update("0.2.0", TARGET, &releases), None);
+    assert_eq!(newest_update("1.0.0", TARGET, &releases), None);
+}
+
+#[test]
+fn releases_without_a_build_for_this_target_are_skipped() {
+    let releases = [
+        release("0.5.0", "aarch64-apple-darwin"),
+        release("0.3.0", TARGET),
+    ];
+    let update = newest_update("0.2.0", TARGET, &releases).unwrap();
+    assert_eq!(update.version, "0.3.0");
+}
@@ -0,0 +1,319 @@
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex, atomic::Ordering},
+};
+
+use lol_auto_accept_rs::{
+    capture::{self, CaptureBackend, CapturedFrame, DirectoryBackend},
+    config::{AppConfig, CaptureSource},
+    detect,
+    worker::{self, LiveSettings, WorkerControl, WorkerEvent},
+};
+
+fn resource(parts: &[&str]) -> PathBuf {
+    parts.iter().fold(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("resources"),
+        |path, part| path.join(part),
+    )
+}
+
+fn frame_dir(name: &str, frames: &[(&str, &str)], sequence: Option<&str>) -> PathBuf {
+    let dir = std::env::temp_dir()
+        .join("lol-auto-accept-tests")
+        .join(format!("{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("temp dir");
+    for (target, sample) in frames {
+        fs::copy(resource(&["samples", sample]), dir.join(target)).expect("copy sample");
+    }
+    if let Some(sequence) = sequence {
+        fs::write(dir.join("sequence.txt"), sequence).expect("write sequence");
+    }
+    dir
+}
+
+#[test]
+fn directory_backend_plays_sorted_frames_once() {
+    let dir = frame_dir(
+        "once",
+        &[
+            ("b.png", "negative_mock.png"),
+            ("a.png", "positive_mock.png"),
+        ],
+        None,
+    );
+    let mut backend = DirectoryBackend::open(&dir, false).expect("backend opens");
+    let mut frame = CapturedFrame::empty();
+    assert_eq!(backend.len(), 2);
+
+    backend.capture_into(0, &mut frame).expect("first frame");
+    assert_eq!(frame.image.dimensions(), (160, 90));
+    backend.capture_into(0, &mut frame).expect("second frame");
+    assert!(backend.is_exhausted());
+    assert!(backend.capture_into(0, &mut frame).is_err());
+}
+
+#[test]
+fn directory_backend_follows_sequence_and_loops() {
+    let dir = frame_dir(
+        "sequence",
+        &[("neg.png", "negative_mock.png")],
+        Some("# scripted\nneg.png\nneg.png\nneg.png\n"),
+    );
+    let mut backend = DirectoryBackend::open(&dir, true).expect("backend opens");
+    let mut frame = CapturedFrame::empty();
+    assert_eq!(backend.len(), 3);
+    for _ in 0..5 {
+        backend.capture_into(0, &mut frame).expect("looping frame");
+    }
+    assert!(!backend.is_exhausted());
+}
+
+#[test]
+fn worker_runs_directory_source_to_completion() {
+    let dir = frame_dir(
+        "worker",
+        &[
+            ("1.png", "negative_mock.png"),
+            ("2.png", "negative_mock.png"),
+        ],
+        None,
+    );
+    let config = AppConfig {
+        interval_ms: 10,
+        capture_source: CaptureSource::Directory {
+            path: dir,
+            looping: false,
+        },
+        ..AppConfig::default()
+    };
+    let template =
+        detect::load_template(&resource(&["templates", "accept_button.png"])).expect("template");
+    let backend = capture::open_backend(&config.capture_source).expect("backend");
+    let (tx, rx) = crossbeam_channel::unbounded();
+
+    worker::run_worker(
+        config,
+        vec![template],
+        backend,
+        None,
+        tx,
+        WorkerControl::default(),
+    );
+
+    let events: Vec<WorkerEvent> = rx.try_iter().collect();
+    assert!(matches!(events.last(), Some(WorkerEvent::Stopped)));
+    assert!(
+        !events
+            .iter()
+            .any(|event| matches!(event, WorkerEvent::Clicked { .. })),
+        "negative frames must not trigger a click"
+    );
+}
+
+#[test]
+fn dry_run_reports_would_click_without_clicking() {
+    let dir = frame_dir("dry_run", &[("1.png", "positive_mock.png")], None);
+    let config = AppConfig {
+        threshold: 0.0,
+        interval_ms: 10,
+        dry_run: true,
+        capture_source: CaptureSource::Directory {
+            path: dir,
+            looping: false,
+        },
+        ..AppConfig::default()
+    };
+    let template =
+        detect::load_template(&resource(&["templates", "accept_button.png"])).expect("template");
+    let backend = capture::open_backend(&config.capture_source).expect("backend");
+    let (tx, rx) = crossbeam_channel::unbounded();
+
+    worker::run_worker(
+        config,
+        vec![template],
+        backend,
+        None,
+        tx,
+        WorkerControl::default(),
+    );
+
+    let events: Vec<WorkerEvent> = rx.try_iter().collect();
+    assert!(
+        events
+            .iter()
+            .any(|event| matches!(event, WorkerEvent::WouldClick { .. }))
+    );
+    assert!(
+        !events
+            .iter()
+            .any(|event| matches!(event, WorkerEvent::Clicked { .. }))
+    );
+}
+
+#[test]
+fn unchanged_frames_reuse_the_previous_score() {
+    let dir = frame_dir(
+        "skip_unchanged",
+        &[
+            ("1.png", "negative_mock.png"),
+            ("2.png", "negative_mock.png"),
+        ],
+        None,
+    );
+    let config = AppConfig {
+        interval_ms: 10,
+        skip_unchanged: true,
+        capture_source: CaptureSource::Directory {
+            path: dir,
+            looping: false,
+        },
+        ..AppConfig::default()
+    };
+    let template =
+        detect::load_template(&resource(&["templates", "accept_button.png"])).expect("template");
+    let backend = capture::open_backend(&config.capture_source).expect("backend");
+    let (tx, rx) = crossbeam_channel::unbounded();
+
+    worker::run_worker(
+        config,
+        vec![template],
+        backend,
+        None,
+        tx,
+        WorkerControl::default(),
+    );
+
+    let scores: Vec<f32> = rx
+        .try_iter()
+        .filter_map(|event| match event {
+            WorkerEvent::FrameScored(score) => Some(score),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(scores.len(), 2);
+    assert_eq!(scores[0], scores[1]);
+}
+
+#[test]
+fn requested_frame_is_sent_once() {
+    let dir = frame_dir(
+        "frame_request",
+        &[
+            ("1.png", "positive_mock.png"),
+            ("2.png", "negative_mock.png"),
+        ],
+        None,
+    );
+    let config = AppConfig {
+        interval_ms: 10,
+        dry_run: true,
+        capture_source: CaptureSource::Directory {
+            path: dir,
+            looping: false,
+        },
+        ..AppConfig::default()
+    };
+    let template =
+        detect::load_template(&resource(&["templates", "accept_button.png"])).expect("template");
+    let backend = capture::open_backend(&config.capture_source).expect("backend");
+    let (tx, rx) = crossbeam_channel::unbounded();
+    let control = WorkerControl::default();
+    control.frame_request.store(true, Ordering::Relaxed);
+
+    worker::run_worker(config, vec![template], backend, None, tx, control.clone());
+
+    let frames: Vec<_> = rx
+        .try_iter()
+        .filter_map(|event| match event {
+            WorkerEvent::Frame(snapshot) => Some(snapshot),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(frames.len(), 1, "one request yields one frame");
+    assert!(frames[0].image.width() > 0);
+    assert!(frames[0].detection.is_some());
+    assert!(!control.frame_request.load(Ordering::Relaxed));
+}
+
+#[test]
+fn live_settings_apply_before_the_next_frame() {
+    let dir = frame_dir("live_settings", &[("1.png", "positive_mock.png")], None);
+    let config = AppConfig {
+        threshold: 0.0,
+        interval_ms: 10,
+        dry_run: true,
+        capture_source: CaptureSource::Directory {
+            path: dir,
+            looping: false,
+        },
+        ..AppConfig::default()
+    };
+    let template =
+        detect::load_template(&resource(&["templates", "accept_button.png"])).expect("template");
+    let backend = capture::open_backend(&config.capture_source).expect("backend");
+    let (tx, rx) = crossbeam_channel::unbounded();
+    let control = WorkerControl::default();
+    *control.live.lock().unwrap() = Some(LiveSettings {
+        threshold: 2.0,
+        ..LiveSettings::of(&config)
+    });
+
+    worker::run_worker(config, vec![template], backend, None, tx, control.clone());
+
+    let events: Vec<WorkerEvent> = rx.try_iter().collect();
+    assert!(
+        !events
+            .iter()
+            .any(|event| matches!(event, WorkerEvent::WouldClick { .. })),
+        "the updated threshold rejects the match"
+    );
+    assert!(
+        control.live.lock().unwrap().is_none(),
+        "the update is taken"
+    );
+}
+
+#[test]
+fn live_settings_round_trip_through_the_config() {
+    let config = AppConfig {
+        threshold: 0.8,
+        interval_ms: 250,
+        cooldown_ms: 4_000,
+        dry_run: true,
+        skip_unchanged: true,
+        ..AppConfig::default()
+    };
+    let mut other = AppConfig::default();
+    LiveSettings::of(&config).apply_to(&mut other);
+    assert_eq!(other, config);
+}
+
+#[test]
+fn relay_forwards_events_and_wakes_for_each() {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    let wakes = Arc::new(Mutex::new(Vec::new()));
+    let recorded = wakes.clone();
+    let relayed = worker::relay(rx, move |routine| recorded.lock().unwrap().push(routine))
+        .expect("relay starts");
+
+    tx.send(WorkerEvent::FrameScored(0.5)).unwrap();
+    tx.send(WorkerEvent::Info("Monitoring active".to_string()))
+        .unwrap();
+    tx.send(WorkerEvent::Stopped).unwrap();
+    drop(tx);
+
+    let events: Vec<WorkerEvent> = relayed.iter().collect();
+    assert_eq!(events.len(), 3);
+    assert!(matches!(events[0], WorkerEvent::FrameScored(_)));
+    assert!(matches!(events[2], WorkerEvent::Stopped));
+    // The relay wakes after queuing, so the last wake may still be on its way.
+    for _ in 0..100 {
+        if wakes.lock().unwrap().len() == 3 {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+    assert_eq!(*wakes.lock().unwrap(), vec![true, false, false]);
+}